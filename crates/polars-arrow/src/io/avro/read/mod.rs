@@ -8,7 +8,7 @@ use avro_schema::schema::Field as AvroField;
 
 mod deserialize;
 pub use deserialize::deserialize;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_bail, polars_ensure};
 
 mod nested;
 mod schema;
@@ -66,3 +66,30 @@ impl<R: Read> Iterator for Reader<R> {
             .map(|maybe_block| deserialize(maybe_block?, fields, avro_fields, projection))
     }
 }
+
+/// Skips over the block at the start of `data`, returning its number of rows, or `None` if there
+/// are no more blocks.
+///
+/// This only reads the block header and checks the sync marker that follows the (possibly
+/// compressed) data of the block, which allows locating blocks without decoding them.
+pub fn skip_block(data: &mut &[u8], marker: &[u8; 16]) -> PolarsResult<Option<usize>> {
+    if data.is_empty() {
+        return Ok(None);
+    }
+
+    let num_rows = util::zigzag_i64(data)?;
+    let num_bytes = util::zigzag_i64(data)?;
+    let (Ok(num_rows), Ok(num_bytes)) = (usize::try_from(num_rows), usize::try_from(num_bytes))
+    else {
+        polars_bail!(oos = "Avro block has a negative size");
+    };
+
+    polars_ensure!(
+        data.len() >= num_bytes + marker.len()
+            && &data[num_bytes..num_bytes + marker.len()] == marker,
+        oos = "Avro block is truncated or has an invalid sync marker"
+    );
+    *data = &data[num_bytes + marker.len()..];
+
+    Ok(Some(num_rows))
+}
@@ -32,6 +32,7 @@ pub mod propagate_nulls;
 pub mod rolling;
 pub mod size;
 pub mod sum;
+pub mod tdigest;
 pub mod trim_lists_to_normalized_offsets;
 pub mod unique;
 
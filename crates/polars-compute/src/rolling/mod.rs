@@ -35,6 +35,13 @@ pub enum QuantileMethod {
     Midpoint,
     Linear,
     Equiprobable,
+    /// Approximate the quantile with a t-digest sketch, which needs bounded
+    /// memory regardless of the number of values.
+    ///
+    /// Rolling windows are bounded, so they compute this exactly with linear
+    /// interpolation.
+    #[strum(serialize = "tdigest")]
+    TDigest,
 }
 
 #[deprecated(note = "use QuantileMethod instead")]
@@ -52,7 +52,7 @@ impl<
         let length = self.sorted.len();
 
         let idx = match self.method {
-            Linear => {
+            Linear | TDigest => {
                 // Maybe add a fast path for median case? They could branch depending on odd/even.
                 let length_f = length as f64;
                 let idx = ((length_f - 1.0) * self.prob).floor() as usize;
@@ -218,7 +218,7 @@ where
         },
         (_, Midpoint) => (vk + v_old) * NumCast::from(0.5).unwrap(),
         // This is seemingly the canonical way to do it.
-        (_, Linear) => {
+        (_, Linear | TDigest) => {
             v_old + <T as NumCast>::from((h - s_old) / (s - s_old)).unwrap() * (vk - v_old)
         },
     }
@@ -57,9 +57,10 @@ impl<
         length -= null_count;
         let mut idx = match self.method {
             QuantileMethod::Nearest => ((length as f64) * self.prob) as usize,
-            QuantileMethod::Lower | QuantileMethod::Midpoint | QuantileMethod::Linear => {
-                ((length as f64 - 1.0) * self.prob).floor() as usize
-            },
+            QuantileMethod::Lower
+            | QuantileMethod::Midpoint
+            | QuantileMethod::Linear
+            | QuantileMethod::TDigest => ((length as f64 - 1.0) * self.prob).floor() as usize,
             QuantileMethod::Higher => ((length as f64 - 1.0) * self.prob).ceil() as usize,
             QuantileMethod::Equiprobable => {
                 ((length as f64 * self.prob).ceil() - 1.0).max(0.0) as usize
@@ -78,7 +79,7 @@ impl<
                         / T::from::<f64>(2.0f64).unwrap(),
                 )
             },
-            QuantileMethod::Linear => {
+            QuantileMethod::Linear | QuantileMethod::TDigest => {
                 let float_idx = (length as f64 - 1.0) * self.prob;
                 let top_idx = f64::ceil(float_idx) as usize;
 
@@ -618,7 +618,7 @@ where
 
         use QuantileMethod::*;
         match self.method {
-            Linear => {
+            Linear | TDigest => {
                 let float_idx_top = (valid_length_f - 1.0) * self.quantile;
                 let idx = float_idx_top.floor() as usize;
                 let top_idx = float_idx_top.ceil() as usize;
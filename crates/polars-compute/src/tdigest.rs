@@ -0,0 +1,234 @@
+//! # T-Digest
+//!
+//! A mergeable sketch for approximate quantiles, as described in
+//! "Computing Extremely Accurate Quantiles Using t-Digests" by Dunning & Ertl.
+//!
+//! This is the merging variant of the algorithm: incoming values are buffered
+//! and periodically merged into a sorted list of centroids. The size of each
+//! centroid is bounded by the `k1` scale function, which keeps the centroids
+//! near the tails small, so the extreme quantiles stay accurate.
+//!
+//! # Examples
+//!
+//! ```
+//!     # use polars_compute::tdigest::*;
+//!     let mut digest = TDigest::default();
+//!     for x in 0..=100 {
+//!         digest.insert(x as f64);
+//!     }
+//!
+//!     assert_eq!(digest.quantile(0.5), Some(50.0));
+//! ```
+
+use std::f64::consts::PI;
+
+/// The default compression, bounding the number of centroids to roughly this
+/// number.
+pub const DEFAULT_COMPRESSION: f64 = 200.0;
+
+#[derive(Clone, Copy, Debug)]
+struct Centroid {
+    mean: f64,
+    weight: f64,
+}
+
+#[derive(Clone, Debug)]
+pub struct TDigest {
+    compression: f64,
+    centroids: Vec<Centroid>,
+    unmerged: Vec<Centroid>,
+    total_weight: f64,
+    min: f64,
+    max: f64,
+}
+
+impl Default for TDigest {
+    fn default() -> Self {
+        Self::new(DEFAULT_COMPRESSION)
+    }
+}
+
+impl TDigest {
+    pub fn new(compression: f64) -> Self {
+        Self {
+            compression,
+            centroids: Vec::new(),
+            unmerged: Vec::new(),
+            total_weight: 0.0,
+            min: f64::INFINITY,
+            max: f64::NEG_INFINITY,
+        }
+    }
+
+    /// The number of values inserted into this digest.
+    pub fn count(&self) -> f64 {
+        self.total_weight
+    }
+
+    /// Inserts a value, NaNs are ignored.
+    pub fn insert(&mut self, x: f64) {
+        if x.is_nan() {
+            return;
+        }
+        self.push_unmerged(Centroid {
+            mean: x,
+            weight: 1.0,
+        });
+    }
+
+    /// Merges another digest into this one.
+    pub fn merge(&mut self, other: &TDigest) {
+        for c in other.centroids.iter().chain(&other.unmerged) {
+            self.push_unmerged(*c);
+        }
+    }
+
+    fn push_unmerged(&mut self, c: Centroid) {
+        self.min = self.min.min(c.mean);
+        self.max = self.max.max(c.mean);
+        self.total_weight += c.weight;
+        self.unmerged.push(c);
+        if self.unmerged.len() >= 5 * self.compression as usize {
+            self.compress();
+        }
+    }
+
+    fn k(&self, q: f64) -> f64 {
+        self.compression / (2.0 * PI) * (2.0 * q - 1.0).asin()
+    }
+
+    fn q(&self, k: f64) -> f64 {
+        let k = k.min(self.compression / 4.0);
+        ((2.0 * PI * k / self.compression).sin() + 1.0) / 2.0
+    }
+
+    /// Merges all buffered values into the centroids.
+    fn compress(&mut self) {
+        if self.unmerged.is_empty() {
+            return;
+        }
+
+        let mut all = std::mem::take(&mut self.unmerged);
+        all.append(&mut self.centroids);
+        all.sort_unstable_by(|a, b| a.mean.total_cmp(&b.mean));
+
+        let mut merged = Vec::with_capacity(self.compression as usize);
+        let mut iter = all.into_iter();
+        let mut cur = iter.next().unwrap();
+        let mut weight_so_far = 0.0;
+        let mut q_limit = self.q(self.k(0.0) + 1.0);
+        for c in iter {
+            let q = (weight_so_far + cur.weight + c.weight) / self.total_weight;
+            if q <= q_limit {
+                let weight = cur.weight + c.weight;
+                cur.mean += (c.mean - cur.mean) * c.weight / weight;
+                cur.weight = weight;
+            } else {
+                weight_so_far += cur.weight;
+                merged.push(cur);
+                q_limit = self.q(self.k(weight_so_far / self.total_weight) + 1.0);
+                cur = c;
+            }
+        }
+        merged.push(cur);
+        self.centroids = merged;
+    }
+
+    /// Returns the approximate quantile, or `None` if the digest is empty.
+    ///
+    /// Values between the centroids are linearly interpolated, and the exact
+    /// minimum and maximum are used at the tails.
+    pub fn quantile(&mut self, quantile: f64) -> Option<f64> {
+        self.compress();
+        let centroids = &self.centroids;
+        let (first, last) = (centroids.first()?, centroids.last()?);
+        if centroids.len() == 1 {
+            return Some(first.mean);
+        }
+
+        let target = quantile.clamp(0.0, 1.0) * (self.total_weight - 1.0);
+        // The centroid with weight w covering ranks [r, r + w) is centered at
+        // rank r + (w - 1) / 2.
+        let first_center = (first.weight - 1.0) / 2.0;
+        if target <= first_center {
+            return Some(interpolate(self.min, first.mean, target / first_center));
+        }
+
+        let mut rank = 0.0;
+        for w in centroids.windows(2) {
+            let left_center = rank + (w[0].weight - 1.0) / 2.0;
+            let right_center = rank + w[0].weight + (w[1].weight - 1.0) / 2.0;
+            if target <= right_center {
+                let t = (target - left_center) / (right_center - left_center);
+                return Some(interpolate(w[0].mean, w[1].mean, t));
+            }
+            rank += w[0].weight;
+        }
+
+        let last_center = rank + (last.weight - 1.0) / 2.0;
+        let t = (target - last_center) / (self.total_weight - 1.0 - last_center);
+        Some(interpolate(last.mean, self.max, t))
+    }
+}
+
+fn interpolate(lower: f64, upper: f64, t: f64) -> f64 {
+    if t.is_nan() || lower == upper {
+        lower
+    } else {
+        lower + (upper - lower) * t.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TDigest;
+
+    fn exact_quantile(sorted: &[f64], q: f64) -> f64 {
+        let idx = q * (sorted.len() - 1) as f64;
+        let (lo, hi) = (idx.floor() as usize, idx.ceil() as usize);
+        sorted[lo] + (sorted[hi] - sorted[lo]) * (idx - lo as f64)
+    }
+
+    #[test]
+    fn test_small_is_exact() {
+        let mut digest = TDigest::default();
+        for x in [5.0, 1.0, 4.0, 2.0, 3.0] {
+            digest.insert(x);
+        }
+        assert_eq!(digest.quantile(0.0), Some(1.0));
+        assert_eq!(digest.quantile(0.5), Some(3.0));
+        assert_eq!(digest.quantile(1.0), Some(5.0));
+        assert_eq!(TDigest::default().quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_merged_accuracy() {
+        let n = 100_000;
+        let values: Vec<f64> = (0..n).map(|i| ((i * 7919) % n) as f64).collect();
+        let mut sorted = values.clone();
+        sorted.sort_by(f64::total_cmp);
+
+        let mut digests: Vec<TDigest> = values
+            .chunks(1000)
+            .map(|chunk| {
+                let mut digest = TDigest::default();
+                chunk.iter().for_each(|x| digest.insert(*x));
+                digest
+            })
+            .collect();
+        let mut digest = digests.pop().unwrap();
+        for other in &digests {
+            digest.merge(other);
+        }
+        assert_eq!(digest.count(), n as f64);
+
+        for q in [0.001, 0.01, 0.1, 0.25, 0.5, 0.75, 0.9, 0.99, 0.999] {
+            let expected = exact_quantile(&sorted, q);
+            let got = digest.quantile(q).unwrap();
+            assert!(
+                (got - expected).abs() <= 0.005 * n as f64,
+                "q: {q}, got: {got}, expected: {expected}"
+            );
+        }
+    }
+}
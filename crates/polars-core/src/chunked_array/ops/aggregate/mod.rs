@@ -756,6 +756,7 @@ mod test {
             QuantileMethod::Midpoint,
             QuantileMethod::Linear,
             QuantileMethod::Equiprobable,
+            QuantileMethod::TDigest,
         ];
 
         for method in methods {
@@ -780,6 +781,7 @@ mod test {
             QuantileMethod::Midpoint,
             QuantileMethod::Linear,
             QuantileMethod::Equiprobable,
+            QuantileMethod::TDigest,
         ];
 
         for method in methods {
@@ -816,6 +818,7 @@ mod test {
             QuantileMethod::Midpoint,
             QuantileMethod::Linear,
             QuantileMethod::Equiprobable,
+            QuantileMethod::TDigest,
         ];
 
         for method in methods {
@@ -973,4 +976,29 @@ mod test {
             Some(5.0)
         );
     }
+
+    #[test]
+    fn test_quantile_tdigest() {
+        // small inputs are kept exactly and interpolated linearly
+        let ca = UInt32Chunked::new(
+            PlSmallStr::from_static("a"),
+            &[Some(2), Some(1), None, Some(3), Some(5), None, Some(4)],
+        );
+        for q in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert_eq!(
+                ca.quantile(q, QuantileMethod::TDigest).unwrap(),
+                ca.quantile(q, QuantileMethod::Linear).unwrap()
+            );
+        }
+
+        let ca = Float64Chunked::from_iter_values(
+            PlSmallStr::from_static("a"),
+            (0..100_000).map(|i| ((i * 7919) % 100_000) as f64),
+        );
+        for q in [0.01, 0.25, 0.5, 0.99] {
+            let approx = ca.quantile(q, QuantileMethod::TDigest).unwrap().unwrap();
+            let exact = ca.quantile(q, QuantileMethod::Linear).unwrap().unwrap();
+            assert!((approx - exact).abs() < 100.0, "{q}: {approx} vs {exact}");
+        }
+    }
 }
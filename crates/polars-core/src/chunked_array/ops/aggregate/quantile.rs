@@ -1,4 +1,5 @@
 use polars_compute::rolling::QuantileMethod;
+use polars_compute::tdigest::TDigest;
 
 use super::*;
 
@@ -23,9 +24,11 @@ fn quantile_idx(
             let idx = float_idx.round() as usize;
             return (idx, 0.0, idx);
         },
-        QuantileMethod::Lower | QuantileMethod::Midpoint | QuantileMethod::Linear => {
-            float_idx as usize
-        },
+        // The t-digest doesn't use the index, it is returned before.
+        QuantileMethod::Lower
+        | QuantileMethod::Midpoint
+        | QuantileMethod::Linear
+        | QuantileMethod::TDigest => float_idx as usize,
         QuantileMethod::Higher => float_idx.ceil() as usize,
         QuantileMethod::Equiprobable => {
             let idx = ((nonnull_count * quantile).ceil() - 1.0).max(0.0) as usize + null_count;
@@ -56,6 +59,15 @@ fn midpoint_interpol<T: Float>(lower: T, upper: T) -> T {
     }
 }
 
+/// Approximates the quantile of the values with a t-digest.
+fn tdigest_quantile<T: ToPrimitive>(vals: impl Iterator<Item = T>, quantile: f64) -> Option<f64> {
+    let mut digest = TDigest::default();
+    for v in vals {
+        digest.insert(v.to_f64().unwrap());
+    }
+    digest.quantile(quantile)
+}
+
 // Uses quickselect instead of sorting all data
 fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     vals: &mut [T],
@@ -65,6 +77,9 @@ fn quantile_slice<T: ToPrimitive + TotalOrd + Copy>(
     polars_ensure!((0.0..=1.0).contains(&quantile),
         ComputeError: "quantile should be between 0.0 and 1.0",
     );
+    if method == QuantileMethod::TDigest {
+        return Ok(tdigest_quantile(vals.iter().copied(), quantile));
+    }
     if vals.is_empty() {
         return Ok(None);
     }
@@ -119,6 +134,9 @@ where
     if null_count == length {
         return Ok(None);
     }
+    if method == QuantileMethod::TDigest {
+        return Ok(tdigest_quantile(ca.iter().flatten(), quantile));
+    }
 
     let (idx, float_idx, top_idx) = quantile_idx(quantile, length, null_count, method);
     let sorted = ca.sort(false);
@@ -296,7 +296,8 @@ where
             })
         },
         GroupsType::Slice { groups, .. } => {
-            if _use_rolling_kernels(groups, ca.chunks()) {
+            // the rolling kernels only compute exact quantiles
+            if method != QuantileMethod::TDigest && _use_rolling_kernels(groups, ca.chunks()) {
                 // this cast is a no-op for floats
                 let s = ca
                     .cast_with_options(&K::get_static_dtype(), CastOptions::Overflowing)
@@ -103,33 +103,44 @@ fn sort_by_groups_single_by(
     Ok((*first, new_idx))
 }
 
-fn sort_by_groups_no_match_single<'a>(
+fn sort_by_groups_no_match<'a>(
     mut ac_in: AggregationContext<'a>,
-    mut ac_by: AggregationContext<'a>,
-    descending: bool,
+    ac_by: Vec<AggregationContext<'a>>,
+    sort_options: &SortMultipleOptions,
     expr: &Expr,
 ) -> PolarsResult<AggregationContext<'a>> {
     let s_in = ac_in.aggregated();
-    let s_by = ac_by.aggregated();
     let mut s_in = s_in.list().unwrap().clone();
-    let mut s_by = s_by.list().unwrap().clone();
+    let s_by = ac_by
+        .into_iter()
+        .map(|mut ac| ac.aggregated().list().unwrap().clone())
+        .collect::<Vec<_>>();
+    let options = SortMultipleOptions {
+        // We are already in par iter.
+        multithreaded: false,
+        ..sort_options.clone()
+    };
 
     let dtype = s_in.dtype().clone();
     let ca: PolarsResult<ListChunked> = POOL.install(|| {
         s_in.par_iter_indexed()
-            .zip(s_by.par_iter_indexed())
-            .map(|(opt_s, s_sort_by)| match (opt_s, s_sort_by) {
-                (Some(s), Some(s_sort_by)) => {
+            .enumerate()
+            .map(|(i, opt_s)| {
+                let (Some(s), Some(s_sort_by)) = (
+                    opt_s,
+                    s_by.iter()
+                        .map(|ca| ca.get_as_series(i).map(Column::from))
+                        .collect::<Option<Vec<_>>>(),
+                ) else {
+                    return Ok(None);
+                };
+                for s_sort_by in &s_sort_by {
                     polars_ensure!(s.len() == s_sort_by.len(), ComputeError: "series lengths don't match in 'sort_by' expression");
-                    let idx = s_sort_by.arg_sort(SortOptions {
-                        descending,
-                        // We are already in par iter.
-                        multithreaded: false,
-                        ..Default::default()
-                    });
-                    Ok(Some(unsafe { s.take_unchecked(&idx) }))
-                },
-                _ => Ok(None),
+                }
+                let idx = s_sort_by[0]
+                    .as_materialized_series()
+                    .arg_sort_multiple(&s_sort_by[1..], &options)?;
+                Ok(Some(unsafe { s.take_unchecked(&idx) }))
             })
             .collect_ca_with_dtype(PlSmallStr::EMPTY, dtype)
     });
@@ -334,20 +345,19 @@ impl PhysicalExpr for SortByExpr {
             UpdateGroups::WithSeriesLen | UpdateGroups::WithGroupsLen
         );
 
-        let groups = if self.by.len() == 1 {
-            let mut ac_sort_by = ac_sort_by.pop().unwrap();
-
-            // The groups of the lhs of the expressions do not match the series values,
-            // we must take the slower path.
-            if !matches!(ac_in.update_groups, UpdateGroups::No) {
-                return sort_by_groups_no_match_single(
-                    ac_in,
-                    ac_sort_by,
-                    self.sort_options.descending[0],
-                    &self.expr,
-                );
+        // The groups of the lhs of the expressions do not match the series values,
+        // we must take the slower path.
+        if !matches!(ac_in.update_groups, UpdateGroups::No) {
+            let sort_options = SortMultipleOptions {
+                descending,
+                nulls_last,
+                ..self.sort_options.clone()
             };
+            return sort_by_groups_no_match(ac_in, ac_sort_by, &sort_options, &self.expr);
+        };
 
+        let groups = if self.by.len() == 1 {
+            let mut ac_sort_by = ac_sort_by.pop().unwrap();
             let sort_by_s = sort_by_s.pop().unwrap();
             let groups = ac_sort_by.groups();
 
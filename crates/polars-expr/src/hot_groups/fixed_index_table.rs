@@ -112,9 +112,11 @@ impl<K> FixedIndexTable<K> {
                 }
             }
 
-            // Check if we can insert into an empty slot.
+            // Check if we can insert into an empty slot. An unmapped key takes
+            // up a key index without a slot, so we can't just compare the
+            // number of keys to the number of slots.
             let num_keys = self.keys.len() as IdxSize;
-            if (num_keys as usize) < self.slots.len() {
+            if (num_keys as usize) <= self.slots.len() {
                 // Check the first slot.
                 let s1 = self.slots.get_unchecked_mut(h1);
                 if s1.key_index >= num_keys {
@@ -156,3 +158,34 @@ impl<K> FixedIndexTable<K> {
         &self.keys
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_fills_all_slots_with_unmapped_key() {
+        let mut table = FixedIndexTable::<u64>::new(4);
+        assert_eq!(table.push_unmapped_key(u64::MAX), 0);
+
+        // Each hash has a distinct first slot, so all four keys must be
+        // inserted without eviction even though the unmapped key already
+        // takes up a key index.
+        let hashes: Vec<u64> = (0..4).map(|i| (i << 62) | i).collect();
+        for (i, &h) in hashes.iter().enumerate() {
+            let idx = table
+                .insert_key(h, h, |a, b| a == b, |k| k, |_, _| unreachable!())
+                .unwrap();
+            assert_eq!(idx.idx(), i + 1);
+            assert!(!idx.should_evict());
+        }
+        assert_eq!(table.len(), 5);
+
+        for (i, &h) in hashes.iter().enumerate() {
+            let idx = table
+                .insert_key(h, h, |a, b| a == b, |k| k, |_, _| unreachable!())
+                .unwrap();
+            assert_eq!(idx.idx(), i + 1);
+        }
+    }
+}
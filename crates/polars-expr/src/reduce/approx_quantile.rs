@@ -0,0 +1,62 @@
+use polars_compute::tdigest::TDigest;
+
+use super::*;
+
+pub fn new_approx_quantile_reduction(dtype: DataType, quantile: f64) -> Box<dyn GroupedReduction> {
+    assert!(dtype.is_primitive_numeric());
+    Box::new(VecGroupedReduction::new(dtype, TDigestReducer { quantile }))
+}
+
+/// Approximates the quantile with a t-digest per group, which has a bounded
+/// size regardless of the number of values in the group.
+#[derive(Clone)]
+struct TDigestReducer {
+    quantile: f64,
+}
+
+impl Reducer for TDigestReducer {
+    type Dtype = Float64Type;
+    type Value = TDigest;
+
+    fn init(&self) -> Self::Value {
+        TDigest::default()
+    }
+
+    fn cast_series<'a>(&self, s: &'a Series) -> Cow<'a, Series> {
+        Cow::Owned(s.cast(&DataType::Float64).unwrap())
+    }
+
+    fn combine(&self, a: &mut Self::Value, b: &Self::Value) {
+        a.merge(b);
+    }
+
+    fn reduce_one(&self, a: &mut Self::Value, b: Option<f64>, _seq_id: u64) {
+        if let Some(b) = b {
+            a.insert(b);
+        }
+    }
+
+    fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, _seq_id: u64) {
+        for x in ca.iter().flatten() {
+            v.insert(x);
+        }
+    }
+
+    fn finish(
+        &self,
+        v: Vec<Self::Value>,
+        m: Option<Bitmap>,
+        dtype: &DataType,
+    ) -> PolarsResult<Series> {
+        assert!(m.is_none());
+        let ca: Float64Chunked = v
+            .into_iter()
+            .map(|mut digest| digest.quantile(self.quantile))
+            .collect_ca(PlSmallStr::EMPTY);
+        let out = ca.into_series();
+        match dtype {
+            DataType::Float32 => out.cast(&DataType::Float32),
+            _ => Ok(out),
+        }
+    }
+}
@@ -0,0 +1,256 @@
+#![allow(unsafe_op_in_unsafe_fn)]
+use polars_compute::rolling::QuantileMethod;
+
+use super::*;
+
+pub fn new_median_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
+    Box::new(BufferGroupedReduction::new(dtype, BufferedAgg::Median))
+}
+
+pub fn new_quantile_reduction(
+    dtype: DataType,
+    quantile: f64,
+    method: QuantileMethod,
+) -> Box<dyn GroupedReduction> {
+    Box::new(BufferGroupedReduction::new(
+        dtype,
+        BufferedAgg::Quantile(quantile, method),
+    ))
+}
+
+pub fn new_implode_reduction(dtype: DataType) -> Box<dyn GroupedReduction> {
+    Box::new(BufferGroupedReduction::new(dtype, BufferedAgg::Implode))
+}
+
+#[derive(Clone, Copy)]
+enum BufferedAgg {
+    Median,
+    Quantile(f64, QuantileMethod),
+    Implode,
+}
+
+impl BufferedAgg {
+    fn is_order_sensitive(&self) -> bool {
+        matches!(self, Self::Implode)
+    }
+}
+
+/// A reduction which buffers all values of each group, and computes the
+/// aggregate over the buffered values with the in-memory kernels when
+/// finalized.
+pub struct BufferGroupedReduction {
+    in_dtype: DataType,
+    agg: BufferedAgg,
+    /// All buffered values, shared with the evicted groups.
+    values: Series,
+    /// The row sequence number of every buffered value, only if the aggregate
+    /// is order sensitive.
+    seqs: UInt64Chunked,
+    /// Indices into values for each group.
+    groups: Vec<Vec<IdxSize>>,
+    evicted_groups: Vec<Vec<IdxSize>>,
+}
+
+impl BufferGroupedReduction {
+    fn new(in_dtype: DataType, agg: BufferedAgg) -> Self {
+        Self {
+            values: Series::new_empty(PlSmallStr::EMPTY, &in_dtype),
+            seqs: UInt64Chunked::with_chunk(PlSmallStr::EMPTY, Default::default()),
+            in_dtype,
+            agg,
+            groups: Vec::new(),
+            evicted_groups: Vec::new(),
+        }
+    }
+
+    /// Appends the values to the buffer, values[i] being the i-th of the rows
+    /// of the morsel with seq_id, returning the offset of the first appended
+    /// value.
+    fn append(
+        &mut self,
+        values: &Series,
+        seq_id: u64,
+        rows: impl Iterator<Item = IdxSize>,
+    ) -> PolarsResult<IdxSize> {
+        if self.agg.is_order_sensitive() {
+            check_row_seq_fits(seq_id, values.len())?;
+        }
+        let offset = self.values.len() as IdxSize;
+        self.values.append(values)?;
+        if self.agg.is_order_sensitive() {
+            let seqs: UInt64Chunked = rows
+                .map(|row| row_seq(seq_id, row))
+                .collect_ca(PlSmallStr::EMPTY);
+            self.seqs.append(&seqs)?;
+        }
+        Ok(offset)
+    }
+
+    /// Updates the groups, values[i] being row morsel_idxs[i] of its morsel if
+    /// given, otherwise row i.
+    unsafe fn update_groups_impl(
+        &mut self,
+        values: &Column,
+        morsel_idxs: Option<&[IdxSize]>,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        let values = values.as_materialized_series(); // @scalar-opt
+        let rows = subset
+            .iter()
+            .map(|i| morsel_idxs.map_or(*i, |m| *m.get_unchecked(*i as usize)));
+        let offset = self.append(&values.take_slice_unchecked(subset), seq_id, rows)?;
+        for (i, g) in group_idxs.iter().enumerate() {
+            let grp = self.groups.get_unchecked_mut(g.idx());
+            if g.should_evict() {
+                self.evicted_groups.push(core::mem::take(grp));
+            }
+            grp.push(offset + i as IdxSize);
+        }
+        Ok(())
+    }
+}
+
+impl GroupedReduction for BufferGroupedReduction {
+    fn new_empty(&self) -> Box<dyn GroupedReduction> {
+        Box::new(Self::new(self.in_dtype.clone(), self.agg))
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.groups.reserve(additional);
+    }
+
+    fn resize(&mut self, num_groups: IdxSize) {
+        self.groups.resize(num_groups as usize, Vec::new());
+    }
+
+    fn update_group(
+        &mut self,
+        values: &Column,
+        group_idx: IdxSize,
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        let values = values.as_materialized_series(); // @scalar-opt
+        let offset = self.append(values, seq_id, 0..values.len() as IdxSize)?;
+        self.groups[group_idx as usize].extend(offset..offset + values.len() as IdxSize);
+        Ok(())
+    }
+
+    unsafe fn update_groups_while_evicting(
+        &mut self,
+        values: &Column,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        self.update_groups_impl(values, None, subset, group_idxs, seq_id)
+    }
+
+    unsafe fn update_groups_subset_gathered(
+        &mut self,
+        values: &Column,
+        morsel_idxs: &[IdxSize],
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.len() < (1 << (IdxSize::BITS - 1)));
+        assert!(morsel_idxs.len() == values.len());
+        let evict_group_idxs = core::mem::transmute::<&[IdxSize], &[EvictIdx]>(group_idxs);
+        self.update_groups_impl(values, Some(morsel_idxs), subset, evict_group_idxs, seq_id)
+    }
+
+    unsafe fn combine_subset(
+        &mut self,
+        other: &dyn GroupedReduction,
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+    ) -> PolarsResult<()> {
+        let other = other.as_any().downcast_ref::<Self>().unwrap();
+        assert!(self.in_dtype == other.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+
+        // Only copy the values of the groups we combine, other's buffer can
+        // be shared with many more groups.
+        let other_idxs: Vec<IdxSize> = subset
+            .iter()
+            .flat_map(|i| other.groups.get_unchecked(*i as usize))
+            .copied()
+            .collect();
+        let offset = self.values.len() as IdxSize;
+        self.values
+            .append(&other.values.take_slice_unchecked(&other_idxs))?;
+        if self.agg.is_order_sensitive() {
+            self.seqs.append(&other.seqs.take_unchecked(&other_idxs))?;
+        }
+
+        let mut idx = offset;
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let len = other.groups.get_unchecked(*i as usize).len() as IdxSize;
+            self.groups
+                .get_unchecked_mut(*g as usize)
+                .extend(idx..idx + len);
+            idx += len;
+        }
+        Ok(())
+    }
+
+    fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
+        Box::new(Self {
+            in_dtype: self.in_dtype.clone(),
+            agg: self.agg,
+            values: self.values.clone(),
+            seqs: self.seqs.clone(),
+            groups: core::mem::take(&mut self.evicted_groups),
+            evicted_groups: Vec::new(),
+        })
+    }
+
+    fn is_order_sensitive(&self) -> bool {
+        self.agg.is_order_sensitive()
+    }
+
+    fn finalize(&mut self) -> PolarsResult<Series> {
+        let mut groups = core::mem::take(&mut self.groups);
+        let values = core::mem::replace(
+            &mut self.values,
+            Series::new_empty(PlSmallStr::EMPTY, &self.in_dtype),
+        )
+        .rechunk();
+        let seqs = core::mem::take(&mut self.seqs).rechunk().into_owned();
+
+        if self.agg.is_order_sensitive() {
+            // Values from different morsels can be combined in any order.
+            let seqs = seqs.cont_slice().unwrap();
+            for group in &mut groups {
+                group.sort_unstable_by_key(|i| (seqs[*i as usize], *i));
+            }
+        }
+        let groups = GroupsType::Idx(
+            groups
+                .into_iter()
+                .map(|g| (g.first().copied().unwrap_or(0), g.into()))
+                .collect(),
+        );
+
+        let values = values.into_column();
+        let out = unsafe {
+            match self.agg {
+                BufferedAgg::Median => values.agg_median(&groups),
+                BufferedAgg::Quantile(quantile, method) => {
+                    values.agg_quantile(&groups, quantile, method)
+                },
+                BufferedAgg::Implode => values.agg_list(&groups),
+            }
+        };
+        Ok(out.take_materialized_series())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
@@ -3,6 +3,8 @@ use polars_plan::prelude::*;
 use polars_utils::arena::{Arena, Node};
 
 use super::*;
+use crate::reduce::approx_quantile::new_approx_quantile_reduction;
+use crate::reduce::buffer::{new_implode_reduction, new_median_reduction, new_quantile_reduction};
 use crate::reduce::count::CountReduce;
 use crate::reduce::first_last::{new_first_reduction, new_last_reduction};
 use crate::reduce::len::LenReduce;
@@ -47,11 +49,31 @@ pub fn into_reduction(
                 let count = Box::new(CountReduce::new(*include_nulls)) as Box<_>;
                 (count, *input)
             },
-            IRAggExpr::Quantile { .. } => todo!(),
-            IRAggExpr::Median(_) => todo!(),
+            IRAggExpr::Quantile {
+                expr,
+                quantile,
+                method,
+            } => {
+                let quantile = get_literal_quantile(*quantile, expr_arena)?;
+                let dtype = get_dt(*expr)?;
+                // The t-digest keeps a bounded sketch per group instead of
+                // buffering all values, other dtypes are buffered and digested
+                // when the group finishes.
+                let reduction =
+                    if *method == QuantileMethod::TDigest && dtype.is_primitive_numeric() {
+                        new_approx_quantile_reduction(dtype, quantile)
+                    } else {
+                        new_quantile_reduction(dtype, quantile, *method)
+                    };
+                (reduction, *expr)
+            },
+            IRAggExpr::Median(input) => (new_median_reduction(get_dt(*input)?), *input),
             IRAggExpr::NUnique(_) => todo!(),
-            IRAggExpr::Implode(_) => todo!(),
-            IRAggExpr::AggGroups(_) => todo!(),
+            IRAggExpr::Implode(input) => (new_implode_reduction(get_dt(*input)?), *input),
+            IRAggExpr::AggGroups(_) => {
+                // Group-by lowering rewrites this into an implode of a row index.
+                polars_bail!(InvalidOperation: "`agg_groups` is not supported as a streaming reduction")
+            },
         },
         AExpr::Len => {
             if let Some(first_column) = schema.iter_names().next() {
@@ -76,3 +98,19 @@ pub fn into_reduction(
     };
     Ok(out)
 }
+
+/// Extracts the quantile of a quantile aggregation, which must be a literal.
+fn get_literal_quantile(node: Node, expr_arena: &Arena<AExpr>) -> PolarsResult<f64> {
+    let AExpr::Literal(lv) = expr_arena.get(node) else {
+        polars_bail!(ComputeError: "streaming quantile requires a literal quantile")
+    };
+    let quantile = lv.to_any_value().and_then(|av| av.extract::<f64>());
+    let Some(quantile) = quantile else {
+        polars_bail!(ComputeError: "quantile must be a number, got {:?}", lv)
+    };
+    polars_ensure!(
+        (0.0..=1.0).contains(&quantile),
+        ComputeError: "`quantile` should be between 0.0 and 1.0",
+    );
+    Ok(quantile)
+}
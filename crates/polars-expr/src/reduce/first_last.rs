@@ -38,6 +38,9 @@ fn new_reduction_with_policy<P: Policy + 'static>(dtype: DataType) -> Box<dyn Gr
 trait Policy: Send + Sync + 'static {
     fn index(len: usize) -> usize;
     fn should_replace(new: u64, old: u64) -> bool;
+    fn is_order_sensitive() -> bool {
+        true
+    }
 }
 
 struct First;
@@ -74,6 +77,10 @@ impl Policy for Arbitrary {
     fn should_replace(_new: u64, old: u64) -> bool {
         old == 0
     }
+
+    fn is_order_sensitive() -> bool {
+        false
+    }
 }
 
 struct NumFirstLastReducer<P, T>(PhantomData<(P, T)>);
@@ -120,6 +127,10 @@ where
         }
     }
 
+    fn is_order_sensitive(&self) -> bool {
+        P::is_order_sensitive()
+    }
+
     fn finish(
         &self,
         v: Vec<Self::Value>,
@@ -186,6 +197,10 @@ where
         }
     }
 
+    fn is_order_sensitive(&self) -> bool {
+        P::is_order_sensitive()
+    }
+
     fn finish(
         &self,
         v: Vec<Self::Value>,
@@ -237,6 +252,10 @@ where
         }
     }
 
+    fn is_order_sensitive(&self) -> bool {
+        P::is_order_sensitive()
+    }
+
     fn finish(
         &self,
         v: Vec<Self::Value>,
@@ -271,6 +290,40 @@ impl<P> GenericFirstLastGroupedReduction<P> {
     }
 }
 
+impl<P> GenericFirstLastGroupedReduction<P> {
+    /// Updates the groups, values[i] being row morsel_idxs[i] of its morsel if
+    /// given, otherwise row i.
+    unsafe fn update_groups_impl(
+        &mut self,
+        values: &Column,
+        morsel_idxs: Option<&[IdxSize]>,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()>
+    where
+        P: Policy,
+    {
+        check_row_seq_fits(seq_id, values.len())?;
+        for (i, g) in subset.iter().zip(group_idxs) {
+            let grp_val = self.values.get_unchecked_mut(g.idx());
+            let grp_seq = self.seqs.get_unchecked_mut(g.idx());
+            if g.should_evict() {
+                self.evicted_values
+                    .push(core::mem::replace(grp_val, AnyValue::Null));
+                self.evicted_seqs.push(core::mem::replace(grp_seq, 0));
+            }
+            let row = morsel_idxs.map_or(*i, |m| *m.get_unchecked(*i as usize));
+            let seq_id = row_seq(seq_id, row);
+            if P::should_replace(seq_id, *grp_seq) {
+                *grp_val = values.get_unchecked(*i as usize).into_static();
+                *grp_seq = seq_id;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<P: Policy + 'static> GroupedReduction for GenericFirstLastGroupedReduction<P> {
     fn new_empty(&self) -> Box<dyn GroupedReduction> {
         Box::new(Self::new(self.in_dtype.clone()))
@@ -293,9 +346,11 @@ impl<P: Policy + 'static> GroupedReduction for GenericFirstLastGroupedReduction<
         seq_id: u64,
     ) -> PolarsResult<()> {
         if !values.is_empty() {
-            let seq_id = seq_id + 1; // We use 0 for 'no value'.
+            check_row_seq_fits(seq_id, values.len())?;
+            let idx = P::index(values.len());
+            let seq_id = row_seq(seq_id, idx as IdxSize);
             if P::should_replace(seq_id, self.seqs[group_idx as usize]) {
-                self.values[group_idx as usize] = values.get(P::index(values.len()))?.into_static();
+                self.values[group_idx as usize] = values.get(idx)?.into_static();
                 self.seqs[group_idx as usize] = seq_id;
             }
         }
@@ -309,21 +364,21 @@ impl<P: Policy + 'static> GroupedReduction for GenericFirstLastGroupedReduction<
         group_idxs: &[EvictIdx],
         seq_id: u64,
     ) -> PolarsResult<()> {
-        let seq_id = seq_id + 1; // We use 0 for 'no value'.
-        for (i, g) in subset.iter().zip(group_idxs) {
-            let grp_val = self.values.get_unchecked_mut(g.idx());
-            let grp_seq = self.seqs.get_unchecked_mut(g.idx());
-            if g.should_evict() {
-                self.evicted_values
-                    .push(core::mem::replace(grp_val, AnyValue::Null));
-                self.evicted_seqs.push(core::mem::replace(grp_seq, 0));
-            }
-            if P::should_replace(seq_id, *grp_seq) {
-                *grp_val = values.get_unchecked(*i as usize).into_static();
-                *grp_seq = seq_id;
-            }
-        }
-        Ok(())
+        self.update_groups_impl(values, None, subset, group_idxs, seq_id)
+    }
+
+    unsafe fn update_groups_subset_gathered(
+        &mut self,
+        values: &Column,
+        morsel_idxs: &[IdxSize],
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.len() < (1 << (IdxSize::BITS - 1)));
+        assert!(morsel_idxs.len() == values.len());
+        let evict_group_idxs = core::mem::transmute::<&[IdxSize], &[EvictIdx]>(group_idxs);
+        self.update_groups_impl(values, Some(morsel_idxs), subset, evict_group_idxs, seq_id)
     }
 
     unsafe fn combine_subset(
@@ -347,6 +402,10 @@ impl<P: Policy + 'static> GroupedReduction for GenericFirstLastGroupedReduction<
         Ok(())
     }
 
+    fn is_order_sensitive(&self) -> bool {
+        P::is_order_sensitive()
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             in_dtype: self.in_dtype.clone(),
@@ -1,4 +1,6 @@
 #![allow(unsafe_op_in_unsafe_fn)]
+mod approx_quantile;
+mod buffer;
 mod convert;
 mod count;
 mod first_last;
@@ -49,7 +51,8 @@ pub trait GroupedReduction: Any + Send + Sync {
     /// Updates this GroupedReduction with new values. values[subset[i]] should
     /// be added to reduction self[group_idxs[i]]. For order-sensitive grouped
     /// reductions, seq_id can be used to resolve order between calls/multiple
-    /// reductions.
+    /// reductions, and values within a single call are ordered by their index
+    /// in values.
     ///
     /// # Safety
     /// The subset and group_idxs are in-bounds.
@@ -68,7 +71,8 @@ pub trait GroupedReduction: Any + Send + Sync {
     /// Updates this GroupedReduction with new values. values[subset[i]] should
     /// be added to reduction self[group_idxs[i]]. For order-sensitive grouped
     /// reductions, seq_id can be used to resolve order between calls/multiple
-    /// reductions. If the group_idxs[i] has its evict bit set the current value
+    /// reductions, and values within a single call are ordered by their index
+    /// in values. If the group_idxs[i] has its evict bit set the current value
     /// in the group should be evicted and reset before updating.
     ///
     /// # Safety
@@ -81,6 +85,27 @@ pub trait GroupedReduction: Any + Send + Sync {
         seq_id: u64,
     ) -> PolarsResult<()>;
 
+    /// Like [`GroupedReduction::update_groups_subset`], for values gathered
+    /// from a morsel, values[i] being row morsel_idxs[i] of the morsel with
+    /// seq_id. Order-sensitive grouped reductions order these values by their
+    /// row in the morsel, so they can be combined with other values from the
+    /// same morsel.
+    ///
+    /// # Safety
+    /// The subset and group_idxs are in-bounds, morsel_idxs has the same
+    /// length as values.
+    unsafe fn update_groups_subset_gathered(
+        &mut self,
+        values: &Column,
+        morsel_idxs: &[IdxSize],
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        debug_assert!(morsel_idxs.len() == values.len());
+        self.update_groups_subset(values, subset, group_idxs, seq_id)
+    }
+
     /// Combines this GroupedReduction with another. Group other[subset[i]]
     /// should be combined into group self[group_idxs[i]].
     ///
@@ -97,6 +122,13 @@ pub trait GroupedReduction: Any + Send + Sync {
     /// Take the accumulated evicted groups.
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction>;
 
+    /// Whether this reduction depends on the order of the values within a
+    /// single seq_id. If so, the values are ordered by their row sequence
+    /// number, see [`row_seq`].
+    fn is_order_sensitive(&self) -> bool {
+        false
+    }
+
     /// Returns the finalized value per group as a Series.
     ///
     /// After this operation the number of groups is reset to 0.
@@ -106,6 +138,26 @@ pub trait GroupedReduction: Any + Send + Sync {
     fn as_any(&self) -> &dyn Any;
 }
 
+/// The sequence number of a row, ordering rows by the seq_id of their morsel
+/// and then by their index in that morsel. Zero is never returned, so it can
+/// be used for 'none yet'.
+#[inline(always)]
+pub(crate) fn row_seq(seq_id: u64, row: IdxSize) -> u64 {
+    ((seq_id + 1) << 32) | row as u64
+}
+
+/// Checks that the row sequence numbers of a morsel with the given seq_id and
+/// length don't overflow.
+#[inline(always)]
+pub(crate) fn check_row_seq_fits(seq_id: u64, len: usize) -> PolarsResult<()> {
+    polars_ensure!(
+        seq_id < (1 << 31) && len <= u32::MAX as usize,
+        ComputeError: "too many morsels ({}) or rows in a morsel ({}) for an order-sensitive reduction",
+        seq_id, len
+    );
+    Ok(())
+}
+
 // Helper traits used in the VecGroupedReduction and VecMaskGroupedReduction to
 // reduce code duplication.
 pub trait Reducer: Send + Sync + Clone + 'static {
@@ -124,6 +176,10 @@ pub trait Reducer: Send + Sync + Clone + 'static {
         seq_id: u64,
     );
     fn reduce_ca(&self, v: &mut Self::Value, ca: &ChunkedArray<Self::Dtype>, seq_id: u64);
+    #[inline(always)]
+    fn is_order_sensitive(&self) -> bool {
+        false
+    }
     fn finish(
         &self,
         v: Vec<Self::Value>,
@@ -223,6 +279,65 @@ impl<R: Reducer> VecGroupedReduction<R> {
     }
 }
 
+impl<R: Reducer> VecGroupedReduction<R> {
+    /// Updates the groups, values[i] being row morsel_idxs[i] of its morsel if
+    /// given, otherwise row i.
+    unsafe fn update_groups_impl(
+        &mut self,
+        values: &Column,
+        morsel_idxs: Option<&[IdxSize]>,
+        subset: &[IdxSize],
+        group_idxs: &[EvictIdx],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.dtype() == &self.in_dtype);
+        assert!(subset.len() == group_idxs.len());
+        let order_sensitive = self.reducer.is_order_sensitive();
+        if order_sensitive {
+            check_row_seq_fits(seq_id, values.len())?;
+        }
+        let seq_of = |i: IdxSize| {
+            if order_sensitive {
+                row_seq(
+                    seq_id,
+                    morsel_idxs.map_or(i, |m| *m.get_unchecked(i as usize)),
+                )
+            } else {
+                seq_id + 1 // So we can use 0 for 'none yet'.
+            }
+        };
+        let values = values.as_materialized_series(); // @scalar-opt
+        let values = self.reducer.cast_series(values);
+        let ca: &ChunkedArray<R::Dtype> = values.as_ref().as_ref().as_ref();
+        let arr = ca.downcast_as_array();
+        unsafe {
+            // SAFETY: indices are in-bounds guaranteed by trait.
+            if values.has_nulls() {
+                for (i, g) in subset.iter().zip(group_idxs) {
+                    let ov = arr.get_unchecked(*i as usize);
+                    let grp = self.values.get_unchecked_mut(g.idx());
+                    if g.should_evict() {
+                        let old = core::mem::replace(grp, self.reducer.init());
+                        self.evicted_values.push(old);
+                    }
+                    self.reducer.reduce_one(grp, ov, seq_of(*i));
+                }
+            } else {
+                for (i, g) in subset.iter().zip(group_idxs) {
+                    let v = arr.value_unchecked(*i as usize);
+                    let grp = self.values.get_unchecked_mut(g.idx());
+                    if g.should_evict() {
+                        let old = core::mem::replace(grp, self.reducer.init());
+                        self.evicted_values.push(old);
+                    }
+                    self.reducer.reduce_one(grp, Some(v), seq_of(*i));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl<R> GroupedReduction for VecGroupedReduction<R>
 where
     R: Reducer,
@@ -251,7 +366,12 @@ where
         seq_id: u64,
     ) -> PolarsResult<()> {
         assert!(values.dtype() == &self.in_dtype);
-        let seq_id = seq_id + 1; // So we can use 0 for 'none yet'.
+        let seq_id = if self.reducer.is_order_sensitive() {
+            check_row_seq_fits(seq_id, values.len())?;
+            row_seq(seq_id, 0)
+        } else {
+            seq_id + 1 // So we can use 0 for 'none yet'.
+        };
         let values = values.as_materialized_series(); // @scalar-opt
         let values = self.reducer.cast_series(values);
         let ca: &ChunkedArray<R::Dtype> = values.as_ref().as_ref().as_ref();
@@ -267,38 +387,21 @@ where
         group_idxs: &[EvictIdx],
         seq_id: u64,
     ) -> PolarsResult<()> {
-        assert!(values.dtype() == &self.in_dtype);
-        assert!(subset.len() == group_idxs.len());
-        let seq_id = seq_id + 1; // So we can use 0 for 'none yet'.
-        let values = values.as_materialized_series(); // @scalar-opt
-        let values = self.reducer.cast_series(values);
-        let ca: &ChunkedArray<R::Dtype> = values.as_ref().as_ref().as_ref();
-        let arr = ca.downcast_as_array();
-        unsafe {
-            // SAFETY: indices are in-bounds guaranteed by trait.
-            if values.has_nulls() {
-                for (i, g) in subset.iter().zip(group_idxs) {
-                    let ov = arr.get_unchecked(*i as usize);
-                    let grp = self.values.get_unchecked_mut(g.idx());
-                    if g.should_evict() {
-                        let old = core::mem::replace(grp, self.reducer.init());
-                        self.evicted_values.push(old);
-                    }
-                    self.reducer.reduce_one(grp, ov, seq_id);
-                }
-            } else {
-                for (i, g) in subset.iter().zip(group_idxs) {
-                    let v = arr.value_unchecked(*i as usize);
-                    let grp = self.values.get_unchecked_mut(g.idx());
-                    if g.should_evict() {
-                        let old = core::mem::replace(grp, self.reducer.init());
-                        self.evicted_values.push(old);
-                    }
-                    self.reducer.reduce_one(grp, Some(v), seq_id);
-                }
-            }
-        }
-        Ok(())
+        self.update_groups_impl(values, None, subset, group_idxs, seq_id)
+    }
+
+    unsafe fn update_groups_subset_gathered(
+        &mut self,
+        values: &Column,
+        morsel_idxs: &[IdxSize],
+        subset: &[IdxSize],
+        group_idxs: &[IdxSize],
+        seq_id: u64,
+    ) -> PolarsResult<()> {
+        assert!(values.len() < (1 << (IdxSize::BITS - 1)));
+        assert!(morsel_idxs.len() == values.len());
+        let evict_group_idxs = core::mem::transmute::<&[IdxSize], &[EvictIdx]>(group_idxs);
+        self.update_groups_impl(values, Some(morsel_idxs), subset, evict_group_idxs, seq_id)
     }
 
     unsafe fn combine_subset(
@@ -321,6 +424,10 @@ where
         Ok(())
     }
 
+    fn is_order_sensitive(&self) -> bool {
+        self.reducer.is_order_sensitive()
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values),
@@ -457,6 +564,10 @@ where
         Ok(())
     }
 
+    fn is_order_sensitive(&self) -> bool {
+        self.reducer.is_order_sensitive()
+    }
+
     fn take_evictions(&mut self) -> Box<dyn GroupedReduction> {
         Box::new(Self {
             values: core::mem::take(&mut self.evicted_values),
@@ -557,3 +668,14 @@ impl GroupedReduction for NullGroupedReduction {
         self
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_row_seq_overflow_is_an_error() {
+        assert!(check_row_seq_fits((1 << 31) - 1, u32::MAX as usize).is_ok());
+        assert!(check_row_seq_fits(1 << 31, 1).is_err());
+    }
+}
@@ -4,10 +4,32 @@ use arrow::io::avro::{self, read};
 use arrow::record_batch::RecordBatch;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 use crate::prelude::*;
 use crate::shared::{ArrowReader, finish_reader};
 
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroScanOptions;
+
+/// Count the rows of an Avro file.
+///
+/// This only walks the block headers of the file, the blocks themselves are not decompressed or
+/// decoded.
+pub fn count_rows(bytes: &[u8]) -> PolarsResult<usize> {
+    let mut data = bytes;
+    let metadata = avro::avro_schema::read::read_metadata(&mut data).map_err(to_compute_err)?;
+
+    let mut num_rows = 0;
+    while let Some(block_rows) = read::skip_block(&mut data, &metadata.marker)? {
+        num_rows += block_rows;
+    }
+    Ok(num_rows)
+}
+
 /// Read [Apache Avro] format into a [`DataFrame`]
 ///
 /// [Apache Avro]: https://avro.apache.org
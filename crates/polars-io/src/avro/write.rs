@@ -2,13 +2,140 @@ use std::io::Write;
 
 pub use Compression as AvroCompression;
 pub use arrow::io::avro::avro_schema::file::Compression;
+use arrow::io::avro::avro_schema::schema::Record;
 use arrow::io::avro::avro_schema::{self};
 use arrow::io::avro::write;
 use polars_core::error::to_compute_err;
 use polars_core::prelude::*;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::shared::{SerWriter, schema_to_arrow_checked};
 
+/// Mirrors [`Compression`], which is defined outside of this crate, to (de)serialize it.
+#[cfg(any(feature = "serde", feature = "dsl-schema"))]
+#[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+enum CompressionDef {
+    Deflate,
+    Snappy,
+}
+
+#[cfg(feature = "serde")]
+fn serialize_compression<S: Serializer>(
+    compression: &Option<Compression>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    compression
+        .map(|compression| match compression {
+            Compression::Deflate => CompressionDef::Deflate,
+            Compression::Snappy => CompressionDef::Snappy,
+        })
+        .serialize(serializer)
+}
+
+#[cfg(feature = "serde")]
+fn deserialize_compression<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<Compression>, D::Error> {
+    let compression = Option::<CompressionDef>::deserialize(deserializer)?;
+    Ok(compression.map(|compression| match compression {
+        CompressionDef::Deflate => Compression::Deflate,
+        CompressionDef::Snappy => Compression::Snappy,
+    }))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub struct AvroWriterOptions {
+    /// Block compression
+    #[cfg_attr(
+        feature = "serde",
+        serde(
+            serialize_with = "serialize_compression",
+            deserialize_with = "deserialize_compression"
+        )
+    )]
+    #[cfg_attr(feature = "dsl-schema", schemars(with = "Option<CompressionDef>"))]
+    pub compression: Option<AvroCompression>,
+    /// Name of the record schema
+    pub name: PlSmallStr,
+}
+
+impl AvroWriterOptions {
+    pub fn to_writer<W: Write>(&self, writer: W) -> AvroWriter<W> {
+        AvroWriter::new(writer)
+            .with_compression(self.compression)
+            .with_name(self.name.to_string())
+    }
+}
+
+/// Encodes [`DataFrame`]s into the blocks of an Avro object container file.
+///
+/// As blocks are independent of each other, they can be encoded in parallel and written in order
+/// after the header.
+#[derive(Clone)]
+pub struct AvroBlockEncoder {
+    record: Record,
+    compression: Option<Compression>,
+}
+
+impl AvroBlockEncoder {
+    pub fn new(schema: &Schema, options: &AvroWriterOptions) -> PolarsResult<Self> {
+        let schema = schema_to_arrow_checked(schema, CompatLevel::oldest(), "avro")?;
+        let record = write::to_record(&schema, options.name.to_string())?;
+
+        Ok(Self {
+            record,
+            compression: options.compression,
+        })
+    }
+
+    /// Write the header of the file, this has to precede all blocks.
+    pub fn write_header<W: Write>(&self, writer: &mut W) -> PolarsResult<()> {
+        avro_schema::write::write_metadata(writer, self.record.clone(), self.compression)
+            .map_err(to_compute_err)
+    }
+
+    /// Encode every chunk of `df` as a block and write them to `writer`.
+    ///
+    /// Empty chunks are skipped, as readers consider an empty block to be the end of the file.
+    pub fn encode_blocks<W: Write>(&self, df: &DataFrame, writer: &mut W) -> PolarsResult<()> {
+        let mut data = vec![];
+        let mut compressed_block = avro_schema::file::CompressedBlock::default();
+        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
+            if chunk.is_empty() {
+                continue;
+            }
+
+            let mut serializers = chunk
+                .iter()
+                .zip(self.record.fields.iter())
+                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
+                .collect::<Vec<_>>();
+
+            let mut block = avro_schema::file::Block::new(chunk.len(), std::mem::take(&mut data));
+            write::serialize(&mut serializers, &mut block);
+            let _was_compressed =
+                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
+                    .map_err(to_compute_err)?;
+
+            avro_schema::write::write_block(writer, &compressed_block).map_err(to_compute_err)?;
+            // reuse block for next iteration.
+            data = block.data;
+            data.clear();
+
+            // reuse block for next iteration
+            compressed_block.data.clear();
+            compressed_block.number_of_rows = 0
+        }
+
+        Ok(())
+    }
+}
+
 /// Write a [`DataFrame`] to [Apache Avro] format
 ///
 /// [Apache Avro]: https://avro.apache.org
@@ -64,39 +191,13 @@ where
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
-        let schema = schema_to_arrow_checked(df.schema(), CompatLevel::oldest(), "avro")?;
-        let record = write::to_record(&schema, self.name.clone())?;
+        let options = AvroWriterOptions {
+            compression: self.compression,
+            name: self.name.as_str().into(),
+        };
+        let encoder = AvroBlockEncoder::new(df.schema(), &options)?;
 
-        let mut data = vec![];
-        let mut compressed_block = avro_schema::file::CompressedBlock::default();
-        for chunk in df.iter_chunks(CompatLevel::oldest(), true) {
-            let mut serializers = chunk
-                .iter()
-                .zip(record.fields.iter())
-                .map(|(array, field)| write::new_serializer(array.as_ref(), &field.schema))
-                .collect::<Vec<_>>();
-
-            let mut block =
-                avro_schema::file::Block::new(chunk.arrays()[0].len(), std::mem::take(&mut data));
-            write::serialize(&mut serializers, &mut block);
-            let _was_compressed =
-                avro_schema::write::compress(&mut block, &mut compressed_block, self.compression)
-                    .map_err(to_compute_err)?;
-
-            avro_schema::write::write_metadata(&mut self.writer, record.clone(), self.compression)
-                .map_err(to_compute_err)?;
-
-            avro_schema::write::write_block(&mut self.writer, &compressed_block)
-                .map_err(to_compute_err)?;
-            // reuse block for next iteration.
-            data = block.data;
-            data.clear();
-
-            // reuse block for next iteration
-            compressed_block.data.clear();
-            compressed_block.number_of_rows = 0
-        }
-
-        Ok(())
+        encoder.write_header(&mut self.writer)?;
+        encoder.encode_blocks(df, &mut self.writer)
     }
 }
@@ -11,7 +11,7 @@ use polars_utils::vec::PushUnchecked;
 
 use super::options::CsvEncoding;
 use super::parser::{is_whitespace, skip_whitespace};
-use super::utils::escape_field;
+use super::utils::{escape_field, unescape_field};
 
 pub(crate) trait PrimitiveParser: PolarsNumericType {
     fn parse(bytes: &[u8]) -> Option<Self::Native>;
@@ -158,6 +158,7 @@ pub struct Utf8Field {
     mutable: MutableBinaryViewArray<[u8]>,
     scratch: Vec<u8>,
     quote_char: u8,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
 }
 
@@ -166,6 +167,7 @@ impl Utf8Field {
         name: PlSmallStr,
         capacity: usize,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         encoding: CsvEncoding,
     ) -> Self {
         Self {
@@ -173,11 +175,19 @@ impl Utf8Field {
             mutable: MutableBinaryViewArray::with_capacity(capacity),
             scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             encoding,
         }
     }
 }
 
+/// Whether `bytes` contain the escape character, these fields are unescaped by
+/// [`unescape_field`] instead of [`escape_field`].
+#[inline]
+fn has_escape_char(bytes: &[u8], escape_char: Option<u8>) -> Option<u8> {
+    escape_char.filter(|&c| memchr::memchr(c, bytes).is_some())
+}
+
 #[inline]
 pub fn validate_utf8(bytes: &[u8]) -> bool {
     simdutf8::basic::from_utf8(bytes).is_ok()
@@ -203,7 +213,24 @@ impl ParsedBuffer for Utf8Field {
         }
 
         // note that one branch writes without updating the length, so we must do that later.
-        let escaped_bytes = if needs_escaping {
+        let escaped_bytes = if let Some(escape_char) = has_escape_char(bytes, self.escape_char) {
+            self.scratch.clear();
+            self.scratch.reserve(bytes.len());
+
+            // SAFETY:
+            // we just allocated enough capacity and data_len is correct.
+            unsafe {
+                let n_written = unescape_field(
+                    bytes,
+                    needs_escaping.then_some(self.quote_char),
+                    escape_char,
+                    self.scratch.spare_capacity_mut(),
+                );
+                self.scratch.set_len(n_written);
+            }
+
+            self.scratch.as_slice()
+        } else if needs_escaping {
             self.scratch.clear();
             self.scratch.reserve(bytes.len());
             polars_ensure!(bytes.len() > 1 && bytes.last() == Some(&self.quote_char), ComputeError: "invalid csv file\n\nField `{}` is not properly escaped.", std::str::from_utf8(bytes).map_err(to_compute_err)?);
@@ -265,6 +292,7 @@ pub struct CategoricalField {
 pub struct CategoricalField {
     escape_scratch: Vec<u8>,
     quote_char: u8,
+    escape_char: Option<u8>,
     builder: CategoricalChunkedBuilder,
     is_enum: bool,
 }
@@ -275,6 +303,7 @@ impl CategoricalField {
         name: PlSmallStr,
         capacity: usize,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
         ordering: CategoricalOrdering,
     ) -> Self {
         let builder = CategoricalChunkedBuilder::new(name, capacity, ordering);
@@ -282,15 +311,21 @@ impl CategoricalField {
         Self {
             escape_scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             builder,
             is_enum: false,
         }
     }
 
-    fn new_enum(quote_char: Option<u8>, builder: CategoricalChunkedBuilder) -> Self {
+    fn new_enum(
+        quote_char: Option<u8>,
+        escape_char: Option<u8>,
+        builder: CategoricalChunkedBuilder,
+    ) -> Self {
         Self {
             escape_scratch: vec![],
             quote_char: quote_char.unwrap_or(b'"'),
+            escape_char,
             builder,
             is_enum: true,
         }
@@ -310,18 +345,27 @@ impl CategoricalField {
             return Ok(());
         }
         if validate_utf8(bytes) {
-            if needs_escaping {
-                polars_ensure!(bytes.len() > 1, ComputeError: "invalid csv file\n\nField `{}` is not properly escaped.", std::str::from_utf8(bytes).map_err(to_compute_err)?);
+            let escape_char = has_escape_char(bytes, self.escape_char);
+            if needs_escaping || escape_char.is_some() {
+                polars_ensure!(bytes.len() > 1 || escape_char.is_some(), ComputeError: "invalid csv file\n\nField `{}` is not properly escaped.", std::str::from_utf8(bytes).map_err(to_compute_err)?);
                 self.escape_scratch.clear();
                 self.escape_scratch.reserve(bytes.len());
                 // SAFETY:
                 // we just allocated enough capacity and data_len is correct.
                 unsafe {
-                    let n_written = escape_field(
-                        bytes,
-                        self.quote_char,
-                        self.escape_scratch.spare_capacity_mut(),
-                    );
+                    let n_written = match escape_char {
+                        Some(escape_char) => unescape_field(
+                            bytes,
+                            needs_escaping.then_some(self.quote_char),
+                            escape_char,
+                            self.escape_scratch.spare_capacity_mut(),
+                        ),
+                        None => escape_field(
+                            bytes,
+                            self.quote_char,
+                            self.escape_scratch.spare_capacity_mut(),
+                        ),
+                    };
                     self.escape_scratch.set_len(n_written);
                 }
 
@@ -518,6 +562,7 @@ pub fn init_buffers(
     capacity: usize,
     schema: &Schema,
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
     encoding: CsvEncoding,
     decimal_comma: bool,
 ) -> PolarsResult<Vec<Buffer>> {
@@ -562,9 +607,13 @@ pub fn init_buffers(
                         Buffer::Float64(PrimitiveChunkedBuilder::new(name, capacity))
                     }
                 },
-                &DataType::String => {
-                    Buffer::Utf8(Utf8Field::new(name, capacity, quote_char, encoding))
-                },
+                &DataType::String => Buffer::Utf8(Utf8Field::new(
+                    name,
+                    capacity,
+                    quote_char,
+                    escape_char,
+                    encoding,
+                )),
                 #[cfg(feature = "dtype-datetime")]
                 DataType::Datetime(time_unit, time_zone) => Buffer::Datetime {
                     buf: DatetimeField::new(name, capacity),
@@ -575,7 +624,11 @@ pub fn init_buffers(
                 &DataType::Date => Buffer::Date(DatetimeField::new(name, capacity)),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Categorical(_, ordering) => Buffer::Categorical(CategoricalField::new(
-                    name, capacity, quote_char, *ordering,
+                    name,
+                    capacity,
+                    quote_char,
+                    escape_char,
+                    *ordering,
                 )),
                 #[cfg(feature = "dtype-categorical")]
                 DataType::Enum(rev_map, _) => {
@@ -588,7 +641,11 @@ pub fn init_buffers(
                     for cat in cats.values_iter() {
                         builder.register_value(cat);
                     }
-                    Buffer::Categorical(CategoricalField::new_enum(quote_char, builder))
+                    Buffer::Categorical(CategoricalField::new_enum(
+                        quote_char,
+                        escape_char,
+                        builder,
+                    ))
                 },
                 dt => polars_bail!(
                     ComputeError: "unsupported data type when reading CSV: {} when reading CSV", dt,
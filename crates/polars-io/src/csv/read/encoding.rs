@@ -0,0 +1,244 @@
+//! Transcoding of CSV data in other encodings than UTF-8.
+//!
+//! The parser only understands UTF-8, data in other encodings is transcoded to UTF-8 before it is
+//! parsed. This happens in chunks, so that files can be transcoded while they are read.
+use std::io::{Cursor, Read};
+
+use polars_error::{PolarsResult, feature_gated, to_compute_err};
+use polars_utils::mmap::MemSlice;
+
+use super::options::CsvEncoding;
+use crate::utils::compression::{StreamingDecompressor, SupportedCompression};
+
+/// The characters of the bytes `0x80..=0x9F` in Windows-1252. The bytes that are undefined in
+/// Windows-1252 map to the C1 control character with the same value, like in Latin-1.
+const WINDOWS_1252_80_9F: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+impl CsvEncoding {
+    /// Whether data in this encoding has to be transcoded to UTF-8 before it can be parsed.
+    pub fn requires_transcoding(&self) -> bool {
+        !matches!(self, Self::Utf8 | Self::LossyUtf8)
+    }
+
+    /// Whether a byte order mark can be written in this encoding.
+    pub fn supports_bom(&self) -> bool {
+        matches!(
+            self,
+            Self::Utf8 | Self::LossyUtf8 | Self::Utf16Le | Self::Utf16Be
+        )
+    }
+
+    /// Encode `c` in this encoding and append it to `out`. Returns `false` if `c` cannot be
+    /// represented in this encoding.
+    pub(crate) fn encode_char(&self, c: char, out: &mut Vec<u8>) -> bool {
+        match self {
+            Self::Utf8 | Self::LossyUtf8 => {
+                out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            },
+            Self::Latin1 => match u8::try_from(c) {
+                Ok(b) => out.push(b),
+                Err(_) => return false,
+            },
+            Self::Windows1252 => match u8::try_from(c) {
+                Ok(b)
+                    if !(0x80..=0x9F).contains(&b)
+                        || WINDOWS_1252_80_9F[b as usize - 0x80] == c =>
+                {
+                    out.push(b)
+                },
+                _ => match WINDOWS_1252_80_9F.iter().position(|&x| x == c) {
+                    Some(i) => out.push(0x80 + i as u8),
+                    None => return false,
+                },
+            },
+            Self::Utf16Le => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    out.extend_from_slice(&unit.to_le_bytes());
+                }
+            },
+            Self::Utf16Be => {
+                for unit in c.encode_utf16(&mut [0; 2]) {
+                    out.extend_from_slice(&unit.to_be_bytes());
+                }
+            },
+        }
+        true
+    }
+
+    /// Transcode `bytes` to UTF-8 and append them to `out`. Returns the number of bytes that were
+    /// transcoded, the remaining bytes are the start of a character that continues in the next
+    /// bytes. If `is_last` is set all bytes are transcoded.
+    ///
+    /// Bytes that do not form a valid character are replaced with �.
+    fn decode_to_utf8(&self, bytes: &[u8], is_last: bool, out: &mut Vec<u8>) -> usize {
+        match self {
+            Self::Utf8 | Self::LossyUtf8 => {
+                out.extend_from_slice(bytes);
+                bytes.len()
+            },
+            Self::Latin1 => {
+                out.reserve(bytes.len());
+                for &b in bytes {
+                    push_char(char::from(b), out);
+                }
+                bytes.len()
+            },
+            Self::Windows1252 => {
+                out.reserve(bytes.len());
+                for &b in bytes {
+                    let c = match b {
+                        0x80..=0x9F => WINDOWS_1252_80_9F[b as usize - 0x80],
+                        _ => char::from(b),
+                    };
+                    push_char(c, out);
+                }
+                bytes.len()
+            },
+            Self::Utf16Le => decode_utf16(bytes, u16::from_le_bytes, is_last, out),
+            Self::Utf16Be => decode_utf16(bytes, u16::from_be_bytes, is_last, out),
+        }
+    }
+}
+
+fn push_char(c: char, out: &mut Vec<u8>) {
+    if c.is_ascii() {
+        out.push(c as u8);
+    } else {
+        out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+    }
+}
+
+fn decode_utf16(
+    bytes: &[u8],
+    from_bytes: fn([u8; 2]) -> u16,
+    is_last: bool,
+    out: &mut Vec<u8>,
+) -> usize {
+    let unit = |i: usize| from_bytes([bytes[2 * i], bytes[2 * i + 1]]);
+
+    let mut n_units = bytes.len() / 2;
+    // A high surrogate needs the next code unit to form a character.
+    if !is_last && n_units > 0 && (0xD800..0xDC00).contains(&unit(n_units - 1)) {
+        n_units -= 1;
+    }
+
+    out.reserve(n_units * 3 / 2);
+    for c in char::decode_utf16((0..n_units).map(unit)) {
+        push_char(c.unwrap_or(char::REPLACEMENT_CHARACTER), out);
+    }
+
+    if is_last && bytes.len() % 2 == 1 {
+        push_char(char::REPLACEMENT_CHARACTER, out);
+        return bytes.len();
+    }
+    2 * n_units
+}
+
+/// A reader that transcodes the data of the inner reader to UTF-8.
+pub(crate) struct TranscodingReader<R: Read> {
+    inner: R,
+    encoding: CsvEncoding,
+    /// Bytes read from `inner`, starting with the bytes that could not be transcoded yet.
+    in_buf: Vec<u8>,
+    /// Transcoded bytes that have not been returned yet.
+    out_buf: Vec<u8>,
+    out_pos: usize,
+    finished: bool,
+}
+
+impl<R: Read> TranscodingReader<R> {
+    const BUF_SIZE: usize = 64 * 1024;
+
+    pub(crate) fn new(inner: R, encoding: CsvEncoding) -> Self {
+        Self {
+            inner,
+            encoding,
+            in_buf: vec![],
+            out_buf: vec![],
+            out_pos: 0,
+            finished: false,
+        }
+    }
+
+    fn fill_out_buf(&mut self) -> std::io::Result<()> {
+        let offset = self.in_buf.len();
+        self.in_buf.resize(offset + Self::BUF_SIZE, 0);
+        let n_read = loop {
+            match self.inner.read(&mut self.in_buf[offset..]) {
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                result => break result?,
+            }
+        };
+        self.in_buf.truncate(offset + n_read);
+        self.finished = n_read == 0;
+
+        self.out_buf.clear();
+        self.out_pos = 0;
+        let n_decoded =
+            self.encoding
+                .decode_to_utf8(&self.in_buf, self.finished, &mut self.out_buf);
+        self.in_buf.drain(..n_decoded);
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for TranscodingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.out_pos == self.out_buf.len() {
+            if self.finished {
+                return Ok(0);
+            }
+            self.fill_out_buf()?;
+        }
+
+        let n = buf.len().min(self.out_buf.len() - self.out_pos);
+        buf[..n].copy_from_slice(&self.out_buf[self.out_pos..self.out_pos + n]);
+        self.out_pos += n;
+        Ok(n)
+    }
+}
+
+/// Transcode `bytes` to UTF-8 if `encoding` requires it, otherwise simply return it.
+/// An `out` vec must be given for ownership of the transcoded data.
+pub fn maybe_transcode_bytes<'a>(
+    bytes: &'a [u8],
+    encoding: CsvEncoding,
+    out: &'a mut Vec<u8>,
+) -> PolarsResult<&'a [u8]> {
+    assert!(out.is_empty());
+
+    if encoding.requires_transcoding() {
+        TranscodingReader::new(bytes, encoding)
+            .read_to_end(out)
+            .map_err(to_compute_err)?;
+        Ok(out)
+    } else {
+        Ok(bytes)
+    }
+}
+
+/// Returns a [`StreamingDecompressor`] that decompresses `bytes` if compression is detected and
+/// transcodes them to UTF-8 if `encoding` requires it. Returns `None` if neither is needed.
+pub fn streaming_utf8_decoder(
+    bytes: MemSlice,
+    encoding: CsvEncoding,
+) -> PolarsResult<Option<StreamingDecompressor>> {
+    if !encoding.requires_transcoding() {
+        return StreamingDecompressor::try_new(bytes);
+    }
+
+    let reader: Box<dyn Read + Send> = match SupportedCompression::check(&bytes) {
+        #[cfg_attr(not(feature = "decompress"), allow(unused_variables))]
+        Some(algo) => feature_gated!("decompress", algo.decoder(Cursor::new(bytes))?),
+        None => Box::new(Cursor::new(bytes)),
+    };
+
+    Ok(Some(StreamingDecompressor::from_reader(Box::new(
+        TranscodingReader::new(reader, encoding),
+    ))))
+}
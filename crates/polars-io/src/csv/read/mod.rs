@@ -17,18 +17,24 @@
 //! ```
 
 pub mod buffer;
+mod encoding;
 mod options;
 mod parser;
 mod read_impl;
 mod reader;
+mod rejects;
 pub mod schema_inference;
 mod splitfields;
 mod utils;
 
-pub use options::{CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues};
+pub use encoding::{maybe_transcode_bytes, streaming_utf8_decoder};
+pub use options::{
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, Separator,
+};
 pub use parser::{count_rows, count_rows_from_slice, count_rows_from_slice_par};
 pub use read_impl::batched::{BatchedCsvReader, OwnedBatchedCsvReader};
 pub use reader::CsvReader;
+pub use rejects::{CsvRejectsWriter, rejects_schema};
 pub use schema_inference::infer_file_schema;
 
 pub mod _csv_read_internal {
@@ -37,4 +43,6 @@ pub mod _csv_read_internal {
     pub use super::parser::CountLines;
     pub use super::read_impl::{cast_columns, find_starting_point, read_chunk};
     pub use super::reader::prepare_csv_schema;
+    pub use super::rejects::{ChunkRejects, concat_rejects, count_eol_chars};
+    pub use super::schema_inference::n_lines_for_schema_inference;
 }
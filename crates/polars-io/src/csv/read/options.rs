@@ -41,6 +41,9 @@ pub struct CsvReadOptions {
     pub infer_schema_length: Option<usize>,
     pub raise_if_empty: bool,
     pub ignore_errors: bool,
+    /// Write the rows that fail to parse or cast to a CSV file at this path, instead of failing
+    /// the read.
+    pub rejects_path: Option<PathBuf>,
     pub fields_to_cast: Vec<Field>,
 }
 
@@ -48,8 +51,11 @@ pub struct CsvReadOptions {
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
 pub struct CsvParseOptions {
-    pub separator: u8,
+    pub separator: Separator,
     pub quote_char: Option<u8>,
+    /// The character that makes the next character a literal, e.g. a backslash. Quotes within
+    /// quoted fields are still unescaped by doubling them.
+    pub escape_char: Option<u8>,
     pub eol_char: u8,
     pub encoding: CsvEncoding,
     pub null_values: Option<NullValues>,
@@ -87,6 +93,7 @@ impl Default for CsvReadOptions {
             infer_schema_length: Some(100),
             raise_if_empty: true,
             ignore_errors: false,
+            rejects_path: None,
             fields_to_cast: vec![],
         }
     }
@@ -96,8 +103,9 @@ impl Default for CsvReadOptions {
 impl Default for CsvParseOptions {
     fn default() -> Self {
         Self {
-            separator: b',',
+            separator: Separator::default(),
             quote_char: Some(b'"'),
+            escape_char: None,
             eol_char: b'\n',
             encoding: Default::default(),
             null_values: None,
@@ -247,6 +255,13 @@ impl CsvReadOptions {
         self
     }
 
+    /// Remove the rows that fail to parse or cast from the output and write them to a CSV file
+    /// at this path, along with the source file, line number and error message of every row.
+    pub fn with_rejects_path(mut self, rejects_path: Option<PathBuf>) -> Self {
+        self.rejects_path = rejects_path;
+        self
+    }
+
     /// Apply a function to the parse options.
     pub fn map_parse_options<F: Fn(CsvParseOptions) -> CsvParseOptions>(
         mut self,
@@ -259,10 +274,10 @@ impl CsvReadOptions {
 }
 
 impl CsvParseOptions {
-    /// The character used to separate fields in the CSV file. This
-    /// is most often a comma ','.
-    pub fn with_separator(mut self, separator: u8) -> Self {
-        self.separator = separator;
+    /// The character(s) used to separate fields in the CSV file. This
+    /// is most often a comma ',', but can also be a string such as '||'.
+    pub fn with_separator<T: Into<Separator>>(mut self, separator: T) -> Self {
+        self.separator = separator.into();
         self
     }
 
@@ -273,6 +288,13 @@ impl CsvParseOptions {
         self
     }
 
+    /// Set the character that escapes the next character, e.g. a backslash. Set this
+    /// to [None] to disable escaping.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Set the character used to indicate an end-of-line (eol).
     pub fn with_eol_char(mut self, eol_char: u8) -> Self {
         self.eol_char = eol_char;
@@ -339,6 +361,61 @@ pub enum CsvEncoding {
     Utf8,
     /// Utf8 encoding and unknown bytes are replaced with �.
     LossyUtf8,
+    /// ISO-8859-1 encoding.
+    Latin1,
+    /// Windows-1252 encoding, a superset of ISO-8859-1 that is common on Windows.
+    Windows1252,
+    /// UTF-16 little-endian encoding.
+    Utf16Le,
+    /// UTF-16 big-endian encoding.
+    Utf16Be,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum Separator {
+    /// A single byte character that separates the fields.
+    Single(u8),
+    /// A string that separates the fields, e.g. `||`.
+    Multi(PlSmallStr),
+}
+
+impl Separator {
+    /// Creates a new `Separator` from a `&str`.
+    pub fn new_from_str(separator: &str) -> Self {
+        if separator.len() == 1 {
+            Separator::Single(separator.as_bytes()[0])
+        } else {
+            Separator::Multi(PlSmallStr::from_str(separator))
+        }
+    }
+
+    /// The bytes of the separator.
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Separator::Single(c) => std::slice::from_ref(c),
+            Separator::Multi(s) => s.as_bytes(),
+        }
+    }
+}
+
+impl Default for Separator {
+    fn default() -> Self {
+        Separator::Single(b',')
+    }
+}
+
+impl From<u8> for Separator {
+    fn from(value: u8) -> Self {
+        Separator::Single(value)
+    }
+}
+
+impl From<&str> for Separator {
+    fn from(value: &str) -> Self {
+        Self::new_from_str(value)
+    }
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -10,8 +10,10 @@ use polars_utils::select::select_unpredictable;
 use rayon::prelude::*;
 
 use super::CsvParseOptions;
-use super::buffer::Buffer;
-use super::options::{CommentPrefix, NullValuesCompiled};
+use super::buffer::{Buffer, validate_utf8};
+use super::encoding::maybe_transcode_bytes;
+use super::options::{CommentPrefix, CsvEncoding, NullValuesCompiled};
+use super::rejects::ChunkRejects;
 use super::splitfields::SplitFields;
 use super::utils::get_file_chunks;
 use crate::path_utils::is_cloud_url;
@@ -19,13 +21,16 @@ use crate::utils::compression::maybe_decompress_bytes;
 
 /// Read the number of rows without parsing columns
 /// useful for count(*) queries
+#[allow(clippy::too_many_arguments)]
 pub fn count_rows(
     path: &Path,
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
+    encoding: CsvEncoding,
+    escape_char: Option<u8>,
 ) -> PolarsResult<usize> {
     let file = if is_cloud_url(path) || config::force_async() {
         feature_gated!("cloud", {
@@ -42,6 +47,8 @@ pub fn count_rows(
     let mmap = MMapSemaphore::new_from_file(&file).unwrap();
     let owned = &mut vec![];
     let reader_bytes = maybe_decompress_bytes(mmap.as_ref(), owned)?;
+    let transcoded = &mut vec![];
+    let reader_bytes = maybe_transcode_bytes(reader_bytes, encoding, transcoded)?;
 
     count_rows_from_slice_par(
         reader_bytes,
@@ -50,6 +57,7 @@ pub fn count_rows(
         comment_prefix,
         eol_char,
         has_header,
+        escape_char,
     )
 }
 
@@ -57,11 +65,12 @@ pub fn count_rows(
 /// useful for count(*) queries
 pub fn count_rows_from_slice_par(
     mut bytes: &[u8],
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
+    escape_char: Option<u8>,
 ) -> PolarsResult<usize> {
     for _ in 0..bytes.len() {
         if bytes[0] != eol_char {
@@ -82,6 +91,7 @@ pub fn count_rows_from_slice_par(
         None,
         separator,
         quote_char,
+        escape_char,
     )
     .map(|(mean, std)| {
         let n_rows = (bytes.len() as f32 / (mean - 0.01 * std)) as usize;
@@ -90,21 +100,39 @@ pub fn count_rows_from_slice_par(
     .unwrap_or(1);
 
     if n_threads == 1 {
-        return count_rows_from_slice(bytes, quote_char, comment_prefix, eol_char, has_header);
+        return count_rows_from_slice(
+            bytes,
+            quote_char,
+            comment_prefix,
+            eol_char,
+            has_header,
+            escape_char,
+        );
     }
 
-    let file_chunks: Vec<(usize, usize)> =
-        get_file_chunks(bytes, n_threads, None, separator, quote_char, eol_char);
+    let file_chunks: Vec<(usize, usize)> = get_file_chunks(
+        bytes,
+        n_threads,
+        None,
+        separator,
+        quote_char,
+        eol_char,
+        escape_char,
+    );
 
     let iter = file_chunks.into_par_iter().map(|(start, stop)| {
         let bytes = &bytes[start..stop];
 
         if comment_prefix.is_some() {
             SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
+                .with_escape_char(escape_char)
                 .filter(|line| !is_comment_line(line, comment_prefix))
                 .count()
         } else {
-            CountLines::new(quote_char, eol_char).count(bytes).0
+            CountLines::new(quote_char, eol_char)
+                .with_escape_char(escape_char)
+                .count(bytes)
+                .0
                 + bytes.last().is_some_and(|x| *x != b'\n') as usize
         }
     });
@@ -121,6 +149,7 @@ pub fn count_rows_from_slice(
     comment_prefix: Option<&CommentPrefix>,
     eol_char: u8,
     has_header: bool,
+    escape_char: Option<u8>,
 ) -> PolarsResult<usize> {
     for _ in 0..bytes.len() {
         if bytes[0] != eol_char {
@@ -132,10 +161,14 @@ pub fn count_rows_from_slice(
 
     let n = if comment_prefix.is_some() {
         SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
+            .with_escape_char(escape_char)
             .filter(|line| !is_comment_line(line, comment_prefix))
             .count()
     } else {
-        CountLines::new(quote_char, eol_char).count(bytes).0
+        CountLines::new(quote_char, eol_char)
+            .with_escape_char(escape_char)
+            .count(bytes)
+            .0
             + bytes.last().is_some_and(|x| *x != b'\n') as usize
     };
 
@@ -189,20 +222,22 @@ pub(super) fn skip_lines_naive(mut input: &[u8], eol_char: u8, skip: usize) -> &
 pub(super) fn next_line_position(
     mut input: &[u8],
     mut expected_fields: Option<usize>,
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
 ) -> Option<usize> {
     fn accept_line(
         line: &[u8],
         expected_fields: usize,
-        separator: u8,
+        separator: &[u8],
         eol_char: u8,
         quote_char: Option<u8>,
+        escape_char: Option<u8>,
     ) -> bool {
         let mut count = 0usize;
-        for (field, _) in SplitFields::new(line, separator, quote_char, eol_char) {
-            if memchr2_iter(separator, eol_char, field).count() >= expected_fields {
+        for (field, _) in SplitFields::new(line, separator, quote_char, eol_char, escape_char) {
+            if memchr2_iter(separator[0], eol_char, field).count() >= expected_fields {
                 return false;
             }
             count += 1;
@@ -248,16 +283,27 @@ pub(super) fn next_line_position(
         }
         debug_assert!(pos <= input.len());
         let new_input = unsafe { input.get_unchecked(pos..) };
-        let mut lines = SplitLines::new(new_input, quote_char, eol_char, None);
+        let mut lines =
+            SplitLines::new(new_input, quote_char, eol_char, None).with_escape_char(escape_char);
         let line = lines.next();
+        let accept = |line| {
+            accept_line(
+                line,
+                expected_fields.unwrap(),
+                separator,
+                eol_char,
+                quote_char,
+                escape_char,
+            )
+        };
 
         match (line, expected_fields) {
             // count the fields, and determine if they are equal to what we expect from the schema
-            (Some(line), Some(expected_fields)) => {
-                if accept_line(line, expected_fields, separator, eol_char, quote_char) {
+            (Some(line), Some(_)) => {
+                if accept(line) {
                     let mut valid = true;
                     for line in lines.take(2) {
-                        if !accept_line(line, expected_fields, separator, eol_char, quote_char) {
+                        if !accept(line) {
                             valid = false;
                             break;
                         }
@@ -325,8 +371,9 @@ pub(super) fn get_line_stats(
     n_lines: usize,
     eol_char: u8,
     expected_fields: Option<usize>,
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
+    escape_char: Option<u8>,
 ) -> Option<(f32, f32)> {
     let mut lengths = Vec::with_capacity(n_lines);
 
@@ -344,6 +391,7 @@ pub(super) fn get_line_stats(
             separator,
             quote_char,
             eol_char,
+            escape_char,
         )?;
         bytes_trunc = &bytes_trunc[pos + 1..];
 
@@ -388,6 +436,7 @@ pub(super) struct SplitLines<'a> {
     total_index: usize,
     quoting: bool,
     comment_prefix: Option<&'a CommentPrefix>,
+    escape_char: Option<u8>,
 }
 
 #[cfg(feature = "simd")]
@@ -427,8 +476,16 @@ impl<'a> SplitLines<'a> {
             total_index: 0,
             quoting,
             comment_prefix,
+            escape_char: None,
         }
     }
+
+    /// Set the character that makes the next character a literal, so that escaped quotes and
+    /// end of line characters don't split the lines.
+    pub(super) fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
 }
 
 impl<'a> SplitLines<'a> {
@@ -449,7 +506,12 @@ impl<'a> SplitLines<'a> {
                     Some(&c) => {
                         pos += 1;
 
-                        if self.quoting && c == self.quote_char {
+                        if Some(c) == self.escape_char {
+                            // the next character is a literal
+                            if iter.next().is_some() {
+                                pos += 1;
+                            }
+                        } else if self.quoting && c == self.quote_char {
                             // toggle between string field enclosure
                             //      if we encounter a starting '"' -> in_field = true;
                             //      if we encounter a closing '"' -> in_field = false;
@@ -529,7 +591,7 @@ impl<'a> Iterator for SplitLines<'a> {
         if self.v.is_empty() {
             return None;
         }
-        if self.comment_prefix.is_some() {
+        if self.comment_prefix.is_some() || self.escape_char.is_some() {
             return self.next_scalar();
         }
 
@@ -636,6 +698,7 @@ pub struct CountLines {
     #[cfg(feature = "simd")]
     simd_quote_char: SimdVec,
     quoting: bool,
+    escape_char: Option<u8>,
 }
 
 #[derive(Copy, Clone, Debug)]
@@ -661,9 +724,17 @@ impl CountLines {
             #[cfg(feature = "simd")]
             simd_quote_char,
             quoting,
+            escape_char: None,
         }
     }
 
+    /// Set the character that makes the next character a literal, so that escaped quotes and
+    /// end of line characters are not counted. This disables the SIMD path.
+    pub fn with_escape_char(mut self, escape_char: Option<u8>) -> Self {
+        self.escape_char = escape_char;
+        self
+    }
+
     /// Analyzes a chunk of CSV data.
     ///
     /// Returns (newline_count, last_newline_offset, end_inside_string) twice,
@@ -692,7 +763,7 @@ impl CountLines {
         {
             // 0 if even number of quotes seen so far, u64::MAX otherwise.
             let mut global_quote_parity_mask = 0;
-            while scan_offset + 64 <= bytes.len() {
+            while self.escape_char.is_none() && scan_offset + 64 <= bytes.len() {
                 let block: [u8; 64] = unsafe {
                     bytes
                         .get_unchecked(scan_offset..scan_offset + 64)
@@ -741,6 +812,11 @@ impl CountLines {
 
         while scan_offset < bytes.len() {
             let c = unsafe { *bytes.get_unchecked(scan_offset) };
+            if Some(c) == self.escape_char {
+                // the next character is a literal
+                scan_offset += 2;
+                continue;
+            }
             global_quote_parity ^= (c == self.quote_char) & self.quoting;
 
             let state = &mut states[global_quote_parity as usize];
@@ -770,9 +846,19 @@ impl CountLines {
         }
     }
 
+    /// Returns the offset after the last line end in `bytes`, if `bytes` contains at least
+    /// `min_lines` line ends.
+    pub fn find_last_line_end(&self, bytes: &[u8], min_lines: usize) -> Option<usize> {
+        let (count, position) = self.count(bytes);
+        (count > 0 && count >= min_lines).then_some(position + 1)
+    }
+
     /// Returns count and offset to split for remainder in slice.
     #[cfg(feature = "simd")]
     pub fn count(&self, bytes: &[u8]) -> (usize, usize) {
+        if self.escape_char.is_some() {
+            return self.count_no_simd(bytes, false);
+        }
         let mut total_idx = 0;
         let original_bytes = bytes;
         let mut count = 0;
@@ -835,14 +921,17 @@ impl CountLines {
     }
 
     fn count_no_simd(&self, bytes: &[u8], in_field: bool) -> (usize, usize) {
-        let iter = bytes.iter();
+        let mut iter = bytes.iter();
         let mut in_field = in_field;
         let mut count = 0;
         let mut position = 0;
 
-        for b in iter {
+        while let Some(b) = iter.next() {
             let c = *b;
-            if self.quoting && c == self.quote_char {
+            if Some(c) == self.escape_char {
+                // the next character is a literal
+                iter.next();
+            } else if self.quoting && c == self.quote_char {
                 // toggle between string field enclosure
                 //      if we encounter a starting '"' -> in_field = true;
                 //      if we encounter a closing '"' -> in_field = false;
@@ -861,14 +950,28 @@ impl CountLines {
 }
 
 #[inline]
-fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
+fn find_quoted(
+    bytes: &[u8],
+    quote_char: Option<u8>,
+    needle: u8,
+    escape_char: Option<u8>,
+) -> Option<usize> {
     let mut in_field = false;
 
     let mut idx = 0u32;
+    let mut escaped = false;
     // micro optimizations
     #[allow(clippy::explicit_counter_loop)]
     for &c in bytes.iter() {
-        if c == quote_char {
+        if escaped {
+            escaped = false;
+            idx += 1;
+            continue;
+        }
+        if Some(c) == escape_char {
+            // the next character is a literal
+            escaped = true;
+        } else if Some(c) == quote_char {
             // toggle between string field enclosure
             //      if we encounter a starting '"' -> in_field = true;
             //      if we encounter a closing '"' -> in_field = false;
@@ -884,10 +987,15 @@ fn find_quoted(bytes: &[u8], quote_char: u8, needle: u8) -> Option<usize> {
 }
 
 #[inline]
-pub(super) fn skip_this_line(bytes: &[u8], quote: Option<u8>, eol_char: u8) -> &[u8] {
-    let pos = match quote {
-        Some(quote) => find_quoted(bytes, quote, eol_char),
-        None => bytes.iter().position(|x| *x == eol_char),
+pub(super) fn skip_this_line(
+    bytes: &[u8],
+    quote: Option<u8>,
+    eol_char: u8,
+    escape_char: Option<u8>,
+) -> &[u8] {
+    let pos = match (quote, escape_char) {
+        (None, None) => bytes.iter().position(|x| *x == eol_char),
+        _ => find_quoted(bytes, quote, eol_char, escape_char),
     };
     match pos {
         None => &[],
@@ -913,6 +1021,8 @@ pub(super) fn skip_this_line_naive(input: &[u8], eol_char: u8) -> &[u8] {
 /// * `projection` - Indices of the columns to project.
 /// * `buffers` - Parsed output will be written to these buffers. Except for UTF8 data. The offsets of the
 ///   fields are written to the buffers. The UTF8 data will be parsed later.
+/// * `rejects` - If given, rows that fail to parse are recorded here instead of returning an error.
+///   The fields that failed are added as null. The byte ranges are relative to `bytes`.
 #[allow(clippy::too_many_arguments)]
 pub(super) fn parse_lines(
     mut bytes: &[u8],
@@ -926,12 +1036,15 @@ pub(super) fn parse_lines(
     // length of original schema
     schema_len: usize,
     schema: &Schema,
+    mut rejects: Option<&mut ChunkRejects>,
 ) -> PolarsResult<usize> {
     assert!(
         !projection.is_empty(),
         "at least one column should be projected"
     );
     let mut truncate_ragged_lines = parse_options.truncate_ragged_lines;
+    let separator = parse_options.separator.as_bytes();
+    let multi_byte_separator = separator.len() > 1;
     // During projection pushdown we are not checking other csv fields.
     // This would be very expensive and we don't care as we only want
     // the projected columns.
@@ -966,12 +1079,16 @@ pub(super) fn parse_lines(
         let mut projection_iter = projection.iter().copied();
         let mut next_projected = unsafe { projection_iter.next().unwrap_unchecked() };
         let mut processed_fields = 0;
+        let line_start = bytes.as_ptr() as usize - start;
+        // The first error in this line, if rows that fail to parse are rejected.
+        let mut line_error = None;
 
         let mut iter = SplitFields::new(
             bytes,
-            parse_options.separator,
+            separator,
             parse_options.quote_char,
             parse_options.eol_char,
+            parse_options.escape_char,
         );
         let mut idx = 0u32;
         let mut read_sol = 0;
@@ -987,6 +1104,14 @@ pub(super) fn parse_lines(
 
                     // +1 is the split character that is consumed by the iterator.
                     read_sol += field_len + 1;
+                    // A separator of multiple bytes consumes the remaining bytes as well.
+                    if multi_byte_separator
+                        && bytes
+                            .get(read_sol - 1..)
+                            .is_some_and(|rest| rest.starts_with(separator))
+                    {
+                        read_sol += separator.len() - 1;
+                    }
 
                     if idx == next_projected as u32 {
                         // the iterator is finished when it encounters a `\n`
@@ -1006,7 +1131,10 @@ pub(super) fn parse_lines(
 
                         // if we have null values argument, check if this field equal null value
                         if let Some(null_values) = null_values {
-                            let field = if needs_escaping && !field.is_empty() {
+                            let field = if needs_escaping
+                                && !field.is_empty()
+                                && parse_options.quote_char == Some(field[0])
+                            {
                                 unsafe { field.get_unchecked(1..field.len() - 1) }
                             } else {
                                 field
@@ -1016,34 +1144,61 @@ pub(super) fn parse_lines(
                             // process fields is in bounds
                             add_null = unsafe { null_values.is_null(field, idx as usize) }
                         }
-                        if add_null {
-                            buf.add_null(!parse_options.missing_is_null && field.is_empty())
+                        let result = if add_null {
+                            buf.add_null(!parse_options.missing_is_null && field.is_empty());
+                            Ok(())
+                        } else if rejects.as_ref().is_some_and(|r| r.validate_utf8)
+                            && !validate_utf8(field)
+                        {
+                            Err(polars_err!(ComputeError: "invalid utf-8 sequence"))
                         } else {
-                            buf.add(field, ignore_errors, needs_escaping, parse_options.missing_is_null)
-                                .map_err(|e| {
-                                    let bytes_offset = offset + field.as_ptr() as usize - start;
-                                    let unparsable = String::from_utf8_lossy(field);
-                                    let column_name = schema.get_at_index(idx as usize).unwrap().0;
-                                    polars_err!(
-                                        ComputeError:
-                                        "could not parse `{}` as dtype `{}` at column '{}' (column number {})\n\n\
-                                        The current offset in the file is {} bytes.\n\
-                                        \n\
-                                        You might want to try:\n\
-                                        - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
-                                        - specifying correct dtype with the `schema_overrides` argument\n\
-                                        - setting `ignore_errors` to `True`,\n\
-                                        - adding `{}` to the `null_values` list.\n\n\
-                                        Original error: ```{}```",
+                            buf.add(
+                                field,
+                                ignore_errors,
+                                needs_escaping,
+                                parse_options.missing_is_null,
+                            )
+                        };
+
+                        if let Err(e) = result {
+                            let unparsable = String::from_utf8_lossy(field);
+                            let column_name = schema.get_at_index(idx as usize).unwrap().0;
+
+                            if rejects.is_some() {
+                                buf.add_null(false);
+                                line_error.get_or_insert_with(|| {
+                                    format!(
+                                        "could not parse `{}` as dtype `{}` at column '{}' \
+                                        (column number {}): {}",
                                         &unparsable,
                                         buf.dtype(),
                                         column_name,
                                         idx + 1,
-                                        bytes_offset,
-                                        &unparsable,
                                         e
                                     )
-                                })?;
+                                });
+                            } else {
+                                let bytes_offset = offset + field.as_ptr() as usize - start;
+                                polars_bail!(
+                                    ComputeError:
+                                    "could not parse `{}` as dtype `{}` at column '{}' (column number {})\n\n\
+                                    The current offset in the file is {} bytes.\n\
+                                    \n\
+                                    You might want to try:\n\
+                                    - increasing `infer_schema_length` (e.g. `infer_schema_length=10000`),\n\
+                                    - specifying correct dtype with the `schema_overrides` argument\n\
+                                    - setting `ignore_errors` to `True`,\n\
+                                    - adding `{}` to the `null_values` list.\n\n\
+                                    Original error: ```{}```",
+                                    &unparsable,
+                                    buf.dtype(),
+                                    column_name,
+                                    idx + 1,
+                                    bytes_offset,
+                                    &unparsable,
+                                    e
+                                )
+                            }
                         }
                         processed_fields += 1;
 
@@ -1055,14 +1210,20 @@ pub(super) fn parse_lines(
                                     bytes = &bytes[read_sol..];
                                 } else {
                                     if !truncate_ragged_lines && read_sol < bytes.len() {
-                                        polars_bail!(ComputeError: r#"found more fields than defined in 'Schema'
+                                        if rejects.is_none() {
+                                            polars_bail!(ComputeError: r#"found more fields than defined in 'Schema'
 
 Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
+                                        }
+                                        line_error.get_or_insert_with(|| {
+                                            "found more fields than defined in 'Schema'".to_string()
+                                        });
                                     }
                                     let bytes_rem = skip_this_line(
                                         unsafe { bytes.get_unchecked(read_sol - 1..) },
                                         parse_options.quote_char,
                                         parse_options.eol_char,
+                                        parse_options.escape_char,
                                     );
                                     bytes = bytes_rem;
                                 }
@@ -1087,6 +1248,19 @@ Consider setting 'truncate_ragged_lines={}'."#, polars_error::constants::TRUE)
             buf.add_null(!parse_options.missing_is_null);
             processed_fields += 1;
         }
+
+        if let Some(rejects) = rejects.as_deref_mut() {
+            if let Some(error) = line_error {
+                rejects.errors.push((rejects.rows.len(), error));
+            }
+            // `bytes` may be a static empty slice at the end of the input.
+            let line_end = if bytes.is_empty() {
+                original_bytes_len
+            } else {
+                bytes.as_ptr() as usize - start
+            };
+            rejects.rows.push(line_start..line_end);
+        }
         line_count += 1;
     }
 }
@@ -1109,4 +1283,14 @@ mod test {
         assert_eq!(lines2.next(), Some("2,'foo\n'".as_bytes()));
         assert_eq!(lines2.next(), None);
     }
+
+    #[test]
+    fn test_splitlines_escape_char() {
+        let input = "1,\"fo\\\"o\n\"\n2,foo\\\nbar\n";
+        let mut lines = SplitLines::new(input.as_bytes(), Some(b'"'), b'\n', None)
+            .with_escape_char(Some(b'\\'));
+        assert_eq!(lines.next(), Some("1,\"fo\\\"o\n\"".as_bytes()));
+        assert_eq!(lines.next(), Some("2,foo\\\nbar".as_bytes()));
+        assert_eq!(lines.next(), None);
+    }
 }
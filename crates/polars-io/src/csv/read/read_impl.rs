@@ -19,9 +19,9 @@ use super::parser::{
     skip_lines_naive, skip_this_line,
 };
 use super::reader::prepare_csv_schema;
-use super::schema_inference::{check_decimal_comma, infer_file_schema};
-#[cfg(feature = "decompress")]
-use super::utils::decompress;
+use super::rejects::{ChunkRejects, concat_rejects, count_eol_chars, rejects_schema};
+use super::schema_inference::{check_decimal_comma, check_separator, infer_file_schema};
+use super::utils::decompress_and_transcode;
 use crate::RowIndex;
 use crate::csv::read::parser::skip_this_line_naive;
 use crate::mmap::ReaderBytes;
@@ -29,46 +29,49 @@ use crate::predicates::PhysicalIoExpr;
 use crate::utils::compression::SupportedCompression;
 use crate::utils::update_row_counts2;
 
+/// Cast a column that was read as another dtype to the dtype of `fld`.
+pub(super) fn cast_column(c: &Column, fld: &Field, ignore_errors: bool) -> PolarsResult<Column> {
+    let out = match (c.dtype(), fld.dtype()) {
+        #[cfg(feature = "temporal")]
+        (DataType::String, DataType::Date) => c
+            .str()
+            .unwrap()
+            .as_date(None, false)
+            .map(|ca| ca.into_column()),
+        #[cfg(feature = "temporal")]
+        (DataType::String, DataType::Time) => c
+            .str()
+            .unwrap()
+            .as_time(None, false)
+            .map(|ca| ca.into_column()),
+        #[cfg(feature = "temporal")]
+        (DataType::String, DataType::Datetime(tu, _)) => c
+            .str()
+            .unwrap()
+            .as_datetime(
+                None,
+                *tu,
+                false,
+                false,
+                None,
+                &StringChunked::from_iter(std::iter::once("raise")),
+            )
+            .map(|ca| ca.into_column()),
+        (_, dt) => c.cast(dt),
+    }?;
+    if !ignore_errors && c.null_count() != out.null_count() {
+        handle_casting_failures(c.as_materialized_series(), out.as_materialized_series())?;
+    }
+    Ok(out)
+}
+
 pub fn cast_columns(
     df: &mut DataFrame,
     to_cast: &[Field],
     parallel: bool,
     ignore_errors: bool,
 ) -> PolarsResult<()> {
-    let cast_fn = |c: &Column, fld: &Field| {
-        let out = match (c.dtype(), fld.dtype()) {
-            #[cfg(feature = "temporal")]
-            (DataType::String, DataType::Date) => c
-                .str()
-                .unwrap()
-                .as_date(None, false)
-                .map(|ca| ca.into_column()),
-            #[cfg(feature = "temporal")]
-            (DataType::String, DataType::Time) => c
-                .str()
-                .unwrap()
-                .as_time(None, false)
-                .map(|ca| ca.into_column()),
-            #[cfg(feature = "temporal")]
-            (DataType::String, DataType::Datetime(tu, _)) => c
-                .str()
-                .unwrap()
-                .as_datetime(
-                    None,
-                    *tu,
-                    false,
-                    false,
-                    None,
-                    &StringChunked::from_iter(std::iter::once("raise")),
-                )
-                .map(|ca| ca.into_column()),
-            (_, dt) => c.cast(dt),
-        }?;
-        if !ignore_errors && c.null_count() != out.null_count() {
-            handle_casting_failures(c.as_materialized_series(), out.as_materialized_series())?;
-        }
-        Ok(out)
-    };
+    let cast_fn = |c: &Column, fld: &Field| cast_column(c, fld, ignore_errors);
 
     if parallel {
         let cols = POOL.install(|| {
@@ -123,6 +126,10 @@ pub(crate) struct CoreReader<'a> {
     row_index: Option<RowIndex>,
     #[cfg_attr(not(feature = "dtype-categorical"), allow(unused))]
     has_categorical: bool,
+    /// Reject the rows that fail to parse or cast instead of failing the read.
+    collect_rejects: bool,
+    /// Path of the file, reported for rejected rows.
+    path: Option<String>,
 }
 
 impl fmt::Debug for CoreReader<'_> {
@@ -158,11 +165,19 @@ impl<'a> CoreReader<'a> {
         skip_rows_after_header: usize,
         row_index: Option<RowIndex>,
         raise_if_empty: bool,
+        collect_rejects: bool,
+        path: Option<String>,
     ) -> PolarsResult<CoreReader<'a>> {
-        let separator = parse_options.separator;
-
-        check_decimal_comma(parse_options.decimal_comma, separator)?;
-        #[cfg(feature = "decompress")]
+        polars_ensure!(
+            !(collect_rejects && ignore_errors),
+            InvalidOperation: "rejected rows cannot be captured when 'ignore_errors' is set"
+        );
+
+        check_separator(&parse_options)?;
+        check_decimal_comma(
+            parse_options.decimal_comma,
+            parse_options.separator.as_bytes(),
+        )?;
         let mut reader_bytes = reader_bytes;
 
         if !cfg!(feature = "decompress") && SupportedCompression::check(&reader_bytes).is_some() {
@@ -174,16 +189,16 @@ impl<'a> CoreReader<'a> {
         // We keep track of the inferred schema bool
         // In case the file is compressed this schema inference is wrong and has to be done
         // again after decompression.
-        #[cfg(feature = "decompress")]
         {
             let total_n_rows =
                 n_rows.map(|n| skip_rows + (has_header as usize) + skip_rows_after_header + n);
-            if let Some(b) = decompress(
+            if let Some(b) = decompress_and_transcode(
                 &reader_bytes,
                 total_n_rows,
-                separator,
                 parse_options.quote_char,
                 parse_options.eol_char,
+                parse_options.escape_char,
+                parse_options.encoding,
             ) {
                 reader_bytes = ReaderBytes::Owned(b.into());
             }
@@ -254,6 +269,8 @@ impl<'a> CoreReader<'a> {
             to_cast,
             row_index,
             has_categorical,
+            collect_rejects,
+            path,
         })
     }
 
@@ -267,6 +284,7 @@ impl<'a> CoreReader<'a> {
             bytes,
             quote_char,
             eol_char,
+            self.parse_options.escape_char,
             self.schema.len(),
             self.skip_lines,
             self.skip_rows_before_header,
@@ -293,6 +311,9 @@ impl<'a> CoreReader<'a> {
             .unwrap_or_else(|| Ok((0..self.schema.len()).collect()))
     }
 
+    /// Returns the rejected rows if `rejects` is given. `first_line` is the line number of the
+    /// start of `bytes`.
+    #[allow(clippy::too_many_arguments)]
     fn read_chunk(
         &self,
         bytes: &[u8],
@@ -301,7 +322,9 @@ impl<'a> CoreReader<'a> {
         capacity: usize,
         starting_point_offset: Option<usize>,
         stop_at_nbytes: usize,
-    ) -> PolarsResult<DataFrame> {
+        mut rejects: Option<ChunkRejects>,
+        first_line: usize,
+    ) -> PolarsResult<(DataFrame, Option<DataFrame>)> {
         let mut df = read_chunk(
             bytes,
             &self.parse_options,
@@ -314,20 +337,52 @@ impl<'a> CoreReader<'a> {
             usize::MAX,
             stop_at_nbytes,
             starting_point_offset,
+            rejects.as_mut(),
         )?;
 
-        cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
-        Ok(df)
+        let Some(rejects) = rejects else {
+            cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
+            return Ok((df, None));
+        };
+
+        let rejected = rejects.finish(
+            &mut df,
+            &self.to_cast,
+            bytes,
+            self.parse_options.eol_char,
+            first_line,
+            self.path.as_deref(),
+        )?;
+        Ok((df, Some(rejected)))
     }
 
-    fn parse_csv(&mut self, bytes: &[u8]) -> PolarsResult<DataFrame> {
+    /// Returns the rejected rows if `collect_rejects` is set.
+    fn parse_csv(&mut self, bytes: &[u8]) -> PolarsResult<(DataFrame, Option<DataFrame>)> {
+        let full_bytes = bytes;
         let (bytes, _) = self.find_starting_point(
             bytes,
             self.parse_options.quote_char,
             self.parse_options.eol_char,
         )?;
 
-        let projection = self.get_projection()?;
+        let mut projection = self.get_projection()?;
+        // All columns are parsed to find the rows that fail to parse, the projection is applied
+        // after the rejected rows have been removed.
+        let mut output_columns = None;
+        if self.collect_rejects && projection.len() != self.schema.len() {
+            output_columns = Some(
+                projection
+                    .iter()
+                    .map(|&i| self.schema.get_at_index(i).unwrap().0.clone())
+                    .collect::<Vec<_>>(),
+            );
+            projection = (0..self.schema.len()).collect();
+        }
+        let output_columns = output_columns.as_deref();
+        let empty_rejects = || {
+            self.collect_rejects
+                .then(|| DataFrame::empty_with_schema(&rejects_schema()))
+        };
 
         // An empty file with a schema should return an empty DataFrame with that schema
         if bytes.is_empty() {
@@ -345,10 +400,13 @@ impl<'a> CoreReader<'a> {
                         .collect::<Schema>(),
                 )
             };
+            if let Some(output_columns) = output_columns {
+                df = df.select(output_columns.iter().cloned())?;
+            }
             if let Some(ref row_index) = self.row_index {
                 df.insert_column(0, Series::new_empty(row_index.name.clone(), &IDX_DTYPE))?;
             }
-            return Ok(df);
+            return Ok((df, empty_rejects()));
         }
 
         let n_threads = self.n_threads.unwrap_or_else(|| POOL.current_num_threads());
@@ -388,10 +446,17 @@ impl<'a> CoreReader<'a> {
         #[cfg(target_family = "wasm")]
         let pool = &POOL;
 
-        let counter = CountLines::new(self.parse_options.quote_char, self.parse_options.eol_char);
+        let counter = CountLines::new(self.parse_options.quote_char, self.parse_options.eol_char)
+            .with_escape_char(self.parse_options.escape_char);
         let mut total_offset = 0;
         let check_utf8 = matches!(self.parse_options.encoding, CsvEncoding::Utf8)
             && self.schema.iter_fields().any(|f| f.dtype().is_string());
+        let eol_char = self.parse_options.eol_char;
+        // The line number of the start of the next chunk, used for rejected rows.
+        let mut first_line = 1;
+        if self.collect_rejects {
+            first_line += count_eol_chars(&full_bytes[..full_bytes.len() - bytes.len()], eol_char);
+        }
 
         pool.scope(|s| {
             loop {
@@ -428,8 +493,13 @@ impl<'a> CoreReader<'a> {
                     let results = results.clone();
                     let projection = projection.as_ref();
                     let slf = &(*self);
+                    let chunk_first_line = first_line;
+                    if slf.collect_rejects {
+                        first_line += count_eol_chars(b, eol_char);
+                    }
                     s.spawn(move |_| {
-                        if check_utf8 && !super::buffer::validate_utf8(b) {
+                        let is_valid_utf8 = !check_utf8 || super::buffer::validate_utf8(b);
+                        if !is_valid_utf8 && !slf.collect_rejects {
                             let mut results = results.lock().unwrap();
                             results.push((
                                 b.as_ptr() as usize,
@@ -438,9 +508,24 @@ impl<'a> CoreReader<'a> {
                             return;
                         }
 
+                        let rejects = slf
+                            .collect_rejects
+                            .then(|| ChunkRejects::new(!is_valid_utf8));
                         let result = slf
-                            .read_chunk(b, projection, 0, count, Some(0), b.len())
-                            .and_then(|mut df| {
+                            .read_chunk(
+                                b,
+                                projection,
+                                0,
+                                count,
+                                Some(0),
+                                b.len(),
+                                rejects,
+                                chunk_first_line,
+                            )
+                            .and_then(|(mut df, rejected)| {
+                                if let Some(output_columns) = output_columns {
+                                    df = df.select(output_columns.iter().cloned())?;
+                                }
                                 debug_assert!(df.height() <= count);
 
                                 if slf.n_rows.is_some() {
@@ -464,7 +549,7 @@ impl<'a> CoreReader<'a> {
                                     let mask = s.bool()?;
                                     df = df.filter(mask)?;
                                 }
-                                Ok(df)
+                                Ok((df, rejected))
                             });
 
                         results.lock().unwrap().push((b.as_ptr() as usize, result));
@@ -483,19 +568,27 @@ impl<'a> CoreReader<'a> {
         });
         let mut results = std::mem::take(&mut *results.lock().unwrap());
         results.sort_unstable_by_key(|k| k.0);
-        let mut dfs = results
+        let (mut dfs, rejects): (Vec<_>, Vec<_>) = results
             .into_iter()
             .map(|k| k.1)
-            .collect::<PolarsResult<Vec<_>>>()?;
+            .collect::<PolarsResult<Vec<_>>>()?
+            .into_iter()
+            .unzip();
 
         if let Some(rc) = &self.row_index {
             update_row_counts2(&mut dfs, rc.offset)
         };
-        accumulate_dataframes_vertical(dfs)
+        let rejects = self
+            .collect_rejects
+            .then(|| concat_rejects(rejects.into_iter().flatten().collect()))
+            .transpose()?;
+        Ok((accumulate_dataframes_vertical(dfs)?, rejects))
     }
 
     /// Read the csv into a DataFrame. The predicate can come from a lazy physical plan.
-    pub fn finish(mut self) -> PolarsResult<DataFrame> {
+    ///
+    /// Also returns the rejected rows if rows that fail to parse are rejected.
+    pub fn finish(mut self) -> PolarsResult<(DataFrame, Option<DataFrame>)> {
         #[cfg(feature = "dtype-categorical")]
         let mut _cat_lock = if self.has_categorical {
             Some(polars_core::StringCacheHolder::hold())
@@ -505,7 +598,7 @@ impl<'a> CoreReader<'a> {
 
         let reader_bytes = self.reader_bytes.take().unwrap();
 
-        let mut df = self.parse_csv(&reader_bytes)?;
+        let (mut df, rejects) = self.parse_csv(&reader_bytes)?;
 
         // if multi-threaded the n_rows was probabilistically determined.
         // Let's slice to correct number of rows if possible.
@@ -514,7 +607,7 @@ impl<'a> CoreReader<'a> {
                 df = df.slice(0, n_rows)
             }
         }
-        Ok(df)
+        Ok((df, rejects))
     }
 }
 
@@ -531,6 +624,7 @@ pub fn read_chunk(
     chunk_size: usize,
     stop_at_nbytes: usize,
     starting_point_offset: Option<usize>,
+    mut rejects: Option<&mut ChunkRejects>,
 ) -> PolarsResult<DataFrame> {
     let mut read = bytes_offset_thread;
     // There's an off-by-one error somewhere in the reading code, where it reads
@@ -543,6 +637,7 @@ pub fn read_chunk(
         capacity + 1,
         schema,
         parse_options.quote_char,
+        parse_options.escape_char,
         parse_options.encoding,
         parse_options.decimal_comma,
     )?;
@@ -558,7 +653,8 @@ pub fn read_chunk(
 
         last_read = read;
         let offset = read + starting_point_offset.unwrap();
-        read += parse_lines(
+        let n_rows_before = rejects.as_ref().map_or(0, |r| r.rows.len());
+        let n_read = parse_lines(
             local_bytes,
             parse_options,
             offset,
@@ -569,7 +665,17 @@ pub fn read_chunk(
             chunk_size,
             schema.len(),
             schema,
+            rejects.as_deref_mut(),
         )?;
+
+        // Make the byte ranges relative to `bytes`.
+        if let Some(rejects) = rejects.as_deref_mut() {
+            for row in &mut rejects.rows[n_rows_before..] {
+                row.start += read;
+                row.end += read;
+            }
+        }
+        read += n_read;
     }
 
     let columns = buffers
@@ -584,6 +690,7 @@ pub fn find_starting_point(
     mut bytes: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
     schema_len: usize,
     skip_lines: usize,
     skip_rows_before_header: usize,
@@ -611,7 +718,8 @@ pub fn find_starting_point(
 
     // skip 'n' leading rows
     if skip_rows_before_header > 0 {
-        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix);
+        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
+            .with_escape_char(escape_char);
         let mut current_line = &bytes[..0];
 
         for _ in 0..skip_rows_before_header {
@@ -633,11 +741,12 @@ pub fn find_starting_point(
 
     // skip header row
     if has_header {
-        bytes = skip_this_line(bytes, quote_char, eol_char);
+        bytes = skip_this_line(bytes, quote_char, eol_char, escape_char);
     }
     // skip 'n' rows following the header
     if skip_rows_after_header > 0 {
-        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix);
+        let mut split_lines = SplitLines::new(bytes, quote_char, eol_char, comment_prefix)
+            .with_escape_char(escape_char);
         let mut current_line = &bytes[..0];
 
         for _ in 0..skip_rows_after_header {
@@ -25,8 +25,9 @@ pub(crate) fn get_file_chunks_iterator(
     bytes: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
 ) {
-    let cl = CountLines::new(quote_char, eol_char);
+    let cl = CountLines::new(quote_char, eol_char).with_escape_char(escape_char);
 
     for _ in 0..n_chunks {
         let bytes = &bytes[*last_pos..];
@@ -80,6 +81,7 @@ struct ChunkOffsetIter<'a> {
     rows_per_batch: usize,
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
 }
 
 impl Iterator for ChunkOffsetIter<'_> {
@@ -100,6 +102,7 @@ impl Iterator for ChunkOffsetIter<'_> {
                     self.bytes,
                     self.quote_char,
                     self.eol_char,
+                    self.escape_char,
                 );
                 match self.offsets.pop_front() {
                     Some(offsets) => Some(offsets),
@@ -156,6 +159,7 @@ impl<'a> CoreReader<'a> {
             rows_per_batch: self.chunk_size,
             quote_char: self.parse_options.quote_char,
             eol_char: self.parse_options.eol_char,
+            escape_char: self.parse_options.escape_char,
         };
 
         let projection = self.get_projection()?;
@@ -249,6 +253,7 @@ impl BatchedCsvReader<'_> {
                         usize::MAX,
                         stop_at_nbytes,
                         self.starting_point_offset,
+                        None,
                     )?;
 
                     cast_columns(&mut df, &self.to_cast, false, self.ignore_errors)?;
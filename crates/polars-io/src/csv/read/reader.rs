@@ -6,6 +6,7 @@ use polars_core::prelude::*;
 use super::options::CsvReadOptions;
 use super::read_impl::CoreReader;
 use super::read_impl::batched::to_batched_owned;
+use super::rejects::CsvRejectsWriter;
 use super::{BatchedCsvReader, OwnedBatchedCsvReader};
 use crate::mmap::MmapBytesReader;
 use crate::path_utils::resolve_homedir;
@@ -105,7 +106,7 @@ impl CsvReadOptions {
 }
 
 impl<R: MmapBytesReader> CsvReader<R> {
-    fn core_reader(&mut self) -> PolarsResult<CoreReader> {
+    fn core_reader(&mut self, collect_rejects: bool) -> PolarsResult<CoreReader> {
         let reader_bytes = get_reader_bytes(&mut self.reader)?;
 
         let parse_options = self.options.get_parse_options();
@@ -131,13 +132,55 @@ impl<R: MmapBytesReader> CsvReader<R> {
             self.options.skip_rows_after_header,
             self.options.row_index.clone(),
             self.options.raise_if_empty,
+            collect_rejects,
+            self.options
+                .path
+                .as_ref()
+                .map(|p| p.to_string_lossy().into_owned()),
         )
     }
 
     pub fn batched_borrowed(&mut self) -> PolarsResult<BatchedCsvReader> {
-        let csv_reader = self.core_reader()?;
+        polars_ensure!(
+            self.options.rejects_path.is_none(),
+            InvalidOperation: "rejected rows cannot be captured by the batched CSV reader"
+        );
+        let csv_reader = self.core_reader(false)?;
         csv_reader.batched()
     }
+
+    fn finish_impl(
+        &mut self,
+        collect_rejects: bool,
+    ) -> PolarsResult<(DataFrame, Option<DataFrame>)> {
+        let rechunk = self.options.rechunk;
+        let low_memory = self.options.low_memory;
+
+        let csv_reader = self.core_reader(collect_rejects)?;
+        let (mut df, rejects) = csv_reader.finish()?;
+
+        // Important that this rechunk is never done in parallel.
+        // As that leads to great memory overhead.
+        if rechunk && df.first_col_n_chunks() > 1 {
+            if low_memory {
+                df.as_single_chunk();
+            } else {
+                df.as_single_chunk_par();
+            }
+        }
+
+        Ok((df, rejects))
+    }
+
+    /// Read the file and create the DataFrame, the rows that fail to parse or cast are removed
+    /// and returned in a second DataFrame.
+    ///
+    /// The rejected rows have the columns `file`, `line`, `raw_line` and `error`. The line
+    /// numbers are 1-based and count the lines of the decompressed and transcoded data.
+    pub fn finish_with_rejects(mut self) -> PolarsResult<(DataFrame, DataFrame)> {
+        let (df, rejects) = self.finish_impl(true)?;
+        Ok((df, rejects.unwrap()))
+    }
 }
 
 impl CsvReader<Box<dyn MmapBytesReader>> {
@@ -167,22 +210,12 @@ where
 
     /// Read the file and create the DataFrame.
     fn finish(mut self) -> PolarsResult<DataFrame> {
-        let rechunk = self.options.rechunk;
-        let low_memory = self.options.low_memory;
+        let rejects_path = self.options.rejects_path.clone();
+        let (df, rejects) = self.finish_impl(rejects_path.is_some())?;
 
-        let csv_reader = self.core_reader()?;
-        let mut df = csv_reader.finish()?;
-
-        // Important that this rechunk is never done in parallel.
-        // As that leads to great memory overhead.
-        if rechunk && df.first_col_n_chunks() > 1 {
-            if low_memory {
-                df.as_single_chunk();
-            } else {
-                df.as_single_chunk_par();
-            }
+        if let (Some(rejects_path), Some(rejects)) = (rejects_path, rejects) {
+            CsvRejectsWriter::new(rejects_path).write(&rejects)?;
         }
-
         Ok(df)
     }
 }
@@ -0,0 +1,194 @@
+//! Capturing of the rows that fail to parse or cast.
+//!
+//! Instead of failing the read, these rows are removed from the output and collected into a
+//! rejects frame with the source file, line number, raw line and error message of every row.
+use std::fs::File;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical;
+
+use super::read_impl::cast_column;
+use crate::SerWriter;
+use crate::csv::write::CsvWriter;
+use crate::path_utils::resolve_homedir;
+
+/// The schema of a rejects frame.
+pub fn rejects_schema() -> Schema {
+    Schema::from_iter([
+        Field::new(PlSmallStr::from_static("file"), DataType::String),
+        Field::new(PlSmallStr::from_static("line"), DataType::UInt64),
+        Field::new(PlSmallStr::from_static("raw_line"), DataType::Binary),
+        Field::new(PlSmallStr::from_static("error"), DataType::String),
+    ])
+}
+
+/// Count the occurrences of `eol_char` in `bytes`, including the ones in quoted fields.
+pub fn count_eol_chars(bytes: &[u8], eol_char: u8) -> usize {
+    memchr::memchr_iter(eol_char, bytes).count()
+}
+
+/// The rows of a chunk that failed to parse.
+#[derive(Debug, Default)]
+pub struct ChunkRejects {
+    /// Validate that every field is UTF-8. Set for chunks that contain invalid UTF-8.
+    pub(super) validate_utf8: bool,
+    /// Byte range of every row in the chunk.
+    pub(super) rows: Vec<Range<usize>>,
+    /// Index and error message of the rows that failed to parse.
+    pub(super) errors: Vec<(usize, String)>,
+}
+
+impl ChunkRejects {
+    pub fn new(validate_utf8: bool) -> Self {
+        Self {
+            validate_utf8,
+            ..Default::default()
+        }
+    }
+
+    /// Cast the columns in `to_cast`, reject the rows with a value that fails to cast and remove
+    /// all rejected rows from `df`.
+    ///
+    /// Returns the rejected rows. `bytes` is the chunk `df` was parsed from, `first_line` the
+    /// line number of its first line.
+    pub fn finish(
+        mut self,
+        df: &mut DataFrame,
+        to_cast: &[Field],
+        bytes: &[u8],
+        eol_char: u8,
+        first_line: usize,
+        file: Option<&str>,
+    ) -> PolarsResult<DataFrame> {
+        debug_assert_eq!(self.rows.len(), df.height());
+
+        let mut is_rejected = vec![false; df.height()];
+        for &(i, _) in &self.errors {
+            is_rejected[i] = true;
+        }
+
+        for fld in to_cast {
+            // field may not be projected
+            let Some(idx) = df.get_column_index(fld.name()) else {
+                continue;
+            };
+            let c = &df.get_columns()[idx];
+            let out = cast_column(c, fld, true)?;
+
+            if c.null_count() != out.null_count() {
+                let failed = c.is_not_null() & out.is_null();
+                for (i, failed) in failed.into_iter().enumerate() {
+                    if failed == Some(true) && !is_rejected[i] {
+                        let value = c.get(i)?;
+                        let value = value
+                            .get_str()
+                            .map_or_else(|| value.to_string(), Into::into);
+                        self.errors.push((
+                            i,
+                            format!(
+                                "could not cast `{}` to dtype `{}` at column '{}'",
+                                value,
+                                fld.dtype(),
+                                fld.name()
+                            ),
+                        ));
+                        is_rejected[i] = true;
+                    }
+                }
+            }
+            df.replace_column(idx, out)?;
+        }
+
+        if self.errors.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&rejects_schema()));
+        }
+
+        let mask: BooleanChunked = is_rejected.iter().map(|r| !r).collect();
+        *df = df.filter(&mask)?;
+
+        // Determine the line numbers by counting the line ends from the start of the chunk.
+        self.errors.sort_by_key(|(i, _)| *i);
+        let mut line = first_line;
+        let mut counted_up_to = 0;
+
+        let n_rejects = self.errors.len();
+        let mut lines = Vec::with_capacity(n_rejects);
+        let mut raw_lines = Vec::with_capacity(n_rejects);
+        let mut errors = Vec::with_capacity(n_rejects);
+
+        for (i, error) in self.errors {
+            let Range { start, end } = self.rows[i].clone();
+            line += count_eol_chars(&bytes[counted_up_to..start], eol_char);
+            counted_up_to = start;
+
+            let mut raw_line = &bytes[start..end];
+            raw_line = raw_line.strip_suffix(&[eol_char]).unwrap_or(raw_line);
+            if eol_char == b'\n' {
+                raw_line = raw_line.strip_suffix(b"\r").unwrap_or(raw_line);
+            }
+
+            lines.push(line as u64);
+            raw_lines.push(raw_line);
+            errors.push(error);
+        }
+
+        DataFrame::new(vec![
+            Column::new("file".into(), vec![file; n_rejects]),
+            Column::new("line".into(), lines),
+            Column::new("raw_line".into(), raw_lines),
+            Column::new("error".into(), errors),
+        ])
+    }
+}
+
+/// Concatenate the rejects of the chunks of a file and sort them by line number.
+pub fn concat_rejects(rejects: Vec<DataFrame>) -> PolarsResult<DataFrame> {
+    let rejects: Vec<_> = rejects.into_iter().filter(|df| df.height() > 0).collect();
+    if rejects.is_empty() {
+        return Ok(DataFrame::empty_with_schema(&rejects_schema()));
+    }
+
+    accumulate_dataframes_vertical(rejects)?.sort(["line"], SortMultipleOptions::default())
+}
+
+/// Writes rejected rows to a CSV file. The file is created by the first write, later writes
+/// append to it. The raw lines are written as text, invalid UTF-8 is replaced with �.
+#[derive(Debug)]
+pub struct CsvRejectsWriter {
+    path: PathBuf,
+    file: Mutex<Option<File>>,
+}
+
+impl CsvRejectsWriter {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            file: Mutex::new(None),
+        }
+    }
+
+    pub fn write(&self, rejects: &DataFrame) -> PolarsResult<()> {
+        let mut file = self.file.lock().unwrap();
+        let include_header = file.is_none();
+        if include_header {
+            *file = Some(polars_utils::create_file(&resolve_homedir(&self.path))?);
+        }
+
+        let mut rejects = rejects.clone();
+        rejects.try_apply("raw_line", |s| {
+            Ok(s.binary()?
+                .into_iter()
+                .map(|v| v.map(String::from_utf8_lossy))
+                .collect::<StringChunked>()
+                .with_name(s.name().clone())
+                .into_series())
+        })?;
+
+        CsvWriter::new(file.as_mut().unwrap())
+            .include_header(include_header)
+            .finish(&mut rejects)
+    }
+}
@@ -86,6 +86,27 @@ impl CsvReadOptions {
     }
 }
 
+/// Returns the number of lines at the start of a CSV file that are read to infer its schema from
+/// `infer_schema_length` rows, or `None` if the whole file may be read.
+pub fn n_lines_for_schema_inference(
+    options: &CsvReadOptions,
+    infer_schema_length: Option<usize>,
+) -> Option<usize> {
+    // Comment lines are skipped during inference, so we cannot know how many lines are needed.
+    if options.parse_options.comment_prefix.is_some() {
+        return None;
+    }
+
+    infer_schema_length.map(|n| {
+        n.saturating_add(options.skip_lines)
+            .saturating_add(options.skip_rows)
+            .saturating_add(options.skip_rows_after_header)
+            .saturating_add(usize::from(options.has_header))
+            // A line ending at the start of the file is skipped.
+            .saturating_add(1)
+    })
+}
+
 pub fn finish_infer_field_schema(possibilities: &PlHashSet<DataType>) -> DataType {
     // determine data type based on possible types
     // if there are incompatible types, use DataType::String
@@ -171,7 +192,12 @@ pub fn infer_field_schema(string: &str, try_parse_dates: bool, decimal_comma: bo
 #[inline]
 fn parse_bytes_with_encoding(bytes: &[u8], encoding: CsvEncoding) -> PolarsResult<Cow<str>> {
     Ok(match encoding {
-        CsvEncoding::Utf8 => simdutf8::basic::from_utf8(bytes)
+        // Other encodings have been transcoded to UTF-8 at this point.
+        CsvEncoding::Utf8
+        | CsvEncoding::Latin1
+        | CsvEncoding::Windows1252
+        | CsvEncoding::Utf16Le
+        | CsvEncoding::Utf16Be => simdutf8::basic::from_utf8(bytes)
             .map_err(|_| polars_err!(ComputeError: "invalid utf-8 sequence"))?
             .into(),
         CsvEncoding::LossyUtf8 => String::from_utf8_lossy(bytes),
@@ -213,6 +239,7 @@ fn infer_file_schema_inner(
         parse_options.eol_char,
         parse_options.comment_prefix.as_ref(),
     )
+    .with_escape_char(parse_options.escape_char)
     .skip(skip_rows);
 
     // get or create header names
@@ -246,9 +273,10 @@ fn infer_file_schema_inner(
 
         let byterecord = SplitFields::new(
             header_line,
-            parse_options.separator,
+            parse_options.separator.as_bytes(),
             parse_options.quote_char,
             parse_options.eol_char,
+            parse_options.escape_char,
         );
         if has_header {
             let headers = byterecord
@@ -314,6 +342,7 @@ fn infer_file_schema_inner(
             parse_options.eol_char,
             parse_options.comment_prefix.as_ref(),
         )
+        .with_escape_char(parse_options.escape_char)
         .skip(skip_rows);
     }
 
@@ -370,9 +399,10 @@ fn infer_file_schema_inner(
 
         let record = SplitFields::new(
             line,
-            parse_options.separator,
+            parse_options.separator.as_bytes(),
             parse_options.quote_char,
             parse_options.eol_char,
+            parse_options.escape_char,
         );
 
         for (i, (slice, needs_escaping)) in record.enumerate() {
@@ -508,9 +538,36 @@ fn infer_file_schema_inner(
     Ok((Schema::from_iter(fields), rows_count, end_ptr - start_ptr))
 }
 
-pub(super) fn check_decimal_comma(decimal_comma: bool, separator: u8) -> PolarsResult<()> {
+pub(super) fn check_decimal_comma(decimal_comma: bool, separator: &[u8]) -> PolarsResult<()> {
     if decimal_comma {
-        polars_ensure!(b',' != separator, InvalidOperation: "'decimal_comma' argument cannot be combined with ',' separator")
+        polars_ensure!(b"," != separator, InvalidOperation: "'decimal_comma' argument cannot be combined with ',' separator")
+    }
+    Ok(())
+}
+
+pub(super) fn check_separator(parse_options: &CsvParseOptions) -> PolarsResult<()> {
+    let separator = parse_options.separator.as_bytes();
+    polars_ensure!(!separator.is_empty(), InvalidOperation: "'separator' cannot be empty");
+    polars_ensure!(
+        !separator.contains(&parse_options.eol_char),
+        InvalidOperation: "'separator' cannot contain the end of line character"
+    );
+    for (name, c) in [
+        ("quote_char", parse_options.quote_char),
+        ("escape_char", parse_options.escape_char),
+    ] {
+        if let Some(c) = c {
+            polars_ensure!(
+                !separator.contains(&c),
+                InvalidOperation: "'separator' cannot contain the '{}' character", name
+            );
+        }
+    }
+    if let Some(escape_char) = parse_options.escape_char {
+        polars_ensure!(
+            escape_char != parse_options.eol_char,
+            InvalidOperation: "'escape_char' cannot be the end of line character"
+        );
     }
     Ok(())
 }
@@ -536,7 +593,11 @@ pub fn infer_file_schema(
     skip_rows_after_header: usize,
     raise_if_empty: bool,
 ) -> PolarsResult<(Schema, usize, usize)> {
-    check_decimal_comma(parse_options.decimal_comma, parse_options.separator)?;
+    check_separator(parse_options)?;
+    check_decimal_comma(
+        parse_options.decimal_comma,
+        parse_options.separator.as_bytes(),
+    )?;
 
     if skip_lines > 0 {
         polars_ensure!(skip_rows == 0, InvalidOperation: "only one of 'skip_rows'/'skip_lines' may be set");
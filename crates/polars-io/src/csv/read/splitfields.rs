@@ -10,22 +10,27 @@ mod inner {
         quote_char: u8,
         quoting: bool,
         eol_char: u8,
+        /// Set if the separator has multiple bytes or an escape character is used, these
+        /// fields are found by [`super::GeneralSplit::find_field_end`].
+        general: Option<super::GeneralSplit<'a>>,
     }
 
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            separator: u8,
+            separator: &'a [u8],
             quote_char: Option<u8>,
             eol_char: u8,
+            escape_char: Option<u8>,
         ) -> Self {
             Self {
                 v: slice,
-                separator,
+                separator: separator[0],
                 finished: false,
                 quote_char: quote_char.unwrap_or(b'"'),
                 quoting: quote_char.is_some(),
                 eol_char,
+                general: super::GeneralSplit::opt_new(separator, escape_char),
             }
         }
 
@@ -47,6 +52,29 @@ mod inner {
         fn eof_oel(&self, current_ch: u8) -> bool {
             current_ch == self.separator || current_ch == self.eol_char
         }
+
+        fn next_general(&mut self, general: super::GeneralSplit<'a>) -> Option<(&'a [u8], bool)> {
+            if self.v.is_empty() {
+                return self.finish(false);
+            }
+            let needs_escaping = self.quoting && self.v[0] == self.quote_char;
+            let end = general.find_field_end(
+                self.v,
+                needs_escaping.then_some(self.quote_char),
+                self.eol_char,
+            );
+            match end {
+                super::FieldEnd::Eof => self.finish(needs_escaping),
+                // SAFETY:
+                // the position is in bounds
+                super::FieldEnd::Eol(pos) => unsafe { self.finish_eol(needs_escaping, pos) },
+                super::FieldEnd::Separator(pos) => {
+                    let ret = Some((&self.v[..pos], needs_escaping));
+                    self.v = &self.v[pos + general.separator.len()..];
+                    ret
+                },
+            }
+        }
     }
 
     impl<'a> Iterator for SplitFields<'a> {
@@ -57,6 +85,8 @@ mod inner {
         fn next(&mut self) -> Option<(&'a [u8], bool)> {
             if self.finished {
                 return None;
+            } else if let Some(general) = self.general {
+                return self.next_general(general);
             } else if self.v.is_empty() {
                 return self.finish(false);
             }
@@ -157,15 +187,21 @@ mod inner {
         simd_eol_char: SimdVec,
         simd_quote_char: SimdVec,
         previous_valid_ends: u64,
+        /// Set if the separator has multiple bytes or an escape character is used, these
+        /// fields are found by [`super::GeneralSplit::find_field_end`].
+        general: Option<super::GeneralSplit<'a>>,
     }
 
     impl<'a> SplitFields<'a> {
         pub(crate) fn new(
             slice: &'a [u8],
-            separator: u8,
+            separator: &'a [u8],
             quote_char: Option<u8>,
             eol_char: u8,
+            escape_char: Option<u8>,
         ) -> Self {
+            let general = super::GeneralSplit::opt_new(separator, escape_char);
+            let separator = separator[0];
             let simd_separator = SimdVec::splat(separator);
             let simd_eol_char = SimdVec::splat(eol_char);
             let quoting = quote_char.is_some();
@@ -183,6 +219,7 @@ mod inner {
                 simd_eol_char,
                 simd_quote_char,
                 previous_valid_ends: 0,
+                general,
             }
         }
 
@@ -205,6 +242,29 @@ mod inner {
         fn eof_oel(&self, current_ch: u8) -> bool {
             current_ch == self.separator || current_ch == self.eol_char
         }
+
+        fn next_general(&mut self, general: super::GeneralSplit<'a>) -> Option<(&'a [u8], bool)> {
+            if self.v.is_empty() {
+                return self.finish(false);
+            }
+            let needs_escaping = self.quoting && self.v[0] == self.quote_char;
+            let end = general.find_field_end(
+                self.v,
+                needs_escaping.then_some(self.quote_char),
+                self.eol_char,
+            );
+            match end {
+                super::FieldEnd::Eof => self.finish(needs_escaping),
+                // SAFETY:
+                // the position is in bounds
+                super::FieldEnd::Eol(pos) => unsafe { self.finish_eol(needs_escaping, pos) },
+                super::FieldEnd::Separator(pos) => {
+                    let ret = Some((&self.v[..pos], needs_escaping));
+                    self.v = &self.v[pos + general.separator.len()..];
+                    ret
+                },
+            }
+        }
     }
 
     impl<'a> Iterator for SplitFields<'a> {
@@ -217,6 +277,9 @@ mod inner {
             if self.finished {
                 return None;
             }
+            if let Some(general) = self.general {
+                return self.next_general(general);
+            }
             // Then check cached value as this is hot.
             if self.previous_valid_ends != 0 {
                 let pos = self.previous_valid_ends.trailing_zeros() as usize;
@@ -408,6 +471,59 @@ mod inner {
 
 pub(crate) use inner::SplitFields;
 
+/// Splitting state for separators of multiple bytes and escape characters, these cannot use
+/// the (SIMD) byte search of the fast path.
+#[derive(Clone, Copy)]
+struct GeneralSplit<'a> {
+    separator: &'a [u8],
+    escape_char: Option<u8>,
+}
+
+enum FieldEnd {
+    /// The field ends at the separator at this position.
+    Separator(usize),
+    /// The field ends at the end of line character at this position.
+    Eol(usize),
+    /// The field ends at the end of the slice.
+    Eof,
+}
+
+impl<'a> GeneralSplit<'a> {
+    fn opt_new(separator: &'a [u8], escape_char: Option<u8>) -> Option<Self> {
+        (separator.len() > 1 || escape_char.is_some()).then_some(Self {
+            separator,
+            escape_char,
+        })
+    }
+
+    /// Find the end of the field at the start of `v`, `quote_char` is only given if the field
+    /// is quoted.
+    fn find_field_end(&self, v: &[u8], quote_char: Option<u8>, eol_char: u8) -> FieldEnd {
+        let mut in_field = false;
+        let mut idx = 0;
+        while idx < v.len() {
+            let c = v[idx];
+            if Some(c) == self.escape_char {
+                // The next character is a literal.
+                idx += 2;
+                continue;
+            }
+            if Some(c) == quote_char {
+                in_field = !in_field;
+            } else if !in_field {
+                if c == eol_char {
+                    return FieldEnd::Eol(idx);
+                }
+                if v[idx..].starts_with(self.separator) {
+                    return FieldEnd::Separator(idx);
+                }
+            }
+            idx += 1;
+        }
+        FieldEnd::Eof
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::SplitFields;
@@ -415,18 +531,37 @@ mod test {
     #[test]
     fn test_splitfields() {
         let input = "\"foo\",\"bar\"";
-        let mut fields = SplitFields::new(input.as_bytes(), b',', Some(b'"'), b'\n');
+        let mut fields = SplitFields::new(input.as_bytes(), b",", Some(b'"'), b'\n', None);
 
         assert_eq!(fields.next(), Some(("\"foo\"".as_bytes(), true)));
         assert_eq!(fields.next(), Some(("\"bar\"".as_bytes(), true)));
         assert_eq!(fields.next(), None);
 
         let input2 = "\"foo\n bar\";\"baz\";12345";
-        let mut fields2 = SplitFields::new(input2.as_bytes(), b';', Some(b'"'), b'\n');
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b";", Some(b'"'), b'\n', None);
 
         assert_eq!(fields2.next(), Some(("\"foo\n bar\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("\"baz\"".as_bytes(), true)));
         assert_eq!(fields2.next(), Some(("12345".as_bytes(), false)));
         assert_eq!(fields2.next(), None);
     }
+
+    #[test]
+    fn test_splitfields_multi_byte_separator_and_escape_char() {
+        let input = "a||\"b||c\"||d\\||e||\n";
+        let mut fields = SplitFields::new(input.as_bytes(), b"||", Some(b'"'), b'\n', Some(b'\\'));
+
+        assert_eq!(fields.next(), Some(("a".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("\"b||c\"".as_bytes(), true)));
+        assert_eq!(fields.next(), Some(("d\\||e".as_bytes(), false)));
+        assert_eq!(fields.next(), Some(("".as_bytes(), false)));
+        assert_eq!(fields.next(), None);
+
+        let input2 = "\"a\\\"b\",c\\\nd";
+        let mut fields2 = SplitFields::new(input2.as_bytes(), b",", Some(b'"'), b'\n', Some(b'\\'));
+
+        assert_eq!(fields2.next(), Some(("\"a\\\"b\"".as_bytes(), true)));
+        assert_eq!(fields2.next(), Some(("c\\\nd".as_bytes(), false)));
+        assert_eq!(fields2.next(), None);
+    }
 }
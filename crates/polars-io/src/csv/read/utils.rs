@@ -1,12 +1,10 @@
 #![allow(unsafe_op_in_unsafe_fn)]
-#[cfg(feature = "decompress")]
 use std::io::Read;
 use std::mem::MaybeUninit;
 
-use super::parser::next_line_position;
-#[cfg(feature = "decompress")]
-use super::parser::next_line_position_naive;
-use super::splitfields::SplitFields;
+use super::encoding::TranscodingReader;
+use super::options::CsvEncoding;
+use super::parser::{CountLines, next_line_position};
 
 /// TODO: Remove this in favor of parallel CountLines::analyze_chunk
 ///
@@ -15,9 +13,10 @@ pub(crate) fn get_file_chunks(
     bytes: &[u8],
     n_chunks: usize,
     expected_fields: Option<usize>,
-    separator: u8,
+    separator: &[u8],
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
 ) -> Vec<(usize, usize)> {
     let mut last_pos = 0;
     let total_len = bytes.len();
@@ -36,6 +35,7 @@ pub(crate) fn get_file_chunks(
             separator,
             quote_char,
             eol_char,
+            escape_char,
         ) {
             Some(pos) => search_pos + pos,
             None => {
@@ -49,13 +49,12 @@ pub(crate) fn get_file_chunks(
     offsets
 }
 
-#[cfg(feature = "decompress")]
 fn decompress_impl<R: Read>(
     decoder: &mut R,
     n_rows: Option<usize>,
-    separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
 ) -> Option<Vec<u8>> {
     let chunk_size = 4096;
     Some(match n_rows {
@@ -67,92 +66,58 @@ fn decompress_impl<R: Read>(
             out
         },
         Some(n_rows) => {
-            // we take the first rows first '\n\
+            // Keep decoding until we have at least `n_rows` complete lines, lines within quoted
+            // fields are not counted. The parser stops after `n_rows`, so the output may
+            // contain more lines.
+            let line_counter = CountLines::new(quote_char, eol_char).with_escape_char(escape_char);
             let mut out = vec![];
-            let mut expected_fields = 0;
-            // make sure that we have enough bytes to decode the header (even if it has embedded new line chars)
-            // those extra bytes in the buffer don't matter, we don't need to track them
+            let mut line_count = 0;
+            // Offset after the last line end found so far.
+            let mut line_end = 0;
             loop {
                 let read = decoder.take(chunk_size).read_to_end(&mut out).ok()?;
+                // we depleted the reader
                 if read == 0 {
                     break;
                 }
-                if next_line_position_naive(&out, eol_char).is_some() {
-                    // an extra shot
-                    let read = decoder.take(chunk_size).read_to_end(&mut out).ok()?;
-                    if read == 0 {
-                        break;
-                    }
-                    // now that we have enough, we compute the number of fields (also takes embedding into account)
-                    expected_fields =
-                        SplitFields::new(&out, separator, quote_char, eol_char).count();
+
+                let (count, position) = line_counter.count(&out[line_end..]);
+                if count > 0 {
+                    line_count += count;
+                    line_end += position + 1;
+                }
+                if line_count >= n_rows {
+                    out.truncate(line_end); // retain only the complete lines in out
                     break;
                 }
             }
-
-            let mut line_count = 0;
-            let mut buf_pos = 0;
-            // keep decoding bytes and count lines
-            // keep track of the n_rows we read
-            while line_count < n_rows {
-                match next_line_position(
-                    &out[buf_pos + 1..],
-                    Some(expected_fields),
-                    separator,
-                    quote_char,
-                    eol_char,
-                ) {
-                    Some(pos) => {
-                        line_count += 1;
-                        buf_pos += pos;
-                    },
-                    None => {
-                        // take more bytes so that we might find a new line the next iteration
-                        let read = decoder.take(chunk_size).read_to_end(&mut out).ok()?;
-                        // we depleted the reader
-                        if read == 0 {
-                            break;
-                        }
-                        continue;
-                    },
-                };
-            }
-            if line_count == n_rows {
-                out.truncate(buf_pos); // retain only first n_rows in out
-            }
             out
         },
     })
 }
 
-#[cfg(feature = "decompress")]
-pub(crate) fn decompress(
+/// Decompress `bytes` if compression is detected and transcode them to UTF-8 if `encoding`
+/// requires it. Returns `None` if neither is needed.
+pub(crate) fn decompress_and_transcode(
     bytes: &[u8],
     n_rows: Option<usize>,
-    separator: u8,
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
+    encoding: CsvEncoding,
 ) -> Option<Vec<u8>> {
     use crate::utils::compression::SupportedCompression;
 
-    if let Some(algo) = SupportedCompression::check(bytes) {
-        match algo {
-            SupportedCompression::GZIP => {
-                let mut decoder = flate2::read::MultiGzDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZLIB => {
-                let mut decoder = flate2::read::ZlibDecoder::new(bytes);
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-            SupportedCompression::ZSTD => {
-                let mut decoder = zstd::Decoder::with_buffer(bytes).ok()?;
-                decompress_impl(&mut decoder, n_rows, separator, quote_char, eol_char)
-            },
-        }
-    } else {
-        None
+    let mut reader: Box<dyn Read + Send + '_> = match SupportedCompression::check(bytes) {
+        #[cfg(feature = "decompress")]
+        Some(algo) => algo.decoder(bytes).ok()?,
+        _ if !encoding.requires_transcoding() => return None,
+        _ => Box::new(bytes),
+    };
+    if encoding.requires_transcoding() {
+        reader = Box::new(TranscodingReader::new(reader, encoding));
     }
+    decompress_impl(&mut reader, n_rows, quote_char, eol_char, escape_char)
 }
 
 /// replace double quotes by single ones
@@ -188,6 +153,54 @@ pub(super) unsafe fn escape_field(bytes: &[u8], quote: u8, buf: &mut [MaybeUnini
     count
 }
 
+/// Write a field with an escape character to `buf`, the character after an escape character is
+/// written literally. Quoted fields also have their enclosing quotes removed and doubled quotes
+/// replaced by single ones.
+///
+/// # Safety
+///
+/// The caller must ensure that the output buffer has enough capacity to hold `bytes.len()`.
+pub(super) unsafe fn unescape_field(
+    bytes: &[u8],
+    quote: Option<u8>,
+    escape: u8,
+    buf: &mut [MaybeUninit<u8>],
+) -> usize {
+    let quoted = quote.is_some() && bytes.first() == quote.as_ref();
+    let inner = if quoted && bytes.len() > 1 && bytes.last() == quote.as_ref() {
+        bytes.get_unchecked(1..bytes.len() - 1)
+    } else if quoted {
+        bytes.get_unchecked(1..)
+    } else {
+        bytes
+    };
+
+    let mut prev_quote = false;
+    let mut count = 0;
+    let mut iter = inner.iter();
+    while let Some(&c) = iter.next() {
+        let c = if c == escape {
+            prev_quote = false;
+            match iter.next() {
+                Some(&c) => c,
+                None => break,
+            }
+        } else if quoted && Some(c) == quote {
+            prev_quote = !prev_quote;
+            if prev_quote {
+                continue;
+            }
+            c
+        } else {
+            prev_quote = false;
+            c
+        };
+        buf.get_unchecked_mut(count).write(c);
+        count += 1;
+    }
+    count
+}
+
 #[cfg(test)]
 mod test {
     use super::get_file_chunks;
@@ -199,10 +212,12 @@ mod test {
         let bytes = s.as_bytes();
         // can be within -1 / +1 bounds.
         assert!(
-            (get_file_chunks(bytes, 10, Some(4), b',', None, b'\n').len() as i32 - 10).abs() <= 1
+            (get_file_chunks(bytes, 10, Some(4), b",", None, b'\n', None).len() as i32 - 10).abs()
+                <= 1
         );
         assert!(
-            (get_file_chunks(bytes, 8, Some(4), b',', None, b'\n').len() as i32 - 8).abs() <= 1
+            (get_file_chunks(bytes, 8, Some(4), b",", None, b'\n', None).len() as i32 - 8).abs()
+                <= 1
         );
     }
 }
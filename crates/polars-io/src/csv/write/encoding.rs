@@ -0,0 +1,80 @@
+use std::io::Write;
+
+use polars_error::{PolarsResult, polars_ensure};
+
+use crate::csv::read::CsvEncoding;
+
+/// A writer that encodes the UTF-8 data written to it in another encoding.
+pub(crate) struct TranscodingWriter<W: Write> {
+    inner: W,
+    encoding: CsvEncoding,
+    /// The start of a character that was split between writes.
+    partial: Vec<u8>,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> TranscodingWriter<W> {
+    pub(crate) fn new(inner: W, encoding: CsvEncoding) -> Self {
+        Self {
+            inner,
+            encoding,
+            partial: vec![],
+            buf: vec![],
+        }
+    }
+
+    /// Return the inner writer.
+    pub(crate) fn finish(self) -> PolarsResult<W> {
+        polars_ensure!(
+            self.partial.is_empty(),
+            ComputeError: "invalid utf-8 sequence"
+        );
+        Ok(self.inner)
+    }
+
+    fn encode(&mut self, s: &str) -> std::io::Result<()> {
+        self.buf.clear();
+        self.buf.reserve(s.len());
+        for c in s.chars() {
+            if !self.encoding.encode_char(c, &mut self.buf) {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    format!(
+                        "character {c:?} cannot be represented in the {:?} encoding",
+                        self.encoding
+                    ),
+                ));
+            }
+        }
+        self.inner.write_all(&self.buf)
+    }
+}
+
+impl<W: Write> Write for TranscodingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if !self.encoding.requires_transcoding() {
+            return self.inner.write(buf);
+        }
+
+        let mut bytes = std::mem::take(&mut self.partial);
+        bytes.extend_from_slice(buf);
+
+        let valid_up_to = match std::str::from_utf8(&bytes) {
+            Ok(_) => bytes.len(),
+            // The last character is incomplete.
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(e) => {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, e));
+            },
+        };
+        // SAFETY: validated above.
+        self.encode(unsafe { std::str::from_utf8_unchecked(&bytes[..valid_up_to]) })?;
+        self.partial = bytes.split_off(valid_up_to);
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -17,6 +17,7 @@
 //! }
 //! ```
 
+mod encoding;
 mod options;
 mod write_impl;
 mod writer;
@@ -1,8 +1,12 @@
 use std::num::NonZeroUsize;
 
+use polars_error::{PolarsResult, polars_ensure};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+use crate::csv::read::{CsvEncoding, Separator};
+use crate::utils::compression::ExternalCompression;
+
 /// Options for writing CSV files.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -12,6 +16,8 @@ pub struct CsvWriterOptions {
     pub include_header: bool,
     pub batch_size: NonZeroUsize,
     pub serialize_options: SerializeOptions,
+    pub compression: ExternalCompression,
+    pub encoding: CsvEncoding,
 }
 
 impl Default for CsvWriterOptions {
@@ -21,10 +27,24 @@ impl Default for CsvWriterOptions {
             include_header: true,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             serialize_options: SerializeOptions::default(),
+            compression: ExternalCompression::default(),
+            encoding: CsvEncoding::default(),
         }
     }
 }
 
+impl CsvWriterOptions {
+    /// Check that the options can be used together.
+    pub fn validate(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.include_bom || self.encoding.supports_bom(),
+            InvalidOperation: "a BOM can only be written in a UTF-8 or UTF-16 encoding, got {:?}",
+            self.encoding
+        );
+        Ok(())
+    }
+}
+
 /// Options to serialize logical types to CSV.
 ///
 /// The default is to format times and dates as `chrono` crate formats them.
@@ -43,9 +63,12 @@ pub struct SerializeOptions {
     pub float_scientific: Option<bool>,
     pub float_precision: Option<usize>,
     /// Used as separator.
-    pub separator: u8,
+    pub separator: Separator,
     /// Quoting character.
     pub quote_char: u8,
+    /// Escape character, quotes and escape characters are prefixed with it instead of doubling
+    /// the quotes.
+    pub escape_char: Option<u8>,
     /// Null value representation.
     pub null: String,
     /// String appended after every row.
@@ -62,8 +85,9 @@ impl Default for SerializeOptions {
             datetime_format: None,
             float_scientific: None,
             float_precision: None,
-            separator: b',',
+            separator: Separator::default(),
             quote_char: b'"',
+            escape_char: None,
             null: String::new(),
             line_terminator: "\n".into(),
             quote_style: Default::default(),
@@ -92,5 +116,7 @@ pub enum QuoteStyle {
     /// quotes will be used even if they aren't strictly necessary.
     NonNumeric,
     /// Never quote any fields, even if it would produce invalid CSV data.
+    ///
+    /// If an escape character is set, separators, quotes and line breaks are escaped instead.
     Never,
 }
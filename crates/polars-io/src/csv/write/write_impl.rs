@@ -169,7 +169,7 @@ pub(crate) fn write<W: Write>(
             for _ in 0..len {
                 serializers[0].serialize(write_buffer, options);
                 for serializer in &mut serializers[1..] {
-                    write_buffer.push(options.separator);
+                    write_buffer.extend_from_slice(options.separator.as_bytes());
                     serializer.serialize(write_buffer, options);
                 }
 
@@ -221,7 +221,7 @@ pub(crate) fn write_header<W: Write>(
     for i in 0..names.len() {
         names_serializer.serialize(&mut header, options);
         if i != names.len() - 1 {
-            header.push(options.separator);
+            header.extend_from_slice(options.separator.as_bytes());
         }
     }
     header.extend_from_slice(options.line_terminator.as_bytes());
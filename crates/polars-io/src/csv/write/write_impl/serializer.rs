@@ -38,10 +38,11 @@ use arrow::legacy::time_zone::Tz;
 use arrow::types::NativeType;
 #[cfg(feature = "timezones")]
 use chrono::TimeZone;
-use memchr::{memchr_iter, memchr3};
+use memchr::{memchr_iter, memchr2, memchr3, memmem};
 use num_traits::NumCast;
 use polars_core::prelude::*;
 
+use crate::csv::read::Separator;
 use crate::csv::write::{QuoteStyle, SerializeOptions};
 
 const TOO_MANY_MSG: &str = "too many items requested from CSV serializer";
@@ -375,7 +376,18 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
         }
     }
 
-    fn serialize_str_escaped(buf: &mut Vec<u8>, s: &[u8], quote_char: u8, quoted: bool) {
+    fn serialize_str_escaped(
+        buf: &mut Vec<u8>,
+        s: &[u8],
+        quote_char: u8,
+        quoted: bool,
+        escape_char: Option<u8>,
+    ) {
+        if let Some(escape_char) = escape_char {
+            // Escaped quotes don't require quoting the field.
+            serialize_str_with_escape_char(buf, s, escape_char, |c| c == quote_char);
+            return;
+        }
         let mut iter = memchr_iter(quote_char, s);
         let first_quote = iter.next();
         match first_quote {
@@ -406,6 +418,22 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
         }
     }
 
+    /// Writes `s` with the escape character before the escape characters and the bytes for
+    /// which `needs_escape` returns true.
+    fn serialize_str_with_escape_char(
+        buf: &mut Vec<u8>,
+        s: &[u8],
+        escape_char: u8,
+        needs_escape: impl Fn(u8) -> bool,
+    ) {
+        for &c in s {
+            if c == escape_char || needs_escape(c) {
+                buf.push(escape_char);
+            }
+            buf.push(c);
+        }
+    }
+
     let iter = update(array);
     match options.quote_style {
         QuoteStyle::Always => {
@@ -418,7 +446,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                         buf.push(quote_char);
                         return;
                     };
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true, options.escape_char);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -436,7 +464,7 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
                     };
                     let quote_char = options.quote_char;
                     buf.push(quote_char);
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true);
+                    serialize_str_escaped(buf, s.as_bytes(), quote_char, true, options.escape_char);
                     buf.push(quote_char);
                 };
             Box::new(StringSerializer {
@@ -446,27 +474,46 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
             })
         },
         QuoteStyle::Necessary => {
-            let serialize =
-                move |iter: &mut Iter, buf: &mut Vec<u8>, options: &SerializeOptions| {
-                    let Some(s) = f(iter) else {
-                        buf.extend_from_slice(options.null.as_bytes());
-                        return;
-                    };
-                    let quote_char = options.quote_char;
-                    // An empty string conflicts with null, so it is necessary to quote.
-                    if s.is_empty() {
-                        buf.extend_from_slice(&[quote_char, quote_char]);
-                        return;
-                    }
-                    let needs_quote = memchr3(options.separator, LF, CR, s.as_bytes()).is_some();
-                    if needs_quote {
-                        buf.push(quote_char);
-                    }
-                    serialize_str_escaped(buf, s.as_bytes(), quote_char, needs_quote);
-                    if needs_quote {
-                        buf.push(quote_char);
-                    }
+            let serialize = move |iter: &mut Iter,
+                                  buf: &mut Vec<u8>,
+                                  options: &SerializeOptions| {
+                let Some(s) = f(iter) else {
+                    buf.extend_from_slice(options.null.as_bytes());
+                    return;
                 };
+                let quote_char = options.quote_char;
+                // An empty string conflicts with null, so it is necessary to quote.
+                if s.is_empty() {
+                    buf.extend_from_slice(&[quote_char, quote_char]);
+                    return;
+                }
+                let needs_quote = match &options.separator {
+                    Separator::Single(separator) => {
+                        memchr3(*separator, LF, CR, s.as_bytes()).is_some()
+                    },
+                    // The field must also be quoted if it ends with the start of the
+                    // separator, as the separator would be found too early.
+                    Separator::Multi(separator) => {
+                        let separator = separator.as_bytes();
+                        memchr2(LF, CR, s.as_bytes()).is_some()
+                            || memmem::find(s.as_bytes(), separator).is_some()
+                            || (1..separator.len()).any(|n| s.as_bytes().ends_with(&separator[..n]))
+                    },
+                };
+                if needs_quote {
+                    buf.push(quote_char);
+                }
+                serialize_str_escaped(
+                    buf,
+                    s.as_bytes(),
+                    quote_char,
+                    needs_quote,
+                    options.escape_char,
+                );
+                if needs_quote {
+                    buf.push(quote_char);
+                }
+            };
             Box::new(StringSerializer {
                 serialize,
                 update,
@@ -474,14 +521,23 @@ pub(super) fn string_serializer<'a, Iter: Send + 'a>(
             })
         },
         QuoteStyle::Never => {
-            let serialize =
-                move |iter: &mut Iter, buf: &mut Vec<u8>, options: &SerializeOptions| {
-                    let Some(s) = f(iter) else {
-                        buf.extend_from_slice(options.null.as_bytes());
-                        return;
-                    };
-                    buf.extend_from_slice(s.as_bytes());
+            let serialize = move |iter: &mut Iter,
+                                  buf: &mut Vec<u8>,
+                                  options: &SerializeOptions| {
+                let Some(s) = f(iter) else {
+                    buf.extend_from_slice(options.null.as_bytes());
+                    return;
                 };
+                match options.escape_char {
+                    Some(escape_char) => {
+                        let separator = options.separator.as_bytes();
+                        serialize_str_with_escape_char(buf, s.as_bytes(), escape_char, |c| {
+                            c == options.quote_char || c == LF || c == CR || separator.contains(&c)
+                        })
+                    },
+                    None => buf.extend_from_slice(s.as_bytes()),
+                }
+            };
             Box::new(StringSerializer {
                 serialize,
                 update,
@@ -702,6 +758,7 @@ mod test {
     use polars_core::prelude::ArrowDataType;
 
     use super::string_serializer;
+    use crate::csv::read::Separator;
     use crate::csv::write::options::{QuoteStyle, SerializeOptions};
 
     // It is the most complex serializer with most edge cases, it definitely needs a comprehensive test.
@@ -773,5 +830,28 @@ mod test {
         check_string_serialization(&non_numeric_quote, Some("a,b"), r#""a,b""#);
         check_string_serialization(&non_numeric_quote, Some("a\nb"), "\"a\nb\"");
         check_string_serialization(&non_numeric_quote, Some("a\rb"), "\"a\rb\"");
+
+        let multi_byte_separator = SerializeOptions {
+            separator: Separator::new_from_str("||"),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&multi_byte_separator, Some("a,b"), "a,b");
+        check_string_serialization(&multi_byte_separator, Some("a|b"), "a|b");
+        check_string_serialization(&multi_byte_separator, Some("a||b"), r#""a||b""#);
+        check_string_serialization(&multi_byte_separator, Some("ab|"), r#""ab|""#);
+
+        let escape_char = SerializeOptions {
+            escape_char: Some(b'\\'),
+            ..SerializeOptions::default()
+        };
+        check_string_serialization(&escape_char, Some("a\"b"), r#"a\"b"#);
+        check_string_serialization(&escape_char, Some("a\\b"), r#"a\\b"#);
+        check_string_serialization(&escape_char, Some("a,\"b"), r#""a,\"b""#);
+
+        let escape_char_never_quote = SerializeOptions {
+            quote_style: QuoteStyle::Never,
+            ..escape_char
+        };
+        check_string_serialization(&escape_char_never_quote, Some("a,\"b\n"), "a\\,\\\"b\\\n");
     }
 }
@@ -4,11 +4,14 @@ use std::num::NonZeroUsize;
 use polars_core::POOL;
 use polars_core::frame::DataFrame;
 use polars_core::schema::Schema;
-use polars_error::PolarsResult;
+use polars_error::{PolarsResult, polars_ensure};
 
+use super::encoding::TranscodingWriter;
 use super::write_impl::{write, write_bom, write_header};
 use super::{QuoteStyle, SerializeOptions};
+use crate::csv::read::{CsvEncoding, Separator};
 use crate::shared::SerWriter;
+use crate::utils::compression::{CompressedWriter, ExternalCompression};
 
 /// Write a DataFrame to csv.
 ///
@@ -22,6 +25,8 @@ pub struct CsvWriter<W: Write> {
     bom: bool,
     batch_size: NonZeroUsize,
     n_threads: usize,
+    compression: ExternalCompression,
+    encoding: CsvEncoding,
 }
 
 impl<W> SerWriter<W> for CsvWriter<W>
@@ -42,12 +47,19 @@ where
             bom: false,
             batch_size: NonZeroUsize::new(1024).unwrap(),
             n_threads: POOL.current_num_threads(),
+            compression: ExternalCompression::default(),
+            encoding: CsvEncoding::default(),
         }
     }
 
     fn finish(&mut self, df: &mut DataFrame) -> PolarsResult<()> {
+        self.check_bom()?;
+        let mut buffer = TranscodingWriter::new(
+            CompressedWriter::new(&mut self.buffer, self.compression)?,
+            self.encoding,
+        );
         if self.bom {
-            write_bom(&mut self.buffer)?;
+            write_bom(&mut buffer)?;
         }
         let names = df
             .get_column_names()
@@ -55,15 +67,17 @@ where
             .map(|x| x.as_str())
             .collect::<Vec<_>>();
         if self.header {
-            write_header(&mut self.buffer, names.as_slice(), &self.options)?;
+            write_header(&mut buffer, names.as_slice(), &self.options)?;
         }
         write(
-            &mut self.buffer,
+            &mut buffer,
             df,
             self.batch_size.into(),
             &self.options,
             self.n_threads,
-        )
+        )?;
+        buffer.finish()?.finish()?;
+        Ok(())
     }
 }
 
@@ -83,9 +97,9 @@ where
         self
     }
 
-    /// Set the CSV file's column separator as a byte character.
-    pub fn with_separator(mut self, separator: u8) -> Self {
-        self.options.separator = separator;
+    /// Set the CSV file's column separator, a byte character or a string such as `||`.
+    pub fn with_separator<T: Into<Separator>>(mut self, separator: T) -> Self {
+        self.options.separator = separator.into();
         self
     }
 
@@ -141,6 +155,13 @@ where
         self
     }
 
+    /// Set the single byte character used for escaping, quotes are then escaped with it
+    /// instead of being doubled.
+    pub fn with_escape_char(mut self, char: Option<u8>) -> Self {
+        self.options.escape_char = char;
+        self
+    }
+
     /// Set the CSV file's null value representation.
     pub fn with_null_value(mut self, null_value: String) -> Self {
         self.options.null = null_value;
@@ -165,7 +186,33 @@ where
         self
     }
 
+    /// Set the compression of the output. Defaults to uncompressed.
+    ///
+    /// A [`BatchedWriter`] compresses every batch separately.
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Set the encoding of the output. Defaults to UTF-8.
+    ///
+    /// Characters that cannot be represented in the encoding raise an error.
+    pub fn with_encoding(mut self, encoding: CsvEncoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    fn check_bom(&self) -> PolarsResult<()> {
+        polars_ensure!(
+            !self.bom || self.encoding.supports_bom(),
+            InvalidOperation: "a BOM can only be written in a UTF-8 or UTF-16 encoding, got {:?}",
+            self.encoding
+        );
+        Ok(())
+    }
+
     pub fn batched(self, schema: &Schema) -> PolarsResult<BatchedWriter<W>> {
+        self.check_bom()?;
         let expects_bom = self.bom;
         let expects_header = self.header;
         Ok(BatchedWriter {
@@ -190,9 +237,13 @@ impl<W: Write> BatchedWriter<W> {
     /// # Panics
     /// The caller must ensure the chunks in the given [`DataFrame`] are aligned.
     pub fn write_batch(&mut self, df: &DataFrame) -> PolarsResult<()> {
+        let mut buffer = TranscodingWriter::new(
+            CompressedWriter::new(&mut self.writer.buffer, self.writer.compression)?,
+            self.writer.encoding,
+        );
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut buffer)?;
         }
 
         if !self.has_written_header {
@@ -202,28 +253,33 @@ impl<W: Write> BatchedWriter<W> {
                 .into_iter()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(
-                &mut self.writer.buffer,
-                names.as_slice(),
-                &self.writer.options,
-            )?;
+            write_header(&mut buffer, names.as_slice(), &self.writer.options)?;
         }
 
         write(
-            &mut self.writer.buffer,
+            &mut buffer,
             df,
             self.writer.batch_size.into(),
             &self.writer.options,
             self.writer.n_threads,
         )?;
+        buffer.finish()?.finish()?;
         Ok(())
     }
 
     /// Writes the header of the csv file if not done already. Returns the total size of the file.
     pub fn finish(&mut self) -> PolarsResult<()> {
+        if self.has_written_bom && self.has_written_header {
+            return Ok(());
+        }
+
+        let mut buffer = TranscodingWriter::new(
+            CompressedWriter::new(&mut self.writer.buffer, self.writer.compression)?,
+            self.writer.encoding,
+        );
         if !self.has_written_bom {
             self.has_written_bom = true;
-            write_bom(&mut self.writer.buffer)?;
+            write_bom(&mut buffer)?;
         }
 
         if !self.has_written_header {
@@ -233,9 +289,10 @@ impl<W: Write> BatchedWriter<W> {
                 .iter_names()
                 .map(|x| x.as_str())
                 .collect::<Vec<_>>();
-            write_header(&mut self.writer.buffer, &names, &self.writer.options)?;
+            write_header(&mut buffer, &names, &self.writer.options)?;
         };
 
+        buffer.finish()?.finish()?;
         Ok(())
     }
 }
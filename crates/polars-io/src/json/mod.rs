@@ -79,11 +79,14 @@ use simd_json::BorrowedValue;
 
 use crate::mmap::{MmapBytesReader, ReaderBytes};
 use crate::prelude::*;
+use crate::utils::compression::{CompressedWriter, ExternalCompression};
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Default, Hash)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
-pub struct JsonWriterOptions {}
+pub struct JsonWriterOptions {
+    pub compression: ExternalCompression,
+}
 
 /// The format to use to write the DataFrame to JSON: `Json` (a JSON array)
 /// or `JsonLines` (each row output on a separate line).
@@ -118,6 +121,7 @@ pub struct JsonWriter<W: Write> {
     /// File or Stream handler
     buffer: W,
     json_format: JsonFormat,
+    compression: ExternalCompression,
 }
 
 impl<W: Write> JsonWriter<W> {
@@ -125,6 +129,12 @@ impl<W: Write> JsonWriter<W> {
         self.json_format = format;
         self
     }
+
+    /// Set the compression of the output. Defaults to uncompressed.
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
+    }
 }
 
 impl<W> SerWriter<W> for JsonWriter<W>
@@ -137,6 +147,7 @@ where
         JsonWriter {
             buffer,
             json_format: JsonFormat::JsonLines,
+            compression: ExternalCompression::default(),
         }
     }
 
@@ -154,18 +165,19 @@ where
             .iter_chunks(CompatLevel::newest(), false)
             .map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
 
+        let mut buffer = CompressedWriter::new(&mut self.buffer, self.compression)?;
         match self.json_format {
             JsonFormat::JsonLines => {
                 let serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
-                let writer =
-                    polars_json::ndjson::write::FileWriter::new(&mut self.buffer, serializer);
+                let writer = polars_json::ndjson::write::FileWriter::new(&mut buffer, serializer);
                 writer.collect::<PolarsResult<()>>()?;
             },
             JsonFormat::Json => {
                 let serializer = polars_json::json::write::Serializer::new(batches, vec![]);
-                polars_json::json::write::write(&mut self.buffer, serializer)?;
+                polars_json::json::write::write(&mut buffer, serializer)?;
             },
         }
+        buffer.finish()?;
 
         Ok(())
     }
@@ -173,6 +185,7 @@ where
 
 pub struct BatchedWriter<W: Write> {
     writer: W,
+    compression: ExternalCompression,
 }
 
 impl<W> BatchedWriter<W>
@@ -180,8 +193,19 @@ where
     W: Write,
 {
     pub fn new(writer: W) -> Self {
-        BatchedWriter { writer }
+        BatchedWriter {
+            writer,
+            compression: ExternalCompression::default(),
+        }
     }
+
+    /// Set the compression of the output, every batch is compressed separately. Defaults to
+    /// uncompressed.
+    pub fn with_compression(mut self, compression: ExternalCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     /// Write a batch to the json writer.
     ///
     /// # Panics
@@ -199,9 +223,11 @@ where
         let batches =
             chunks.map(|chunk| Ok(Box::new(chunk_to_struct(chunk, fields.clone())) as ArrayRef));
         let mut serializer = polars_json::ndjson::write::Serializer::new(batches, vec![]);
+        let mut writer = CompressedWriter::new(&mut self.writer, self.compression)?;
         while let Some(block) = serializer.next()? {
-            self.writer.write_all(block)?;
+            writer.write_all(block)?;
         }
+        writer.finish()?;
         Ok(())
     }
 }
@@ -3,7 +3,7 @@ use arrow::bitmap::Bitmap;
 use arrow::datatypes::Field;
 use polars_error::PolarsResult;
 use polars_parquet::read::{
-    BasicDecompressor, ColumnChunkMetadata, Filter, PageReader, column_iter_to_arrays,
+    BasicDecompressor, ColumnChunkMetadata, Filter, PageMetaData, PageReader, column_iter_to_arrays,
 };
 use polars_utils::mmap::{MemReader, MemSlice};
 
@@ -70,3 +70,32 @@ pub fn to_deserializer(
 
     column_iter_to_arrays(columns, types, field, filter)
 }
+
+/// Like [`to_deserializer`], but for column chunks of which only some of the pages were read. The
+/// [`PageMetaData`] holds the number of values in those pages.
+pub fn to_deserializer_with_page_meta(
+    columns: Vec<(PageMetaData, MemSlice)>,
+    field: Field,
+    filter: Option<Filter>,
+) -> PolarsResult<(Box<dyn Array>, Bitmap)> {
+    let types = columns
+        .iter()
+        .map(|(page_meta, _)| page_meta.descriptor.primitive_type.clone())
+        .collect::<Vec<_>>();
+    let columns = columns
+        .into_iter()
+        .map(|(page_meta, chunk)| {
+            chunk.prefetch();
+
+            let pages = PageReader::new_with_page_meta(
+                MemReader::new(chunk),
+                page_meta,
+                vec![],
+                usize::MAX,
+            );
+            BasicDecompressor::new(pages, vec![])
+        })
+        .collect();
+
+    column_iter_to_arrays(columns, types.iter().collect(), field, filter)
+}
@@ -41,8 +41,11 @@ pub use reader::ParquetReader;
 pub use utils::materialize_empty_df;
 
 pub mod _internal {
-    pub use super::mmap::to_deserializer;
-    pub use super::predicates::collect_statistics_with_live_columns;
+    pub use super::mmap::{to_deserializer, to_deserializer_with_page_meta};
+    pub use super::predicates::{
+        ColumnPageIndex, bloom_filter_hashes, bloom_filter_may_contain, calculate_page_row_ranges,
+        collect_statistics_with_live_columns, single_column_chunk,
+    };
     pub use super::read_impl::{PrefilterMaskSetting, calc_prefilter_cost};
     pub use super::utils::ensure_matching_dtypes_if_found;
 }
@@ -1,8 +1,18 @@
+use std::ops::Range;
+
 use arrow::array::{MutablePrimitiveArray, PrimitiveArray};
 use arrow::pushable::Pushable;
 use polars_core::prelude::*;
-use polars_parquet::read::RowGroupMetadata;
-use polars_parquet::read::statistics::{ArrowColumnStatisticsArrays, deserialize_all};
+use polars_parquet::parquet::bloom_filter::is_in_set;
+use polars_parquet::read::indexes::{ColumnIndex, OffsetIndex, page_row_ranges};
+use polars_parquet::read::statistics::{
+    ArrowColumnStatisticsArrays, deserialize_all, deserialize_page_statistics,
+};
+use polars_parquet::read::{ColumnChunkMetadata, RowGroupMetadata};
+use polars_parquet::write::hash_array;
+use polars_utils::format_pl_smallstr;
+
+use crate::predicates::SkipBatchPredicate;
 
 /// Collect the statistics in a row-group
 pub fn collect_statistics_with_live_columns(
@@ -96,3 +106,215 @@ pub fn collect_statistics_with_live_columns(
         })
         .collect::<PolarsResult<Vec<_>>>()
 }
+
+/// The page index of a single non-nested column chunk.
+pub struct ColumnPageIndex {
+    pub column_index: ColumnIndex,
+    pub offset_index: OffsetIndex,
+}
+
+/// Evaluate the `skip_batch_predicate` on the page statistics of a row group.
+///
+/// The row group is cut at every page boundary of the live columns, so that every resulting row
+/// range falls within a single page of each of those columns. Returns the row ranges that cannot
+/// be skipped, or `None` if no rows could be skipped.
+pub fn calculate_page_row_ranges(
+    row_group: &RowGroupMetadata,
+    schema: &ArrowSchema,
+    live_columns: &PlIndexSet<PlSmallStr>,
+    page_indexes: &PlHashMap<PlSmallStr, ColumnPageIndex>,
+    row_index: Option<(&PlSmallStr, IdxSize)>,
+    skip_batch_predicate: &dyn SkipBatchPredicate,
+) -> PolarsResult<Option<Vec<Range<usize>>>> {
+    let num_rows = row_group.num_rows();
+    if page_indexes.is_empty() || num_rows == 0 {
+        return Ok(None);
+    }
+
+    let mut boundaries = Vec::new();
+    for page_index in page_indexes.values() {
+        boundaries.extend(page_row_ranges(&page_index.offset_index, num_rows).map(|r| r.start));
+    }
+    boundaries.push(0);
+    boundaries.push(num_rows);
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    let ranges = boundaries
+        .windows(2)
+        .map(|w| w[0]..w[1])
+        .filter(|r| !r.is_empty())
+        .collect::<Vec<_>>();
+    if ranges.len() <= 1 {
+        return Ok(None);
+    }
+    let num_ranges = ranges.len();
+
+    let mut columns = Vec::with_capacity(1 + live_columns.len() * 3);
+    let lengths: Vec<IdxSize> = ranges.iter().map(|r| r.len() as IdxSize).collect();
+    columns.push(Column::new("len".into(), lengths));
+
+    for c in live_columns.iter() {
+        let min_name = format_pl_smallstr!("{c}_min");
+        let max_name = format_pl_smallstr!("{c}_max");
+        let nc_name = format_pl_smallstr!("{c}_nc");
+
+        let Some(field) = schema.get(c) else {
+            // Should be the row index column
+            let Some((_, offset)) = row_index.filter(|(name, _)| *name == c) else {
+                return Ok(None);
+            };
+
+            let min: Vec<IdxSize> = ranges
+                .iter()
+                .map(|r| offset.saturating_add(r.start as IdxSize))
+                .collect();
+            let max: Vec<IdxSize> = ranges
+                .iter()
+                .map(|r| offset.saturating_add(r.end as IdxSize - 1))
+                .collect();
+            columns.extend([
+                Column::new(min_name, min),
+                Column::new(max_name, max),
+                Column::new(nc_name, vec![0 as IdxSize; num_ranges]),
+            ]);
+            continue;
+        };
+
+        let dtype = DataType::from_arrow_field(field);
+        let stats = match (
+            page_indexes.get(c),
+            single_column_chunk(row_group, &field.name),
+        ) {
+            (Some(page_index), Some(column)) => {
+                deserialize_page_statistics(field, column, &page_index.column_index)?
+                    .map(|stats| (page_index, stats))
+            },
+            _ => None,
+        };
+
+        let Some((page_index, stats)) = stats else {
+            columns.extend([
+                Column::full_null(min_name, num_ranges, &dtype),
+                Column::full_null(max_name, num_ranges, &dtype),
+                Column::full_null(nc_name, num_ranges, &IDX_DTYPE),
+            ]);
+            continue;
+        };
+
+        // Find the page of this column that holds each row range. The null count only holds for
+        // a row range if it spans the whole page.
+        let page_ranges = page_row_ranges(&page_index.offset_index, num_rows).collect::<Vec<_>>();
+        let mut page_idxs = Vec::with_capacity(num_ranges);
+        let mut null_counts = Vec::with_capacity(num_ranges);
+        for r in ranges.iter() {
+            let page_idx = page_ranges
+                .partition_point(|p| p.start <= r.start)
+                .saturating_sub(1);
+            page_idxs.push(page_idx as IdxSize);
+            null_counts.push(
+                (page_ranges[page_idx] == *r)
+                    .then(|| stats.null_count.get(page_idx))
+                    .flatten(),
+            );
+        }
+        let page_idxs = IdxCa::from_vec(PlSmallStr::EMPTY, page_idxs);
+
+        let md = field.metadata.as_deref();
+        let min = unsafe {
+            Series::_try_from_arrow_unchecked_with_md(
+                min_name,
+                vec![stats.min_value],
+                field.dtype(),
+                md,
+            )
+        }?;
+        let max = unsafe {
+            Series::_try_from_arrow_unchecked_with_md(
+                max_name,
+                vec![stats.max_value],
+                field.dtype(),
+                md,
+            )
+        }?;
+        polars_ensure!(
+            min.len() == page_ranges.len(),
+            ComputeError: "parquet column index and offset index of column '{c}' have a different number of pages"
+        );
+
+        columns.extend([
+            min.take(&page_idxs)?.into_column(),
+            max.take(&page_idxs)?.into_column(),
+            IdxCa::from_iter_options(nc_name, null_counts.into_iter()).into_column(),
+        ]);
+    }
+
+    let statistics_df = DataFrame::new_with_height(num_ranges, columns)?;
+    let skip_mask = skip_batch_predicate.evaluate_with_stat_df(&statistics_df)?;
+    if skip_mask.set_bits() == 0 {
+        return Ok(None);
+    }
+
+    let mut out: Vec<Range<usize>> = Vec::new();
+    for (r, skip) in ranges.into_iter().zip(skip_mask.iter()) {
+        if skip {
+            continue;
+        }
+        match out.last_mut() {
+            Some(last) if last.end == r.start => last.end = r.end,
+            _ => out.push(r),
+        }
+    }
+
+    Ok(Some(out))
+}
+
+/// Returns the column chunk of `name` if it is a single non-nested column.
+pub fn single_column_chunk<'a>(
+    row_group: &'a RowGroupMetadata,
+    name: &str,
+) -> Option<&'a ColumnChunkMetadata> {
+    let mut iter = row_group.columns_under_root_iter(name)?;
+    let column = iter.next()?;
+    (iter.next().is_none() && column.descriptor().descriptor.max_rep_level == 0).then_some(column)
+}
+
+/// Returns the hashes to probe the bloom filter of `column` with to find out whether the column
+/// may contain any of `values`.
+///
+/// Returns `None` if the bloom filter cannot be used for `values`, e.g. because the column stores
+/// them in a different representation.
+pub fn bloom_filter_hashes(
+    values: &Series,
+    field: &ArrowField,
+    column: &ColumnChunkMetadata,
+) -> PolarsResult<Option<Vec<u64>>> {
+    let mut values = values.rechunk();
+
+    // Values are hashed by their bytes, but `-0.0 == 0.0` and NaNs can have many bit patterns.
+    if values.dtype().is_float() {
+        let floats = values.cast(&DataType::Float64)?;
+        let floats = floats.f64()?;
+        if floats.is_nan().any() {
+            return Ok(None);
+        }
+        if floats.equal(0.0).any() {
+            let zeros = Series::new(PlSmallStr::EMPTY, [0.0f64, -0.0]).cast(values.dtype())?;
+            values.append(&zeros)?;
+            values = values.rechunk();
+        }
+    }
+
+    let array = values.to_arrow(0, CompatLevel::newest());
+    if array.dtype() != field.dtype() {
+        return Ok(None);
+    }
+
+    let mut hashes = Vec::with_capacity(array.len());
+    Ok(hash_array(array.as_ref(), column.physical_type(), &mut hashes).then_some(hashes))
+}
+
+/// Returns whether the split-block bloom filter `bitset` may contain any of `hashes`.
+pub fn bloom_filter_may_contain(bitset: &[u8], hashes: &[u64]) -> bool {
+    hashes.iter().any(|hash| is_in_set(bitset, *hash))
+}
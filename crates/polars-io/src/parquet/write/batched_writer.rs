@@ -6,14 +6,21 @@ use polars_core::POOL;
 use polars_core::prelude::*;
 use polars_parquet::read::{ParquetError, fallible_streaming_iterator};
 use polars_parquet::write::{
-    ColumnWriteOptions, CompressedPage, Compressor, DynIter, DynStreamingIterator,
-    FallibleStreamingIterator, FileWriter, Page, ParquetType, RowGroupIterColumns,
-    SchemaDescriptor, WriteOptions, array_to_columns, schema_to_metadata_key,
+    ColumnWriteOptions, CompressedPage, CompressionOptions, Compressor, DynIter,
+    DynStreamingIterator, FallibleStreamingIterator, FileWriter, Page, ParquetType,
+    RowGroupIterColumns, SchemaDescriptor, WriteOptions, array_to_bloom_filters, array_to_columns,
+    schema_to_metadata_key,
 };
 use rayon::prelude::*;
 
 use super::{KeyValueMetadata, ParquetMetadataContext};
 
+/// An encoded row group together with the bloom filter bitsets of its leaf columns.
+pub type EncodedRowGroup = (
+    RowGroupIterColumns<'static, PolarsError>,
+    Vec<Option<Vec<u8>>>,
+);
+
 pub struct BatchedWriter<W: Write> {
     // A mutex so that streaming engine can get concurrent read access to
     // compress pages.
@@ -49,7 +56,7 @@ impl<W: Write> BatchedWriter<W> {
     pub fn encode_and_compress<'a>(
         &'a self,
         df: &'a DataFrame,
-    ) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+    ) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
         let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
         rb_iter.filter_map(move |batch| match batch.len() {
             0 => None,
@@ -81,7 +88,8 @@ impl<W: Write> BatchedWriter<W> {
         // Lock before looping so that order is maintained under contention.
         let mut writer = self.writer.lock().unwrap();
         for group in row_group_iter {
-            writer.write(group?)?;
+            let (group, bloom_filters) = group?;
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -91,14 +99,18 @@ impl<W: Write> BatchedWriter<W> {
         writer.parquet_schema()
     }
 
-    pub fn write_row_group(&mut self, rg: &[Vec<CompressedPage>]) -> PolarsResult<()> {
+    pub fn write_row_group(
+        &mut self,
+        rg: &[Vec<CompressedPage>],
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> PolarsResult<()> {
         let writer = self.writer.get_mut().unwrap();
         let rg = DynIter::new(rg.iter().map(|col_pages| {
             Ok(DynStreamingIterator::new(
                 fallible_streaming_iterator::convert(col_pages.iter().map(PolarsResult::Ok)),
             ))
         }));
-        writer.write(rg)?;
+        writer.write_with_bloom_filters(rg, bloom_filters)?;
         Ok(())
     }
 
@@ -106,14 +118,11 @@ impl<W: Write> BatchedWriter<W> {
         &self.writer
     }
 
-    pub fn write_row_groups(
-        &self,
-        rgs: Vec<RowGroupIterColumns<'static, PolarsError>>,
-    ) -> PolarsResult<()> {
+    pub fn write_row_groups(&self, rgs: Vec<EncodedRowGroup>) -> PolarsResult<()> {
         // Lock before looping so that order is maintained.
         let mut writer = self.writer.lock().unwrap();
-        for group in rgs {
-            writer.write(group)?;
+        for (group, bloom_filters) in rgs {
+            writer.write_with_bloom_filters(group, bloom_filters)?;
         }
         Ok(())
     }
@@ -150,7 +159,7 @@ fn prepare_rg_iter<'a>(
     column_options: &'a [ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> impl Iterator<Item = PolarsResult<RowGroupIterColumns<'static, PolarsError>>> + 'a {
+) -> impl Iterator<Item = PolarsResult<EncodedRowGroup>> + 'a {
     let rb_iter = df.iter_chunks(CompatLevel::newest(), false);
     rb_iter.filter_map(move |batch| match batch.len() {
         0 => None,
@@ -170,11 +179,12 @@ fn prepare_rg_iter<'a>(
 
 fn pages_iter_to_compressor(
     encoded_columns: Vec<DynIter<'static, PolarsResult<Page>>>,
-    options: WriteOptions,
+    compressions: Vec<CompressionOptions>,
 ) -> Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>> {
     encoded_columns
         .into_iter()
-        .map(|encoded_pages| {
+        .zip(compressions)
+        .map(|(encoded_pages, compression)| {
             // iterator over pages
             let pages = DynStreamingIterator::new(
                 Compressor::new_from_vec(
@@ -183,7 +193,7 @@ fn pages_iter_to_compressor(
                             ParquetError::FeatureNotSupported(format!("reraised in polars: {e}",))
                         })
                     }),
-                    options.compression,
+                    compression,
                     vec![],
                 )
                 .map_err(PolarsError::from),
@@ -201,7 +211,30 @@ fn array_to_pages_iter(
     options: WriteOptions,
 ) -> Vec<PolarsResult<DynStreamingIterator<'static, CompressedPage, PolarsError>>> {
     let encoded_columns = array_to_columns(array, type_.clone(), column_options, options).unwrap();
-    pages_iter_to_compressor(encoded_columns, options)
+    let compressions = column_options.leaf_compressions(options.compression);
+    pages_iter_to_compressor(encoded_columns, compressions)
+}
+
+/// Creates the bloom filters of all leaf columns of the batch, or none if no column is written
+/// with a bloom filter.
+fn create_bloom_filters(
+    batch: &RecordBatch,
+    fields: &[ParquetType],
+    column_options: &[ColumnWriteOptions],
+) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    if !column_options.iter().any(|o| o.has_bloom_filter()) {
+        return Ok(Vec::new());
+    }
+
+    let mut bloom_filters = Vec::new();
+    for ((array, type_), column_options) in batch.columns().iter().zip(fields).zip(column_options) {
+        bloom_filters.extend(array_to_bloom_filters(
+            array.as_ref(),
+            type_.clone(),
+            column_options,
+        )?);
+    }
+    Ok(bloom_filters)
 }
 
 fn create_serializer(
@@ -210,7 +243,9 @@ fn create_serializer(
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
     parallel: bool,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+) -> PolarsResult<EncodedRowGroup> {
+    let bloom_filters = create_bloom_filters(&batch, fields, column_options)?;
+
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
@@ -238,7 +273,7 @@ fn create_serializer(
 
     let row_group = DynIter::new(columns.into_iter());
 
-    Ok(row_group)
+    Ok((row_group, bloom_filters))
 }
 
 /// This serializer encodes and compresses all eagerly in memory.
@@ -248,7 +283,9 @@ fn create_eager_serializer(
     fields: &[ParquetType],
     column_options: &[ColumnWriteOptions],
     options: WriteOptions,
-) -> PolarsResult<RowGroupIterColumns<'static, PolarsError>> {
+) -> PolarsResult<EncodedRowGroup> {
+    let bloom_filters = create_bloom_filters(&batch, fields, column_options)?;
+
     let func = move |((array, type_), column_options): (
         (&ArrayRef, &ParquetType),
         &ColumnWriteOptions,
@@ -264,5 +301,5 @@ fn create_eager_serializer(
 
     let row_group = DynIter::new(columns.into_iter());
 
-    Ok(row_group)
+    Ok((row_group, bloom_filters))
 }
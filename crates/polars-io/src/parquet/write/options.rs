@@ -1,6 +1,6 @@
 use polars_error::PolarsResult;
 use polars_parquet::write::{
-    BrotliLevel as BrotliLevelParquet, CompressionOptions, GzipLevel as GzipLevelParquet,
+    BrotliLevel as BrotliLevelParquet, CompressionOptions, Encoding, GzipLevel as GzipLevelParquet,
     StatisticsOptions, ZstdLevel as ZstdLevelParquet,
 };
 use polars_utils::pl_str::PlSmallStr;
@@ -55,6 +55,31 @@ pub struct ParquetFieldOverwrites {
     pub children: ChildFieldOverwrites,
     pub field_id: Option<i32>,
     pub metadata: Option<Vec<MetadataKeyValue>>,
+    /// Write a split-block bloom filter for every row group of this (leaf) field.
+    pub bloom_filter: bool,
+    /// Encoding of the data pages of this (leaf) field. Setting an encoding disables dictionary
+    /// encoding, unless `dictionary` is explicitly enabled.
+    pub encoding: Option<ParquetEncoding>,
+    /// Whether to dictionary encode this (leaf) field. If the field cannot be dictionary encoded,
+    /// its data pages are written with `encoding`.
+    pub dictionary: Option<bool>,
+    /// Data page compression of this (leaf) field.
+    pub compression: Option<ParquetCompression>,
+    /// Column statistics of this (leaf) field.
+    pub statistics: Option<StatisticsOptions>,
+}
+
+/// The encoding of the data pages of a Parquet column.
+#[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ParquetEncoding {
+    Plain,
+    Rle,
+    DeltaBinaryPacked,
+    DeltaLengthByteArray,
+    DeltaByteArray,
+    ByteStreamSplit,
 }
 
 /// The compression strategy to use for writing Parquet files.
@@ -136,3 +161,16 @@ impl From<ParquetCompression> for CompressionOptions {
         }
     }
 }
+
+impl From<ParquetEncoding> for Encoding {
+    fn from(value: ParquetEncoding) -> Self {
+        match value {
+            ParquetEncoding::Plain => Encoding::Plain,
+            ParquetEncoding::Rle => Encoding::Rle,
+            ParquetEncoding::DeltaBinaryPacked => Encoding::DeltaBinaryPacked,
+            ParquetEncoding::DeltaLengthByteArray => Encoding::DeltaLengthByteArray,
+            ParquetEncoding::DeltaByteArray => Encoding::DeltaByteArray,
+            ParquetEncoding::ByteStreamSplit => Encoding::ByteStreamSplit,
+        }
+    }
+}
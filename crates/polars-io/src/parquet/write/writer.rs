@@ -27,6 +27,7 @@ impl ParquetWriteOptions {
             .with_row_group_size(self.row_group_size)
             .with_data_page_size(self.data_page_size)
             .with_key_value_metadata(self.key_value_metadata.clone())
+            .with_field_overwrites(self.field_overwrites.clone())
     }
 }
 
@@ -113,6 +114,12 @@ where
         self
     }
 
+    /// Set per-field overwrites for writing properties, such as bloom filters.
+    pub fn with_field_overwrites(mut self, field_overwrites: Vec<ParquetFieldOverwrites>) -> Self {
+        self.field_overwrites = field_overwrites;
+        self
+    }
+
     /// Set context information for the writer
     pub fn with_context_info(mut self, context_info: Option<PlHashMap<String, String>>) -> Self {
         self.context_info = context_info;
@@ -183,9 +190,9 @@ fn to_column_write_options_rec(
         metadata: Vec::new(),
 
         // Dummy value.
-        children: ChildWriteOptions::Leaf(FieldWriteOptions {
-            encoding: Encoding::Plain,
-        }),
+        children: ChildWriteOptions::Leaf(FieldWriteOptions::default_with_encoding(
+            Encoding::Plain,
+        )),
     };
 
     if let Some(overwrites) = overwrites {
@@ -197,8 +204,13 @@ fn to_column_write_options_rec(
     match field.dtype().to_physical_type() {
         Null | Boolean | Primitive(_) | Binary | FixedSizeBinary | LargeBinary | Utf8
         | Dictionary(_) | LargeUtf8 | BinaryView | Utf8View => {
+            let (encoding, fallback_encoding) = leaf_encodings(field.dtype(), overwrites);
             column_options.children = ChildWriteOptions::Leaf(FieldWriteOptions {
-                encoding: encoding_map(field.dtype()),
+                encoding,
+                fallback_encoding,
+                compression: overwrites.and_then(|o| o.compression).map(Into::into),
+                statistics: overwrites.and_then(|o| o.statistics),
+                bloom_filter: overwrites.is_some_and(|o| o.bloom_filter),
             });
         },
         List | FixedSizeList | LargeList => {
@@ -271,6 +283,24 @@ pub fn get_column_write_options(
         .collect()
 }
 
+/// The encoding and the fallback encoding of a leaf field.
+fn leaf_encodings(
+    dtype: &ArrowDataType,
+    overwrites: Option<&ParquetFieldOverwrites>,
+) -> (Encoding, Encoding) {
+    let encoding = overwrites.and_then(|o| o.encoding).map(Encoding::from);
+    let dictionary = overwrites
+        .and_then(|o| o.dictionary)
+        .unwrap_or_else(|| encoding.is_none() && encoding_map(dtype) == Encoding::RleDictionary);
+    let encoding = encoding.unwrap_or(Encoding::Plain);
+
+    if dictionary {
+        (Encoding::RleDictionary, encoding)
+    } else {
+        (encoding, Encoding::Plain)
+    }
+}
+
 /// Declare encodings
 fn encoding_map(dtype: &ArrowDataType) -> Encoding {
     match dtype.to_physical_type() {
@@ -63,47 +63,7 @@ pub static POLARS_TEMP_DIR_BASE_PATH: LazyLock<Box<Path>> = LazyLock::new(|| {
         }
         .into_boxed_path();
 
-        if let Err(err) = std::fs::create_dir_all(path.as_ref()) {
-            if !path.is_dir() {
-                panic!(
-                    "failed to create temporary directory: {} (path = {:?})",
-                    err,
-                    path.as_ref()
-                );
-            }
-        }
-
-        #[cfg(target_family = "unix")]
-        {
-            use std::os::unix::fs::PermissionsExt;
-
-            let result = (|| {
-                std::fs::set_permissions(path.as_ref(), std::fs::Permissions::from_mode(0o700))?;
-                let perms = std::fs::metadata(path.as_ref())?.permissions();
-
-                if (perms.mode() % 0o1000) != 0o700 {
-                    std::io::Result::Err(std::io::Error::other(format!(
-                        "permission mismatch: {perms:?}"
-                    )))
-                } else {
-                    std::io::Result::Ok(())
-                }
-            })()
-            .map_err(|e| {
-                std::io::Error::new(
-                    e.kind(),
-                    format!(
-                        "error setting temporary directory permissions: {} (path = {:?})",
-                        e,
-                        path.as_ref()
-                    ),
-                )
-            });
-
-            if std::env::var("POLARS_ALLOW_UNSECURED_TEMP_DIR").as_deref() != Ok("1") {
-                result?;
-            }
-        }
+        ensure_temp_dir(path.as_ref())?;
 
         std::io::Result::Ok(path)
     })()
@@ -119,6 +79,52 @@ pub static POLARS_TEMP_DIR_BASE_PATH: LazyLock<Box<Path>> = LazyLock::new(|| {
     .unwrap()
 });
 
+/// Creates the given temporary directory if needed and restricts its permissions to the current
+/// user.
+///
+/// On Unix, an error is returned if the permissions can't be set to `0o700`, unless
+/// `POLARS_ALLOW_UNSECURED_TEMP_DIR=1` is set.
+pub fn ensure_temp_dir(path: &Path) -> std::io::Result<()> {
+    if let Err(err) = std::fs::create_dir_all(path) {
+        if !path.is_dir() {
+            return Err(std::io::Error::new(
+                err.kind(),
+                format!("failed to create temporary directory: {err} (path = {path:?})"),
+            ));
+        }
+    }
+
+    #[cfg(target_family = "unix")]
+    {
+        use std::os::unix::fs::PermissionsExt;
+
+        let result = (|| {
+            std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o700))?;
+            let perms = std::fs::metadata(path)?.permissions();
+
+            if (perms.mode() % 0o1000) != 0o700 {
+                std::io::Result::Err(std::io::Error::other(format!(
+                    "permission mismatch: {perms:?}"
+                )))
+            } else {
+                std::io::Result::Ok(())
+            }
+        })()
+        .map_err(|e| {
+            std::io::Error::new(
+                e.kind(),
+                format!("error setting temporary directory permissions: {e} (path = {path:?})"),
+            )
+        });
+
+        if std::env::var("POLARS_ALLOW_UNSECURED_TEMP_DIR").as_deref() != Ok("1") {
+            result?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Replaces a "~" in the Path with the home directory.
 pub fn resolve_homedir(path: &dyn AsRef<Path>) -> PathBuf {
     let path = path.as_ref();
@@ -395,6 +395,10 @@ pub struct ColumnPredicates {
         ),
     >,
     pub is_sumwise_complete: bool,
+
+    /// For a column, the non-null values that it must be equal to for a row to pass the
+    /// predicate.
+    pub equality_sets: PlHashMap<PlSmallStr, Series>,
 }
 
 // I want to be explicit here.
@@ -404,6 +408,7 @@ impl Default for ColumnPredicates {
         Self {
             predicates: PlHashMap::default(),
             is_sumwise_complete: false,
+            equality_sets: PlHashMap::default(),
         }
     }
 }
@@ -493,6 +498,7 @@ impl ScanIOPredicate {
         let mut column_predicates = self.column_predicates.as_ref().clone();
         for (c, _) in constant_columns.iter() {
             column_predicates.predicates.remove(c);
+            column_predicates.equality_sets.remove(c);
         }
         self.column_predicates = Arc::new(column_predicates);
 
@@ -1,13 +1,19 @@
-use std::io::Read;
+use std::io::{Read, Write};
 
 use polars_core::prelude::*;
 use polars_error::{feature_gated, to_compute_err};
+use polars_utils::mmap::MemSlice;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
 
 /// Represents the compression algorithms that we have decoders for
 pub enum SupportedCompression {
     GZIP,
     ZLIB,
     ZSTD,
+    BZIP2,
+    XZ,
+    LZ4,
 }
 
 impl SupportedCompression {
@@ -25,9 +31,29 @@ impl SupportedCompression {
             [0x78, 0xDA, _, _]   // ZLIB2
                                      => Some(Self::ZLIB),
             [0x28, 0xB5, 0x2F, 0xFD] => Some(Self::ZSTD),
+            [b'B', b'Z', b'h', b'1'..=b'9'] => Some(Self::BZIP2),
+            [0xFD, b'7', b'z', b'X'] => Some(Self::XZ),
+            [0x04, 0x22, 0x4D, 0x18] => Some(Self::LZ4),
             _ => None,
         }
     }
+
+    /// Wrap `reader` in a decoder for this compression family. Concatenated streams (e.g. the
+    /// members of a gzip file) are decoded as a single stream.
+    #[cfg(feature = "decompress")]
+    pub fn decoder<'a, R: std::io::BufRead + Send + 'a>(
+        &self,
+        reader: R,
+    ) -> PolarsResult<Box<dyn Read + Send + 'a>> {
+        Ok(match self {
+            Self::GZIP => Box::new(flate2::bufread::MultiGzDecoder::new(reader)),
+            Self::ZLIB => Box::new(flate2::bufread::ZlibDecoder::new(reader)),
+            Self::ZSTD => Box::new(zstd::Decoder::with_buffer(reader)?),
+            Self::BZIP2 => Box::new(bzip2::bufread::MultiBzDecoder::new(reader)),
+            Self::XZ => Box::new(xz2::bufread::XzDecoder::new_multi_decoder(reader)),
+            Self::LZ4 => Box::new(lz4_flex::frame::FrameDecoder::new(reader)),
+        })
+    }
 }
 
 /// Decompress `bytes` if compression is detected, otherwise simply return it.
@@ -37,21 +63,9 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
 
     if let Some(algo) = SupportedCompression::check(bytes) {
         feature_gated!("decompress", {
-            match algo {
-                SupportedCompression::GZIP => {
-                    flate2::read::MultiGzDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZLIB => {
-                    flate2::read::ZlibDecoder::new(bytes)
-                        .read_to_end(out)
-                        .map_err(to_compute_err)?;
-                },
-                SupportedCompression::ZSTD => {
-                    zstd::Decoder::with_buffer(bytes)?.read_to_end(out)?;
-                },
-            }
+            algo.decoder(bytes)?
+                .read_to_end(out)
+                .map_err(to_compute_err)?;
 
             Ok(out)
         })
@@ -59,3 +73,233 @@ pub fn maybe_decompress_bytes<'a>(bytes: &'a [u8], out: &'a mut Vec<u8>) -> Pola
         Ok(bytes)
     }
 }
+
+/// Decompress the start of `bytes` if compression is detected, otherwise simply return it.
+///
+/// Decompression stops at the first chunk for which `find_last_line_end` returns a position. This
+/// is used when only the first lines of a file are needed, e.g. for schema inference.
+pub fn maybe_decompress_prefix(
+    bytes: &MemSlice,
+    find_last_line_end: impl FnMut(&[u8]) -> Option<usize>,
+) -> PolarsResult<MemSlice> {
+    match StreamingDecompressor::try_new(bytes.clone())? {
+        None => Ok(bytes.clone()),
+        Some(mut decompressor) => Ok(decompressor
+            .next_chunk(find_last_line_end)?
+            .unwrap_or_default()),
+    }
+}
+
+/// Incrementally decompresses a file into chunks that end at a line boundary, so that a file can
+/// be parsed without holding all of its decompressed data in memory.
+pub struct StreamingDecompressor {
+    decoder: Box<dyn Read + Send>,
+    /// Decompressed bytes after the last line end of the previous chunk.
+    remainder: Vec<u8>,
+    /// Minimum number of decompressed bytes in a chunk.
+    chunk_size: usize,
+    finished: bool,
+}
+
+impl StreamingDecompressor {
+    /// Default minimum number of decompressed bytes in a chunk.
+    const CHUNK_SIZE: usize = 32 * 1024 * 1024;
+
+    /// Tests can use a small chunk size to catch failures at chunk boundaries.
+    /// An invalid value is ignored.
+    fn chunk_size() -> usize {
+        let Ok(size) = std::env::var("POLARS_FORCE_DECOMPRESS_CHUNK_SIZE") else {
+            return Self::CHUNK_SIZE;
+        };
+        let parsed = size.trim().parse::<usize>().ok();
+        if parsed.is_none() && polars_core::config::verbose() {
+            eprintln!(
+                "ignoring invalid POLARS_FORCE_DECOMPRESS_CHUNK_SIZE '{size}', expected a number of bytes"
+            );
+        }
+        parsed.unwrap_or(Self::CHUNK_SIZE).max(1)
+    }
+
+    /// Returns `None` if no compression is detected.
+    pub fn try_new(bytes: MemSlice) -> PolarsResult<Option<Self>> {
+        let Some(algo) = SupportedCompression::check(&bytes) else {
+            return Ok(None);
+        };
+
+        feature_gated!("decompress", {
+            Ok(Some(Self {
+                decoder: algo.decoder(std::io::Cursor::new(bytes))?,
+                remainder: vec![],
+                chunk_size: Self::chunk_size(),
+                finished: false,
+            }))
+        })
+    }
+
+    /// Chunk the data of an arbitrary reader, e.g. a decoder whose output is transformed further.
+    pub fn from_reader(reader: Box<dyn Read + Send>) -> Self {
+        Self {
+            decoder: reader,
+            remainder: vec![],
+            chunk_size: Self::chunk_size(),
+            finished: false,
+        }
+    }
+
+    /// Decompress the next chunk.
+    ///
+    /// The chunk is cut off after the position returned by `find_last_line_end`, the bytes after
+    /// it are carried over to the next chunk. If no position is returned more data is decompressed
+    /// into the chunk. The last chunk contains all remaining data. Returns `None` once all data
+    /// has been returned.
+    pub fn next_chunk(
+        &mut self,
+        mut find_last_line_end: impl FnMut(&[u8]) -> Option<usize>,
+    ) -> PolarsResult<Option<MemSlice>> {
+        let mut buf = std::mem::take(&mut self.remainder);
+        let mut chunk_size = self.chunk_size.max(buf.len().saturating_mul(2));
+
+        loop {
+            if !self.finished {
+                let n_bytes = chunk_size - buf.len();
+                buf.reserve(n_bytes);
+
+                let n_read = (&mut self.decoder)
+                    .take(n_bytes as u64)
+                    .read_to_end(&mut buf)
+                    .map_err(to_compute_err)?;
+                self.finished = n_read < n_bytes;
+            }
+
+            if self.finished {
+                return Ok((!buf.is_empty()).then(|| MemSlice::from_vec(buf)));
+            }
+
+            if let Some(end) = find_last_line_end(&buf) {
+                debug_assert!(end <= buf.len());
+                self.remainder = buf[end..].to_vec();
+                buf.truncate(end);
+
+                return Ok(Some(MemSlice::from_vec(buf)));
+            }
+
+            // The chunk does not contain a complete line yet.
+            chunk_size = chunk_size.saturating_mul(2);
+        }
+    }
+}
+
+/// Compression of a whole file, as opposed to the compression of the pages or blocks within a
+/// file format.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "dsl-schema", derive(schemars::JsonSchema))]
+pub enum ExternalCompression {
+    #[default]
+    Uncompressed,
+    Gzip {
+        level: Option<u32>,
+    },
+    Zstd {
+        level: Option<i32>,
+    },
+}
+
+impl ExternalCompression {
+    pub fn try_new_gzip(level: Option<u32>) -> PolarsResult<Self> {
+        if let Some(level) = level {
+            polars_ensure!(
+                level <= 9,
+                InvalidOperation: "gzip compression level must be between 0 and 9, got {}", level
+            );
+        }
+        Ok(Self::Gzip { level })
+    }
+
+    pub fn try_new_zstd(level: Option<i32>) -> PolarsResult<Self> {
+        if let Some(level) = level {
+            polars_ensure!(
+                (1..=22).contains(&level),
+                InvalidOperation: "zstd compression level must be between 1 and 22, got {}", level
+            );
+        }
+        Ok(Self::Zstd { level })
+    }
+
+    /// The extension that is appended to the extension of the file format, e.g. `gz` in
+    /// `csv.gz`.
+    pub fn file_extension(&self) -> Option<&'static str> {
+        match self {
+            Self::Uncompressed => None,
+            Self::Gzip { .. } => Some("gz"),
+            Self::Zstd { .. } => Some("zst"),
+        }
+    }
+}
+
+/// A writer that compresses everything written to it.
+///
+/// Every writer produces a complete gzip member or zstd frame. Both formats allow these to be
+/// concatenated, so a file can be compressed in independent parts.
+pub enum CompressedWriter<W: Write> {
+    Uncompressed(W),
+    #[cfg(feature = "compress")]
+    Gzip(flate2::write::GzEncoder<W>),
+    #[cfg(feature = "compress")]
+    Zstd(zstd::Encoder<'static, W>),
+}
+
+impl<W: Write> CompressedWriter<W> {
+    pub fn new(writer: W, compression: ExternalCompression) -> PolarsResult<Self> {
+        if compression == ExternalCompression::Uncompressed {
+            return Ok(Self::Uncompressed(writer));
+        }
+
+        feature_gated!("compress", {
+            Ok(match compression {
+                ExternalCompression::Uncompressed => unreachable!(),
+                ExternalCompression::Gzip { level } => Self::Gzip(flate2::write::GzEncoder::new(
+                    writer,
+                    level.map_or_else(flate2::Compression::default, flate2::Compression::new),
+                )),
+                ExternalCompression::Zstd { level } => Self::Zstd(
+                    zstd::Encoder::new(writer, level.unwrap_or(zstd::DEFAULT_COMPRESSION_LEVEL))
+                        .map_err(to_compute_err)?,
+                ),
+            })
+        })
+    }
+
+    /// Write the end of the compressed stream and return the inner writer.
+    pub fn finish(self) -> PolarsResult<W> {
+        match self {
+            Self::Uncompressed(writer) => Ok(writer),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => Ok(encoder.finish()?),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => Ok(encoder.finish()?),
+        }
+    }
+}
+
+impl<W: Write> Write for CompressedWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        match self {
+            Self::Uncompressed(writer) => writer.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.write(buf),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        match self {
+            Self::Uncompressed(writer) => writer.flush(),
+            #[cfg(feature = "compress")]
+            Self::Gzip(encoder) => encoder.flush(),
+            #[cfg(feature = "compress")]
+            Self::Zstd(encoder) => encoder.flush(),
+        }
+    }
+}
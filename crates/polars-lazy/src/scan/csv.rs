@@ -3,7 +3,8 @@ use std::path::{Path, PathBuf};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::csv::read::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, infer_file_schema,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, NullValues, Separator,
+    infer_file_schema, maybe_transcode_bytes,
 };
 use polars_io::path_utils::expand_paths;
 use polars_io::utils::compression::maybe_decompress_bytes;
@@ -93,6 +94,14 @@ impl LazyCsvReader {
         self
     }
 
+    /// Remove the rows that fail to parse or cast and write them to a CSV file at this path,
+    /// along with the source file, line number and error message of every row.
+    #[must_use]
+    pub fn with_rejects_path(mut self, rejects_path: Option<PathBuf>) -> Self {
+        self.read_options.rejects_path = rejects_path;
+        self
+    }
+
     /// Set the CSV file's schema
     #[must_use]
     pub fn with_schema(mut self, schema: Option<SchemaRef>) -> Self {
@@ -138,10 +147,11 @@ impl LazyCsvReader {
         self
     }
 
-    /// Set the CSV file's column separator as a byte character
+    /// Set the CSV file's column separator as a byte character or a string such as `||`.
     #[must_use]
-    pub fn with_separator(self, separator: u8) -> Self {
-        self.map_parse_options(|opts| opts.with_separator(separator))
+    pub fn with_separator<T: Into<Separator>>(self, separator: T) -> Self {
+        let separator = separator.into();
+        self.map_parse_options(|opts| opts.with_separator(separator.clone()))
     }
 
     /// Set the comment prefix for this instance. Lines starting with this prefix will be ignored.
@@ -164,6 +174,12 @@ impl LazyCsvReader {
         self.map_parse_options(|opts| opts.with_quote_char(quote_char))
     }
 
+    /// Set the `char` used to escape the next character, e.g. `b'\\'`. The default is [`None`].
+    #[must_use]
+    pub fn with_escape_char(self, escape_char: Option<u8>) -> Self {
+        self.map_parse_options(|opts| opts.with_escape_char(escape_char))
+    }
+
     /// Set the `char` used as end of line. The default is `b'\n'`.
     #[must_use]
     pub fn with_eol_char(self, eol_char: u8) -> Self {
@@ -254,6 +270,8 @@ impl LazyCsvReader {
 
             let mut owned = vec![];
             let bytes = maybe_decompress_bytes(bytes.as_ref(), &mut owned)?;
+            let mut transcoded = vec![];
+            let bytes = maybe_transcode_bytes(bytes, parse_options.encoding, &mut transcoded)?;
 
             PolarsResult::Ok(
                 infer_file_schema(
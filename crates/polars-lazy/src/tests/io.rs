@@ -739,6 +739,90 @@ fn scan_anonymous_fn_count() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "new_streaming")]
+fn scan_anonymous_fn_streaming() -> PolarsResult<()> {
+    struct MyScan {
+        batch_size: usize,
+        offset: std::sync::Mutex<usize>,
+    }
+
+    impl AnonymousScan for MyScan {
+        fn as_any(&self) -> &dyn std::any::Any {
+            self
+        }
+
+        fn allows_predicate_pushdown(&self) -> bool {
+            true
+        }
+
+        fn allows_projection_pushdown(&self) -> bool {
+            true
+        }
+
+        fn produces_batches(&self) -> bool {
+            true
+        }
+
+        fn scan(&self, _scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame> {
+            unreachable!()
+        }
+
+        fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
+            let df = fruits_cars();
+            let mut offset = self.offset.lock().unwrap();
+            if *offset >= df.height() {
+                return Ok(None);
+            }
+            let mut out = df.slice(*offset as i64, self.batch_size);
+            *offset += self.batch_size;
+
+            if let Some(predicate) = scan_opts.predicate {
+                out = out.lazy().filter(predicate).collect()?;
+            }
+            if let Some(with_columns) = scan_opts.with_columns {
+                out = out.select(with_columns.iter().cloned())?;
+            }
+            Ok(Some(out))
+        }
+    }
+
+    let args = ScanArgsAnonymous {
+        schema: Some(fruits_cars().schema().clone()),
+        ..ScanArgsAnonymous::default()
+    };
+    let lf = |args| {
+        let function = Arc::new(MyScan {
+            batch_size: 2,
+            offset: Default::default(),
+        });
+        LazyFrame::anonymous_scan(function, args)
+    };
+
+    let out = lf(args.clone())?
+        .filter(col("A").gt(lit(1)))
+        .select([col("fruits"), col("B")])
+        .collect_with_engine(Engine::Streaming)?;
+    let expected = fruits_cars()
+        .lazy()
+        .filter(col("A").gt(lit(1)))
+        .select([col("fruits"), col("B")])
+        .collect()?;
+    assert!(out.equals(&expected));
+
+    let out = lf(args.clone())?
+        .slice(1, 3)
+        .collect_with_engine(Engine::Streaming)?;
+    assert!(out.equals(&fruits_cars().slice(1, 3)));
+
+    let out = lf(args)?
+        .select([len()])
+        .collect_with_engine(Engine::Streaming)?;
+    assert_eq!(out.get_columns()[0].get(0)?.extract::<usize>(), Some(5));
+
+    Ok(())
+}
+
 #[test]
 #[cfg(feature = "dtype-full")]
 fn scan_small_dtypes() -> PolarsResult<()> {
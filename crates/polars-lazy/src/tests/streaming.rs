@@ -120,6 +120,58 @@ fn test_streaming_first_sum() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_first_last_evicted_groups() -> PolarsResult<()> {
+    // Scatter a few keys pseudo-randomly so that groups are evicted from the
+    // hot table and rows of the same morsel are reduced out of order.
+    let n = 50_000i64;
+    let keys = (0..n)
+        .map(|i| ((i as u64).wrapping_mul(0x9E3779B97F4A7C15) >> 59) as i64 % 7)
+        .collect::<Vec<_>>();
+    let df = df! {
+        "k" => keys,
+        "v" => (0..n).collect::<Vec<_>>(),
+        "s" => (0..n).map(|i| format!("s{i}")).collect::<Vec<_>>(),
+    }?;
+
+    let q = df
+        .lazy()
+        .group_by([col("k")])
+        .agg([
+            col("v").first().alias("v_first"),
+            col("v").last().alias("v_last"),
+            col("s").first().alias("s_first"),
+            col("s").last().alias("s_last"),
+            col("v").implode().alias("v_list"),
+        ])
+        .sort(["k"], Default::default());
+
+    let out = q.clone().collect_with_engine(Engine::Streaming)?;
+    let expected = q.collect()?;
+    assert_eq!(out, expected);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "new_streaming")]
+fn test_streaming_with_context() -> PolarsResult<()> {
+    // The context has a different height than the input.
+    let df = df!["a" => [1, 2, 3]]?.lazy();
+    let other = df!["b" => [1, 2, 3, 4, 5]]?.lazy();
+    let q = df.with_context([other]);
+
+    for q in [
+        q.clone().select([col("b") * lit(2)]),
+        q.select([col("a"), (col("a") + col("b").sum()).alias("c")]),
+    ] {
+        let out = q.clone().collect_with_engine(Engine::Streaming)?;
+        let expected = q.collect()?;
+        assert_eq!(out, expected);
+    }
+    Ok(())
+}
+
 #[test]
 fn test_streaming_unique() -> PolarsResult<()> {
     let q = get_csv_file();
@@ -267,6 +267,8 @@ fn create_physical_plan_impl(
                         FileType::Csv(_) => "csv",
                         #[cfg(feature = "json")]
                         FileType::Json(_) => "json",
+                        #[cfg(feature = "avro")]
+                        FileType::Avro(_) => "avro",
                         #[allow(unreachable_patterns)]
                         _ => panic!("enable filetype feature"),
                     };
@@ -308,11 +310,12 @@ fn create_physical_plan_impl(
                                     CsvWriter::new(BufWriter::new(writer))
                                         .include_bom(options.include_bom)
                                         .include_header(options.include_header)
-                                        .with_separator(options.serialize_options.separator)
+                                        .with_separator(options.serialize_options.separator.clone())
                                         .with_line_terminator(
                                             options.serialize_options.line_terminator.clone(),
                                         )
                                         .with_quote_char(options.serialize_options.quote_char)
+                                        .with_escape_char(options.serialize_options.escape_char)
                                         .with_batch_size(options.batch_size)
                                         .with_datetime_format(
                                             options.serialize_options.datetime_format.clone(),
@@ -331,17 +334,25 @@ fn create_physical_plan_impl(
                                         )
                                         .with_null_value(options.serialize_options.null.clone())
                                         .with_quote_style(options.serialize_options.quote_style)
+                                        .with_compression(options.compression)
+                                        .with_encoding(options.encoding)
                                         .finish(&mut df)?;
                                 },
                                 #[cfg(feature = "json")]
-                                FileType::Json(_options) => {
+                                FileType::Json(options) => {
                                     use polars_io::SerWriter;
                                     use polars_io::json::{JsonFormat, JsonWriter};
 
                                     JsonWriter::new(BufWriter::new(writer))
                                         .with_json_format(JsonFormat::JsonLines)
+                                        .with_compression(options.compression)
                                         .finish(&mut df)?;
                                 },
+                                #[cfg(feature = "avro")]
+                                FileType::Avro(options) => {
+                                    use polars_io::SerWriter;
+                                    options.to_writer(BufWriter::new(writer)).finish(&mut df)?;
+                                },
                                 #[allow(unreachable_patterns)]
                                 _ => panic!("enable filetype feature"),
                             }
@@ -983,11 +994,13 @@ pub fn create_scan_predicate(
                 })
                 .collect::<PolarsResult<PlHashMap<_, _>>>()?,
             is_sumwise_complete: column_predicates.is_sumwise_complete,
+            equality_sets: column_predicates.equality_sets,
         }
     } else {
         PhysicalColumnPredicates {
             predicates: PlHashMap::default(),
             is_sumwise_complete: false,
+            equality_sets: PlHashMap::default(),
         }
     };
 
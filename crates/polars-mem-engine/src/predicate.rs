@@ -6,6 +6,7 @@ use polars_core::frame::DataFrame;
 use polars_core::prelude::{AnyValue, Column, Field, GroupPositions, PlHashMap, PlIndexSet};
 use polars_core::scalar::Scalar;
 use polars_core::schema::{Schema, SchemaRef};
+use polars_core::series::Series;
 use polars_error::PolarsResult;
 use polars_expr::prelude::{AggregationContext, PhysicalExpr, phys_expr_to_io_expr};
 use polars_expr::state::ExecutionState;
@@ -51,6 +52,7 @@ pub struct PhysicalColumnPredicates {
         ),
     >,
     pub is_sumwise_complete: bool,
+    pub equality_sets: PlHashMap<PlSmallStr, Series>,
 }
 
 /// Helper to implement [`SkipBatchPredicate`].
@@ -197,6 +199,7 @@ impl ScanPredicate {
                     .map(|(n, (p, s))| (n.clone(), (phys_expr_to_io_expr(p.clone()), s.clone())))
                     .collect(),
                 is_sumwise_complete: self.column_predicates.is_sumwise_complete,
+                equality_sets: self.column_predicates.equality_sets.clone(),
             }),
         }
     }
@@ -204,6 +204,115 @@ where
     Ok((left_row_idx, right_row_idx))
 }
 
+impl IEJoinOptions {
+    fn l1_descending(&self) -> bool {
+        matches!(
+            self.operator1,
+            InequalityOperator::Gt | InequalityOperator::GtEq
+        )
+    }
+}
+
+/// Returns the row order of the first join key of one side of an inequality join, leaving out
+/// the null rows as they can never match.
+pub fn iejoin_l1_order(s: &Series, options: &IEJoinOptions) -> IdxCa {
+    let l1_sort_options = SortOptions::default()
+        .with_maintain_order(true)
+        .with_nulls_last(false)
+        .with_order_descending(options.l1_descending());
+
+    s.arg_sort(l1_sort_options)
+        .slice(s.null_count() as i64, s.len() - s.null_count())
+}
+
+/// Inequality join of a block of rows from each side, given as (a slice of) the row order
+/// returned by [`iejoin_l1_order`].
+///
+/// Returns the indices of the matching rows in `selected_left` and `selected_right`, or `None` if
+/// there are no matches.
+pub fn iejoin_block(
+    l_l1_idx: &IdxCa,
+    r_l1_idx: &IdxCa,
+    selected_left: &[Series],
+    selected_right: &[Series],
+    options: &IEJoinOptions,
+) -> PolarsResult<Option<(IdxCa, IdxCa)>> {
+    if l_l1_idx.is_empty() || r_l1_idx.is_empty() {
+        return Ok(None);
+    }
+    fn get_extrema<'a>(l1_idx: &'a IdxCa, s: &'a Series) -> Option<(AnyValue<'a>, AnyValue<'a>)> {
+        let first = l1_idx.first()?;
+        let last = l1_idx.last()?;
+
+        let start = s.get(first as usize).unwrap();
+        let end = s.get(last as usize).unwrap();
+
+        Some(if start < end {
+            (start, end)
+        } else {
+            (end, start)
+        })
+    }
+    let Some((min_l, max_l)) = get_extrema(l_l1_idx, &selected_left[0]) else {
+        return Ok(None);
+    };
+    let Some((min_r, max_r)) = get_extrema(r_l1_idx, &selected_right[0]) else {
+        return Ok(None);
+    };
+
+    let include_block = match options.operator1 {
+        InequalityOperator::Lt => min_l < max_r,
+        InequalityOperator::LtEq => min_l <= max_r,
+        InequalityOperator::Gt => max_l > min_r,
+        InequalityOperator::GtEq => max_l >= min_r,
+    };
+
+    if !include_block {
+        return Ok(None);
+    }
+
+    let (mut l, mut r) = unsafe {
+        (
+            selected_left
+                .iter()
+                .map(|s| s.take_unchecked(l_l1_idx))
+                .collect_vec(),
+            selected_right
+                .iter()
+                .map(|s| s.take_unchecked(r_l1_idx))
+                .collect_vec(),
+        )
+    };
+    let sorted_flag = if options.l1_descending() {
+        IsSorted::Descending
+    } else {
+        IsSorted::Ascending
+    };
+    // We sorted using the first series
+    l[0].set_sorted_flag(sorted_flag);
+    r[0].set_sorted_flag(sorted_flag);
+
+    // Compute the row indexes
+    let (idx_l, idx_r) = if options.operator2.is_some() {
+        iejoin_tuples(l, r, options, None)
+    } else {
+        piecewise_merge_join_tuples(l, r, options, None)
+    }?;
+
+    if idx_l.is_empty() {
+        return Ok(None);
+    }
+
+    // These are row indexes in the slices we have given, so we use those to gather in the
+    // original l1 offset arrays. This gives us indexes in the original tables.
+    unsafe {
+        Ok(Some((
+            l_l1_idx.take_unchecked(&idx_l),
+            r_l1_idx.take_unchecked(&idx_r),
+        )))
+    }
+}
+
 pub(super) fn iejoin_par(
     left: &DataFrame,
     right: &DataFrame,
@@ -213,25 +322,8 @@ pub(super) fn iejoin_par(
     suffix: Option<PlSmallStr>,
     slice: Option<(i64, usize)>,
 ) -> PolarsResult<DataFrame> {
-    let l1_descending = matches!(
-        options.operator1,
-        InequalityOperator::Gt | InequalityOperator::GtEq
-    );
-
-    let l1_sort_options = SortOptions::default()
-        .with_maintain_order(true)
-        .with_nulls_last(false)
-        .with_order_descending(l1_descending);
-
-    let sl = &selected_left[0];
-    let l1_s_l = sl
-        .arg_sort(l1_sort_options)
-        .slice(sl.null_count() as i64, sl.len() - sl.null_count());
-
-    let sr = &selected_right[0];
-    let l1_s_r = sr
-        .arg_sort(l1_sort_options)
-        .slice(sr.null_count() as i64, sr.len() - sr.null_count());
+    let l1_s_l = iejoin_l1_order(&selected_left[0], options);
+    let l1_s_r = iejoin_l1_order(&selected_right[0], options);
 
     // Because we do a cartesian product, the number of partitions is squared.
     // We take the sqrt, but we don't expect every partition to produce results and work can be
@@ -246,83 +338,7 @@ pub(super) fn iejoin_par(
         .collect::<Vec<_>>();
 
     let iter = cartesian_prod.par_iter().map(|(l_l1_idx, r_l1_idx)| {
-        if l_l1_idx.is_empty() || r_l1_idx.is_empty() {
-            return Ok(None);
-        }
-        fn get_extrema<'a>(
-            l1_idx: &'a IdxCa,
-            s: &'a Series,
-        ) -> Option<(AnyValue<'a>, AnyValue<'a>)> {
-            let first = l1_idx.first()?;
-            let last = l1_idx.last()?;
-
-            let start = s.get(first as usize).unwrap();
-            let end = s.get(last as usize).unwrap();
-
-            Some(if start < end {
-                (start, end)
-            } else {
-                (end, start)
-            })
-        }
-        let Some((min_l, max_l)) = get_extrema(l_l1_idx, sl) else {
-            return Ok(None);
-        };
-        let Some((min_r, max_r)) = get_extrema(r_l1_idx, sr) else {
-            return Ok(None);
-        };
-
-        let include_block = match options.operator1 {
-            InequalityOperator::Lt => min_l < max_r,
-            InequalityOperator::LtEq => min_l <= max_r,
-            InequalityOperator::Gt => max_l > min_r,
-            InequalityOperator::GtEq => max_l >= min_r,
-        };
-
-        if include_block {
-            let (mut l, mut r) = unsafe {
-                (
-                    selected_left
-                        .iter()
-                        .map(|s| s.take_unchecked(l_l1_idx))
-                        .collect_vec(),
-                    selected_right
-                        .iter()
-                        .map(|s| s.take_unchecked(r_l1_idx))
-                        .collect_vec(),
-                )
-            };
-            let sorted_flag = if l1_descending {
-                IsSorted::Descending
-            } else {
-                IsSorted::Ascending
-            };
-            // We sorted using the first series
-            l[0].set_sorted_flag(sorted_flag);
-            r[0].set_sorted_flag(sorted_flag);
-
-            // Compute the row indexes
-            let (idx_l, idx_r) = if options.operator2.is_some() {
-                iejoin_tuples(l, r, options, None)
-            } else {
-                piecewise_merge_join_tuples(l, r, options, None)
-            }?;
-
-            if idx_l.is_empty() {
-                return Ok(None);
-            }
-
-            // These are row indexes in the slices we have given, so we use those to gather in the
-            // original l1 offset arrays. This gives us indexes in the original tables.
-            unsafe {
-                Ok(Some((
-                    l_l1_idx.take_unchecked(&idx_l),
-                    r_l1_idx.take_unchecked(&idx_r),
-                )))
-            }
-        } else {
-            Ok(None)
-        }
+        iejoin_block(l_l1_idx, r_l1_idx, &selected_left, &selected_right, options)
     });
 
     let row_indices = POOL.install(|| iter.collect::<PolarsResult<Vec<_>>>())?;
@@ -5,7 +5,7 @@ use polars_error::PolarsResult;
 
 use super::super::{WriteOptions, utils};
 use crate::arrow::read::schema::is_nullable;
-use crate::parquet::encoding::{Encoding, delta_bitpacked};
+use crate::parquet::encoding::{Encoding, delta_bitpacked, delta_byte_array};
 use crate::parquet::schema::types::PrimitiveType;
 use crate::parquet::statistics::{BinaryStatistics, ParquetStatistics};
 use crate::write::utils::invalid_encoding;
@@ -46,6 +46,16 @@ pub(crate) fn encode_plain<O: Offset>(
     }
 }
 
+/// Encodes the non-null values according to DELTA_BYTE_ARRAY.
+pub(crate) fn encode_delta_byte_array<'a, I: Iterator<Item = &'a [u8]> + Clone>(
+    non_null_values: I,
+    num_non_null_values: usize,
+    buffer: &mut Vec<u8>,
+) {
+    let iterator = utils::ExactSizedIter::new(non_null_values, num_non_null_values);
+    delta_byte_array::encode(iterator, buffer);
+}
+
 pub fn array_to_page<O: Offset>(
     array: &BinaryArray<O>,
     options: WriteOptions,
@@ -76,6 +86,11 @@ pub fn array_to_page<O: Offset>(
             encode_options,
             &mut buffer,
         ),
+        Encoding::DeltaByteArray => encode_delta_byte_array(
+            array.non_null_values_iter(),
+            array.len() - array.null_count(),
+            &mut buffer,
+        ),
         _ => return Err(invalid_encoding(encoding, array.dtype())),
     }
 
@@ -0,0 +1,146 @@
+use arrow::array::{
+    Array, BinaryArray, BinaryViewArray, DictionaryArray, PrimitiveArray, Utf8Array, Utf8ViewArray,
+};
+use arrow::datatypes::{PhysicalType, PrimitiveType};
+use arrow::match_integer_type;
+use arrow::types::NativeType;
+use polars_error::{PolarsResult, polars_bail};
+
+use super::{ColumnWriteOptions, to_leaves, to_parquet_leaves};
+use crate::parquet::bloom_filter::{hash_byte, hash_native, insert, optimal_num_bytes};
+use crate::parquet::schema::types::{ParquetType, PhysicalType as ParquetPhysicalType};
+use crate::parquet::types::NativeType as ParquetNativeType;
+
+/// The false positive probability of the bloom filters that are written.
+const BLOOM_FILTER_FPP: f64 = 0.01;
+
+fn hash_primitive<T: NativeType, P: ParquetNativeType>(
+    array: &dyn Array,
+    to_physical: impl Fn(T) -> P,
+    hashes: &mut Vec<u64>,
+) {
+    let array = array.as_any().downcast_ref::<PrimitiveArray<T>>().unwrap();
+    hashes.extend(
+        array
+            .non_null_values_iter()
+            .map(|v| hash_native(to_physical(v))),
+    );
+}
+
+/// Hashes the non-null values of `array` the way they are stored in a column of
+/// `physical_type`, as is done for its split-block bloom filter.
+///
+/// Returns `false` if values of `array` are not stored as `physical_type`.
+pub fn hash_array(
+    array: &dyn Array,
+    physical_type: ParquetPhysicalType,
+    hashes: &mut Vec<u64>,
+) -> bool {
+    use {ParquetPhysicalType as PP, PhysicalType as P, PrimitiveType as PT};
+
+    match (array.dtype().to_physical_type(), physical_type) {
+        (P::Primitive(PT::Int8), PP::Int32) => hash_primitive(array, |v: i8| v as i32, hashes),
+        (P::Primitive(PT::Int16), PP::Int32) => hash_primitive(array, |v: i16| v as i32, hashes),
+        (P::Primitive(PT::Int32), PP::Int32) => hash_primitive(array, |v: i32| v, hashes),
+        (P::Primitive(PT::UInt8), PP::Int32) => hash_primitive(array, |v: u8| v as i32, hashes),
+        (P::Primitive(PT::UInt16), PP::Int32) => hash_primitive(array, |v: u16| v as i32, hashes),
+        (P::Primitive(PT::UInt32), PP::Int32) => hash_primitive(array, |v: u32| v as i32, hashes),
+        (P::Primitive(PT::Int64), PP::Int64) => hash_primitive(array, |v: i64| v, hashes),
+        (P::Primitive(PT::UInt64), PP::Int64) => hash_primitive(array, |v: u64| v as i64, hashes),
+        (P::Primitive(PT::Float32), PP::Float) => hash_primitive(array, |v: f32| v, hashes),
+        (P::Primitive(PT::Float64), PP::Double) => hash_primitive(array, |v: f64| v, hashes),
+        (P::BinaryView, PP::ByteArray) => {
+            let array = array.as_any().downcast_ref::<BinaryViewArray>().unwrap();
+            hashes.extend(array.non_null_values_iter().map(hash_byte));
+        },
+        (P::Utf8View, PP::ByteArray) => {
+            let array = array.as_any().downcast_ref::<Utf8ViewArray>().unwrap();
+            hashes.extend(array.non_null_values_iter().map(hash_byte));
+        },
+        (P::Binary, PP::ByteArray) => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i32>>().unwrap();
+            hashes.extend(array.iter().flatten().map(hash_byte));
+        },
+        (P::LargeBinary, PP::ByteArray) => {
+            let array = array.as_any().downcast_ref::<BinaryArray<i64>>().unwrap();
+            hashes.extend(array.iter().flatten().map(hash_byte));
+        },
+        (P::Utf8, PP::ByteArray) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i32>>().unwrap();
+            hashes.extend(array.iter().flatten().map(hash_byte));
+        },
+        (P::LargeUtf8, PP::ByteArray) => {
+            let array = array.as_any().downcast_ref::<Utf8Array<i64>>().unwrap();
+            hashes.extend(array.iter().flatten().map(hash_byte));
+        },
+        (P::Dictionary(key_type), _) => {
+            // All values of the dictionary are hashed, which may include values that are not
+            // referenced. This only makes the filter less selective.
+            let values = match_integer_type!(key_type, |$T| {
+                array
+                    .as_any()
+                    .downcast_ref::<DictionaryArray<$T>>()
+                    .unwrap()
+                    .values()
+                    .clone()
+            });
+            return hash_array(values.as_ref(), physical_type, hashes);
+        },
+        _ => return false,
+    }
+
+    true
+}
+
+/// Creates the split-block bloom filter bitset of every leaf column of `array` that has
+/// [`FieldWriteOptions::bloom_filter`] set.
+///
+/// [`FieldWriteOptions::bloom_filter`]: super::FieldWriteOptions::bloom_filter
+pub fn array_to_bloom_filters(
+    array: &dyn Array,
+    type_: ParquetType,
+    column_options: &ColumnWriteOptions,
+) -> PolarsResult<Vec<Option<Vec<u8>>>> {
+    let types = to_parquet_leaves(type_);
+
+    let mut field_options = Vec::with_capacity(types.len());
+    column_options.to_leaves(&mut field_options);
+
+    assert_eq!(field_options.len(), types.len());
+
+    if field_options.iter().all(|o| !o.bloom_filter) {
+        return Ok(vec![None; types.len()]);
+    }
+
+    let mut values = Vec::new();
+    to_leaves(array, &mut values);
+
+    let mut hashes = Vec::new();
+    values
+        .iter()
+        .zip(types)
+        .zip(field_options)
+        .map(|((values, type_), field_options)| {
+            if !field_options.bloom_filter {
+                return Ok(None);
+            }
+
+            hashes.clear();
+            if !hash_array(values.as_ref(), type_.physical_type, &mut hashes) {
+                polars_bail!(
+                    InvalidOperation: "cannot write a parquet bloom filter for column '{}' of type {:?}",
+                    type_.field_info.name, values.dtype()
+                );
+            }
+
+            hashes.sort_unstable();
+            hashes.dedup();
+
+            let mut bitset = vec![0; optimal_num_bytes(hashes.len(), BLOOM_FILTER_FPP)];
+            for hash in hashes.iter() {
+                insert(&mut bitset, *hash);
+            }
+            Ok(Some(bitset))
+        })
+        .collect()
+}
@@ -14,6 +14,8 @@
 
 mod binary;
 mod binview;
+#[cfg(feature = "bloom_filter")]
+mod bloom_filter;
 mod boolean;
 mod dictionary;
 mod file;
@@ -28,6 +30,8 @@ mod utils;
 use arrow::array::*;
 use arrow::datatypes::*;
 use arrow::types::{NativeType, days_ms, i256};
+#[cfg(feature = "bloom_filter")]
+pub use bloom_filter::{array_to_bloom_filters, hash_array};
 pub use nested::{num_values, write_rep_and_def};
 pub use pages::{to_leaves, to_nested, to_parquet_leaves};
 use polars_utils::pl_str::PlSmallStr;
@@ -122,9 +126,36 @@ impl ColumnWriteOptions {
 #[derive(Clone)]
 pub struct FieldWriteOptions {
     pub encoding: Encoding,
+    /// The encoding used if `encoding` is [`Encoding::RleDictionary`] and the column cannot be
+    /// dictionary encoded.
+    pub fallback_encoding: Encoding,
+    /// Overwrites the compression of the [`WriteOptions`] for the column.
+    pub compression: Option<CompressionOptions>,
+    /// Overwrites the statistics of the [`WriteOptions`] for the column.
+    pub statistics: Option<StatisticsOptions>,
+    /// Whether to write a split-block bloom filter for the column.
+    pub bloom_filter: bool,
 }
 
 impl ColumnWriteOptions {
+    /// Whether any of the leaf columns is written with a bloom filter.
+    pub fn has_bloom_filter(&self) -> bool {
+        let mut leaves = Vec::new();
+        self.to_leaves(&mut leaves);
+        leaves.iter().any(|o| o.bloom_filter)
+    }
+
+    /// The compression of every leaf column, where `compression` is used for the leaves without
+    /// an overwrite.
+    pub fn leaf_compressions(&self, compression: CompressionOptions) -> Vec<CompressionOptions> {
+        let mut leaves = Vec::new();
+        self.to_leaves(&mut leaves);
+        leaves
+            .iter()
+            .map(|o| o.compression.unwrap_or(compression))
+            .collect()
+    }
+
     pub fn default_with(children: ChildWriteOptions) -> Self {
         Self {
             field_id: None,
@@ -136,7 +167,24 @@ impl ColumnWriteOptions {
 
 impl FieldWriteOptions {
     pub fn default_with_encoding(encoding: Encoding) -> Self {
-        Self { encoding }
+        Self {
+            encoding,
+            fallback_encoding: Encoding::Plain,
+            compression: None,
+            statistics: None,
+            bloom_filter: false,
+        }
+    }
+
+    /// Applies the overwrites of the column to `options`.
+    pub fn apply_to(&self, mut options: WriteOptions) -> WriteOptions {
+        if let Some(compression) = self.compression {
+            options.compression = compression;
+        }
+        if let Some(statistics) = self.statistics {
+            options.statistics = statistics;
+        }
+        options
     }
 
     pub fn into_default_column_write_options(self) -> ColumnWriteOptions {
@@ -351,6 +399,7 @@ pub fn array_to_pages(
     options: WriteOptions,
     field_options: &FieldWriteOptions,
 ) -> PolarsResult<DynIter<'static, PolarsResult<Page>>> {
+    let options = field_options.apply_to(options);
     let mut encoding = field_options.encoding;
     if let ArrowDataType::Dictionary(key_type, _, _) = primitive_array.dtype().to_logical_type() {
         return match_integer_type!(key_type, |$T| {
@@ -373,8 +422,8 @@ pub fn array_to_pages(
             }
         }
 
-        // We didn't succeed, fallback to plain
-        encoding = Encoding::Plain;
+        // We didn't succeed, fallback to the non-dictionary encoding
+        encoding = field_options.fallback_encoding;
     }
 
     let nested = nested.to_vec();
@@ -448,6 +497,15 @@ pub fn array_to_page_simple(
 ) -> PolarsResult<Page> {
     let dtype = array.dtype();
 
+    // Fixed-size binary values are only written with the plain encoding.
+    if matches!(
+        type_.physical_type,
+        ParquetPhysicalType::FixedLenByteArray(_)
+    ) && encoding != Encoding::Plain
+    {
+        return Err(utils::invalid_encoding(encoding, dtype));
+    }
+
     match dtype.to_logical_type() {
         ArrowDataType::Boolean => boolean::array_to_page(
             array.as_any().downcast_ref().unwrap(),
@@ -524,16 +582,22 @@ pub fn array_to_page_simple(
                 encoding,
             );
         },
-        ArrowDataType::Float32 => primitive::array_to_page_plain::<f32, f32>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
-        ArrowDataType::Float64 => primitive::array_to_page_plain::<f64, f64>(
-            array.as_any().downcast_ref().unwrap(),
-            options,
-            type_,
-        ),
+        ArrowDataType::Float32 => {
+            return primitive::array_to_page_float::<f32, f32>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
+        ArrowDataType::Float64 => {
+            return primitive::array_to_page_float::<f64, f64>(
+                array.as_any().downcast_ref().unwrap(),
+                options,
+                type_,
+                encoding,
+            );
+        },
         ArrowDataType::LargeUtf8 => {
             let array =
                 polars_compute::cast::cast(array, &ArrowDataType::LargeBinary, Default::default())
@@ -814,8 +878,12 @@ fn array_to_page_nested(
     type_: ParquetPrimitiveType,
     nested: &[Nested],
     options: WriteOptions,
-    _encoding: Encoding,
+    encoding: Encoding,
 ) -> PolarsResult<Page> {
+    if encoding != Encoding::Plain {
+        polars_bail!(nyi = "Writing nested parquet columns with the {encoding:?} encoding");
+    }
+
     use ArrowDataType::*;
     match array.dtype().to_logical_type() {
         Null => {
@@ -4,9 +4,27 @@ mod read;
 mod split_block;
 
 pub use hash::{hash_byte, hash_native};
-pub use read::read;
+pub use read::{deserialize_header, read};
 pub use split_block::{insert, is_in_set};
 
+/// The smallest bitset that is written, a single block.
+const MIN_NUM_BYTES: usize = 32;
+/// The largest bitset that is written.
+const MAX_NUM_BYTES: usize = 128 * 1024 * 1024;
+
+/// Returns the number of bytes of a bitset that holds `num_distinct_values` with a false positive
+/// probability of at most `fpp`.
+///
+/// The result is a power of two between 32 bytes and 128 MiB.
+pub fn optimal_num_bytes(num_distinct_values: usize, fpp: f64) -> usize {
+    let num_bits = -8.0 * num_distinct_values as f64 / (1.0 - fpp.powf(1.0 / 8.0)).ln();
+    let num_bytes = (num_bits / 8.0).ceil() as usize;
+    num_bytes
+        .clamp(MIN_NUM_BYTES, MAX_NUM_BYTES)
+        .next_power_of_two()
+        .min(MAX_NUM_BYTES)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,4 +86,12 @@ mod tests {
         ];
         assert_eq!(bitset, expected);
     }
+
+    #[test]
+    fn num_bytes() {
+        assert_eq!(optimal_num_bytes(0, 0.01), 32);
+        assert_eq!(optimal_num_bytes(10, 0.01), 32);
+        assert_eq!(optimal_num_bytes(1_000_000, 0.01), 2 * 1024 * 1024);
+        assert_eq!(optimal_num_bytes(usize::MAX, 0.01), 128 * 1024 * 1024);
+    }
 }
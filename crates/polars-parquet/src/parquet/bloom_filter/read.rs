@@ -49,3 +49,28 @@ pub fn read<R: Read + Seek>(
 
     Ok(())
 }
+
+/// Deserializes the [`BloomFilterHeader`] at the start of `bytes`.
+///
+/// Returns the length of the header and the length of the bitset that follows it, or `None` if
+/// the algorithm or the compression of the bloom filter is not supported.
+pub fn deserialize_header(bytes: &[u8]) -> ParquetResult<Option<(usize, usize)>> {
+    let mut reader = bytes;
+    let mut prot = TCompactInputProtocol::new(&mut reader, usize::MAX); // max is ok since `BloomFilterHeader` never allocates
+    let header = BloomFilterHeader::read_from_in_protocol(&mut prot)?;
+    let header_len = bytes.len() - reader.len();
+
+    if header.algorithm != BloomFilterAlgorithm::BLOCK(SplitBlockAlgorithm {})
+        || header.compression != BloomFilterCompression::UNCOMPRESSED(Uncompressed {})
+    {
+        return Ok(None);
+    }
+
+    let length: usize = header.num_bytes.try_into()?;
+    // The bitset is made up of 32-byte blocks.
+    if length == 0 || length % 32 != 0 {
+        return Ok(None);
+    }
+
+    Ok(Some((header_len, length)))
+}
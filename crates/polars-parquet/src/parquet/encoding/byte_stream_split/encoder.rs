@@ -0,0 +1,18 @@
+use crate::parquet::types::NativeType;
+
+/// Encodes an iterator of values according to BYTE_STREAM_SPLIT: the k-th byte of every value
+/// is written to the k-th stream.
+pub fn encode<T: NativeType, I: ExactSizeIterator<Item = T>>(values: I, buffer: &mut Vec<u8>) {
+    let element_size = size_of::<T>();
+    let num_elements = values.len();
+
+    let offset = buffer.len();
+    buffer.resize(offset + num_elements * element_size, 0);
+    let streams = &mut buffer[offset..];
+
+    for (i, v) in values.enumerate() {
+        for (n, byte) in v.to_le_bytes().into_iter().enumerate() {
+            streams[(num_elements * n) + i] = byte;
+        }
+    }
+}
@@ -24,4 +24,19 @@ mod tests {
         assert_eq!(values, b"Helloicopter");
         Ok(())
     }
+
+    #[test]
+    fn shorter_than_previous() -> Result<(), ParquetError> {
+        let data = vec![b"Hello".as_ref(), b"Hel", b"Help"];
+        let mut buffer = vec![];
+        encode(data.clone().into_iter(), &mut buffer);
+
+        let mut decoder = Decoder::try_new(&buffer)?;
+        let values = decoder.by_ref().collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(
+            values,
+            vec![b"Hello".to_vec(), b"Hel".to_vec(), b"Help".to_vec()]
+        );
+        Ok(())
+    }
 }
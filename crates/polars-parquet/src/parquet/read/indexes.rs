@@ -0,0 +1,115 @@
+//! Reading of the [page index](https://github.com/apache/parquet-format/blob/master/PageIndex.md).
+use std::ops::Range;
+
+use polars_parquet_format::thrift::protocol::TCompactInputProtocol;
+pub use polars_parquet_format::{ColumnIndex, OffsetIndex, PageLocation};
+
+use crate::parquet::error::{ParquetError, ParquetResult};
+use crate::parquet::schema::types::PrimitiveType;
+use crate::parquet::statistics::{ParquetStatistics, Statistics};
+
+/// Deserializes the thrift-encoded [`ColumnIndex`] of a column chunk.
+pub fn deserialize_column_index(mut bytes: &[u8]) -> ParquetResult<ColumnIndex> {
+    // Every page statistic is a separate allocation, even if it only takes a few bytes in the
+    // file.
+    let max_size = bytes.len() * 32 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    let index = ColumnIndex::read_from_in_protocol(&mut prot)?;
+
+    if index.min_values.len() != index.null_pages.len()
+        || index.max_values.len() != index.null_pages.len()
+        || index
+            .null_counts
+            .as_ref()
+            .is_some_and(|nc| nc.len() != index.null_pages.len())
+    {
+        return Err(ParquetError::oos(
+            "The column index has a different number of values per page statistic",
+        ));
+    }
+
+    Ok(index)
+}
+
+/// Deserializes the thrift-encoded [`OffsetIndex`] of a column chunk.
+pub fn deserialize_offset_index(mut bytes: &[u8]) -> ParquetResult<OffsetIndex> {
+    let max_size = bytes.len() * 32 + 1024;
+    let mut prot = TCompactInputProtocol::new(&mut bytes, max_size);
+    let index = OffsetIndex::read_from_in_protocol(&mut prot)?;
+
+    let mut prev_first_row = None;
+    for location in &index.page_locations {
+        if location.offset < 0
+            || location.compressed_page_size < 0
+            || location.first_row_index < 0
+            || prev_first_row.is_some_and(|prev| prev > location.first_row_index)
+        {
+            return Err(ParquetError::oos(
+                "The offset index has invalid page locations",
+            ));
+        }
+        prev_first_row = Some(location.first_row_index);
+    }
+
+    Ok(index)
+}
+
+/// Returns the rows of the row group that are in each data page.
+pub fn page_row_ranges(
+    offset_index: &OffsetIndex,
+    num_rows: usize,
+) -> impl ExactSizeIterator<Item = Range<usize>> + '_ {
+    let locations = &offset_index.page_locations;
+    (0..locations.len()).map(move |i| {
+        let start = locations[i].first_row_index as usize;
+        let end = locations
+            .get(i + 1)
+            .map_or(num_rows, |l| l.first_row_index as usize);
+        start.min(num_rows)..end.min(num_rows)
+    })
+}
+
+/// Returns the byte range of each data page in the file.
+pub fn page_byte_ranges(
+    offset_index: &OffsetIndex,
+) -> impl ExactSizeIterator<Item = Range<u64>> + '_ {
+    offset_index.page_locations.iter().map(|l| {
+        let start = l.offset as u64;
+        start..start + l.compressed_page_size as u64
+    })
+}
+
+/// Decodes the per-page statistics of a [`ColumnIndex`] into [`Statistics`].
+///
+/// The minimum and maximum of pages that only contain nulls are left empty.
+pub fn column_index_statistics(
+    column_index: &ColumnIndex,
+    primitive_type: &PrimitiveType,
+) -> ParquetResult<Vec<Statistics>> {
+    (0..column_index.null_pages.len())
+        .map(|i| {
+            let is_null_page = column_index.null_pages[i];
+            let (min_value, max_value) = if is_null_page {
+                (None, None)
+            } else {
+                (
+                    Some(column_index.min_values[i].clone()),
+                    Some(column_index.max_values[i].clone()),
+                )
+            };
+
+            let statistics = ParquetStatistics {
+                max: None,
+                min: None,
+                null_count: column_index.null_counts.as_ref().map(|nc| nc[i]),
+                distinct_count: None,
+                max_value,
+                min_value,
+                is_max_value_exact: None,
+                is_min_value_exact: None,
+            };
+
+            Statistics::deserialize(&statistics, primitive_type.clone())
+        })
+        .collect()
+}
@@ -1,5 +1,6 @@
 mod column;
 mod compression;
+pub mod indexes;
 pub mod levels;
 mod metadata;
 mod page;
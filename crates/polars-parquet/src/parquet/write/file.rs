@@ -3,7 +3,8 @@ use std::io::Write;
 use polars_parquet_format::RowGroup;
 use polars_parquet_format::thrift::protocol::TCompactOutputProtocol;
 
-use super::indexes::{write_column_index, write_offset_index};
+use super::bloom_filter::write_bloom_filter;
+use super::indexes::{has_column_index_statistics, write_column_index, write_offset_index};
 use super::page::PageWriteSpec;
 use super::row_group::write_row_group;
 use super::{RowGroupIterColumns, WriteOptions};
@@ -66,6 +67,7 @@ pub struct FileWriter<W: Write> {
     offset: u64,
     row_groups: Vec<RowGroup>,
     page_specs: Vec<Vec<Vec<PageWriteSpec>>>,
+    bloom_filters: Vec<Vec<Option<Vec<u8>>>>,
     /// Used to store the current state for writing the file
     state: State,
     // when the file is written, metadata becomes available
@@ -125,6 +127,7 @@ impl<W: Write> FileWriter<W> {
             offset: 0,
             row_groups: vec![],
             page_specs: vec![],
+            bloom_filters: vec![],
             state: State::Initialised,
             metadata: None,
         }
@@ -156,6 +159,29 @@ impl<W: Write> FileWriter<W> {
         ParquetError: From<E>,
         E: std::error::Error,
     {
+        self.write_with_bloom_filters(row_group, vec![])
+    }
+
+    /// Writes a row group to the file together with the split-block bloom filter bitsets of its
+    /// columns.
+    ///
+    /// `bloom_filters` is either empty or has one entry per column of the row group. The bloom
+    /// filters are written with the indexes when the file is ended.
+    pub fn write_with_bloom_filters<E>(
+        &mut self,
+        row_group: RowGroupIterColumns<'_, E>,
+        bloom_filters: Vec<Option<Vec<u8>>>,
+    ) -> ParquetResult<()>
+    where
+        ParquetError: From<E>,
+        E: std::error::Error,
+    {
+        if !bloom_filters.is_empty() && bloom_filters.len() != self.schema.columns().len() {
+            return Err(ParquetError::InvalidParameter(
+                "The number of bloom filters must match the number of columns".to_string(),
+            ));
+        }
+
         if self.offset == 0 {
             self.start()?;
         }
@@ -170,6 +196,7 @@ impl<W: Write> FileWriter<W> {
         self.offset += size;
         self.row_groups.push(group);
         self.page_specs.push(specs);
+        self.bloom_filters.push(bloom_filters);
         Ok(())
     }
 
@@ -188,6 +215,29 @@ impl<W: Write> FileWriter<W> {
         // compute file stats
         let num_rows = self.row_groups.iter().map(|group| group.num_rows).sum();
 
+        // write bloom filters
+        self.row_groups
+            .iter_mut()
+            .zip(self.bloom_filters.iter())
+            .try_for_each(|(group, bloom_filters)| {
+                group
+                    .columns
+                    .iter_mut()
+                    .zip(bloom_filters.iter())
+                    .try_for_each(|(column, bitset)| {
+                        let Some(bitset) = bitset else {
+                            return ParquetResult::Ok(());
+                        };
+                        let offset = self.offset;
+                        self.offset += write_bloom_filter(&mut self.writer, bitset)?;
+                        let meta_data = column.meta_data.as_mut().unwrap();
+                        meta_data.bloom_filter_offset = Some(offset as i64);
+                        meta_data.bloom_filter_length = Some((self.offset - offset).try_into()?);
+                        ParquetResult::Ok(())
+                    })?;
+                ParquetResult::Ok(())
+            })?;
+
         if self.options.write_statistics {
             // write column indexes (require page statistics)
             self.row_groups
@@ -196,6 +246,10 @@ impl<W: Write> FileWriter<W> {
                 .try_for_each(|(group, pages)| {
                     group.columns.iter_mut().zip(pages.iter()).try_for_each(
                         |(column, pages)| {
+                            // Columns written without statistics have no column index.
+                            if !has_column_index_statistics(pages) {
+                                return Ok(());
+                            }
                             let offset = self.offset;
                             column.column_index_offset = Some(offset as i64);
                             self.offset += write_column_index(&mut self.writer, pages)?;
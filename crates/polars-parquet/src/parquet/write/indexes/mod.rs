@@ -1,4 +1,5 @@
 mod serialize;
 mod write;
 
+pub use serialize::has_column_index_statistics;
 pub use write::*;
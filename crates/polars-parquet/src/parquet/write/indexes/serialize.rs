@@ -3,6 +3,20 @@ use polars_parquet_format::{BoundaryOrder, ColumnIndex, OffsetIndex, PageLocatio
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::write::page::{PageWriteSpec, is_data_page};
 
+/// Whether the data pages have the statistics that are needed to serialize a column index. This
+/// is not the case for columns that are written without min, max or null count statistics.
+pub fn has_column_index_statistics(pages: &[PageWriteSpec]) -> bool {
+    pages.iter().filter(|x| is_data_page(x)).all(|spec| {
+        spec.statistics.as_ref().is_some_and(|stats| {
+            let stats = stats.serialize();
+            stats.null_count.is_some_and(|null_count| {
+                (stats.min_value.is_some() && stats.max_value.is_some())
+                    || null_count as usize == spec.num_values
+            })
+        })
+    })
+}
+
 pub fn serialize_column_index(pages: &[PageWriteSpec]) -> ParquetResult<ColumnIndex> {
     let mut null_pages = Vec::with_capacity(pages.len());
     let mut min_values = Vec::with_capacity(pages.len());
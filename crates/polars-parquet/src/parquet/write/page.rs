@@ -37,7 +37,6 @@ fn maybe_bytes(uncompressed: usize, compressed: usize) -> ParquetResult<(i32, i3
 /// Contains page write metrics.
 pub struct PageWriteSpec {
     pub header: ParquetPageHeader,
-    #[allow(dead_code)]
     pub num_values: usize,
     /// The number of actual rows. For non-nested values, this is equal to the number of values.
     pub num_rows: usize,
@@ -9,7 +9,9 @@ use super::{RowGroupIterColumns, WriteOptions};
 use crate::parquet::error::{ParquetError, ParquetResult};
 use crate::parquet::metadata::{KeyValue, SchemaDescriptor};
 use crate::parquet::write::State;
-use crate::parquet::write::indexes::{write_column_index_async, write_offset_index_async};
+use crate::parquet::write::indexes::{
+    has_column_index_statistics, write_column_index_async, write_offset_index_async,
+};
 use crate::parquet::write::page::PageWriteSpec;
 use crate::parquet::{FOOTER_SIZE, PARQUET_MAGIC};
 
@@ -150,6 +152,10 @@ impl<W: AsyncWrite + Unpin + Send> FileStreamer<W> {
             // write column indexes (require page statistics)
             for (group, pages) in self.row_groups.iter_mut().zip(self.page_specs.iter()) {
                 for (column, pages) in group.columns.iter_mut().zip(pages.iter()) {
+                    // Columns written without statistics have no column index.
+                    if !has_column_index_statistics(pages) {
+                        continue;
+                    }
                     let offset = self.offset;
                     column.column_index_offset = Some(offset as i64);
                     self.offset += write_column_index_async(&mut self.writer, pages).await?;
@@ -25,6 +25,7 @@ impl CsvSink {
             .with_separator(options.serialize_options.separator)
             .with_line_terminator(options.serialize_options.line_terminator)
             .with_quote_char(options.serialize_options.quote_char)
+            .with_escape_char(options.serialize_options.escape_char)
             .with_batch_size(options.batch_size)
             .with_datetime_format(options.serialize_options.datetime_format)
             .with_date_format(options.serialize_options.date_format)
@@ -33,6 +34,8 @@ impl CsvSink {
             .with_float_precision(options.serialize_options.float_precision)
             .with_null_value(options.serialize_options.null)
             .with_quote_style(options.serialize_options.quote_style)
+            .with_compression(options.compression)
+            .with_encoding(options.encoding)
             .n_threads(1)
             .batched(schema)?;
 
@@ -6,7 +6,7 @@ use crossbeam_channel::{Receiver, Sender, bounded};
 use polars_core::prelude::*;
 use polars_io::cloud::CloudOptions;
 use polars_io::parquet::write::{
-    BatchedWriter, ParquetWriteOptions, ParquetWriter, RowGroupIterColumns,
+    BatchedWriter, EncodedRowGroup, ParquetWriteOptions, ParquetWriter,
 };
 use polars_io::utils::file::try_get_writeable;
 use polars_utils::file::WriteClose;
@@ -15,7 +15,7 @@ use crate::executors::sinks::output::file_sink::SinkWriter;
 use crate::operators::{DataChunk, FinalizedSink, PExecutionContext, Sink, SinkResult};
 use crate::pipeline::morsels_per_sink;
 
-type RowGroups = Vec<RowGroupIterColumns<'static, PolarsError>>;
+type RowGroups = Vec<EncodedRowGroup>;
 
 pub(super) fn init_row_group_writer_thread<W>(
     receiver: Receiver<Option<(IdxSize, RowGroups)>>,
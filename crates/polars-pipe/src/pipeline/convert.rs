@@ -177,7 +177,10 @@ where
                         )?)
                             as Box<dyn SinkTrait>,
                         #[allow(unreachable_patterns)]
-                        _ => unreachable!(),
+                        _ => polars_bail!(
+                            InvalidOperation: "{} sinks are not supported in the old streaming engine",
+                            file_type.extension()
+                        ),
                     }
                 },
                 SinkTypeIR::Partition { .. } => {
@@ -8,6 +8,8 @@ mod sink;
 
 use polars_core::error::PolarsResult;
 use polars_core::prelude::*;
+#[cfg(feature = "avro")]
+use polars_io::avro::AvroWriterOptions;
 #[cfg(feature = "csv")]
 use polars_io::csv::write::CsvWriterOptions;
 #[cfg(feature = "ipc")]
@@ -16,6 +18,8 @@ use polars_io::ipc::IpcWriterOptions;
 use polars_io::json::JsonWriterOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::write::ParquetWriteOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars_io::utils::compression::ExternalCompression;
 #[cfg(feature = "iejoin")]
 use polars_ops::frame::IEJoinOptions;
 use polars_ops::frame::{CrossJoinFilter, CrossJoinOptions, JoinTypeOptions};
@@ -341,6 +345,8 @@ pub enum FileType {
     Csv(CsvWriterOptions),
     #[cfg(feature = "json")]
     Json(JsonWriterOptions),
+    #[cfg(feature = "avro")]
+    Avro(AvroWriterOptions),
 }
 
 impl FileType {
@@ -351,9 +357,19 @@ impl FileType {
             #[cfg(feature = "ipc")]
             Self::Ipc(_) => "ipc",
             #[cfg(feature = "csv")]
-            Self::Csv(_) => "csv",
+            Self::Csv(options) => match options.compression {
+                ExternalCompression::Uncompressed => "csv",
+                ExternalCompression::Gzip { .. } => "csv.gz",
+                ExternalCompression::Zstd { .. } => "csv.zst",
+            },
             #[cfg(feature = "json")]
-            Self::Json(_) => "jsonl",
+            Self::Json(options) => match options.compression {
+                ExternalCompression::Uncompressed => "jsonl",
+                ExternalCompression::Gzip { .. } => "jsonl.gz",
+                ExternalCompression::Zstd { .. } => "jsonl.zst",
+            },
+            #[cfg(feature = "avro")]
+            Self::Avro(_) => "avro",
 
             #[allow(unreachable_patterns)]
             _ => unreachable!("enable file type features"),
@@ -1,15 +1,24 @@
 //! This module creates predicates splits predicates into partial per-column predicates.
 
 use polars_core::datatypes::DataType;
+#[cfg(feature = "is_in")]
+use polars_core::prelude::AnyValue;
 use polars_core::scalar::Scalar;
 use polars_core::schema::Schema;
+use polars_core::series::Series;
 use polars_io::predicates::SpecializedColumnPredicateExpr;
 use polars_utils::aliases::PlHashMap;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::pl_str::PlSmallStr;
 
+#[cfg(feature = "is_in")]
+use super::super::evaluate::{constant_evaluate, into_column};
 use super::get_binary_expr_col_and_lv;
 use crate::dsl::Operator;
+#[cfg(feature = "is_in")]
+use crate::dsl::{BooleanFunction, FunctionExpr};
+#[cfg(feature = "is_in")]
+use crate::plans::LiteralValue;
 use crate::plans::{AExpr, MintermIter, aexpr_to_leaf_names_iter};
 
 pub struct ColumnPredicates {
@@ -17,6 +26,11 @@ pub struct ColumnPredicates {
 
     /// Are all column predicates AND-ed together the original predicate.
     pub is_sumwise_complete: bool,
+
+    /// For a column, the non-null values that it must be equal to for a row to pass the
+    /// predicate. These come from `col == lit` and `col.is_in(lit)` and can be used to skip
+    /// batches with e.g. bloom filters.
+    pub equality_sets: PlHashMap<PlSmallStr, Series>,
 }
 
 pub fn aexpr_to_column_predicates(
@@ -27,6 +41,7 @@ pub fn aexpr_to_column_predicates(
     let mut predicates =
         PlHashMap::<PlSmallStr, (Node, Option<SpecializedColumnPredicateExpr>)>::default();
     let mut is_sumwise_complete = true;
+    let mut equality_sets = PlHashMap::<PlSmallStr, Series>::default();
 
     let minterms = MintermIter::new(root, expr_arena).collect::<Vec<_>>();
 
@@ -66,6 +81,12 @@ pub fn aexpr_to_column_predicates(
             _ => {},
         }
 
+        if !equality_sets.contains_key(&column) {
+            if let Some(values) = minterm_to_equality_set(minterm, expr_arena, schema, dtype) {
+                equality_sets.insert(column.clone(), values);
+            }
+        }
+
         let dtype = dtype.clone();
         let entry = predicates.entry(column);
 
@@ -115,5 +136,64 @@ pub fn aexpr_to_column_predicates(
     ColumnPredicates {
         predicates,
         is_sumwise_complete,
+        equality_sets,
+    }
+}
+
+/// Returns the non-null values that the column of a single-column `minterm` must be equal to for
+/// a row to pass it.
+fn minterm_to_equality_set(
+    minterm: Node,
+    expr_arena: &Arena<AExpr>,
+    schema: &Schema,
+    dtype: &DataType,
+) -> Option<Series> {
+    match expr_arena.get(minterm) {
+        AExpr::BinaryExpr {
+            left,
+            op: Operator::Eq | Operator::EqValidity,
+            right,
+        } => {
+            let (_, (lv, _)) = get_binary_expr_col_and_lv(*left, *right, expr_arena, schema)?;
+            let av = lv?.to_any_value()?.into_static();
+            if av.is_null() || av.dtype() != *dtype {
+                return None;
+            }
+            Some(Scalar::new(dtype.clone(), av).into_series(PlSmallStr::EMPTY))
+        },
+        #[cfg(feature = "is_in")]
+        AExpr::Function {
+            input,
+            function: FunctionExpr::Boolean(BooleanFunction::IsIn { nulls_equal }),
+            ..
+        } => {
+            into_column(input[0].node(), expr_arena, schema, 0)?;
+            let lv = constant_evaluate(input[1].node(), expr_arena, schema, 0)??;
+            let values = match lv.as_ref() {
+                LiteralValue::Series(s) => (**s).clone(),
+                LiteralValue::Scalar(sc) => match sc.value() {
+                    AnyValue::List(s) => s.clone(),
+                    _ => return None,
+                },
+                _ => return None,
+            };
+            let values = match values.dtype() {
+                DataType::List(_) => values.explode(true).ok()?,
+                _ => values,
+            };
+            if values.dtype() != dtype {
+                return None;
+            }
+
+            if !values.has_nulls() {
+                Some(values)
+            } else if *nulls_equal {
+                // Null rows pass the predicate.
+                None
+            } else {
+                Some(values.drop_nulls())
+            }
+        },
+        _ => None,
     }
 }
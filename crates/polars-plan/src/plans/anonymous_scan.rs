@@ -18,8 +18,8 @@ pub trait AnonymousScan: Send + Sync {
     /// Creates a DataFrame from the supplied function & scan options.
     fn scan(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<DataFrame>;
 
-    /// Produce the next batch Polars can consume. Implement this method to get proper
-    /// streaming support.
+    /// Produce the next batch Polars can consume. Implement this method together with
+    /// [`AnonymousScan::produces_batches`] to get proper streaming support.
     fn next_batch(&self, scan_opts: AnonymousScanArgs) -> PolarsResult<Option<DataFrame>> {
         self.scan(scan_opts).map(Some)
     }
@@ -47,6 +47,14 @@ pub trait AnonymousScan: Send + Sync {
     fn allows_slice_pushdown(&self) -> bool {
         false
     }
+    /// Specify if the scan provider produces its data in batches. The streaming engine then
+    /// calls [`AnonymousScan::next_batch`] until it returns `None`, instead of calling
+    /// [`AnonymousScan::scan`] once.
+    ///
+    /// Defaults to `false`
+    fn produces_batches(&self) -> bool {
+        false
+    }
 }
 
 impl Debug for dyn AnonymousScan {
@@ -4,11 +4,12 @@ use polars_io::path_utils::is_cloud_url;
 #[cfg(feature = "cloud")]
 use polars_io::pl_async::get_runtime;
 use polars_io::prelude::*;
-use polars_io::utils::compression::maybe_decompress_bytes;
+#[cfg(feature = "json")]
+use polars_io::utils::compression::maybe_decompress_prefix;
 
 use super::*;
 
-#[cfg(any(feature = "parquet", feature = "ipc"))]
+#[cfg(any(feature = "parquet", feature = "ipc", feature = "avro"))]
 fn prepare_output_schema(mut schema: Schema, row_index: Option<&RowIndex>) -> SchemaRef {
     if let Some(rc) = row_index {
         let _ = schema.insert_at_index(0, rc.name.clone(), IDX_DTYPE);
@@ -129,6 +130,71 @@ pub(super) fn ipc_file_info(
     Ok((file_info, metadata))
 }
 
+#[cfg(feature = "avro")]
+pub(super) fn avro_file_info(
+    sources: &ScanSources,
+    row_index: Option<&RowIndex>,
+    cloud_options: Option<&polars_io::cloud::CloudOptions>,
+) -> PolarsResult<FileInfo> {
+    use arrow::io::avro::{avro_schema, read};
+    use polars_core::config;
+    use polars_core::error::{feature_gated, to_compute_err};
+
+    let Some(first) = sources.first() else {
+        polars_bail!(ComputeError: "expected at least 1 source");
+    };
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    &[Arc::from(sources.as_paths().unwrap()[0].to_str().unwrap())],
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
+    let metadata = avro_schema::read::read_metadata(&mut std::io::Cursor::new(memslice.as_ref()))
+        .map_err(to_compute_err)?;
+    let reader_schema = Arc::new(read::infer_schema(&metadata.record)?);
+
+    let file_info = FileInfo::new(
+        prepare_output_schema(Schema::from_arrow_schema(reader_schema.as_ref()), row_index),
+        Some(Either::Left(reader_schema)),
+        (None, usize::MAX),
+    );
+
+    Ok(file_info)
+}
+
+/// Decompresses and transcodes the start of a file, as far as it is needed to infer the schema.
+#[cfg(feature = "csv")]
+fn maybe_decompress_csv_for_inference(
+    memslice: &polars_utils::mmap::MemSlice,
+    csv_options: &CsvReadOptions,
+) -> PolarsResult<polars_utils::mmap::MemSlice> {
+    use polars_io::csv::read::streaming_utf8_decoder;
+    use polars_io::prelude::_csv_read_internal::{CountLines, n_lines_for_schema_inference};
+
+    let parse_options = csv_options.parse_options.as_ref();
+    let line_counter = CountLines::new(parse_options.quote_char, parse_options.eol_char)
+        .with_escape_char(parse_options.escape_char);
+    let min_lines = n_lines_for_schema_inference(csv_options, csv_options.infer_schema_length);
+
+    let find_last_line_end = |bytes: &[u8]| line_counter.find_last_line_end(bytes, min_lines?);
+
+    match streaming_utf8_decoder(memslice.clone(), parse_options.encoding)? {
+        None => Ok(memslice.clone()),
+        Some(mut decoder) => Ok(decoder.next_chunk(find_last_line_end)?.unwrap_or_default()),
+    }
+}
+
 #[cfg(feature = "csv")]
 pub fn isolated_csv_file_info(
     source: ScanSourceRef,
@@ -144,8 +210,8 @@ pub fn isolated_csv_file_info(
     let run_async = source.run_async();
 
     let memslice = source.to_memslice_async_assume_latest(run_async)?;
-    let owned = &mut vec![];
-    let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+    let memslice = maybe_decompress_csv_for_inference(&memslice, csv_options)?;
+    let mut reader = std::io::Cursor::new(memslice.as_ref());
     if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
         polars_bail!(NoData: "empty CSV")
     }
@@ -229,8 +295,8 @@ pub fn csv_file_info(
     let infer_schema_func = |i| {
         let source = sources.at(i);
         let memslice = source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
-        let owned = &mut vec![];
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        let memslice = maybe_decompress_csv_for_inference(&memslice, csv_options)?;
+        let mut reader = std::io::Cursor::new(memslice.as_ref());
         if reader.read(&mut [0; 4])? < 2 && csv_options.raise_if_empty {
             polars_bail!(NoData: "empty CSV")
         }
@@ -342,13 +408,18 @@ pub fn ndjson_file_info(
         }
     };
 
-    let owned = &mut vec![];
-
     let mut schema = if let Some(schema) = ndjson_options.schema.clone() {
         schema.clone()
     } else {
         let memslice = first.to_memslice_possibly_async(run_async, cache_entries.as_ref(), 0)?;
-        let mut reader = std::io::Cursor::new(maybe_decompress_bytes(&memslice, owned)?);
+        // Only decompress the lines used for the inference.
+        let infer_schema_length = ndjson_options.infer_schema_length;
+        let memslice = maybe_decompress_prefix(&memslice, |bytes| {
+            let end = bytes.iter().rposition(|&c| c == b'\n')? + 1;
+            (polars_io::ndjson::count_rows(&bytes[..end]) >= infer_schema_length?.get())
+                .then_some(end)
+        })?;
+        let mut reader = std::io::Cursor::new(memslice.as_ref());
 
         Arc::new(polars_io::ndjson::infer_schema(
             &mut reader,
@@ -4,12 +4,13 @@ use arrow::io::ipc::read::get_row_count as count_rows_ipc_sync;
     feature = "parquet",
     feature = "ipc",
     feature = "json",
-    feature = "csv"
+    feature = "csv",
+    feature = "avro"
 ))]
 use polars_core::error::feature_gated;
 #[cfg(any(feature = "json", feature = "parquet"))]
 use polars_io::SerReader;
-#[cfg(any(feature = "parquet", feature = "json"))]
+#[cfg(any(feature = "parquet", feature = "json", feature = "avro"))]
 use polars_io::cloud::CloudOptions;
 #[cfg(feature = "parquet")]
 use polars_io::parquet::read::ParquetReader;
@@ -29,7 +30,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     )))]
     {
         unreachable!()
@@ -39,7 +41,8 @@ pub fn count_rows(
         feature = "parquet",
         feature = "ipc",
         feature = "json",
-        feature = "csv"
+        feature = "csv",
+        feature = "avro"
     ))]
     {
         let count: PolarsResult<usize> = match scan_type {
@@ -56,6 +59,8 @@ pub fn count_rows(
             ),
             #[cfg(feature = "json")]
             FileScan::NDJson { options } => count_rows_ndjson(sources, cloud_options),
+            #[cfg(feature = "avro")]
+            FileScan::Avro { .. } => count_rows_avro(sources, cloud_options),
             #[cfg(feature = "python")]
             FileScan::PythonDataset { .. } => unreachable!(),
             FileScan::Anonymous { .. } => {
@@ -83,22 +88,30 @@ fn count_all_rows_csv(
         .map(|source| match source {
             ScanSourceRef::Path(path) => polars_io::csv::read::count_rows(
                 path,
-                parse_options.separator,
+                parse_options.separator.as_bytes(),
                 parse_options.quote_char,
                 parse_options.comment_prefix.as_ref(),
                 parse_options.eol_char,
                 options.has_header,
+                parse_options.encoding,
+                parse_options.escape_char,
             ),
             _ => {
                 let memslice = source.to_memslice()?;
+                let owned = &mut vec![];
 
                 polars_io::csv::read::count_rows_from_slice_par(
-                    &memslice[..],
-                    parse_options.separator,
+                    polars_io::csv::read::maybe_transcode_bytes(
+                        &memslice[..],
+                        parse_options.encoding,
+                        owned,
+                    )?,
+                    parse_options.separator.as_bytes(),
                     parse_options.quote_char,
                     parse_options.comment_prefix.as_ref(),
                     parse_options.eol_char,
                     options.has_header,
+                    parse_options.escape_char,
                 )
             },
         })
@@ -248,3 +261,46 @@ pub(super) fn count_rows_ndjson(
         })
         .sum()
 }
+
+#[cfg(feature = "avro")]
+fn count_rows_avro(
+    sources: &ScanSources,
+    cloud_options: Option<&CloudOptions>,
+) -> PolarsResult<usize> {
+    use polars_core::config;
+
+    if sources.is_empty() {
+        return Ok(0);
+    }
+
+    let run_async = sources.is_cloud_url() || (sources.is_paths() && config::force_async());
+
+    let cache_entries = {
+        if run_async {
+            feature_gated!("cloud", {
+                Some(polars_io::file_cache::init_entries_from_uri_list(
+                    sources
+                        .as_paths()
+                        .unwrap()
+                        .iter()
+                        .map(|path| Arc::from(path.to_str().unwrap()))
+                        .collect::<Vec<_>>()
+                        .as_slice(),
+                    cloud_options,
+                )?)
+            })
+        } else {
+            None
+        }
+    };
+
+    sources
+        .iter()
+        .enumerate()
+        .map(|(i, source)| {
+            let memslice =
+                source.to_memslice_possibly_async(run_async, cache_entries.as_ref(), i)?;
+            polars_io::avro::count_rows(&memslice)
+        })
+        .sum()
+}
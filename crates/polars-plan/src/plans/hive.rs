@@ -178,6 +178,7 @@ pub fn hive_partitions_from_paths(
         paths.len(),
         hive_schema.as_ref(),
         None,
+        None,
         polars_io::prelude::CsvEncoding::Utf8,
         false,
     )?;
@@ -166,6 +166,16 @@ fn visit_logical_plan_for_scan_paths(
             // New-streaming is generally on par for all except CSV (see https://github.com/pola-rs/polars/pull/22363).
             // In the future we can potentially remove the dedicated count codepaths.
 
+            // The rows that are rejected are only known after parsing.
+            let rejects_rows = match scan_type.as_ref() {
+                #[cfg(feature = "csv")]
+                FileScan::Csv { options } => options.rejects_path.is_some(),
+                _ => false,
+            };
+            if rejects_rows {
+                return None;
+            }
+
             let use_fast_file_count = use_fast_file_count.unwrap_or(match scan_type.as_ref() {
                 #[cfg(feature = "csv")]
                 FileScan::Csv { .. } => true,
@@ -232,6 +232,9 @@ impl SlicePushDown {
                 #[cfg(feature = "ipc")]
                 FileScan::Ipc { .. } => true,
 
+                #[cfg(feature = "avro")]
+                FileScan::Avro { .. } => true,
+
                 #[cfg(feature = "csv")]
                 FileScan::Csv { .. } => true,
 
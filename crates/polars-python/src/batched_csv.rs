@@ -28,7 +28,7 @@ impl PyBatchedCsv {
         projection, separator, rechunk, columns, encoding, n_threads, path, schema_overrides,
         overwrite_dtype_slice, low_memory, comment_prefix, quote_char, null_values,
         missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header, row_index,
-        eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma)
+        eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, escape_char)
     )]
     fn new(
         infer_schema_length: Option<usize>,
@@ -59,6 +59,7 @@ impl PyBatchedCsv {
         raise_if_empty: bool,
         truncate_ragged_lines: bool,
         decimal_comma: bool,
+        escape_char: Option<&str>,
     ) -> PyResult<PyBatchedCsv> {
         let null_values = null_values.map(|w| w.0);
         let eol_char = eol_char.as_bytes()[0];
@@ -76,6 +77,8 @@ impl PyBatchedCsv {
             None
         };
 
+        let escape_char = escape_char.and_then(|s| s.as_bytes().first().copied());
+
         let schema_overrides = schema_overrides.map(|overwrite_dtype| {
             overwrite_dtype
                 .iter()
@@ -115,13 +118,14 @@ impl PyBatchedCsv {
             .with_raise_if_empty(raise_if_empty)
             .with_parse_options(
                 CsvParseOptions::default()
-                    .with_separator(separator.as_bytes()[0])
+                    .with_separator(separator)
                     .with_encoding(encoding.0)
                     .with_missing_is_null(!missing_utf8_is_empty_string)
                     .with_comment_prefix(comment_prefix)
                     .with_null_values(null_values)
                     .with_try_parse_dates(try_parse_dates)
                     .with_quote_char(quote_char)
+                    .with_escape_char(escape_char)
                     .with_eol_char(eol_char)
                     .with_truncate_ragged_lines(truncate_ragged_lines)
                     .with_decimal_comma(decimal_comma),
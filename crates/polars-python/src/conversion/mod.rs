@@ -15,6 +15,8 @@ use polars::frame::row::Row;
 use polars::io::avro::AvroCompression;
 #[cfg(feature = "cloud")]
 use polars::io::cloud::CloudOptions;
+#[cfg(any(feature = "csv", feature = "json"))]
+use polars::io::utils::compression::ExternalCompression;
 use polars::series::ops::NullBehavior;
 use polars_core::utils::arrow::array::Array;
 use polars_core::utils::arrow::types::NativeType;
@@ -863,9 +865,13 @@ impl<'py> FromPyObject<'py> for Wrap<CsvEncoding> {
         let parsed = match &*ob.extract::<PyBackedStr>()? {
             "utf8" => CsvEncoding::Utf8,
             "utf8-lossy" => CsvEncoding::LossyUtf8,
+            "latin1" => CsvEncoding::Latin1,
+            "windows-1252" => CsvEncoding::Windows1252,
+            "utf-16-le" => CsvEncoding::Utf16Le,
+            "utf-16-be" => CsvEncoding::Utf16Be,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy'}}, got {v}",
+                    "csv `encoding` must be one of {{'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf-16-le', 'utf-16-be'}}, got {v}",
                 )));
             },
         };
@@ -1030,9 +1036,10 @@ impl<'py> FromPyObject<'py> for Wrap<QuantileMethod> {
             "linear" => QuantileMethod::Linear,
             "midpoint" => QuantileMethod::Midpoint,
             "equiprobable" => QuantileMethod::Equiprobable,
+            "tdigest" => QuantileMethod::TDigest,
             v => {
                 return Err(PyValueError::new_err(format!(
-                    "`interpolation` must be one of {{'lower', 'higher', 'nearest', 'linear', 'midpoint', 'equiprobable'}}, got {v}",
+                    "`interpolation` must be one of {{'lower', 'higher', 'nearest', 'linear', 'midpoint', 'equiprobable', 'tdigest'}}, got {v}",
                 )));
             },
         };
@@ -1404,6 +1411,26 @@ pub(crate) fn parse_fill_null_strategy(
     Ok(parsed)
 }
 
+#[cfg(feature = "parquet")]
+impl<'py> FromPyObject<'py> for Wrap<ParquetEncoding> {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let parsed = match &*ob.extract::<PyBackedStr>()? {
+            "plain" => ParquetEncoding::Plain,
+            "rle" => ParquetEncoding::Rle,
+            "delta_binary_packed" => ParquetEncoding::DeltaBinaryPacked,
+            "delta_length_byte_array" => ParquetEncoding::DeltaLengthByteArray,
+            "delta_byte_array" => ParquetEncoding::DeltaByteArray,
+            "byte_stream_split" => ParquetEncoding::ByteStreamSplit,
+            v => {
+                return Err(PyValueError::new_err(format!(
+                    "parquet `encoding` must be one of {{'plain', 'rle', 'delta_binary_packed', 'delta_length_byte_array', 'delta_byte_array', 'byte_stream_split'}}, got {v}",
+                )));
+            },
+        };
+        Ok(Wrap(parsed))
+    }
+}
+
 #[cfg(feature = "parquet")]
 pub(crate) fn parse_parquet_compression(
     compression: &str,
@@ -1446,6 +1473,37 @@ pub(crate) fn parse_parquet_compression(
     Ok(parsed)
 }
 
+#[cfg(any(feature = "csv", feature = "json"))]
+pub(crate) fn parse_external_compression(
+    compression: &str,
+    compression_level: Option<i32>,
+) -> PyResult<ExternalCompression> {
+    let parsed = match compression {
+        "uncompressed" => ExternalCompression::Uncompressed,
+        "gzip" => {
+            let level = compression_level
+                .map(|lvl| {
+                    u32::try_from(lvl).map_err(|_| {
+                        PyValueError::new_err(format!(
+                            "gzip compression level must be between 0 and 9, got {lvl}"
+                        ))
+                    })
+                })
+                .transpose()?;
+            ExternalCompression::try_new_gzip(level)
+                .map_err(|e| PyValueError::new_err(e.to_string()))?
+        },
+        "zstd" => ExternalCompression::try_new_zstd(compression_level)
+            .map_err(|e| PyValueError::new_err(e.to_string()))?,
+        e => {
+            return Err(PyValueError::new_err(format!(
+                "`compression` must be one of {{'uncompressed', 'gzip', 'zstd'}}, got {e}",
+            )));
+        },
+    };
+    Ok(parsed)
+}
+
 pub(crate) fn strings_to_pl_smallstr<I, S>(container: I) -> Vec<PlSmallStr>
 where
     I: IntoIterator<Item = S>,
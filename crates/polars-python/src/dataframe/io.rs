@@ -1,5 +1,6 @@
 use std::io::BufWriter;
 use std::num::NonZeroUsize;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 use polars::io::RowIndex;
@@ -27,7 +28,8 @@ impl PyDataFrame {
     skip_rows, skip_lines, projection, separator, rechunk, columns, encoding, n_threads, path,
     overwrite_dtype, overwrite_dtype_slice, low_memory, comment_prefix, quote_char,
     null_values, missing_utf8_is_empty_string, try_parse_dates, skip_rows_after_header,
-    row_index, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema)
+    row_index, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, schema,
+    rejects_path, escape_char)
 )]
     pub fn read_csv(
         py: Python<'_>,
@@ -61,6 +63,8 @@ impl PyDataFrame {
         truncate_ragged_lines: bool,
         decimal_comma: bool,
         schema: Option<Wrap<Schema>>,
+        rejects_path: Option<PathBuf>,
+        escape_char: Option<&str>,
     ) -> PyResult<Self> {
         let null_values = null_values.map(|w| w.0);
         let eol_char = eol_char.as_bytes()[0];
@@ -69,6 +73,7 @@ impl PyDataFrame {
             offset,
         });
         let quote_char = quote_char.and_then(|s| s.as_bytes().first().copied());
+        let escape_char = escape_char.and_then(|s| s.as_bytes().first().copied());
 
         let overwrite_dtype = overwrite_dtype.map(|overwrite_dtype| {
             overwrite_dtype
@@ -109,15 +114,17 @@ impl PyDataFrame {
                 .with_skip_rows_after_header(skip_rows_after_header)
                 .with_row_index(row_index)
                 .with_raise_if_empty(raise_if_empty)
+                .with_rejects_path(rejects_path)
                 .with_parse_options(
                     CsvParseOptions::default()
-                        .with_separator(separator.as_bytes()[0])
+                        .with_separator(separator)
                         .with_encoding(encoding.0)
                         .with_missing_is_null(!missing_utf8_is_empty_string)
                         .with_comment_prefix(comment_prefix)
                         .with_null_values(null_values)
                         .with_try_parse_dates(try_parse_dates)
                         .with_quote_char(quote_char)
+                        .with_escape_char(escape_char)
                         .with_eol_char(eol_char)
                         .with_truncate_ragged_lines(truncate_ragged_lines)
                         .with_decimal_comma(decimal_comma),
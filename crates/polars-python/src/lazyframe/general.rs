@@ -152,7 +152,8 @@ impl PyLazyFrame {
         low_memory, comment_prefix, quote_char, null_values, missing_utf8_is_empty_string,
         infer_schema_length, with_schema_modify, rechunk, skip_rows_after_header,
         encoding, row_index, try_parse_dates, eol_char, raise_if_empty, truncate_ragged_lines, decimal_comma, glob, schema,
-        cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths
+        cloud_options, credential_provider, retries, file_cache_ttl, include_file_paths, rejects_path,
+        escape_char
     )
     )]
     fn new_from_csv(
@@ -189,18 +190,21 @@ impl PyLazyFrame {
         retries: usize,
         file_cache_ttl: Option<u64>,
         include_file_paths: Option<String>,
+        rejects_path: Option<PathBuf>,
+        escape_char: Option<&str>,
     ) -> PyResult<Self> {
         #[cfg(feature = "cloud")]
         use cloud::credential_provider::PlCredentialProvider;
 
         let null_values = null_values.map(|w| w.0);
         let quote_char = quote_char.and_then(|s| s.as_bytes().first()).copied();
-        let separator = separator
-            .as_bytes()
-            .first()
-            .ok_or_else(|| polars_err!(InvalidOperation: "`separator` cannot be empty"))
-            .copied()
-            .map_err(PyPolarsErr::from)?;
+        let escape_char = escape_char.and_then(|s| s.as_bytes().first()).copied();
+        if separator.is_empty() {
+            return Err(PyPolarsErr::from(
+                polars_err!(InvalidOperation: "`separator` cannot be empty"),
+            )
+            .into());
+        }
         let eol_char = eol_char
             .as_bytes()
             .first()
@@ -258,6 +262,7 @@ impl PyLazyFrame {
             .with_low_memory(low_memory)
             .with_comment_prefix(comment_prefix.map(|x| x.into()))
             .with_quote_char(quote_char)
+            .with_escape_char(escape_char)
             .with_eol_char(eol_char)
             .with_rechunk(rechunk)
             .with_skip_rows_after_header(skip_rows_after_header)
@@ -270,7 +275,8 @@ impl PyLazyFrame {
             .with_decimal_comma(decimal_comma)
             .with_glob(glob)
             .with_raise_if_empty(raise_if_empty)
-            .with_include_file_paths(include_file_paths.map(|x| x.into()));
+            .with_include_file_paths(include_file_paths.map(|x| x.into()))
+            .with_rejects_path(rejects_path);
 
         if let Some(lambda) = with_schema_modify {
             let f = |schema: Schema| {
@@ -488,6 +494,87 @@ impl PyLazyFrame {
         Ok(lf.into())
     }
 
+    #[cfg(feature = "avro")]
+    #[staticmethod]
+    #[pyo3(signature = (
+        source, sources, n_rows, cache, rechunk, row_index, cloud_options, credential_provider,
+        hive_partitioning, hive_schema, try_parse_hive_dates, retries, file_cache_ttl, glob,
+        include_file_paths, allow_missing_columns, cast_options
+    ))]
+    fn new_from_avro(
+        source: Option<PyObject>,
+        sources: Wrap<ScanSources>,
+        n_rows: Option<usize>,
+        cache: bool,
+        rechunk: bool,
+        row_index: Option<(String, IdxSize)>,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        hive_partitioning: Option<bool>,
+        hive_schema: Option<Wrap<Schema>>,
+        try_parse_hive_dates: bool,
+        retries: usize,
+        file_cache_ttl: Option<u64>,
+        glob: bool,
+        include_file_paths: Option<String>,
+        allow_missing_columns: bool,
+        cast_options: Wrap<CastColumnsPolicy>,
+    ) -> PyResult<Self> {
+        #[cfg(feature = "cloud")]
+        use cloud::credential_provider::PlCredentialProvider;
+        let row_index = row_index.map(|(name, offset)| RowIndex {
+            name: name.into(),
+            offset,
+        });
+
+        let hive_options = HiveOptions {
+            enabled: hive_partitioning,
+            hive_start_idx: 0,
+            schema: hive_schema.map(|x| Arc::new(x.0)),
+            try_parse_dates: try_parse_hive_dates,
+        };
+
+        let mut args = ScanArgsAvro {
+            n_rows,
+            cache,
+            rechunk,
+            row_index,
+            cloud_options: None,
+            hive_options,
+            glob,
+            include_file_paths: include_file_paths.map(|x| x.into()),
+            allow_missing_columns,
+            cast_columns_policy: cast_options.0,
+        };
+
+        let sources = sources.0;
+        let (first_path, sources) = match source {
+            None => (sources.first_path().map(|p| p.to_path_buf()), sources),
+            Some(source) => pyobject_to_first_path_and_scan_sources(source)?,
+        };
+
+        #[cfg(feature = "cloud")]
+        if let Some(first_path) = first_path {
+            let first_path_url = first_path.to_string_lossy();
+
+            let mut cloud_options =
+                parse_cloud_options(&first_path_url, cloud_options.unwrap_or_default())?;
+            if let Some(file_cache_ttl) = file_cache_ttl {
+                cloud_options.file_cache_ttl = file_cache_ttl;
+            }
+            args.cloud_options = Some(
+                cloud_options
+                    .with_max_retries(retries)
+                    .with_credential_provider(
+                        credential_provider.map(PlCredentialProvider::from_python_builder),
+                    ),
+            );
+        }
+
+        let lf = LazyFrame::scan_avro_sources(sources, args).map_err(PyPolarsErr::from)?;
+        Ok(lf.into())
+    }
+
     #[staticmethod]
     #[pyo3(signature = (
         dataset_object
@@ -916,11 +1003,73 @@ impl PyLazyFrame {
         .map_err(Into::into)
     }
 
+    #[cfg(all(feature = "streaming", feature = "avro"))]
+    #[pyo3(signature = (
+        target, compression, name, cloud_options, credential_provider, retries, sink_options
+    ))]
+    fn sink_avro(
+        &self,
+        py: Python<'_>,
+        target: SinkTarget,
+        compression: Wrap<Option<polars::io::avro::AvroCompression>>,
+        name: String,
+        cloud_options: Option<Vec<(String, String)>>,
+        credential_provider: Option<PyObject>,
+        retries: usize,
+        sink_options: Wrap<SinkOptions>,
+    ) -> PyResult<PyLazyFrame> {
+        let options = AvroWriterOptions {
+            compression: compression.0,
+            name: name.into(),
+        };
+
+        #[cfg(feature = "cloud")]
+        let cloud_options = match target.base_path() {
+            None => None,
+            Some(base_path) => {
+                let cloud_options = parse_cloud_options(
+                    base_path.to_str().unwrap(),
+                    cloud_options.unwrap_or_default(),
+                )?;
+                Some(
+                    cloud_options
+                        .with_max_retries(retries)
+                        .with_credential_provider(
+                            credential_provider.map(polars::prelude::cloud::credential_provider::PlCredentialProvider::from_python_builder),
+                        ),
+                )
+            },
+        };
+
+        #[cfg(not(feature = "cloud"))]
+        let cloud_options = None;
+
+        py.enter_polars(|| {
+            let ldf = self.ldf.clone();
+            match target {
+                SinkTarget::File(target) => {
+                    ldf.sink_avro(target, options, cloud_options, sink_options.0)
+                },
+                SinkTarget::Partition(partition) => ldf.sink_avro_partitioned(
+                    Arc::new(partition.base_path),
+                    partition.file_path_cb.map(PartitionTargetCallback::Python),
+                    partition.variant,
+                    options,
+                    cloud_options,
+                    sink_options.0,
+                ),
+            }
+        })
+        .map(Into::into)
+        .map_err(Into::into)
+    }
+
     #[cfg(all(feature = "streaming", feature = "csv"))]
     #[pyo3(signature = (
         target, include_bom, include_header, separator, line_terminator, quote_char, batch_size,
         datetime_format, date_format, time_format, float_scientific, float_precision, null_value,
-        quote_style, cloud_options, credential_provider, retries, sink_options
+        quote_style, compression, compression_level, encoding, cloud_options, credential_provider,
+        retries, sink_options, escape_char
     ))]
     fn sink_csv(
         &self,
@@ -928,7 +1077,7 @@ impl PyLazyFrame {
         target: SinkTarget,
         include_bom: bool,
         include_header: bool,
-        separator: u8,
+        separator: &str,
         line_terminator: String,
         quote_char: u8,
         batch_size: NonZeroUsize,
@@ -939,10 +1088,14 @@ impl PyLazyFrame {
         float_precision: Option<usize>,
         null_value: Option<String>,
         quote_style: Option<Wrap<QuoteStyle>>,
+        compression: &str,
+        compression_level: Option<i32>,
+        encoding: Wrap<CsvEncoding>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
+        escape_char: Option<u8>,
     ) -> PyResult<PyLazyFrame> {
         let quote_style = quote_style.map_or(QuoteStyle::default(), |wrap| wrap.0);
         let null_value = null_value.unwrap_or(SerializeOptions::default().null);
@@ -953,8 +1106,9 @@ impl PyLazyFrame {
             datetime_format,
             float_scientific,
             float_precision,
-            separator,
+            separator: Separator::new_from_str(separator),
             quote_char,
+            escape_char,
             null: null_value,
             line_terminator,
             quote_style,
@@ -965,6 +1119,8 @@ impl PyLazyFrame {
             include_header,
             batch_size,
             serialize_options,
+            compression: parse_external_compression(compression, compression_level)?,
+            encoding: encoding.0,
         };
 
         #[cfg(feature = "cloud")]
@@ -1010,17 +1166,24 @@ impl PyLazyFrame {
 
     #[allow(clippy::too_many_arguments)]
     #[cfg(all(feature = "streaming", feature = "json"))]
-    #[pyo3(signature = (target, cloud_options, credential_provider, retries, sink_options))]
+    #[pyo3(signature = (
+        target, compression, compression_level, cloud_options, credential_provider, retries,
+        sink_options
+    ))]
     fn sink_json(
         &self,
         py: Python<'_>,
         target: SinkTarget,
+        compression: &str,
+        compression_level: Option<i32>,
         cloud_options: Option<Vec<(String, String)>>,
         credential_provider: Option<PyObject>,
         retries: usize,
         sink_options: Wrap<SinkOptions>,
     ) -> PyResult<PyLazyFrame> {
-        let options = JsonWriterOptions {};
+        let options = JsonWriterOptions {
+            compression: parse_external_compression(compression, compression_level)?,
+        };
 
         let cloud_options = match target.base_path() {
             None => None,
@@ -1650,7 +1813,7 @@ impl PyLazyFrame {
 #[cfg(feature = "parquet")]
 impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOverwrites> {
     fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
-        use polars_io::parquet::write::ParquetFieldOverwrites;
+        use polars_io::parquet::write::{ParquetEncoding, ParquetFieldOverwrites};
 
         let parsed = ob.extract::<pyo3::Bound<'_, PyDict>>()?;
 
@@ -1688,11 +1851,36 @@ impl<'py> FromPyObject<'py> for Wrap<polars_io::parquet::write::ParquetFieldOver
                 .collect()
         });
 
+        let bloom_filter = PyDictMethods::get_item(&parsed, "bloom_filter")?
+            .map(|v| v.extract::<bool>())
+            .transpose()?
+            .unwrap_or(false);
+        let encoding = PyDictMethods::get_item(&parsed, "encoding")?
+            .map(|v| PyResult::Ok(v.extract::<Wrap<ParquetEncoding>>()?.0))
+            .transpose()?;
+        let dictionary = PyDictMethods::get_item(&parsed, "dictionary")?
+            .map(|v| v.extract::<bool>())
+            .transpose()?;
+        let compression_level = PyDictMethods::get_item(&parsed, "compression_level")?
+            .map(|v| v.extract::<i32>())
+            .transpose()?;
+        let compression = PyDictMethods::get_item(&parsed, "compression")?
+            .map(|v| parse_parquet_compression(&v.extract::<PyBackedStr>()?, compression_level))
+            .transpose()?;
+        let statistics = PyDictMethods::get_item(&parsed, "statistics")?
+            .map(|v| PyResult::Ok(v.extract::<Wrap<StatisticsOptions>>()?.0))
+            .transpose()?;
+
         Ok(Wrap(ParquetFieldOverwrites {
             name,
             children,
             field_id,
             metadata,
+            bloom_filter,
+            encoding,
+            dictionary,
+            compression,
+            statistics,
         }))
     }
 }
@@ -45,4 +45,9 @@ impl PySQLContext {
     pub fn unregister(&mut self, name: &str) {
         self.context.unregister(name)
     }
+
+    pub fn set_max_recursion_iterations(&mut self, max_iterations: usize) {
+        self.context =
+            std::mem::take(&mut self.context).with_max_recursion_iterations(max_iterations)
+    }
 }
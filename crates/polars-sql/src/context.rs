@@ -1,19 +1,22 @@
+use std::borrow::Cow;
 use std::cell::RefCell;
 use std::ops::Deref;
 
 use polars_core::frame::row::Row;
 use polars_core::prelude::*;
+use polars_lazy::frame::pivot::pivot_stable;
 use polars_lazy::prelude::*;
 use polars_ops::frame::JoinCoalesce;
 use polars_plan::dsl::function_expr::StructFunction;
 use polars_plan::prelude::*;
 use polars_utils::format_pl_smallstr;
 use sqlparser::ast::{
-    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr, FromTable,
-    FunctionArg, GroupByExpr, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
-    OrderBy, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator, SetQuantifier,
-    Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator, Value as SQLValue, Values,
-    WildcardAdditionalOptions,
+    BinaryOperator, CreateTable, Delete, Distinct, ExcludeSelectItem, Expr as SQLExpr,
+    ExprWithAlias, FromTable, FunctionArg, FunctionArgExpr, FunctionArguments, GroupByExpr,
+    GroupByWithModifier, Ident, JoinConstraint, JoinOperator, ObjectName, ObjectType, Offset,
+    OrderBy, PivotValueSource, Query, RenameSelectItem, Select, SelectItem, SetExpr, SetOperator,
+    SetQuantifier, Statement, TableAlias, TableFactor, TableWithJoins, UnaryOperator,
+    Value as SQLValue, Values, WildcardAdditionalOptions,
 };
 use sqlparser::dialect::GenericDialect;
 use sqlparser::parser::{Parser, ParserOptions};
@@ -63,6 +66,9 @@ pub struct SQLContext {
     cte_map: RefCell<PlHashMap<String, LazyFrame>>,
     table_aliases: RefCell<PlHashMap<String, String>>,
     joined_aliases: RefCell<PlHashMap<String, PlHashMap<String, String>>>,
+    /// The (SQL) name and arguments of the GROUPING() calls of the current query.
+    grouping_functions: Vec<(PlSmallStr, Vec<Expr>)>,
+    max_recursion_iterations: usize,
 }
 
 impl Default for SQLContext {
@@ -73,6 +79,8 @@ impl Default for SQLContext {
             cte_map: Default::default(),
             table_aliases: Default::default(),
             joined_aliases: Default::default(),
+            grouping_functions: Default::default(),
+            max_recursion_iterations: DEFAULT_MAX_RECURSION_ITERATIONS,
             lp_arena: Default::default(),
             expr_arena: Default::default(),
         }
@@ -166,6 +174,7 @@ impl SQLContext {
         self.cte_map.borrow_mut().clear();
         self.table_aliases.borrow_mut().clear();
         self.joined_aliases.borrow_mut().clear();
+        self.grouping_functions.clear();
 
         Ok(res)
     }
@@ -177,6 +186,13 @@ impl SQLContext {
         self
     }
 
+    /// Set the maximum number of iterations used to evaluate a recursive CTE
+    /// (`WITH RECURSIVE`); evaluation fails if the CTE has not converged by then.
+    pub fn with_max_recursion_iterations(mut self, max_iterations: usize) -> Self {
+        self.max_recursion_iterations = max_iterations;
+        self
+    }
+
     /// Get the function registry of the SQLContext
     pub fn registry(&self) -> &Arc<dyn FunctionRegistry> {
         &self.function_registry
@@ -227,10 +243,12 @@ impl SQLContext {
         table
             .or_else(|| self.cte_map.borrow().get(name).cloned())
             .or_else(|| {
-                self.table_aliases
-                    .borrow()
-                    .get(name)
-                    .and_then(|alias| self.table_map.get(alias).cloned())
+                self.table_aliases.borrow().get(name).and_then(|alias| {
+                    self.table_map
+                        .get(alias)
+                        .cloned()
+                        .or_else(|| self.cte_map.borrow().get(alias).cloned())
+                })
             })
     }
 
@@ -594,19 +612,115 @@ impl SQLContext {
 
     fn register_ctes(&mut self, query: &Query) -> PolarsResult<()> {
         if let Some(with) = &query.with {
-            if with.recursive {
-                polars_bail!(SQLInterface: "recursive CTEs are not supported")
-            }
             for cte in &with.cte_tables {
                 let cte_name = cte.alias.name.value.clone();
-                let mut lf = self.execute_query(&cte.query)?;
-                lf = self.rename_columns_from_table_alias(lf, &cte.alias)?;
+                let lf = if with.recursive && references_table(&cte.query.body, &cte_name) {
+                    self.execute_recursive_cte(&cte_name, &cte.query, &cte.alias)?
+                } else {
+                    let lf = self.execute_query(&cte.query)?;
+                    self.rename_columns_from_table_alias(lf, &cte.alias)?
+                };
                 self.register_cte(&cte_name, lf);
             }
         }
         Ok(())
     }
 
+    /// Evaluate a recursive CTE (`anchor UNION [ALL] recursive-member`) by fixed-point
+    /// iteration; the recursive member is repeatedly applied to the rows produced by the
+    /// previous iteration until no new rows are produced. This is deferred until the
+    /// resulting frame is collected.
+    fn execute_recursive_cte(
+        &mut self,
+        name: &str,
+        query: &Query,
+        alias: &TableAlias,
+    ) -> PolarsResult<LazyFrame> {
+        let SetExpr::SetOperation {
+            op: SetOperator::Union,
+            set_quantifier,
+            left,
+            right,
+        } = query.body.as_ref()
+        else {
+            polars_bail!(SQLSyntax: "recursive CTE '{}' must be of the form '<anchor> UNION [ALL] <recursive member>'", name)
+        };
+        let distinct = match set_quantifier {
+            SetQuantifier::All => false,
+            SetQuantifier::Distinct | SetQuantifier::None => true,
+            _ => {
+                polars_bail!(SQLInterface: "'UNION {}' is not supported in recursive CTE '{}'", set_quantifier, name)
+            },
+        };
+        polars_ensure!(
+            !references_table(left, name),
+            SQLSyntax: "the anchor of recursive CTE '{}' cannot reference itself", name
+        );
+        polars_ensure!(
+            query.order_by.is_none() && query.limit.is_none() && query.offset.is_none(),
+            SQLInterface: "ORDER BY, LIMIT and OFFSET are not supported in recursive CTE '{}'", name
+        );
+
+        // Establish the anchor (which determines the output schema)
+        let anchor = self.process_query(left, query)?;
+        let mut anchor = self.rename_columns_from_table_alias(anchor, alias)?;
+        let schema = self.get_frame_schema(&mut anchor)?;
+
+        // Validate the recursive member against the anchor schema (checking the
+        // projection width up-front where we can, as the member's own column names
+        // are not significant and could otherwise raise a less helpful error)
+        let ensure_width = |n_cols: usize| {
+            polars_ensure!(
+                n_cols == schema.len(),
+                SQLSyntax: "recursive CTE '{}' requires an equal number of columns in the anchor ({}) and recursive member ({})",
+                name, schema.len(), n_cols
+            );
+            Ok(())
+        };
+        if let SetExpr::Select(select) = right.as_ref() {
+            if !select.projection.iter().any(|item| {
+                matches!(
+                    item,
+                    SelectItem::Wildcard(_) | SelectItem::QualifiedWildcard(_, _)
+                )
+            }) {
+                ensure_width(select.projection.len())?;
+            }
+        }
+        self.register_cte(name, anchor.clone());
+        let mut member = self.process_query(right, query)?;
+        ensure_width(self.get_frame_schema(&mut member)?.len())?;
+
+        // The recursive member is re-translated for each iteration against a snapshot
+        // of the tables (and CTEs) that are visible at this point; CTEs shadow tables
+        // of the same name.
+        let mut tables = self.table_map.clone();
+        tables.extend(
+            self.cte_map
+                .borrow()
+                .iter()
+                .filter(|(k, _)| k.as_str() != name)
+                .map(|(k, v)| (k.clone(), v.clone())),
+        );
+
+        let cte = RecursiveCte {
+            name: name.to_string(),
+            query: query.clone(),
+            member: right.as_ref().clone(),
+            tables,
+            function_registry: self.function_registry.clone(),
+            schema: schema.clone(),
+            distinct,
+            max_iterations: self.max_recursion_iterations,
+        };
+        Ok(anchor.map(
+            move |df| cte.evaluate(df),
+            AllowedOptimizations::empty(),
+            Some(Arc::new(move |_: &Schema| Ok(schema.clone()))),
+            Some("RECURSIVE CTE"),
+        ))
+    }
+
     /// execute the 'FROM' part of the query
     fn execute_from_statement(&mut self, tbl_expr: &TableWithJoins) -> PolarsResult<LazyFrame> {
         let (l_name, mut lf) = self.get_table(&tbl_expr.relation)?;
@@ -634,21 +748,25 @@ impl SQLContext {
                     | JoinOperator::LeftSemi(constraint)
                     | JoinOperator::RightAnti(constraint)
                     | JoinOperator::RightSemi(constraint)) => {
-                        let (lf, rf) = match op {
-                            JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => (rf, lf),
-                            _ => (lf, rf),
+                        let tbl_left = TableInfo {
+                            frame: lf,
+                            name: (&l_name).into(),
+                            schema: left_schema.clone(),
                         };
-                        self.process_join(
-                            &TableInfo {
-                                frame: lf,
-                                name: (&l_name).into(),
-                                schema: left_schema.clone(),
-                            },
-                            &TableInfo {
-                                frame: rf,
-                                name: (&r_name).into(),
-                                schema: right_schema.clone(),
+                        let tbl_right = TableInfo {
+                            frame: rf,
+                            name: (&r_name).into(),
+                            schema: right_schema.clone(),
+                        };
+                        let (tbl_left, tbl_right) = match op {
+                            JoinOperator::RightAnti(_) | JoinOperator::RightSemi(_) => {
+                                (tbl_right, tbl_left)
                             },
+                            _ => (tbl_left, tbl_right),
+                        };
+                        self.process_join(
+                            &tbl_left,
+                            &tbl_right,
                             constraint,
                             match op {
                                 JoinOperator::FullOuter(_) => JoinType::Full,
@@ -734,12 +852,25 @@ impl SQLContext {
 
         // Check for "GROUP BY ..." (after determining projections)
         let mut group_by_keys: Vec<Expr> = Vec::new();
+        let mut grouping_sets: Option<Vec<Vec<usize>>> = None;
         match &select_stmt.group_by {
+            // "GROUP BY ROLLUP|CUBE|GROUPING SETS (...)" syntax
+            GroupByExpr::Expressions(group_by_exprs, modifiers)
+                if !modifiers.is_empty()
+                    || group_by_exprs.iter().any(|e| {
+                        matches!(
+                            e,
+                            SQLExpr::Rollup(_) | SQLExpr::Cube(_) | SQLExpr::GroupingSets(_)
+                        ) || matches!(e, SQLExpr::Tuple(t) if t.is_empty())
+                    }) =>
+            {
+                let (keys, sets) =
+                    self.process_grouping_sets(group_by_exprs, modifiers, &projections, &schema)?;
+                group_by_keys = keys;
+                grouping_sets = Some(sets);
+            },
             // Standard "GROUP BY x, y, z" syntax (also recognising ordinal values)
-            GroupByExpr::Expressions(group_by_exprs, modifiers) => {
-                if !modifiers.is_empty() {
-                    polars_bail!(SQLInterface: "GROUP BY does not support CUBE, ROLLUP, or TOTALS modifiers")
-                }
+            GroupByExpr::Expressions(group_by_exprs, _) => {
                 // translate the group expressions, allowing ordinal values
                 group_by_keys = group_by_exprs
                     .iter()
@@ -785,7 +916,7 @@ impl SQLContext {
             },
         };
 
-        lf = if group_by_keys.is_empty() {
+        lf = if group_by_keys.is_empty() && grouping_sets.is_none() {
             // The 'having' clause is only valid inside 'group by'
             if select_stmt.having.is_some() {
                 polars_bail!(SQLSyntax: "HAVING clause not valid outside of GROUP BY; found:\n{:?}", select_stmt.having);
@@ -795,13 +926,15 @@ impl SQLContext {
             let mut retained_cols = Vec::with_capacity(projections.len());
             let mut retained_names = Vec::with_capacity(projections.len());
             let have_order_by = query.order_by.is_some();
+            // QUALIFY can reference both the projections and the original columns
+            let project_all = have_order_by || select_stmt.qualify.is_some();
             // Initialize containing InheritsContext to handle empty projection case.
             let mut projection_heights = ExprSqlProjectionHeightBehavior::InheritsContext;
 
-            // Note: if there is an 'order by' then we project everything (original cols
-            // and new projections) and *then* select the final cols; the retained cols
-            // are used to ensure a correct final projection. If there's no 'order by',
-            // clause then we can project the final column *expressions* directly.
+            // Note: if there is an 'order by' (or 'qualify') then we project everything
+            // (original cols and new projections) and *then* select the final cols; the
+            // retained cols are used to ensure a correct final projection. If there's no
+            // 'order by' clause then we can project the final column *expressions* directly.
             for p in projections.iter() {
                 let name = p
                     .to_field(schema.deref(), Context::Default)?
@@ -812,7 +945,7 @@ impl SQLContext {
                 {
                     projection_heights |= ExprSqlProjectionHeightBehavior::identify_from_expr(p);
 
-                    retained_cols.push(if have_order_by {
+                    retained_cols.push(if project_all {
                         col(name.as_str())
                     } else {
                         p.clone()
@@ -822,7 +955,7 @@ impl SQLContext {
             }
 
             // Apply the remaining modifiers and establish the final projection
-            if have_order_by {
+            if project_all {
                 // We can safely use `with_columns()` and avoid a join if:
                 // * There is already a projection that projects to the table height.
                 // * All projection heights inherit from context (e.g. all scalar literals that
@@ -870,11 +1003,12 @@ impl SQLContext {
                 lf = lf.with_columns(select_modifiers.renamed_cols());
             }
 
+            lf = self.process_qualify(lf, &select_stmt.qualify)?;
             lf = self.process_order_by(lf, &query.order_by, Some(&retained_cols))?;
 
-            // Note: If `have_order_by`, with_columns is already done above.
+            // Note: If `project_all`, with_columns is already done above.
             if projection_heights == ExprSqlProjectionHeightBehavior::InheritsContext
-                && !have_order_by
+                && !project_all
             {
                 // All projections need to be broadcasted to table height, so evaluate in `with_columns()`
                 lf = lf.with_columns(retained_cols).select(retained_names);
@@ -891,15 +1025,18 @@ impl SQLContext {
             };
             lf
         } else {
-            lf = self.process_group_by(lf, &group_by_keys, &projections)?;
+            lf =
+                self.process_group_by(lf, &group_by_keys, grouping_sets.as_deref(), &projections)?;
             lf = self.process_order_by(lf, &query.order_by, None)?;
 
             // Apply optional 'having' clause, post-aggregation.
             let schema = Some(self.get_frame_schema(&mut lf)?);
-            match select_stmt.having.as_ref() {
+            lf = match select_stmt.having.as_ref() {
                 Some(expr) => lf.filter(parse_sql_expr(expr, self, schema.as_deref())?),
                 None => lf,
-            }
+            };
+            // Apply optional 'qualify' clause, post-aggregation.
+            self.process_qualify(lf, &select_stmt.qualify)?
         };
 
         // Apply optional DISTINCT clause.
@@ -929,6 +1066,20 @@ impl SQLContext {
         Ok(lf)
     }
 
+    /// Filter on the result of window functions (QUALIFY clause).
+    fn process_qualify(
+        &mut self,
+        mut lf: LazyFrame,
+        expr: &Option<SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let Some(expr) = expr else {
+            return Ok(lf);
+        };
+        let schema = self.get_frame_schema(&mut lf)?;
+        let expr = parse_sql_expr(expr, self, Some(schema.deref()))?;
+        Ok(lf.filter(expr))
+    }
+
     fn column_projections(
         &mut self,
         select_stmt: &Select,
@@ -1027,7 +1178,17 @@ impl SQLContext {
         constraint: &JoinConstraint,
         join_type: JoinType,
     ) -> PolarsResult<LazyFrame> {
-        let (left_on, right_on) = process_join_constraint(constraint, tbl_left, tbl_right)?;
+        let (left_on, right_on, predicates) =
+            process_join_constraint(constraint, tbl_left, tbl_right)?;
+        if !predicates.is_empty() {
+            return self.process_non_equi_join(
+                tbl_left,
+                tbl_right,
+                (left_on, right_on),
+                &predicates,
+                join_type,
+            );
+        }
 
         let joined = tbl_left
             .frame
@@ -1044,6 +1205,178 @@ impl SQLContext {
         Ok(joined)
     }
 
+    /// Join on constraints that are not (exclusively) equalities between the two tables.
+    ///
+    /// Inner joins use `join_where` for the comparison predicates (allowing the use of
+    /// IEJoin and other range-join strategies), with any remaining predicates applied
+    /// as a post-join filter. Other join types are derived from the pairs of row indices
+    /// that satisfy the constraint, so that unmatched rows are handled correctly.
+    fn process_non_equi_join(
+        &mut self,
+        tbl_left: &TableInfo,
+        tbl_right: &TableInfo,
+        (left_on, right_on): (Vec<Expr>, Vec<Expr>),
+        predicates: &[&SQLExpr],
+        join_type: JoinType,
+    ) -> PolarsResult<LazyFrame> {
+        // Columns from the right table that are also present in the left table take
+        // a suffix; register these so that the predicates resolve to the joined names
+        let suffix = format_pl_smallstr!(":{}", tbl_right.name);
+        let mut joined_schema = tbl_left.schema.as_ref().clone();
+        let mut right_aliases = PlHashMap::new();
+        let mut right_names = PlHashSet::new();
+        for (name, dtype) in tbl_right.schema.iter() {
+            if tbl_left.schema.contains(name) {
+                let aliased_name = format_pl_smallstr!("{}{}", name, suffix);
+                right_aliases.insert(name.to_string(), aliased_name.to_string());
+                right_names.insert(aliased_name.clone());
+                joined_schema.with_column(aliased_name, dtype.clone());
+            } else {
+                right_names.insert(name.clone());
+                joined_schema.with_column(name.clone(), dtype.clone());
+            }
+        }
+        self.joined_aliases
+            .borrow_mut()
+            .insert(tbl_right.name.to_string(), right_aliases.clone());
+
+        // Equi-join keys are expressed as predicates on the joined columns
+        let mut join_predicates = left_on
+            .into_iter()
+            .zip(right_on)
+            .map(|(l, r)| match r {
+                Expr::Column(name) => match right_aliases.get(name.as_str()) {
+                    Some(aliased_name) => l.eq(col(aliased_name.as_str())),
+                    None => l.eq(col(name)),
+                },
+                r => l.eq(r),
+            })
+            .collect::<Vec<_>>();
+        let mut filter_predicates = vec![];
+        for sql_expr in predicates {
+            // unqualified columns must identify a single one of the joined tables
+            let mut ambiguous = None;
+            any_sql_expr(sql_expr, &mut |e| match e {
+                SQLExpr::Identifier(ident)
+                    if tbl_left.schema.contains(&ident.value)
+                        && tbl_right.schema.contains(&ident.value) =>
+                {
+                    ambiguous = Some(ident.value.clone());
+                    true
+                },
+                _ => false,
+            });
+            if let Some(name) = ambiguous {
+                polars_bail!(
+                    SQLInterface: "ambiguous column reference '{}' in join constraint; found in both '{}' and '{}'",
+                    name, tbl_left.name, tbl_right.name
+                );
+            }
+            // (resolved column names are aliased back to the table column names, but
+            // aliases are not allowed in join predicates)
+            let expr =
+                parse_sql_expr(sql_expr, self, Some(&joined_schema))?.map_expr(|e| match e {
+                    Expr::Alias(e, _) => Arc::unwrap_or_clone(e),
+                    e => e,
+                });
+            let leaf_names = expr_to_leaf_column_names(&expr);
+            polars_ensure!(
+                !leaf_names.is_empty(),
+                SQLInterface: "join constraint must reference at least one of the joined tables; found {}", sql_expr
+            );
+            let dtype = expr.to_field(&joined_schema, Context::Default)?.dtype;
+            polars_ensure!(
+                dtype == DataType::Boolean,
+                SQLInterface: "join constraint must be a boolean expression; found {} (of type {})", sql_expr, dtype
+            );
+            // only comparisons between the two tables can be used as join predicates;
+            // predicates on a single table are applied to the matching rows
+            let references_both = leaf_names.iter().any(|n| tbl_left.schema.contains(n))
+                && leaf_names.iter().any(|n| right_names.contains(n));
+            let is_comparison = references_both
+                && match sql_expr {
+                    SQLExpr::BinaryOp { op, .. } => matches!(
+                        op,
+                        BinaryOperator::Eq
+                            | BinaryOperator::NotEq
+                            | BinaryOperator::Lt
+                            | BinaryOperator::LtEq
+                            | BinaryOperator::Gt
+                            | BinaryOperator::GtEq
+                    ),
+                    SQLExpr::Between { negated, .. } => !negated,
+                    _ => false,
+                };
+            if is_comparison {
+                join_predicates.push(expr);
+            } else {
+                filter_predicates.push(expr);
+            }
+        }
+        let inner_join = |left: LazyFrame, right: LazyFrame| {
+            let joined = if join_predicates.is_empty() {
+                left.cross_join(right, Some(suffix.clone()))
+            } else {
+                left.join_builder()
+                    .with(right)
+                    .how(JoinType::Inner)
+                    .suffix(suffix.clone())
+                    .join_where(join_predicates.clone())
+            };
+            match filter_predicates
+                .iter()
+                .cloned()
+                .reduce(|acc, e| acc.and(e))
+            {
+                Some(predicate) => joined.filter(predicate),
+                None => joined,
+            }
+        };
+        if join_type == JoinType::Inner {
+            return Ok(inner_join(tbl_left.frame.clone(), tbl_right.frame.clone()));
+        }
+
+        // Establish the matching rows, then join them back to the source tables
+        const LEFT_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_LEFT_ROW");
+        const RIGHT_ROW: PlSmallStr = PlSmallStr::from_static("__POLARS_JOIN_RIGHT_ROW");
+        let left = tbl_left.frame.clone().with_row_index(LEFT_ROW, None);
+        let right = tbl_right.frame.clone().with_row_index(RIGHT_ROW, None);
+        let matches =
+            inner_join(left.clone(), right.clone()).select([col(LEFT_ROW), col(RIGHT_ROW)]);
+
+        let join_on = |lf: LazyFrame, other: LazyFrame, key: PlSmallStr, how: JoinType| {
+            lf.join_builder()
+                .with(other)
+                .left_on([col(key.clone())])
+                .right_on([col(key)])
+                .how(how)
+                .suffix(suffix.clone())
+                .coalesce(JoinCoalesce::CoalesceColumns)
+                .finish()
+        };
+        match join_type {
+            JoinType::Left | JoinType::Right | JoinType::Full => {
+                let how_matches = if join_type == JoinType::Right {
+                    JoinType::Inner
+                } else {
+                    JoinType::Left
+                };
+                let lf = join_on(left, matches, LEFT_ROW, how_matches);
+                Ok(join_on(lf, right, RIGHT_ROW, join_type).drop([LEFT_ROW, RIGHT_ROW]))
+            },
+            #[cfg(feature = "semi_anti_join")]
+            JoinType::Semi | JoinType::Anti => {
+                let matches = matches.select([col(LEFT_ROW)]);
+                Ok(join_on(left, matches, LEFT_ROW, join_type).drop([LEFT_ROW]))
+            },
+            join_type => polars_bail!(
+                SQLInterface:
+                "join type '{:?}' is not supported with non-equi join constraints",
+                join_type
+            ),
+        }
+    }
+
     fn process_subqueries(&self, lf: LazyFrame, exprs: Vec<&mut Expr>) -> LazyFrame {
         let mut contexts = vec![];
         for expr in exprs {
@@ -1209,11 +1542,225 @@ impl SQLContext {
                     None => Ok(("".to_string(), lf)),
                 }
             },
+            TableFactor::Pivot {
+                table,
+                aggregate_functions,
+                value_column,
+                value_source,
+                default_on_null,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.process_pivot(
+                    lf,
+                    aggregate_functions,
+                    value_column,
+                    value_source,
+                    default_on_null.as_ref(),
+                )?;
+                self.register_table_operator(tbl_name, lf, alias)
+            },
+            TableFactor::Unpivot {
+                table,
+                value,
+                name,
+                columns,
+                alias,
+            } => {
+                let (tbl_name, lf) = self.get_table(table)?;
+                let lf = self.process_unpivot(lf, value, name, columns)?;
+                self.register_table_operator(tbl_name, lf, alias)
+            },
             // Support bare table, optionally with an alias, for now
             _ => polars_bail!(SQLInterface: "not yet implemented: {}", relation),
         }
     }
 
+    /// Register the result of a PIVOT/UNPIVOT table operator under its (optional) alias.
+    fn register_table_operator(
+        &mut self,
+        tbl_name: String,
+        lf: LazyFrame,
+        alias: &Option<TableAlias>,
+    ) -> PolarsResult<(String, LazyFrame)> {
+        match alias {
+            Some(alias) => {
+                let lf = self.rename_columns_from_table_alias(lf, alias)?;
+                self.table_map.insert(alias.name.value.clone(), lf.clone());
+                Ok((alias.name.value.clone(), lf))
+            },
+            None => Ok((tbl_name, lf)),
+        }
+    }
+
+    /// Lower `PIVOT (agg FOR col IN (...))` onto the eager pivot implementation.
+    ///
+    /// The IN list is required to be an explicit list of literals so that the output
+    /// schema is known up-front; the pivot itself is deferred until the frame is
+    /// collected. Columns that are neither the pivot column nor referenced by an
+    /// aggregate form the implicit group key.
+    fn process_pivot(
+        &mut self,
+        mut lf: LazyFrame,
+        aggregate_functions: &[ExprWithAlias],
+        value_column: &[Ident],
+        value_source: &PivotValueSource,
+        default_on_null: Option<&SQLExpr>,
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on = match value_column.last() {
+            Some(ident) => PlSmallStr::from_str(ident.value.as_str()),
+            None => polars_bail!(SQLSyntax: "PIVOT requires a pivot column"),
+        };
+        let on_dtype = schema.try_get(&on)?.clone();
+
+        // Pivot values (and the names of the columns they produce)
+        let PivotValueSource::List(pivot_values) = value_source else {
+            polars_bail!(SQLInterface: "PIVOT requires an explicit list of values; found IN ({})", value_source)
+        };
+        polars_ensure!(!pivot_values.is_empty(), SQLSyntax: "PIVOT requires at least one value");
+        let mut values = Vec::with_capacity(pivot_values.len());
+        for value in pivot_values {
+            let Expr::Literal(lv) = parse_sql_expr(&value.expr, self, Some(schema.deref()))? else {
+                polars_bail!(SQLSyntax: "PIVOT values must be literals; found {}", value.expr)
+            };
+            let av = lv
+                .to_any_value()
+                .ok_or_else(|| polars_err!(SQLInterface: "invalid PIVOT value: {:?}", lv))?
+                .into_static();
+            // the pivot names its columns after the values of the pivot column (as strings)
+            let key = Series::from_any_values(PlSmallStr::EMPTY, &[av], true)?
+                .strict_cast(&on_dtype)?
+                .cast(&DataType::String)?;
+            let key = key
+                .str()?
+                .get(0)
+                .map_or_else(|| PlSmallStr::from_static("null"), PlSmallStr::from_str);
+            let name = match &value.alias {
+                Some(alias) => PlSmallStr::from_str(alias.value.as_str()),
+                None => key.clone(),
+            };
+            values.push((Expr::Literal(lv).strict_cast(on_dtype.clone()), key, name));
+        }
+
+        // Aggregations, each applied to a single value column
+        let mut aggregations = Vec::with_capacity(aggregate_functions.len());
+        for agg in aggregate_functions {
+            let expr = parse_sql_expr(&agg.expr, self, Some(schema.deref()))?;
+            polars_ensure!(
+                has_expr(&expr, |e| matches!(e, Expr::Agg(_) | Expr::Len)),
+                SQLSyntax: "PIVOT requires an aggregate function; found {}", agg.expr
+            );
+            let mut leaves = expr_to_leaf_column_names(&expr);
+            leaves.dedup();
+            polars_ensure!(
+                leaves.len() == 1,
+                SQLInterface: "PIVOT aggregates must reference exactly one column; found {}", agg.expr
+            );
+            let suffix = match (&agg.alias, aggregate_functions.len()) {
+                (_, 1) => None,
+                (Some(alias), _) => Some(alias.value.clone()),
+                (None, _) => {
+                    polars_bail!(SQLSyntax: "PIVOT with multiple aggregates requires an alias for each; found {}", agg.expr)
+                },
+            };
+            let dtype = expr.to_field(&schema, Context::Default)?.dtype;
+            aggregations.push(PivotAggregation {
+                expr,
+                value: leaves.pop().unwrap(),
+                dtype,
+                suffix,
+            });
+        }
+        let index: Vec<PlSmallStr> = schema
+            .iter_names()
+            .filter(|name| **name != on && !aggregations.iter().any(|agg| agg.value == **name))
+            .cloned()
+            .collect();
+
+        // Establish the output schema
+        let mut output_schema = Schema::with_capacity(index.len() + values.len());
+        for name in index.iter() {
+            output_schema.with_column(name.clone(), schema.try_get(name)?.clone());
+        }
+        let mut output_columns = Vec::with_capacity(aggregations.len() * values.len());
+        for agg in aggregations.iter() {
+            for (_, key, name) in values.iter() {
+                let name = match &agg.suffix {
+                    Some(suffix) => format_pl_smallstr!("{}_{}", name, suffix),
+                    None => name.clone(),
+                };
+                polars_ensure!(
+                    output_schema.insert(name.clone(), agg.dtype.clone()).is_none(),
+                    Duplicate: "PIVOT produces duplicate column name '{}'", name
+                );
+                output_columns.push((key.clone(), name));
+            }
+        }
+
+        // Only rows matching one of the given values contribute to the pivot
+        let predicate = values
+            .iter()
+            .map(|(value, _, _)| col(on.clone()).eq(value.clone()))
+            .reduce(|acc, e| acc.or(e))
+            .unwrap();
+
+        let pivoted_names: Vec<PlSmallStr> = output_columns
+            .iter()
+            .map(|(_, name)| name.clone())
+            .collect();
+        let output_schema = Arc::new(output_schema);
+        lf = lf.filter(predicate).map(
+            move |df| pivot_frame(&df, &on, &index, &aggregations, &output_columns),
+            AllowedOptimizations::empty(),
+            Some(Arc::new(move |_: &Schema| Ok(output_schema.clone()))),
+            Some("PIVOT"),
+        );
+        if let Some(default) = default_on_null {
+            let default = parse_sql_expr(default, self, None)?;
+            let filled: Vec<Expr> = pivoted_names
+                .into_iter()
+                .map(|name| col(name).fill_null(default.clone()))
+                .collect();
+            lf = lf.with_columns(filled);
+        }
+        Ok(lf)
+    }
+
+    /// Lower `UNPIVOT (value FOR name IN (...))` onto the lazy unpivot operation.
+    ///
+    /// As with other engines, rows where the unpivoted value is NULL are excluded.
+    fn process_unpivot(
+        &mut self,
+        mut lf: LazyFrame,
+        value: &Ident,
+        name: &Ident,
+        columns: &[Ident],
+    ) -> PolarsResult<LazyFrame> {
+        let schema = self.get_frame_schema(&mut lf)?;
+        let on: Vec<PlSmallStr> = columns
+            .iter()
+            .map(|c| PlSmallStr::from_str(c.value.as_str()))
+            .collect();
+        for c in on.iter() {
+            schema.try_get(c)?;
+        }
+        let index: Vec<Selector> = schema
+            .iter_names()
+            .filter(|name| !on.contains(name))
+            .map(|name| name.clone().into())
+            .collect();
+
+        let value_name = PlSmallStr::from_str(value.value.as_str());
+        let args = UnpivotArgsDSL {
+            on: on.into_iter().map(Selector::from).collect(),
+            index,
+            variable_name: Some(PlSmallStr::from_str(name.value.as_str())),
+            value_name: Some(value_name.clone()),
+        };
+        Ok(lf.unpivot(args).filter(col(value_name).is_not_null()))
+    }
+
     fn execute_table_function(
         &mut self,
         name: &ObjectName,
@@ -1289,18 +1836,161 @@ impl SQLContext {
         ))
     }
 
+    /// Expand ROLLUP, CUBE and GROUPING SETS into the distinct group keys and the
+    /// grouping sets (as indices into those keys) that they represent.
+    fn process_grouping_sets(
+        &mut self,
+        group_by_exprs: &[SQLExpr],
+        modifiers: &[GroupByWithModifier],
+        projections: &[Expr],
+        schema: &Schema,
+    ) -> PolarsResult<(Vec<Expr>, Vec<Vec<usize>>)> {
+        let mut keys = Vec::new();
+        let mut key_index = |ctx: &mut Self, e: &SQLExpr| -> PolarsResult<usize> {
+            let expr = ctx.expr_or_ordinal(e, projections, None, Some(schema), "GROUP BY")?;
+            Ok(match keys.iter().position(|k| *k == expr) {
+                Some(idx) => idx,
+                None => {
+                    keys.push(expr);
+                    keys.len() - 1
+                },
+            })
+        };
+
+        // Each GROUP BY element contributes one or more alternative sets of keys;
+        // the grouping sets are the cross product of these alternatives.
+        let mut elements: Vec<Vec<Vec<usize>>> = Vec::with_capacity(group_by_exprs.len());
+        let mut has_grouping_sets = false;
+        for e in group_by_exprs {
+            let mut key_sets = |ctx: &mut Self, sets: &[Vec<SQLExpr>]| {
+                sets.iter()
+                    .map(|set| set.iter().map(|e| key_index(ctx, e)).collect())
+                    .collect::<PolarsResult<Vec<Vec<usize>>>>()
+            };
+            elements.push(match e {
+                SQLExpr::Rollup(items) => {
+                    has_grouping_sets = true;
+                    rollup(&key_sets(self, items)?)
+                },
+                SQLExpr::Cube(items) => {
+                    has_grouping_sets = true;
+                    cube(&key_sets(self, items)?)?
+                },
+                SQLExpr::GroupingSets(sets) => {
+                    has_grouping_sets = true;
+                    key_sets(self, sets)?
+                },
+                SQLExpr::Tuple(exprs) if exprs.is_empty() => vec![vec![]],
+                _ => vec![vec![key_index(self, e)?]],
+            });
+        }
+        match modifiers {
+            [] => {},
+            [modifier @ (GroupByWithModifier::Rollup | GroupByWithModifier::Cube)] => {
+                polars_ensure!(
+                    !has_grouping_sets,
+                    SQLSyntax: "GROUP BY ... {} cannot be combined with ROLLUP, CUBE or GROUPING SETS", modifier
+                );
+                let items: Vec<Vec<usize>> = elements.into_iter().flatten().collect();
+                elements = vec![match modifier {
+                    GroupByWithModifier::Rollup => rollup(&items),
+                    _ => cube(&items)?,
+                }];
+            },
+            _ => {
+                polars_bail!(SQLInterface: "GROUP BY does not support the {} modifier(s)", modifiers.iter().map(|m| m.to_string()).collect::<Vec<_>>().join(", "))
+            },
+        }
+
+        let mut grouping_sets: Vec<Vec<usize>> = vec![vec![]];
+        for alternatives in elements {
+            grouping_sets = grouping_sets
+                .iter()
+                .flat_map(|set| {
+                    alternatives.iter().map(move |alt| {
+                        let mut set = set.clone();
+                        for idx in alt {
+                            if !set.contains(idx) {
+                                set.push(*idx);
+                            }
+                        }
+                        set
+                    })
+                })
+                .collect();
+        }
+        Ok((keys, grouping_sets))
+    }
+
+    /// Register the arguments of a GROUPING() call, returning the (internal) column
+    /// that holds its result once the enclosing GROUP BY has been processed.
+    pub(crate) fn register_grouping_function(&mut self, name: PlSmallStr, args: Vec<Expr>) -> Expr {
+        self.grouping_functions.push((name, args));
+        col(format_pl_smallstr!(
+            "{}{}",
+            GROUPING_PREFIX,
+            self.grouping_functions.len() - 1
+        ))
+    }
+
+    /// Look up the GROUPING() call whose result is held by the given (internal) column.
+    fn get_grouping_function(&self, column: &str) -> Option<&(PlSmallStr, Vec<Expr>)> {
+        let suffix = column.strip_prefix(GROUPING_PREFIX)?;
+        let idx = suffix.parse::<usize>().ok()?;
+        // (only the canonical representation of the index is ever generated)
+        if idx.to_string() != suffix {
+            return None;
+        }
+        self.grouping_functions.get(idx)
+    }
+
     fn process_group_by(
         &mut self,
         mut lf: LazyFrame,
         group_by_keys: &[Expr],
+        grouping_sets: Option<&[Vec<usize>]>,
         projections: &[Expr],
     ) -> PolarsResult<LazyFrame> {
-        let schema_before = self.get_frame_schema(&mut lf)?;
-        let group_by_keys_schema =
+        let mut schema_before = self.get_frame_schema(&mut lf)?;
+        let mut group_by_keys_schema =
             expressions_to_schema(group_by_keys, &schema_before, Context::Default)?;
 
+        // Resolve any GROUPING() calls against the group keys; the results are
+        // made available as (constant) columns of each aggregated grouping set.
+        let mut grouping_columns: Vec<(PlSmallStr, Vec<usize>)> = Vec::new();
+        for name in projections.iter().flat_map(expr_to_leaf_column_names) {
+            let Some((_, args)) = self.get_grouping_function(&name) else {
+                continue;
+            };
+            if grouping_columns.iter().any(|(n, _)| *n == name) {
+                continue;
+            }
+            polars_ensure!(
+                !schema_before.contains(&name),
+                SQLInterface: "column name '{}' is reserved for the result of GROUPING()", name
+            );
+            let key_indices = args
+                .iter()
+                .map(|arg| {
+                    group_by_keys
+                        .iter()
+                        .position(|k| k == arg || k.clone().meta().undo_aliases() == *arg)
+                        .ok_or_else(|| polars_err!(SQLSyntax: "GROUPING arguments must be GROUP BY expressions; found {}", arg))
+                })
+                .collect::<PolarsResult<Vec<_>>>()?;
+            grouping_columns.push((name, key_indices));
+        }
+        if !grouping_columns.is_empty() {
+            let schema = Arc::make_mut(&mut schema_before);
+            for (name, _) in grouping_columns.iter() {
+                schema.with_column(name.clone(), DataType::Int64);
+                group_by_keys_schema.with_column(name.clone(), DataType::Int64);
+            }
+        }
+
         // Remove the group_by keys as polars adds those implicitly.
         let mut aggregation_projection = Vec::with_capacity(projections.len());
+        let mut scalar_projection = Vec::with_capacity(projections.len());
         let mut projection_overrides = PlHashMap::with_capacity(projections.len());
         let mut projection_aliases = PlHashSet::new();
         let mut group_key_aliases = PlHashSet::new();
@@ -1329,6 +2019,7 @@ impl SQLContext {
             }
             let field = e.to_field(&schema_before, Context::Default)?;
             if group_by_keys_schema.get(&field.name).is_none() && is_agg_or_window {
+                scalar_projection.push(e.clone());
                 let mut e = e.clone();
                 if let Expr::Agg(AggExpr::Implode(expr)) = &e {
                     e = (**expr).clone();
@@ -1350,7 +2041,19 @@ impl SQLContext {
                 }
             }
         }
-        let aggregated = lf.group_by(group_by_keys).agg(&aggregation_projection);
+        let aggregated = if grouping_sets.is_none() && grouping_columns.is_empty() {
+            lf.group_by(group_by_keys).agg(&aggregation_projection)
+        } else {
+            let all_keys = [(0..group_by_keys.len()).collect()];
+            self.aggregate_grouping_sets(
+                lf,
+                group_by_keys,
+                grouping_sets.unwrap_or(&all_keys),
+                &grouping_columns,
+                (&aggregation_projection, &scalar_projection),
+                &schema_before,
+            )?
+        };
         let projection_schema =
             expressions_to_schema(projections, &schema_before, Context::Default)?;
 
@@ -1361,6 +2064,8 @@ impl SQLContext {
             .map(|(name, projection_expr)| {
                 if let Some(expr) = projection_overrides.get(name.as_str()) {
                     expr.clone()
+                } else if let Some((sql_name, _)) = self.get_grouping_function(name) {
+                    projection_expr.clone().alias(sql_name.clone())
                 } else if group_by_keys_schema.get(name).is_some()
                     || projection_aliases.contains(name.as_str())
                     || group_key_aliases.contains(name.as_str())
@@ -1375,6 +2080,64 @@ impl SQLContext {
         Ok(aggregated.select(&final_projection))
     }
 
+    /// Aggregate each grouping set separately, concatenating the results. Keys that
+    /// are not part of a given grouping set are NULL in its aggregated rows.
+    ///
+    /// The aggregations are given both in their GROUP BY form and in the form that
+    /// aggregates the whole frame to a single row (for the empty grouping set).
+    fn aggregate_grouping_sets(
+        &mut self,
+        lf: LazyFrame,
+        group_by_keys: &[Expr],
+        grouping_sets: &[Vec<usize>],
+        grouping_columns: &[(PlSmallStr, Vec<usize>)],
+        (aggregation_projection, scalar_projection): (&[Expr], &[Expr]),
+        schema: &Schema,
+    ) -> PolarsResult<LazyFrame> {
+        let key_fields = group_by_keys
+            .iter()
+            .map(|e| e.to_field(schema, Context::Default))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut output_names: Vec<Expr> = key_fields.iter().map(|f| col(f.name.clone())).collect();
+        output_names.extend(grouping_columns.iter().map(|(name, _)| col(name.clone())));
+        for e in aggregation_projection {
+            output_names.push(col(e.to_field(schema, Context::Default)?.name));
+        }
+
+        // Note: each aggregation shares the same input, which is cached (and so only
+        // evaluated once) by the common-subplan-elimination optimisation.
+        let aggregated = grouping_sets
+            .iter()
+            .map(|set| {
+                // (the grand total is a single row, even if there are no input rows)
+                let mut lf = if set.is_empty() {
+                    lf.clone().select(scalar_projection)
+                } else {
+                    let keys: Vec<Expr> =
+                        set.iter().map(|idx| group_by_keys[*idx].clone()).collect();
+                    lf.clone().group_by(keys).agg(aggregation_projection)
+                };
+                let mut constants: Vec<Expr> = key_fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(idx, _)| !set.contains(idx))
+                    .map(|(_, f)| lit(Null {}).cast(f.dtype.clone()).alias(f.name.clone()))
+                    .collect();
+                for (name, key_indices) in grouping_columns {
+                    let bits = key_indices
+                        .iter()
+                        .fold(0i64, |acc, idx| (acc << 1) | !set.contains(idx) as i64);
+                    constants.push(lit(bits).cast(DataType::Int64).alias(name.clone()));
+                }
+                if !constants.is_empty() {
+                    lf = lf.with_columns(constants);
+                }
+                lf.select(&output_names)
+            })
+            .collect::<Vec<_>>();
+        concat(aggregated, UnionArgs::default())
+    }
+
     fn process_limit_offset(
         &self,
         lf: LazyFrame,
@@ -1546,6 +2309,286 @@ impl SQLContext {
     }
 }
 
+/// Default maximum number of iterations used to evaluate a recursive CTE.
+const DEFAULT_MAX_RECURSION_ITERATIONS: usize = 1000;
+
+/// A recursive CTE, evaluated by fixed-point iteration from its anchor rows.
+struct RecursiveCte {
+    name: String,
+    query: Query,
+    member: SetExpr,
+    tables: PlHashMap<String, LazyFrame>,
+    function_registry: Arc<dyn FunctionRegistry>,
+    schema: SchemaRef,
+    distinct: bool,
+    max_iterations: usize,
+}
+
+impl RecursiveCte {
+    fn evaluate(&self, anchor: DataFrame) -> PolarsResult<DataFrame> {
+        let mut result = if self.distinct {
+            anchor.unique_stable(None, UniqueKeepStrategy::First, None)?
+        } else {
+            anchor
+        };
+        let mut delta = result.clone();
+        let mut iterations = 0;
+        while delta.height() > 0 {
+            polars_ensure!(
+                iterations < self.max_iterations,
+                ComputeError: "recursive CTE '{}' did not complete within {} iterations",
+                self.name, self.max_iterations
+            );
+            iterations += 1;
+
+            // Apply the recursive member to the rows produced by the previous iteration
+            let mut ctx = SQLContext::new_from_table_map(self.tables.clone())
+                .with_function_registry(self.function_registry.clone());
+            ctx.register(&self.name, delta.lazy());
+            let mut lf = ctx.process_query(&self.member, &self.query)?;
+            let member_schema = ctx.get_frame_schema(&mut lf)?;
+            let next = lf
+                .select(
+                    member_schema
+                        .iter_names()
+                        .zip(self.schema.iter())
+                        .map(|(src, (name, dtype))| {
+                            col(src.clone())
+                                .strict_cast(dtype.clone())
+                                .alias(name.clone())
+                        })
+                        .collect::<Vec<_>>(),
+                )
+                .collect()?;
+
+            let height = result.height();
+            result.vstack_mut_owned(next)?;
+            if self.distinct {
+                // rows that were not already present are kept after the existing (unique) rows
+                result = result.unique_stable(None, UniqueKeepStrategy::First, None)?;
+            }
+            delta = result.slice(height as i64, usize::MAX);
+        }
+        result.as_single_chunk_par();
+        Ok(result)
+    }
+}
+
+/// Check whether `f` holds for the given SQL expression or any of its sub-expressions.
+/// Subqueries are not traversed here, but are passed to `f`, which can inspect them.
+fn any_sql_expr(expr: &SQLExpr, f: &mut impl FnMut(&SQLExpr) -> bool) -> bool {
+    if f(expr) {
+        return true;
+    }
+    let children: Vec<&SQLExpr> = match expr {
+        SQLExpr::BinaryOp { left, right, .. }
+        | SQLExpr::IsDistinctFrom(left, right)
+        | SQLExpr::IsNotDistinctFrom(left, right) => vec![left, right],
+        SQLExpr::UnaryOp { expr, .. }
+        | SQLExpr::Nested(expr)
+        | SQLExpr::Cast { expr, .. }
+        | SQLExpr::IsNull(expr)
+        | SQLExpr::IsNotNull(expr)
+        | SQLExpr::IsTrue(expr)
+        | SQLExpr::IsNotTrue(expr)
+        | SQLExpr::IsFalse(expr)
+        | SQLExpr::IsNotFalse(expr)
+        | SQLExpr::InSubquery { expr, .. } => vec![expr],
+        SQLExpr::Like { expr, pattern, .. } | SQLExpr::ILike { expr, pattern, .. } => {
+            vec![expr, pattern]
+        },
+        SQLExpr::InList { expr, list, .. } => std::iter::once(expr.as_ref()).chain(list).collect(),
+        SQLExpr::Between {
+            expr, low, high, ..
+        } => vec![expr, low, high],
+        SQLExpr::Case {
+            operand,
+            conditions,
+            results,
+            else_result,
+        } => operand
+            .iter()
+            .chain(else_result)
+            .map(|e| e.as_ref())
+            .chain(conditions)
+            .chain(results)
+            .collect(),
+        SQLExpr::Function(function) => match &function.args {
+            FunctionArguments::List(list) => list
+                .args
+                .iter()
+                .filter_map(|arg| match arg {
+                    FunctionArg::Named {
+                        arg: FunctionArgExpr::Expr(e),
+                        ..
+                    }
+                    | FunctionArg::ExprNamed {
+                        arg: FunctionArgExpr::Expr(e),
+                        ..
+                    }
+                    | FunctionArg::Unnamed(FunctionArgExpr::Expr(e)) => Some(e),
+                    _ => None,
+                })
+                .collect(),
+            _ => vec![],
+        },
+        _ => vec![],
+    };
+    children.into_iter().any(|e| any_sql_expr(e, f))
+}
+
+/// Check whether the given query references the named table in its FROM clause(s),
+/// including those of any subqueries in its projection, WHERE or HAVING clauses.
+fn references_table(expr: &SetExpr, name: &str) -> bool {
+    fn expr_references_table(expr: &SQLExpr, name: &str) -> bool {
+        any_sql_expr(expr, &mut |e| match e {
+            SQLExpr::Subquery(subquery)
+            | SQLExpr::Exists { subquery, .. }
+            | SQLExpr::InSubquery { subquery, .. } => references_table(&subquery.body, name),
+            SQLExpr::Function(function) => matches!(
+                &function.args,
+                FunctionArguments::Subquery(subquery) if references_table(&subquery.body, name)
+            ),
+            _ => false,
+        })
+    }
+    fn factor_references_table(relation: &TableFactor, name: &str) -> bool {
+        match relation {
+            TableFactor::Table { name: tbl, .. } => tbl.0.first().is_some_and(|i| i.value == name),
+            TableFactor::Derived { subquery, .. } => references_table(&subquery.body, name),
+            TableFactor::NestedJoin {
+                table_with_joins, ..
+            } => from_references_table(table_with_joins, name),
+            TableFactor::Pivot { table, .. } | TableFactor::Unpivot { table, .. } => {
+                factor_references_table(table, name)
+            },
+            _ => false,
+        }
+    }
+    fn from_references_table(from: &TableWithJoins, name: &str) -> bool {
+        factor_references_table(&from.relation, name)
+            || from
+                .joins
+                .iter()
+                .any(|j| factor_references_table(&j.relation, name))
+    }
+    match expr {
+        SetExpr::Select(select) => {
+            select
+                .from
+                .iter()
+                .any(|from| from_references_table(from, name))
+                || select.projection.iter().any(|item| match item {
+                    SelectItem::UnnamedExpr(e) | SelectItem::ExprWithAlias { expr: e, .. } => {
+                        expr_references_table(e, name)
+                    },
+                    _ => false,
+                })
+                || select
+                    .selection
+                    .iter()
+                    .chain(&select.having)
+                    .any(|e| expr_references_table(e, name))
+        },
+        SetExpr::Query(query) => references_table(&query.body, name),
+        SetExpr::SetOperation { left, right, .. } => {
+            references_table(left, name) || references_table(right, name)
+        },
+        _ => false,
+    }
+}
+
+/// Prefix of the internal columns holding the results of GROUPING() calls.
+const GROUPING_PREFIX: &str = "__POLARS_GROUPING_";
+
+/// The grouping sets of `ROLLUP (a, b, ...)`; (a, b, ...), ..., (a), ().
+fn rollup(items: &[Vec<usize>]) -> Vec<Vec<usize>> {
+    (0..=items.len())
+        .rev()
+        .map(|n| items[..n].concat())
+        .collect()
+}
+
+/// The grouping sets of `CUBE (a, b, ...)`; all subsets of the given items.
+fn cube(items: &[Vec<usize>]) -> PolarsResult<Vec<Vec<usize>>> {
+    polars_ensure!(
+        items.len() <= 16,
+        SQLInterface: "CUBE supports at most 16 elements (found {})", items.len()
+    );
+    let n = items.len();
+    Ok((0..1usize << n)
+        .rev()
+        .map(|mask| {
+            items
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| mask & (1 << (n - 1 - i)) != 0)
+                .flat_map(|(_, item)| item.iter().copied())
+                .collect()
+        })
+        .collect())
+}
+
+/// An aggregation applied to a single value column of a PIVOT.
+struct PivotAggregation {
+    expr: Expr,
+    value: PlSmallStr,
+    dtype: DataType,
+    suffix: Option<String>,
+}
+
+/// Pivot a materialized frame; `output_columns` holds the (pivot value, output name)
+/// pairs for each aggregation in turn.
+fn pivot_frame(
+    df: &DataFrame,
+    on: &PlSmallStr,
+    index: &[PlSmallStr],
+    aggregations: &[PivotAggregation],
+    output_columns: &[(PlSmallStr, PlSmallStr)],
+) -> PolarsResult<DataFrame> {
+    // pivoting requires an index; if there are no group columns then use a constant one
+    const PIVOT_INDEX: PlSmallStr = PlSmallStr::from_static("__POLARS_PIVOT_INDEX");
+    let (df, pivot_index) = if index.is_empty() {
+        let mut df = df.clone();
+        df.with_column(Column::new_scalar(
+            PIVOT_INDEX,
+            Scalar::from(0 as IdxSize),
+            df.height(),
+        ))?;
+        (Cow::Owned(df), vec![PIVOT_INDEX])
+    } else {
+        (Cow::Borrowed(df), index.to_vec())
+    };
+    let mut columns = Vec::with_capacity(index.len() + output_columns.len());
+    let n_values = output_columns.len() / aggregations.len();
+    for (i, (agg, agg_columns)) in aggregations
+        .iter()
+        .zip(output_columns.chunks(n_values))
+        .enumerate()
+    {
+        let pivoted = pivot_stable(
+            &df,
+            [on.clone()],
+            Some(pivot_index.iter().cloned()),
+            Some([agg.value.clone()]),
+            false,
+            Some(agg.expr.clone()),
+            None,
+        )?;
+        if i == 0 {
+            columns.extend(pivoted.select_columns(index.iter().cloned())?);
+        }
+        for (key, name) in agg_columns {
+            columns.push(match pivoted.column(key) {
+                Ok(c) => c.cast(&agg.dtype)?.with_name(name.clone()),
+                // values that do not occur in the data produce NULL columns
+                Err(_) => Column::full_null(name.clone(), pivoted.height(), &agg.dtype),
+            });
+        }
+    }
+    DataFrame::new(columns)
+}
+
 fn collect_compound_identifiers(
     left: &[Ident],
     right: &[Ident],
@@ -1593,51 +2636,64 @@ fn is_regex_colname(nm: &str) -> bool {
     nm.starts_with('^') && nm.ends_with('$')
 }
 
-fn process_join_on(
-    expression: &sqlparser::ast::Expr,
+fn process_join_on<'a>(
+    expression: &'a sqlparser::ast::Expr,
     tbl_left: &TableInfo,
     tbl_right: &TableInfo,
+    predicates: &mut Vec<&'a SQLExpr>,
 ) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
     match expression {
-        SQLExpr::BinaryOp { left, op, right } => match op {
-            BinaryOperator::And => {
-                let (mut left_i, mut right_i) = process_join_on(left, tbl_left, tbl_right)?;
-                let (mut left_j, mut right_j) = process_join_on(right, tbl_left, tbl_right)?;
-                left_i.append(&mut left_j);
-                right_i.append(&mut right_j);
-                Ok((left_i, right_i))
-            },
-            BinaryOperator::Eq => match (left.as_ref(), right.as_ref()) {
-                (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right)) => {
-                    collect_compound_identifiers(left, right, &tbl_left.name, &tbl_right.name)
-                },
-                _ => {
-                    polars_bail!(SQLInterface: "only equi-join constraints (on identifiers) are currently supported; found lhs={:?}, rhs={:?}", left, right)
-                },
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::And,
+            right,
+        } => {
+            let (mut left_i, mut right_i) = process_join_on(left, tbl_left, tbl_right, predicates)?;
+            let (mut left_j, mut right_j) =
+                process_join_on(right, tbl_left, tbl_right, predicates)?;
+            left_i.append(&mut left_j);
+            right_i.append(&mut right_j);
+            Ok((left_i, right_i))
+        },
+        SQLExpr::BinaryOp {
+            left,
+            op: BinaryOperator::Eq,
+            right,
+        } => match (left.as_ref(), right.as_ref()) {
+            (SQLExpr::CompoundIdentifier(left), SQLExpr::CompoundIdentifier(right)) => {
+                collect_compound_identifiers(left, right, &tbl_left.name, &tbl_right.name)
             },
             _ => {
-                polars_bail!(SQLInterface: "only equi-join constraints (combined with 'AND') are currently supported; found op = '{:?}'", op)
+                predicates.push(expression);
+                Ok((vec![], vec![]))
             },
         },
-        SQLExpr::Nested(expr) => process_join_on(expr, tbl_left, tbl_right),
+        SQLExpr::Nested(expr) => process_join_on(expr, tbl_left, tbl_right, predicates),
+        // constraints that are not an equality between the two tables are
+        // returned as predicates, to be evaluated against the joined columns
         _ => {
-            polars_bail!(SQLInterface: "only equi-join constraints are currently supported; found expression = {:?}", expression)
+            predicates.push(expression);
+            Ok((vec![], vec![]))
         },
     }
 }
 
-fn process_join_constraint(
-    constraint: &JoinConstraint,
+/// Resolve a join constraint into equi-join keys (for the left and right table) and
+/// any additional (non-equi) predicates.
+fn process_join_constraint<'a>(
+    constraint: &'a JoinConstraint,
     tbl_left: &TableInfo,
     tbl_right: &TableInfo,
-) -> PolarsResult<(Vec<Expr>, Vec<Expr>)> {
+) -> PolarsResult<(Vec<Expr>, Vec<Expr>, Vec<&'a SQLExpr>)> {
     match constraint {
-        JoinConstraint::On(expr @ SQLExpr::BinaryOp { .. }) => {
-            process_join_on(expr, tbl_left, tbl_right)
+        JoinConstraint::On(expr) => {
+            let mut predicates = vec![];
+            let (left_on, right_on) = process_join_on(expr, tbl_left, tbl_right, &mut predicates)?;
+            Ok((left_on, right_on, predicates))
         },
         JoinConstraint::Using(idents) if !idents.is_empty() => {
             let using: Vec<Expr> = idents.iter().map(|id| col(id.value.as_str())).collect();
-            Ok((using.clone(), using))
+            Ok((using.clone(), using, vec![]))
         },
         JoinConstraint::Natural => {
             let left_names = tbl_left.schema.iter_names().collect::<PlHashSet<_>>();
@@ -1649,7 +2705,7 @@ fn process_join_constraint(
             if on.is_empty() {
                 polars_bail!(SQLInterface: "no common columns found for NATURAL JOIN")
             }
-            Ok((on.clone(), on))
+            Ok((on.clone(), on, vec![]))
         },
         _ => polars_bail!(SQLInterface: "unsupported SQL join constraint:\n{:?}", constraint),
     }
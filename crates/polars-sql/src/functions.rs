@@ -2,27 +2,35 @@ use std::ops::Sub;
 
 use polars_core::chunked_array::ops::{SortMultipleOptions, SortOptions};
 use polars_core::prelude::{
-    DataType, PolarsResult, QuantileMethod, Schema, TimeUnit, polars_bail, polars_err,
+    DataType, FillNullStrategy, IDX_DTYPE, PolarsResult, QuantileMethod, RollingOptionsFixedWindow,
+    Schema, TimeUnit, polars_bail, polars_ensure, polars_err,
 };
 use polars_lazy::dsl::Expr;
 #[cfg(feature = "list_eval")]
 use polars_lazy::dsl::ListNameSpaceExtension;
 use polars_ops::chunked_array::UnicodeForm;
 use polars_ops::series::RoundMode;
-use polars_plan::dsl::{coalesce, concat_str, len, max_horizontal, min_horizontal, when};
+use polars_plan::dsl::{
+    coalesce, concat_str, int_range, len, max_horizontal, min_horizontal, when,
+};
 use polars_plan::plans::{DynLiteralValue, LiteralValue, typed_lit};
 use polars_plan::prelude::{StrptimeOptions, col, cols, lit};
+use polars_time::prelude::RollingOptionsDynamicWindow;
+use polars_time::{ClosedWindow, Duration};
 use polars_utils::pl_str::PlSmallStr;
 use sqlparser::ast::helpers::attached_token::AttachedToken;
 use sqlparser::ast::{
     DateTimeField, DuplicateTreatment, Expr as SQLExpr, Function as SQLFunction, FunctionArg,
     FunctionArgExpr, FunctionArgumentClause, FunctionArgumentList, FunctionArguments, Ident,
-    OrderByExpr, Value as SQLValue, WindowSpec, WindowType,
+    OrderByExpr, Value as SQLValue, WindowFrame, WindowFrameBound, WindowFrameUnits, WindowSpec,
+    WindowType,
 };
 use sqlparser::tokenizer::Span;
 
 use crate::SQLContext;
-use crate::sql_expr::{adjust_one_indexed_param, parse_extract_date_part, parse_sql_expr};
+use crate::sql_expr::{
+    adjust_one_indexed_param, interval_to_duration, parse_extract_date_part, parse_sql_expr,
+};
 
 pub(crate) struct SQLFunctionVisitor<'a> {
     pub(crate) func: &'a SQLFunction,
@@ -539,6 +547,13 @@ pub(crate) enum PolarsSQLFunctions {
     /// SELECT FIRST(column_1) FROM df;
     /// ```
     First,
+    /// SQL 'grouping' function.
+    /// Returns a bitmask indicating which of the given GROUP BY expressions are
+    /// aggregated over (rolled up) in the current grouping set.
+    /// ```sql
+    /// SELECT a, b, GROUPING(a, b), SUM(c) FROM df GROUP BY ROLLUP (a, b);
+    /// ```
+    Grouping,
     /// SQL 'last' function.
     /// Returns the last element of the grouping.
     /// ```sql
@@ -596,6 +611,65 @@ pub(crate) enum PolarsSQLFunctions {
     /// ```
     Variance,
 
+    // ----
+    // Window functions
+    // ----
+    /// SQL 'dense_rank' function.
+    /// Returns the rank of the current row within its partition, without gaps.
+    /// ```sql
+    /// SELECT DENSE_RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    DenseRank,
+    /// SQL 'first_value' function.
+    /// Returns the value of the first row in the window frame.
+    /// ```sql
+    /// SELECT FIRST_VALUE(column_1) OVER (ORDER BY column_2) FROM df;
+    /// ```
+    FirstValue,
+    /// SQL 'lag' function.
+    /// Returns the value `offset` rows before the current row within its partition
+    /// (defaulting to one row), or `default` (or NULL) if there is no such row.
+    /// ```sql
+    /// SELECT LAG(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LAG(column_1, 2, 0) OVER (PARTITION BY column_3 ORDER BY column_2) FROM df;
+    /// ```
+    Lag,
+    /// SQL 'last_value' function.
+    /// Returns the value of the last row in the window frame.
+    /// ```sql
+    /// SELECT LAST_VALUE(column_1) OVER (
+    ///   ORDER BY column_2 ROWS BETWEEN UNBOUNDED PRECEDING AND UNBOUNDED FOLLOWING
+    /// ) FROM df;
+    /// ```
+    LastValue,
+    /// SQL 'lead' function.
+    /// Returns the value `offset` rows after the current row within its partition
+    /// (defaulting to one row), or `default` (or NULL) if there is no such row.
+    /// ```sql
+    /// SELECT LEAD(column_1) OVER (ORDER BY column_2) FROM df;
+    /// SELECT LEAD(column_1, 2, 0) OVER (PARTITION BY column_3 ORDER BY column_2) FROM df;
+    /// ```
+    Lead,
+    /// SQL 'ntile' function.
+    /// Divides the rows of each partition into `n` buckets of (as far as possible)
+    /// equal size, and returns the bucket number of the current row.
+    /// ```sql
+    /// SELECT NTILE(4) OVER (ORDER BY column_1) FROM df;
+    /// ```
+    Ntile,
+    /// SQL 'rank' function.
+    /// Returns the rank of the current row within its partition, with gaps.
+    /// ```sql
+    /// SELECT RANK() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    Rank,
+    /// SQL 'row_number' function.
+    /// Returns the (one-indexed) number of the current row within its partition.
+    /// ```sql
+    /// SELECT ROW_NUMBER() OVER (PARTITION BY column_1 ORDER BY column_2) FROM df;
+    /// ```
+    RowNumber,
+
     // ----
     // Array functions
     // ----
@@ -729,15 +803,21 @@ impl PolarsSQLFunctions {
             "date",
             "date_part",
             "degrees",
+            "dense_rank",
             "ends_with",
             "exp",
             "first",
+            "first_value",
             "floor",
             "greatest",
+            "grouping",
             "if",
             "ifnull",
             "initcap",
+            "lag",
             "last",
+            "last_value",
+            "lead",
             "least",
             "left",
             "length",
@@ -753,6 +833,7 @@ impl PolarsSQLFunctions {
             "quantile_disc",
             "min",
             "mod",
+            "ntile",
             "nullif",
             "octet_length",
             "pi",
@@ -761,11 +842,13 @@ impl PolarsSQLFunctions {
             "quantile_cont",
             "quantile_disc",
             "radians",
+            "rank",
             "regexp_like",
             "replace",
             "reverse",
             "right",
             "round",
+            "row_number",
             "rtrim",
             "sign",
             "sin",
@@ -901,6 +984,7 @@ impl PolarsSQLFunctions {
             "avg" => Self::Avg,
             "count" => Self::Count,
             "first" => Self::First,
+            "grouping" => Self::Grouping,
             "last" => Self::Last,
             "max" => Self::Max,
             "median" => Self::Median,
@@ -911,6 +995,18 @@ impl PolarsSQLFunctions {
             "sum" => Self::Sum,
             "var" | "variance" | "var_samp" => Self::Variance,
 
+            // ----
+            // Window functions
+            // ----
+            "dense_rank" => Self::DenseRank,
+            "first_value" => Self::FirstValue,
+            "lag" => Self::Lag,
+            "last_value" => Self::LastValue,
+            "lead" => Self::Lead,
+            "ntile" => Self::Ntile,
+            "rank" => Self::Rank,
+            "row_number" => Self::RowNumber,
+
             // ----
             // Array functions
             // ----
@@ -1406,10 +1502,16 @@ impl SQLFunctionVisitor<'_> {
             // ----
             // Aggregate functions
             // ----
+            Avg if self.has_window_frame() => self.visit_framed_aggregate(FrameAggregation::Mean),
             Avg => self.visit_unary(Expr::mean),
+            Count if self.has_window_frame() => {
+                self.visit_framed_aggregate(FrameAggregation::Count)
+            },
             Count => self.visit_count(),
             First => self.visit_unary(Expr::first),
+            Grouping => self.visit_grouping(),
             Last => self.visit_unary(Expr::last),
+            Max if self.has_window_frame() => self.visit_framed_aggregate(FrameAggregation::Max),
             Max => self.visit_unary_with_opt_cumulative(Expr::max, Expr::cum_max),
             Median => self.visit_unary(Expr::median),
             QuantileCont => {
@@ -1464,11 +1566,36 @@ impl SQLFunctionVisitor<'_> {
                     _ => polars_bail!(SQLSyntax: "QUANTILE_DISC expects 2 arguments (found {})", args.len()),
                 }
             },
+            Min if self.has_window_frame() => self.visit_framed_aggregate(FrameAggregation::Min),
             Min => self.visit_unary_with_opt_cumulative(Expr::min, Expr::cum_min),
             StdDev => self.visit_unary(|e| e.std(1)),
+            Sum if self.has_window_frame() => self.visit_framed_aggregate(FrameAggregation::Sum),
             Sum => self.visit_unary_with_opt_cumulative(Expr::sum, Expr::cum_sum),
             Variance => self.visit_unary(|e| e.var(1)),
 
+            // ----
+            // Window functions
+            // ----
+            DenseRank => self.visit_ranking(|window| {
+                window.unsorted(window.peer_group_starts().cast(IDX_DTYPE).cum_sum(false))
+            }),
+            FirstValue => self.visit_framed_aggregate(FrameAggregation::FirstValue),
+            Lag => self.visit_lag_lead(false),
+            LastValue => self.visit_framed_aggregate(FrameAggregation::LastValue),
+            Lead => self.visit_lag_lead(true),
+            Ntile => self.visit_ntile(),
+            Rank => self.visit_ranking(|window| {
+                window.unsorted(
+                    when(window.peer_group_starts())
+                        .then(WindowContext::row_index() + lit(1))
+                        .otherwise(lit(LiteralValue::untyped_null()))
+                        .fill_null_with_strategy(FillNullStrategy::Forward(None)),
+                )
+            }),
+            RowNumber => {
+                self.visit_ranking(|window| window.unsorted(WindowContext::row_index() + lit(1)))
+            },
+
             // ----
             // Array functions
             // ----
@@ -1678,6 +1805,26 @@ impl SQLFunctionVisitor<'_> {
         }
     }
 
+    /// GROUPING() is resolved against the grouping sets of the enclosing GROUP BY; here we
+    /// register its arguments and return a reference to the column that will hold the result.
+    fn visit_grouping(&mut self) -> PolarsResult<Expr> {
+        polars_ensure!(
+            self.func.over.is_none(),
+            SQLSyntax: "GROUPING does not support an OVER clause"
+        );
+        let args = extract_args(self.func)?;
+        polars_ensure!(!args.is_empty(), SQLSyntax: "GROUPING expects at least one argument");
+        let mut exprs = Vec::with_capacity(args.len());
+        for arg in args {
+            let FunctionArgExpr::Expr(sql_expr) = arg else {
+                return self.not_supported_error();
+            };
+            exprs.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?);
+        }
+        let name = PlSmallStr::from_string(self.func.to_string());
+        Ok(self.ctx.register_grouping_function(name, exprs))
+    }
+
     fn visit_variadic(&mut self, f: impl Fn(&[Expr]) -> Expr) -> PolarsResult<Expr> {
         self.try_visit_variadic(|e| Ok(f(e)))
     }
@@ -1845,6 +1992,12 @@ impl SQLFunctionVisitor<'_> {
     ) -> PolarsResult<Expr> {
         Ok(match &window_type {
             Some(WindowType::WindowSpec(window_spec)) => {
+                if window_spec.window_frame.is_some() {
+                    polars_bail!(
+                        SQLInterface: "window frames are not supported for {}",
+                        self.func.name.to_string().to_uppercase()
+                    )
+                }
                 if window_spec.partition_by.is_empty() {
                     let exprs = window_spec
                         .order_by
@@ -1875,6 +2028,255 @@ impl SQLFunctionVisitor<'_> {
         })
     }
 
+    fn has_window_frame(&self) -> bool {
+        matches!(
+            &self.func.over,
+            Some(WindowType::WindowSpec(spec)) if spec.window_frame.is_some()
+        )
+    }
+
+    fn parse_window_context(&mut self) -> PolarsResult<WindowContext> {
+        let function = self.func;
+        let spec = match &function.over {
+            Some(WindowType::WindowSpec(spec)) => spec,
+            Some(WindowType::NamedWindow(named_window)) => polars_bail!(
+                SQLInterface: "Named windows are not currently supported; found {:?}",
+                named_window
+            ),
+            None => polars_bail!(
+                SQLSyntax: "{} requires an OVER clause",
+                function.name.to_string().to_uppercase()
+            ),
+        };
+        let partition_by = spec
+            .partition_by
+            .iter()
+            .map(|p| parse_sql_expr(p, self.ctx, self.active_schema))
+            .collect::<PolarsResult<Vec<_>>>()?;
+
+        let mut order_by = Vec::with_capacity(spec.order_by.len());
+        let mut descending = Vec::with_capacity(spec.order_by.len());
+        let mut nulls_last = Vec::with_capacity(spec.order_by.len());
+        for ob in &spec.order_by {
+            // note: if not specified 'NULLS FIRST' is default for DESC, 'NULLS LAST' otherwise
+            let desc_order = !ob.asc.unwrap_or(true);
+            order_by.push(parse_sql_expr(&ob.expr, self.ctx, self.active_schema)?);
+            nulls_last.push(!ob.nulls_first.unwrap_or(desc_order));
+            descending.push(desc_order);
+        }
+        Ok(WindowContext {
+            partition_by,
+            order_by,
+            sort_options: SortMultipleOptions::default()
+                .with_order_descending_multi(descending)
+                .with_nulls_last_multi(nulls_last)
+                .with_maintain_order(true),
+            frame: spec.window_frame.clone(),
+        })
+    }
+
+    /// Determine which rows of the partition make up the window frame of each row;
+    /// without an explicit frame this is 'RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW'
+    fn parse_window_frame(&mut self, window: &WindowContext) -> PolarsResult<WindowFrameKind> {
+        let frame = window.frame.clone().unwrap_or_default();
+        let end_bound = frame.end_bound.unwrap_or(WindowFrameBound::CurrentRow);
+        match frame.units {
+            WindowFrameUnits::Rows => {
+                let (start, end) = match (&frame.start_bound, &end_bound) {
+                    (WindowFrameBound::Following(None), _)
+                    | (_, WindowFrameBound::Preceding(None)) => polars_bail!(
+                        SQLSyntax: "invalid window frame (ROWS BETWEEN {} AND {})",
+                        frame.start_bound, end_bound
+                    ),
+                    (start, end) => (self.parse_rows_offset(start)?, self.parse_rows_offset(end)?),
+                };
+                if let (Some(start), Some(end)) = (start, end) {
+                    polars_ensure!(
+                        start <= end,
+                        SQLSyntax: "window frame cannot start after it ends (ROWS BETWEEN {} AND {})",
+                        frame.start_bound, end_bound
+                    );
+                }
+                Ok(match (start, end) {
+                    (None, None) => WindowFrameKind::Partition,
+                    (start, end) => WindowFrameKind::Rows(start, end),
+                })
+            },
+            // without ORDER BY all rows of the partition are peers of the current row
+            WindowFrameUnits::Range if window.order_by.is_empty() => Ok(WindowFrameKind::Partition),
+            WindowFrameUnits::Range => match (&frame.start_bound, &end_bound) {
+                (WindowFrameBound::Preceding(None), WindowFrameBound::CurrentRow) => {
+                    Ok(WindowFrameKind::RangeToCurrentRow)
+                },
+                (WindowFrameBound::CurrentRow, WindowFrameBound::Following(None)) => {
+                    Ok(WindowFrameKind::RangeFromCurrentRow)
+                },
+                (WindowFrameBound::Preceding(None), WindowFrameBound::Following(None)) => {
+                    Ok(WindowFrameKind::Partition)
+                },
+                (WindowFrameBound::Preceding(Some(offset)), WindowFrameBound::CurrentRow) => {
+                    polars_ensure!(
+                        window.order_by.len() == 1 && !window.sort_options.descending[0],
+                        SQLInterface: "RANGE window frames with an offset require a single ascending ORDER BY expression"
+                    );
+                    let offset = match offset.as_ref() {
+                        SQLExpr::Interval(interval) => interval_to_duration(interval, false)?,
+                        _ => match self.parse_frame_offset(offset)? {
+                            0 => polars_bail!(
+                                SQLSyntax: "RANGE window frame offset must be positive; found {}",
+                                offset
+                            ),
+                            n => Duration::new(n),
+                        },
+                    };
+                    Ok(WindowFrameKind::RangeOffsetToCurrentRow(offset))
+                },
+                (start, end) => polars_bail!(
+                    SQLInterface: "window frame (RANGE BETWEEN {} AND {}) is not supported",
+                    start, end
+                ),
+            },
+            WindowFrameUnits::Groups => {
+                polars_bail!(SQLInterface: "GROUPS window frames are not supported")
+            },
+        }
+    }
+
+    /// Offset of a ROWS frame bound relative to the current row (`None` if unbounded)
+    fn parse_rows_offset(&mut self, bound: &WindowFrameBound) -> PolarsResult<Option<i64>> {
+        Ok(match bound {
+            WindowFrameBound::CurrentRow => Some(0),
+            WindowFrameBound::Preceding(None) | WindowFrameBound::Following(None) => None,
+            WindowFrameBound::Preceding(Some(offset)) => Some(-self.parse_frame_offset(offset)?),
+            WindowFrameBound::Following(Some(offset)) => Some(self.parse_frame_offset(offset)?),
+        })
+    }
+
+    fn parse_frame_offset(&mut self, offset: &SQLExpr) -> PolarsResult<i64> {
+        match parse_sql_expr(offset, self.ctx, self.active_schema)? {
+            Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n >= 0 => Ok(n as i64),
+            _ => polars_bail!(
+                SQLSyntax: "window frame offset must be a non-negative integer; found {}",
+                offset
+            ),
+        }
+    }
+
+    /// Aggregate over the window frame of each row, e.g.
+    /// SUM(a) OVER (ORDER BY b ROWS BETWEEN 2 PRECEDING AND CURRENT ROW)
+    fn visit_framed_aggregate(&mut self, agg: FrameAggregation) -> PolarsResult<Expr> {
+        let (args, is_distinct) = extract_args_distinct(self.func)?;
+        let expr = match (is_distinct, args.as_slice()) {
+            (false, [FunctionArgExpr::Expr(sql_expr)]) => {
+                parse_sql_expr(sql_expr, self.ctx, self.active_schema)?
+            },
+            // count(*), count()
+            (false, [FunctionArgExpr::Wildcard] | []) if agg == FrameAggregation::Count => {
+                WindowContext::row_index()
+            },
+            _ => self.not_supported_error()?,
+        };
+        let window = self.parse_window_context()?;
+        let out = match self.parse_window_frame(&window)? {
+            WindowFrameKind::Partition => agg.partition(&window, expr),
+            WindowFrameKind::Rows(start, end) => agg.rows(&window, expr, start, end),
+            WindowFrameKind::RangeToCurrentRow => agg.range_to_current_row(&window, expr),
+            WindowFrameKind::RangeFromCurrentRow => agg.range_from_current_row(&window, expr),
+            WindowFrameKind::RangeOffsetToCurrentRow(offset) => {
+                agg.range_offset_to_current_row(&window, expr, offset)?
+            },
+        };
+        Ok(window.over(out))
+    }
+
+    /// Ranking functions take no arguments, and are computed from the window ordering
+    fn visit_ranking(&mut self, f: impl Fn(&WindowContext) -> Expr) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        if !args.is_empty() {
+            return self.not_supported_error();
+        }
+        let window = self.parse_window_context()?;
+        self.ensure_no_window_frame(&window)?;
+        Ok(window.over(f(&window)))
+    }
+
+    fn visit_ntile(&mut self) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let n = match args.as_slice() {
+            [FunctionArgExpr::Expr(sql_expr)] => {
+                match parse_sql_expr(sql_expr, self.ctx, self.active_schema)? {
+                    Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n))) if n > 0 => n as i64,
+                    _ => {
+                        polars_bail!(SQLSyntax: "NTILE expects a positive integer; found {}", sql_expr)
+                    },
+                }
+            },
+            _ => polars_bail!(SQLSyntax: "NTILE expects 1 argument (found {})", args.len()),
+        };
+        let window = self.parse_window_context()?;
+        self.ensure_no_window_frame(&window)?;
+
+        // the first `n_rows % n` buckets hold one row more than the others
+        let row = window
+            .unsorted(WindowContext::row_index())
+            .cast(DataType::Int64);
+        let n_rows = len().cast(DataType::Int64);
+        let size = n_rows.clone().floor_div(lit(n));
+        let n_large = n_rows % lit(n);
+        let large_rows = n_large.clone() * (size.clone() + lit(1));
+        let bucket = when(row.clone().lt(large_rows.clone()))
+            .then(row.clone().floor_div(size.clone() + lit(1)))
+            // (guard against dividing by zero; with fewer rows than buckets all are "large")
+            .otherwise(n_large + (row - large_rows).floor_div(size.clip_min(lit(1))));
+        Ok(window.over((bucket + lit(1)).cast(IDX_DTYPE)))
+    }
+
+    fn visit_lag_lead(&mut self, lead: bool) -> PolarsResult<Expr> {
+        let args = extract_args(self.func)?;
+        let mut exprs = Vec::with_capacity(args.len());
+        for arg in &args {
+            match arg {
+                FunctionArgExpr::Expr(sql_expr) => {
+                    exprs.push(parse_sql_expr(sql_expr, self.ctx, self.active_schema)?)
+                },
+                _ => return self.not_supported_error(),
+            }
+        }
+        let name = if lead { "LEAD" } else { "LAG" };
+        let mut exprs = exprs.into_iter();
+        let (expr, offset, default) = match (exprs.next(), exprs.next(), exprs.next(), exprs.next())
+        {
+            (Some(expr), offset, default, None) => (expr, offset, default),
+            _ => polars_bail!(SQLSyntax: "{} expects 1-3 arguments (found {})", name, args.len()),
+        };
+        let offset = match offset {
+            None => 1,
+            Some(Expr::Literal(LiteralValue::Dyn(DynLiteralValue::Int(n)))) => n as i64,
+            Some(_) => {
+                polars_bail!(SQLSyntax: "{} offset must be an integer; found {}", name, args[1])
+            },
+        };
+        let window = self.parse_window_context()?;
+        self.ensure_no_window_frame(&window)?;
+
+        let n = lit(if lead { -offset } else { offset });
+        let sorted = window.sorted(expr);
+        let shifted = match default {
+            Some(default) => sorted.shift_and_fill(n, default),
+            None => sorted.shift(n),
+        };
+        Ok(window.over(window.unsorted(shifted)))
+    }
+
+    fn ensure_no_window_frame(&self, window: &WindowContext) -> PolarsResult<()> {
+        polars_ensure!(
+            window.frame.is_none(),
+            SQLSyntax: "{} does not accept a window frame",
+            self.func.name.to_string().to_uppercase()
+        );
+        Ok(())
+    }
+
     fn not_supported_error(&self) -> PolarsResult<Expr> {
         polars_bail!(
             SQLInterface:
@@ -1884,6 +2286,302 @@ impl SQLFunctionVisitor<'_> {
     }
 }
 
+/// The partitioning and ordering of a window function's OVER clause.
+struct WindowContext {
+    partition_by: Vec<Expr>,
+    order_by: Vec<Expr>,
+    sort_options: SortMultipleOptions,
+    frame: Option<WindowFrame>,
+}
+
+impl WindowContext {
+    /// Zero-based position of each row (within its partition).
+    fn row_index() -> Expr {
+        int_range(lit(0), len(), 1, IDX_DTYPE)
+    }
+
+    /// Put `expr` in the window ORDER BY order.
+    fn sorted(&self, expr: Expr) -> Expr {
+        if self.order_by.is_empty() {
+            expr
+        } else {
+            expr.sort_by(&self.order_by, self.sort_options.clone())
+        }
+    }
+
+    /// Put `expr`, evaluated in the window ORDER BY order, back in the original row order.
+    fn unsorted(&self, expr: Expr) -> Expr {
+        if self.order_by.is_empty() {
+            expr
+        } else {
+            expr.gather(
+                self.sorted(Self::row_index())
+                    .arg_sort(SortOptions::default()),
+            )
+        }
+    }
+
+    /// Whether each row (in ORDER BY order) is the first of its group of peers,
+    /// i.e. the rows with equal ORDER BY values.
+    fn peer_group_starts(&self) -> Expr {
+        self.order_by
+            .iter()
+            .fold(Self::row_index().eq(lit(0)), |starts, e| {
+                let e = self.sorted(e.clone());
+                starts.or(e.clone().neq_missing(e.shift(lit(1))))
+            })
+    }
+
+    /// Index of the first peer of each row (in ORDER BY order).
+    fn first_peer_index(&self) -> Expr {
+        when(self.peer_group_starts())
+            .then(Self::row_index())
+            .otherwise(lit(LiteralValue::untyped_null()))
+            .fill_null_with_strategy(FillNullStrategy::Forward(None))
+    }
+
+    /// Index of the last peer of each row (in ORDER BY order).
+    fn last_peer_index(&self) -> Expr {
+        let ends = self.peer_group_starts().shift(lit(-1)).fill_null(lit(true));
+        when(ends)
+            .then(Self::row_index())
+            .otherwise(lit(LiteralValue::untyped_null()))
+            .fill_null_with_strategy(FillNullStrategy::Backward(None))
+    }
+
+    fn over(&self, expr: Expr) -> Expr {
+        if self.partition_by.is_empty() {
+            expr
+        } else {
+            expr.over(&self.partition_by)
+        }
+    }
+}
+
+/// The rows of the partition that make up the window frame of each row.
+enum WindowFrameKind {
+    /// All rows of the partition.
+    Partition,
+    /// Rows at the given offsets from the current row (`None` if unbounded).
+    Rows(Option<i64>, Option<i64>),
+    /// Rows up to (and including the peers of) the current row.
+    RangeToCurrentRow,
+    /// Rows from (and including the peers of) the current row.
+    RangeFromCurrentRow,
+    /// Rows whose ORDER BY value is within the given offset before that of the current row.
+    RangeOffsetToCurrentRow(Duration),
+}
+
+/// Aggregations that can be evaluated over a window frame.
+#[derive(Clone, Copy, PartialEq)]
+enum FrameAggregation {
+    Count,
+    FirstValue,
+    LastValue,
+    Max,
+    Mean,
+    Min,
+    Sum,
+}
+
+impl FrameAggregation {
+    fn partition(self, window: &WindowContext, expr: Expr) -> Expr {
+        match self {
+            Self::Count => expr.count(),
+            Self::FirstValue => window.sorted(expr).first(),
+            Self::LastValue => window.sorted(expr).last(),
+            Self::Max => expr.max(),
+            Self::Mean => expr.mean(),
+            Self::Min => expr.min(),
+            Self::Sum => expr.sum(),
+        }
+    }
+
+    /// Aggregate `expr` (in ORDER BY order) from the first row (or from the last
+    /// row if `reverse`) up to each row.
+    fn cumulative(self, expr: Expr, reverse: bool) -> Expr {
+        let fill = if reverse {
+            FillNullStrategy::Backward(None)
+        } else {
+            FillNullStrategy::Forward(None)
+        };
+        match self {
+            Self::Count => expr.is_not_null().cast(IDX_DTYPE).cum_sum(reverse),
+            Self::Max => expr.cum_max(reverse).fill_null_with_strategy(fill),
+            Self::Min => expr.cum_min(reverse).fill_null_with_strategy(fill),
+            Self::Sum => expr.cum_sum(reverse).fill_null_with_strategy(fill),
+            Self::Mean => {
+                Self::Sum
+                    .cumulative(expr.clone(), reverse)
+                    .cast(DataType::Float64)
+                    / Self::Count
+                        .cumulative(expr, reverse)
+                        .cast(DataType::Float64)
+            },
+            Self::FirstValue | Self::LastValue => unreachable!(),
+        }
+    }
+
+    /// As `cumulative`, but up to the row `offset` rows after each row.
+    fn cumulative_to(self, expr: Expr, reverse: bool, offset: i64) -> Expr {
+        if self == Self::Mean {
+            return Self::Sum
+                .cumulative_to(expr.clone(), reverse, offset)
+                .cast(DataType::Float64)
+                / Self::Count
+                    .cumulative_to(expr, reverse, offset)
+                    .cast(DataType::Float64);
+        }
+        let out = self.cumulative(expr, reverse);
+        if offset == 0 {
+            return out;
+        }
+        // frames reaching past the end of the partition are clamped to it, while
+        // frames lying entirely outside of it are empty
+        let fill = if reverse {
+            FillNullStrategy::Backward(None)
+        } else {
+            FillNullStrategy::Forward(None)
+        };
+        let out = out.shift(lit(-offset)).fill_null_with_strategy(fill);
+        if self == Self::Count {
+            out.fill_null(lit(0))
+        } else {
+            out
+        }
+    }
+
+    fn rows(
+        self,
+        window: &WindowContext,
+        expr: Expr,
+        start: Option<i64>,
+        end: Option<i64>,
+    ) -> Expr {
+        let sorted = window.sorted(expr);
+        let out = match (self, start, end) {
+            (Self::FirstValue | Self::LastValue, start, end) => {
+                let n_rows = len().cast(DataType::Int64);
+                let row = WindowContext::row_index().cast(DataType::Int64);
+                let first = start.map_or(lit(0i64), |s| (row.clone() + lit(s)).clip_min(lit(0)));
+                let last = end.map_or(n_rows.clone() - lit(1), |e| {
+                    (row + lit(e)).clip_max(n_rows.clone() - lit(1))
+                });
+                let idx = if self == Self::FirstValue {
+                    first.clone()
+                } else {
+                    last.clone()
+                };
+                when(first.lt_eq(last))
+                    .then(sorted.gather(idx.clip(lit(0), n_rows - lit(1)).cast(IDX_DTYPE)))
+                    .otherwise(lit(LiteralValue::untyped_null()))
+            },
+            (_, None, None) => return self.partition(window, sorted),
+            (_, None, Some(end)) => self.cumulative_to(sorted, false, end),
+            (_, Some(start), None) => self.cumulative_to(sorted, true, start),
+            (_, Some(start), Some(end)) => {
+                let options = RollingOptionsFixedWindow {
+                    window_size: (end - start + 1) as usize,
+                    min_periods: 1,
+                    ..Default::default()
+                };
+                // the rolling window ends at the current row, so line it up with the
+                // frame end; frames ending after the current row need trailing nulls
+                let shifted = match end {
+                    0 => sorted,
+                    ..0 => sorted.shift(lit(-end)),
+                    _ => sorted.extend_constant(lit(LiteralValue::untyped_null()), lit(end)),
+                };
+                let out = match self {
+                    Self::Count => shifted.is_not_null().cast(IDX_DTYPE).rolling_sum(options),
+                    Self::Max => shifted.rolling_max(options),
+                    Self::Mean => shifted.rolling_mean(options),
+                    Self::Min => shifted.rolling_min(options),
+                    Self::Sum => shifted.rolling_sum(options),
+                    Self::FirstValue | Self::LastValue => unreachable!(),
+                };
+                if end > 0 {
+                    out.slice(lit(end), len())
+                } else {
+                    out
+                }
+            },
+        };
+        window.unsorted(out)
+    }
+
+    fn range_to_current_row(self, window: &WindowContext, expr: Expr) -> Expr {
+        let sorted = window.sorted(expr);
+        match self {
+            Self::FirstValue => sorted.first(),
+            Self::LastValue => window.unsorted(sorted.gather(window.last_peer_index())),
+            _ => window.unsorted(
+                self.cumulative(sorted, false)
+                    .gather(window.last_peer_index()),
+            ),
+        }
+    }
+
+    fn range_from_current_row(self, window: &WindowContext, expr: Expr) -> Expr {
+        let sorted = window.sorted(expr);
+        match self {
+            Self::FirstValue => window.unsorted(sorted.gather(window.first_peer_index())),
+            Self::LastValue => sorted.last(),
+            _ => window.unsorted(
+                self.cumulative(sorted, true)
+                    .gather(window.first_peer_index()),
+            ),
+        }
+    }
+
+    fn range_offset_to_current_row(
+        self,
+        window: &WindowContext,
+        expr: Expr,
+        offset: Duration,
+    ) -> PolarsResult<Expr> {
+        let by = window.order_by[0].clone();
+        let options = RollingOptionsDynamicWindow {
+            window_size: offset,
+            min_periods: 1,
+            closed_window: ClosedWindow::Both,
+            fn_params: None,
+        };
+        let count = expr
+            .clone()
+            .is_not_null()
+            .cast(IDX_DTYPE)
+            .rolling_sum_by(by.clone(), options.clone());
+
+        // the rolling kernels do not accept nulls, so replace them with values
+        // that do not change the result, and mask out windows of only nulls
+        let out = match self {
+            Self::Count => return Ok(count),
+            Self::Max => expr
+                .clone()
+                .fill_null(expr.min())
+                .rolling_max_by(by, options),
+            Self::Min => expr
+                .clone()
+                .fill_null(expr.max())
+                .rolling_min_by(by, options),
+            Self::Sum => expr.fill_null(lit(0)).rolling_sum_by(by, options),
+            Self::Mean => {
+                return Ok(Self::Sum
+                    .range_offset_to_current_row(window, expr, offset)?
+                    .cast(DataType::Float64)
+                    / count.cast(DataType::Float64));
+            },
+            Self::FirstValue | Self::LastValue => polars_bail!(
+                SQLInterface: "FIRST_VALUE and LAST_VALUE do not support RANGE window frames with an offset"
+            ),
+        };
+        Ok(when(count.eq(lit(0)))
+            .then(lit(LiteralValue::untyped_null()))
+            .otherwise(out))
+    }
+}
+
 fn extract_args(func: &SQLFunction) -> PolarsResult<Vec<&FunctionArgExpr>> {
     let (args, _, _) = _extract_func_args(func, false, false)?;
     Ok(args)
@@ -0,0 +1,179 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_df() -> LazyFrame {
+    df! {
+      "Category" => ["a", "b", "a", "a", "b", "a", "b"],
+      "Key" => [3, 1, 1, 2, 1, 2, 5],
+      "Value" => [Some(10), Some(20), Some(30), None, Some(50), Some(60), Some(70)],
+    }
+    .unwrap()
+    .lazy()
+}
+
+fn execute(sql: &str) -> DataFrame {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    ctx.execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_ranking_functions() {
+    let actual = execute(
+        r#"
+      SELECT
+        ROW_NUMBER() OVER (PARTITION BY Category ORDER BY Key DESC, Value) AS rn,
+        RANK() OVER (PARTITION BY Category ORDER BY Key) AS rnk,
+        DENSE_RANK() OVER (ORDER BY Key) AS dense_rnk,
+        NTILE(3) OVER (ORDER BY Key, Value) AS tile
+      FROM df
+      "#,
+    );
+    let expected = df! {
+      "rn" => [1 as IdxSize, 2, 4, 3, 3, 2, 1],
+      "rnk" => [4 as IdxSize, 1, 1, 2, 1, 2, 3],
+      "dense_rnk" => [3 as IdxSize, 1, 1, 2, 1, 2, 4],
+      "tile" => [3 as IdxSize, 1, 1, 2, 1, 2, 3],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_lag_lead() {
+    let actual = execute(
+        r#"
+      SELECT
+        LAG(Value) OVER (PARTITION BY Category ORDER BY Key, Value) AS prev,
+        LEAD(Value, 2, -1) OVER (ORDER BY Key, Value) AS next2
+      FROM df
+      "#,
+    );
+    let expected = df! {
+      "prev" => [None, None, None, Some(60), Some(20), Some(30), Some(50)],
+      "next2" => [Some(-1), Some(50), Some(60), Some(70), None, Some(10), Some(-1)],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_rows_frames() {
+    let actual = execute(
+        r#"
+      SELECT
+        SUM(Value) OVER (ORDER BY Key, Value ROWS BETWEEN 1 PRECEDING AND CURRENT ROW) AS s,
+        COUNT(*) OVER (ORDER BY Key, Value ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING) AS c,
+        MIN(Value) OVER (ORDER BY Key, Value ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING) AS mn,
+        MAX(Value) OVER (ORDER BY Key, Value ROWS BETWEEN 1 FOLLOWING AND UNBOUNDED FOLLOWING) AS mx,
+        AVG(Value) OVER (ORDER BY Key, Value ROWS BETWEEN UNBOUNDED PRECEDING AND 1 FOLLOWING) AS a
+      FROM df
+      ORDER BY Key, Value
+      "#,
+    );
+    let expected = df! {
+      "s" => [20, 50, 80, 110, 60, 10, 80],
+      "c" => [2 as IdxSize, 3, 3, 3, 3, 3, 2],
+      "mn" => [None, Some(20), Some(20), Some(30), Some(50), Some(60), Some(10)],
+      "mx" => [Some(70), Some(70), Some(70), Some(70), Some(70), Some(70), None],
+      "a" => [25.0, 100.0 / 3.0, 40.0, 40.0, 34.0, 40.0, 40.0],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_range_frames() {
+    let actual = execute(
+        r#"
+      SELECT
+        SUM(Value) OVER (
+          PARTITION BY Category ORDER BY Key
+          RANGE BETWEEN UNBOUNDED PRECEDING AND CURRENT ROW
+        ) AS s,
+        COUNT(Value) OVER (ORDER BY Key RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING) AS c,
+        SUM(Value) OVER (ORDER BY Key RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS s1,
+        MIN(Value) OVER (ORDER BY Key RANGE 1 PRECEDING) AS mn1
+      FROM df
+      "#,
+    );
+    let expected = df! {
+      "s" => [100, 70, 30, 90, 70, 90, 140],
+      "c" => [2 as IdxSize, 6, 6, 3, 6, 3, 1],
+      "s1" => [70, 100, 100, 160, 100, 160, 70],
+      "mn1" => [10, 20, 20, 20, 20, 20, 70],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_first_last_value() {
+    let actual = execute(
+        r#"
+      SELECT
+        FIRST_VALUE(Value) OVER (PARTITION BY Category ORDER BY Key) AS first,
+        LAST_VALUE(Value) OVER (PARTITION BY Category ORDER BY Key) AS last,
+        LAST_VALUE(Value) OVER (
+          ORDER BY Key, Value ROWS BETWEEN CURRENT ROW AND 1 FOLLOWING
+        ) AS next
+      FROM df
+      "#,
+    );
+    let expected = df! {
+      "first" => [30, 20, 30, 30, 20, 30, 20],
+      "last" => [10, 50, 30, 60, 50, 60, 70],
+      "next" => [Some(70), Some(30), Some(50), Some(10), Some(60), None, Some(70)],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_qualify() {
+    let actual = execute(
+        r#"
+      SELECT Category, Key, Value FROM df
+      QUALIFY ROW_NUMBER() OVER (PARTITION BY Category ORDER BY Key DESC, Value) = 1
+      "#,
+    );
+    let expected = df! {
+      "Category" => ["a", "b"],
+      "Key" => [3, 5],
+      "Value" => [10, 70],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+
+    let actual = execute(
+        r#"
+      SELECT Category, COUNT(*) AS n FROM df
+      GROUP BY Category
+      QUALIFY RANK() OVER (ORDER BY n DESC) = 1
+      "#,
+    );
+    let expected = df! {
+      "Category" => ["a"],
+      "n" => [4 as IdxSize],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_invalid_window_frames() {
+    let mut ctx = SQLContext::new();
+    ctx.register("df", create_df());
+    for sql in [
+        "SELECT ROW_NUMBER() FROM df",
+        "SELECT ROW_NUMBER() OVER (ORDER BY Key ROWS 1 PRECEDING) FROM df",
+        "SELECT MEDIAN(Value) OVER (ORDER BY Key ROWS 1 PRECEDING) FROM df",
+        "SELECT SUM(Value) OVER (ORDER BY Key ROWS BETWEEN 1 FOLLOWING AND 1 PRECEDING) FROM df",
+        "SELECT SUM(Value) OVER (ORDER BY Key GROUPS 1 PRECEDING) FROM df",
+        "SELECT SUM(Value) OVER (ORDER BY Key DESC RANGE 1 PRECEDING) FROM df",
+        "SELECT NTILE(0) OVER (ORDER BY Key) FROM df",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
@@ -0,0 +1,160 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let df = df! {
+      "region" => ["n", "n", "s", "s", "n"],
+      "year" => [2023, 2024, 2023, 2024, 2024],
+      "amount" => [10, 20, 30, 40, 50],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", df.lazy());
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    create_ctx().execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_rollup() {
+    let actual = execute(
+        r#"
+      SELECT region, year, SUM(amount) AS total
+      FROM sales
+      GROUP BY ROLLUP (region, year)
+      ORDER BY region NULLS LAST, year NULLS LAST
+      "#,
+    );
+    let expected = df! {
+      "region" => [Some("n"), Some("n"), Some("n"), Some("s"), Some("s"), Some("s"), None],
+      "year" => [Some(2023), Some(2024), None, Some(2023), Some(2024), None, None],
+      "total" => [10, 70, 80, 30, 40, 70, 150],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_cube_with_grouping() {
+    let actual = execute(
+        r#"
+      SELECT region, year, GROUPING(region, year) AS g, COUNT(*) AS n
+      FROM sales
+      GROUP BY CUBE (region, year)
+      ORDER BY g, region, year
+      "#,
+    );
+    let expected = df! {
+      "region" => [Some("n"), Some("n"), Some("s"), Some("s"), Some("n"), Some("s"), None, None, None],
+      "year" => [Some(2023), Some(2024), Some(2023), Some(2024), None, None, Some(2023), Some(2024), None],
+      "g" => [0i64, 0, 0, 0, 1, 1, 2, 2, 3],
+      "n" => [1 as IdxSize, 2, 1, 1, 3, 2, 2, 3, 5],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_grouping_sets() {
+    let actual = execute(
+        r#"
+      SELECT region, year, MAX(amount) AS mx
+      FROM sales
+      GROUP BY GROUPING SETS ((region), (year), ())
+      ORDER BY region NULLS LAST, year NULLS LAST
+      "#,
+    );
+    let expected = df! {
+      "region" => [Some("n"), Some("s"), None, None, None],
+      "year" => [None, None, Some(2023), Some(2024), None],
+      "mx" => [50, 40, 30, 50, 50],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_grouping_sets_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT region, GROUPING(year) FROM sales GROUP BY ROLLUP (region)",
+        "SELECT region, SUM(amount) FROM sales GROUP BY region WITH TOTALS",
+        "SELECT region, SUM(amount) FROM sales GROUP BY ROLLUP (region) WITH CUBE",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
+
+#[test]
+fn test_grouping_unaliased() {
+    let actual = execute(
+        r#"
+      SELECT region, year, GROUPING(region), GROUPING(region, year), COUNT(*) AS n
+      FROM sales
+      GROUP BY ROLLUP (region, year)
+      ORDER BY region NULLS LAST, year NULLS LAST
+      "#,
+    );
+    let expected = df! {
+      "region" => [Some("n"), Some("n"), Some("n"), Some("s"), Some("s"), Some("s"), None],
+      "year" => [Some(2023), Some(2024), None, Some(2023), Some(2024), None, None],
+      "GROUPING(region)" => [0i64, 0, 0, 0, 0, 0, 1],
+      "GROUPING(region, year)" => [0i64, 0, 1, 0, 0, 1, 3],
+      "n" => [1 as IdxSize, 2, 3, 1, 1, 2, 5],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_grouping_user_column_names() {
+    let df = df! {
+      "__POLARS_GROUPING_x" => [1, 2, 1],
+      "grouping" => [10, 20, 30],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("tbl", df.lazy());
+
+    let actual = ctx
+        .execute(
+            r#"
+          SELECT __POLARS_GROUPING_x, GROUPING(__POLARS_GROUPING_x) AS g, SUM(grouping) AS grouping
+          FROM tbl
+          GROUP BY ROLLUP (__POLARS_GROUPING_x)
+          ORDER BY __POLARS_GROUPING_x NULLS LAST
+          "#,
+        )
+        .unwrap()
+        .collect()
+        .unwrap();
+    let expected = df! {
+      "__POLARS_GROUPING_x" => [Some(1), Some(2), None],
+      "g" => [0i64, 0, 1],
+      "grouping" => [40, 20, 60],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_grand_total_of_empty_input() {
+    let actual = execute(
+        r#"
+      SELECT region, COUNT(*) AS n, SUM(amount) AS total
+      FROM sales
+      WHERE amount > 100
+      GROUP BY ROLLUP (region)
+      "#,
+    );
+    let expected = df! {
+      "region" => [None::<&str>],
+      "n" => [0 as IdxSize],
+      "total" => [0],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
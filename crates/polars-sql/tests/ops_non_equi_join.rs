@@ -0,0 +1,214 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let events = df! {
+      "id" => [1, 2, 3, 4],
+      "ts" => [5, 15, 25, 100],
+      "key" => ["a", "b", "a", "b"],
+    }
+    .unwrap();
+    let windows = df! {
+      "lo" => [0, 10, 20],
+      "hi" => [10, 20, 30],
+      "key" => ["a", "b", "b"],
+      "label" => ["w0", "w1", "w2"],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("events", events.lazy());
+    ctx.register("windows", windows.lazy());
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    create_ctx().execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_range_join_between() {
+    let actual = execute(
+        r#"
+      SELECT e.id, w.label
+      FROM events e JOIN windows w ON e.ts BETWEEN w.lo AND w.hi
+      ORDER BY e.id
+      "#,
+    );
+    let expected = df! {
+      "id" => [1, 2, 3],
+      "label" => ["w0", "w1", "w2"],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_mixed_equi_and_non_equi_join() {
+    let actual = execute(
+        r#"
+      SELECT *
+      FROM events e JOIN windows w ON e.ts >= w.lo AND e.key = w.key
+      ORDER BY id, label
+      "#,
+    );
+    // the column name shared by both tables takes the right table suffix
+    let expected = df! {
+      "id" => [1, 2, 3, 4, 4],
+      "ts" => [5, 15, 25, 100, 100],
+      "key" => ["a", "b", "a", "b", "b"],
+      "lo" => [0, 10, 0, 10, 20],
+      "hi" => [10, 20, 10, 20, 30],
+      "key:w" => ["a", "b", "a", "b", "b"],
+      "label" => ["w0", "w1", "w0", "w1", "w2"],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_or_join() {
+    let actual = execute(
+        r#"
+      SELECT e.id, w.label
+      FROM events e JOIN windows w ON e.key = w.key OR e.ts > 50
+      ORDER BY e.id, w.label
+      "#,
+    );
+    let expected = df! {
+      "id" => [1, 2, 2, 3, 4, 4, 4],
+      "label" => ["w0", "w1", "w2", "w0", "w0", "w1", "w2"],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_non_equi_outer_joins() {
+    let on = "e.ts >= w.lo AND e.ts < w.hi AND e.key = w.key";
+
+    let actual = execute(&format!(
+        "SELECT e.id, w.label FROM events e LEFT JOIN windows w ON {on} ORDER BY e.id"
+    ));
+    let expected = df! {
+      "id" => [1, 2, 3, 4],
+      "label" => [Some("w0"), Some("w1"), None, None],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+
+    let actual = execute(&format!(
+        "SELECT e.id, w.label FROM events e RIGHT JOIN windows w ON {on} ORDER BY w.label"
+    ));
+    let expected = df! {
+      "id" => [Some(1), Some(2), None],
+      "label" => ["w0", "w1", "w2"],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+
+    let actual = execute(&format!(
+        "SELECT e.id, w.label FROM events e FULL JOIN windows w ON {on} ORDER BY e.id NULLS LAST"
+    ));
+    let expected = df! {
+      "id" => [Some(1), Some(2), Some(3), Some(4), None],
+      "label" => [Some("w0"), Some("w1"), None, None, Some("w2")],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_single_table_join_constraints() {
+    // predicates on only one of the tables restrict the matches, not the left rows
+    let actual = execute(
+        r#"
+      SELECT e.id, w.label
+      FROM events e LEFT JOIN windows w ON e.ts < w.hi AND w.lo = 0
+      ORDER BY e.id
+      "#,
+    );
+    let expected = df! {
+      "id" => [1, 2, 3, 4],
+      "label" => [Some("w0"), None, None, None],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+
+    let actual = execute(
+        r#"
+      SELECT e.id, w.label
+      FROM events e LEFT JOIN windows w ON e.ts BETWEEN w.lo AND w.hi AND e.id != 2
+      ORDER BY e.id
+      "#,
+    );
+    let expected = df! {
+      "id" => [1, 2, 3, 4],
+      "label" => [Some("w0"), None, Some("w2"), None],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+#[cfg(feature = "semi_anti_join")]
+fn test_non_equi_semi_anti_joins() {
+    for (join, ids) in [("SEMI", [1, 2]), ("ANTI", [3, 4])] {
+        let actual = execute(&format!(
+            "SELECT e.id FROM events e {join} JOIN windows w ON e.ts < w.lo ORDER BY e.id"
+        ));
+        let expected = df! { "id" => ids }.unwrap();
+        assert!(expected.equals(&actual), "{actual}");
+    }
+}
+
+#[test]
+fn test_invalid_join_constraints() {
+    for constraint in [
+        // does not reference either table
+        "e.key = w.key AND 1 = 1",
+        // not a boolean expression
+        "e.key = w.key AND w.hi",
+        "e.key = w.key AND e.ts + 1",
+        // unqualified column that is present in both tables
+        "e.ts < w.hi AND key = 'a'",
+    ] {
+        let sql = format!("SELECT * FROM events e JOIN windows w ON {constraint}");
+        let Err(err) = create_ctx().execute(&sql) else {
+            panic!("{constraint}: expected an error");
+        };
+        assert!(
+            matches!(err, PolarsError::SQLInterface(_)),
+            "{constraint}: {err}"
+        );
+    }
+}
+
+#[test]
+fn test_unqualified_join_constraint_columns() {
+    // unqualified columns that are present in only one of the tables resolve to it
+    let actual = execute(
+        r#"
+      SELECT e.id, w.label
+      FROM events e JOIN windows w ON e.key = w.key AND ts >= lo AND ts < hi
+      ORDER BY e.id
+      "#,
+    );
+    let expected = df! {
+      "id" => [1, 2],
+      "label" => ["w0", "w1"],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+
+    let Err(err) =
+        create_ctx().execute("SELECT * FROM events e JOIN windows w ON e.ts < w.hi AND key = 'a'")
+    else {
+        panic!("expected an error");
+    };
+    assert!(
+        err.to_string()
+            .contains("ambiguous column reference 'key' in join constraint"),
+        "{err}"
+    );
+}
@@ -0,0 +1,153 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let sales = df! {
+      "region" => ["n", "n", "s", "n", "s"],
+      "quarter" => ["Q1", "Q2", "Q1", "Q1", "Q3"],
+      "sales" => [10, 20, 30, 40, 50],
+    }
+    .unwrap();
+    let quarterly = df! {
+      "region" => ["n", "s"],
+      "q1" => [Some(10), Some(30)],
+      "q2" => [Some(20), None],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("sales", sales.lazy());
+    ctx.register("quarterly", quarterly.lazy());
+    ctx
+}
+
+#[test]
+fn test_pivot() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT * FROM sales
+      PIVOT (SUM(sales) FOR quarter IN ('Q1', 'Q2', 'Q4'))
+      ORDER BY region"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "region" => ["n", "s"],
+      "Q1" => [50, 30],
+      "Q2" => [Some(20), None],
+      "Q4" => [None::<i32>, None],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_pivot_multiple_aggregates() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT * FROM sales
+      PIVOT (
+        SUM(sales) AS total, COUNT(sales) AS n
+        FOR quarter IN ('Q1' AS first, 'Q2' AS second)
+        DEFAULT ON NULL (0)
+      ) AS p
+      ORDER BY p.region"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "region" => ["n", "s"],
+      "first_total" => [50, 30],
+      "second_total" => [20, 0],
+      "first_n" => [2 as IdxSize, 1],
+      "second_n" => [1 as IdxSize, 0],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_pivot_without_group_columns() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT * FROM (SELECT quarter, sales FROM sales) AS s
+      PIVOT (AVG(sales) FOR quarter IN ('Q1', 'Q3'))"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "Q1" => [80.0 / 3.0],
+      "Q3" => [50.0],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_pivot_float_and_decimal_values() {
+    let prices = df! {
+      "item" => ["a", "a", "b", "b"],
+      "price" => [1.0, 2.0, 1.5, 2.0],
+      "qty" => [1, 2, 3, 4],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("prices", prices.clone().lazy());
+    ctx.register(
+        "decimal_prices",
+        prices
+            .lazy()
+            .with_column(col("price").cast(DataType::Decimal(Some(10), Some(2)))),
+    );
+
+    // the values are named as the pivot column values would be
+    let sql = r#"
+      SELECT * FROM prices
+      PIVOT (SUM(qty) FOR price IN (1, 2))
+      ORDER BY item"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "item" => ["a", "b"],
+      "1.0" => [Some(1), None],
+      "2.0" => [2, 4],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+
+    let sql = r#"
+      SELECT * FROM decimal_prices
+      PIVOT (SUM(qty) FOR price IN (1.5, 2))
+      ORDER BY item"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "item" => ["a", "b"],
+      "1.50" => [None, Some(3)],
+      "2.00" => [2, 4],
+    }
+    .unwrap();
+    assert!(expected.equals_missing(&actual), "{actual}");
+}
+
+#[test]
+fn test_unpivot() {
+    let mut ctx = create_ctx();
+    let sql = r#"
+      SELECT * FROM quarterly
+      UNPIVOT (amount FOR quarter IN (q1, q2)) AS u
+      ORDER BY u.region, u.quarter"#;
+    let actual = ctx.execute(sql).unwrap().collect().unwrap();
+    let expected = df! {
+      "region" => ["n", "n", "s"],
+      "quarter" => ["q1", "q2", "q1"],
+      "amount" => [10, 20, 30],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_pivot_errors() {
+    let mut ctx = create_ctx();
+    for sql in [
+        "SELECT * FROM sales PIVOT (SUM(sales) FOR quarter IN (ANY))",
+        "SELECT * FROM sales PIVOT (SUM(sales), MAX(sales) FOR quarter IN ('Q1'))",
+        "SELECT * FROM sales PIVOT (SUM(sales) FOR missing IN ('Q1'))",
+        "SELECT * FROM sales UNPIVOT (value FOR name IN (missing))",
+    ] {
+        assert!(ctx.execute(sql).is_err(), "{sql}");
+    }
+}
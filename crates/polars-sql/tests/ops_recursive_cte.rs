@@ -0,0 +1,185 @@
+use polars_core::prelude::*;
+use polars_lazy::prelude::*;
+use polars_sql::*;
+
+fn create_ctx() -> SQLContext {
+    let employees = df! {
+      "id" => [1, 2, 3, 4, 5, 6],
+      "name" => ["ceo", "cto", "cfo", "dev1", "dev2", "intern"],
+      "manager_id" => [None, Some(1), Some(1), Some(2), Some(2), Some(4)],
+    }
+    .unwrap();
+    let edges = df! {
+      "src" => [1, 2, 3, 3],
+      "dst" => [2, 3, 1, 4],
+    }
+    .unwrap();
+    let mut ctx = SQLContext::new();
+    ctx.register("employees", employees.lazy());
+    ctx.register("edges", edges.lazy());
+    ctx
+}
+
+fn execute(sql: &str) -> DataFrame {
+    create_ctx().execute(sql).unwrap().collect().unwrap()
+}
+
+#[test]
+fn test_recursive_cte_sequence() {
+    let actual = execute(
+        r#"
+      WITH RECURSIVE seq(n) AS (
+        SELECT 1
+        UNION ALL
+        SELECT n + 1 FROM seq WHERE n < 5
+      )
+      SELECT n FROM seq
+      "#,
+    );
+    let expected = df! {
+      "n" => [1, 2, 3, 4, 5],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_recursive_cte_hierarchy() {
+    let actual = execute(
+        r#"
+      WITH RECURSIVE chain AS (
+        SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+        UNION ALL
+        SELECT e.id, e.name, c.depth + 1
+        FROM employees e JOIN chain c ON e.manager_id = c.id
+      )
+      SELECT name, depth FROM chain ORDER BY depth, id
+      "#,
+    );
+    let expected = df! {
+      "name" => ["ceo", "cto", "cfo", "dev1", "dev2", "intern"],
+      "depth" => [0, 1, 1, 2, 2, 3],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_recursive_cte_union_distinct() {
+    // the graph contains a cycle (1 -> 2 -> 3 -> 1); UNION terminates
+    // once no new rows are produced
+    let actual = execute(
+        r#"
+      WITH RECURSIVE reachable(node) AS (
+        SELECT 1
+        UNION
+        SELECT e.dst FROM edges e JOIN reachable r ON e.src = r.node
+      )
+      SELECT node FROM reachable ORDER BY node
+      "#,
+    );
+    let expected = df! {
+      "node" => [1, 2, 3, 4],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_recursive_cte_subquery_reference() {
+    // the recursive member only references the CTE from a subquery
+    let actual = execute(
+        r#"
+      WITH RECURSIVE reachable(node) AS (
+        SELECT 1
+        UNION
+        SELECT dst FROM edges WHERE src IN (SELECT node FROM reachable)
+      )
+      SELECT node FROM reachable ORDER BY node
+      "#,
+    );
+    let expected = df! {
+      "node" => [1, 2, 3, 4],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_recursive_cte_shadowed_table() {
+    // a CTE shadows the registered table of the same name in the recursive member
+    let actual = execute(
+        r#"
+      WITH RECURSIVE edges AS (
+        SELECT 1 AS src, 2 AS dst
+      ),
+      reachable(node) AS (
+        SELECT 1
+        UNION
+        SELECT e.dst FROM edges e JOIN reachable r ON e.src = r.node
+      )
+      SELECT node FROM reachable ORDER BY node
+      "#,
+    );
+    let expected = df! {
+      "node" => [1, 2],
+    }
+    .unwrap();
+    assert!(expected.equals(&actual), "{actual}");
+}
+
+#[test]
+fn test_recursive_cte_max_iterations() {
+    let sql = r#"
+      WITH RECURSIVE reachable(node) AS (
+        SELECT 1
+        UNION ALL
+        SELECT e.dst FROM edges e JOIN reachable r ON e.src = r.node
+      )
+      SELECT node FROM reachable
+    "#;
+    let mut ctx = create_ctx().with_max_recursion_iterations(25);
+    let err = ctx
+        .execute(sql)
+        .and_then(|lf| lf.collect())
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains("did not complete within 25 iterations"),
+        "{err}"
+    );
+
+    // the iteration cap is not reached by a CTE that converges
+    let mut ctx = create_ctx().with_max_recursion_iterations(5);
+    let df = ctx
+        .execute("WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM t WHERE n < 5) SELECT * FROM t")
+        .unwrap()
+        .collect()
+        .unwrap();
+    assert_eq!(df.height(), 5);
+}
+
+#[test]
+fn test_recursive_cte_errors() {
+    for (sql, msg) in [
+        (
+            "WITH RECURSIVE t(n) AS (SELECT n FROM t) SELECT * FROM t",
+            "must be of the form",
+        ),
+        (
+            "WITH RECURSIVE t(n) AS (SELECT 1 UNION ALL SELECT n + 1, n FROM t) SELECT * FROM t",
+            "equal number of columns",
+        ),
+        (
+            "WITH RECURSIVE t(n) AS (SELECT id FROM employees WHERE EXISTS (SELECT n FROM t) UNION ALL SELECT n + 1 FROM t WHERE n < 5) SELECT * FROM t",
+            "cannot reference itself",
+        ),
+    ] {
+        let err = create_ctx()
+            .execute(sql)
+            .and_then(|lf| lf.collect())
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains(msg), "{err}");
+    }
+}
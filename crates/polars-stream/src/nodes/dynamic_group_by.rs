@@ -0,0 +1,486 @@
+use std::sync::Arc;
+
+use polars_core::chunked_array::ops::row_encode::encode_rows_vertical_par_unordered;
+use polars_core::prelude::*;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_expr::prelude::PhysicalExpr;
+use polars_time::prelude::{
+    ClosedWindow, Duration, DynamicGroupOptions, PolarsTemporalGroupby, RollingGroupOptions,
+};
+use polars_utils::IdxSize;
+
+use super::compute_node_prelude::*;
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+/// The kind of temporal windows a [`DynamicGroupByNode`] groups on.
+#[derive(Clone, Debug)]
+pub enum TemporalWindow {
+    Dynamic(DynamicGroupOptions),
+    Rolling(RollingGroupOptions),
+}
+
+impl TemporalWindow {
+    pub fn index_column(&self) -> &PlSmallStr {
+        match self {
+            Self::Dynamic(options) => &options.index_column,
+            Self::Rolling(options) => &options.index_column,
+        }
+    }
+}
+
+/// Converts the index column to the i64 values the windows are computed on,
+/// returning these together with their time unit.
+fn window_time_values(time: &Column) -> PolarsResult<(Int64Chunked, TimeUnit)> {
+    let (time, tu) = match time.dtype() {
+        DataType::Datetime(tu, _) => (time.to_physical_repr(), *tu),
+        DataType::Date => (
+            time.cast(&DataType::Datetime(TimeUnit::Milliseconds, None))?
+                .to_physical_repr(),
+            TimeUnit::Milliseconds,
+        ),
+        _ => (time.cast(&DataType::Int64)?, TimeUnit::Nanoseconds),
+    };
+    let time = time.as_materialized_series().rechunk();
+    Ok((time.i64()?.clone(), tu))
+}
+
+fn add_duration(duration: &Duration, t: i64, tu: TimeUnit) -> PolarsResult<i64> {
+    match tu {
+        TimeUnit::Nanoseconds => duration.add_ns(t, None),
+        TimeUnit::Microseconds => duration.add_us(t, None),
+        TimeUnit::Milliseconds => duration.add_ms(t, None),
+    }
+}
+
+/// Whether a window ending at `stop` can't contain `t` or any later time.
+fn window_ends_before(stop: i64, t: i64, closed: ClosedWindow) -> bool {
+    match closed {
+        ClosedWindow::Left | ClosedWindow::None => stop <= t,
+        ClosedWindow::Both | ClosedWindow::Right => stop < t,
+    }
+}
+
+/// A contiguous run of rows with the same key in the (key-sorted) buffer.
+struct KeyRun {
+    key: Vec<u8>,
+    start: usize,
+    end: usize,
+    /// Everything up to and including this time was emitted for this key
+    /// before the current flush. For `group_by_dynamic` this is the lower
+    /// bound of the last emitted window, for `rolling` the time of the last
+    /// emitted row.
+    emitted_before: Option<i64>,
+    /// Same as `emitted_before`, but including the current flush.
+    emitted_until: Option<i64>,
+}
+
+impl KeyRun {
+    fn new(key: &[u8], start: usize, end: usize, emitted_until: Option<i64>) -> Self {
+        Self {
+            key: key.to_vec(),
+            start,
+            end,
+            emitted_before: emitted_until,
+            emitted_until,
+        }
+    }
+}
+
+/// Groups sorted input into temporal windows, emitting each window as soon as
+/// the index column has moved past it.
+///
+/// The input only has to be sorted by the index column within each key. The
+/// buffer is kept sorted by key, and only the rows that can still be part of
+/// a window that has not been emitted yet are retained between flushes.
+///
+/// Windows of different keys are emitted as they complete. If `key_order_name`
+/// is set, the row-encoded key of every window is added as a column with that
+/// name, so the windows can be put in the order of the in-memory engine
+/// afterwards.
+pub struct DynamicGroupByNode {
+    state: DynamicGroupByState,
+    key_selectors: Vec<StreamExpr>,
+    aggs: Vec<Arc<dyn PhysicalExpr>>,
+    window: TemporalWindow,
+    key_order_name: Option<PlSmallStr>,
+    output_schema: Arc<Schema>,
+    seq: MorselSeq,
+
+    retained: DataFrame,
+    new_frames: Vec<DataFrame>,
+    new_rows: usize,
+    emitted_until: PlHashMap<Vec<u8>, i64>,
+}
+
+enum DynamicGroupByState {
+    Running,
+    Source(InMemorySourceNode),
+    Done,
+}
+
+impl DynamicGroupByNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_selectors: Vec<StreamExpr>,
+        aggs: Vec<Arc<dyn PhysicalExpr>>,
+        window: TemporalWindow,
+        key_order_name: Option<PlSmallStr>,
+        output_schema: Arc<Schema>,
+    ) -> Self {
+        Self {
+            state: DynamicGroupByState::Running,
+            key_selectors,
+            aggs,
+            window,
+            key_order_name,
+            output_schema,
+            seq: MorselSeq::default(),
+            retained: DataFrame::empty_with_schema(&input_schema),
+            new_frames: Vec::new(),
+            new_rows: 0,
+            emitted_until: PlHashMap::new(),
+        }
+    }
+
+    fn push(&mut self, df: DataFrame) {
+        self.new_rows += df.height();
+        self.new_frames.push(df);
+    }
+
+    fn should_flush(&self) -> bool {
+        // Flushing reprocesses the retained rows, so we wait for at least as
+        // many new rows to keep the total amount of work linear.
+        self.new_rows >= get_ideal_morsel_size().max(self.retained.height())
+    }
+
+    /// Sorts the buffer by key, keeping the time order within each key, and
+    /// returns the evaluated keys and the run of rows belonging to each key.
+    fn sort_by_key(
+        &self,
+        df: &mut DataFrame,
+        state: &ExecutionState,
+    ) -> PolarsResult<(Vec<Column>, Vec<KeyRun>)> {
+        if self.key_selectors.is_empty() {
+            let emitted_until = self.emitted_until.get(&[][..]).copied();
+            let run = KeyRun::new(&[], 0, df.height(), emitted_until);
+            return Ok((Vec::new(), vec![run]));
+        }
+
+        let keys = self
+            .key_selectors
+            .iter()
+            .map(|s| s.evaluate_blocking(df, state))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let mut keys = DataFrame::new_with_broadcast_len(keys, df.height())?.take_columns();
+        let mut encoded = encode_rows_vertical_par_unordered(&keys)?;
+        let idx = encoded.arg_sort(SortOptions {
+            maintain_order: true,
+            ..Default::default()
+        });
+        if !idx
+            .into_no_null_iter()
+            .enumerate()
+            .all(|(i, j)| i as IdxSize == j)
+        {
+            unsafe {
+                *df = df.take_unchecked(&idx);
+                keys = keys.iter().map(|c| c.take_unchecked(&idx)).collect();
+                encoded = encoded.take_unchecked(&idx);
+            }
+        }
+
+        let mut runs = Vec::new();
+        let mut start = 0;
+        let mut prev_key: Option<&[u8]> = None;
+        for (i, key) in encoded
+            .downcast_iter()
+            .flat_map(|arr| arr.values_iter())
+            .enumerate()
+        {
+            if let Some(prev_key) = prev_key.filter(|k| *k != key) {
+                let emitted_until = self.emitted_until.get(prev_key).copied();
+                runs.push(KeyRun::new(prev_key, start, i, emitted_until));
+                start = i;
+            }
+            prev_key = Some(key);
+        }
+        if let Some(prev_key) = prev_key {
+            let emitted_until = self.emitted_until.get(prev_key).copied();
+            runs.push(KeyRun::new(prev_key, start, df.height(), emitted_until));
+        }
+        Ok((keys, runs))
+    }
+
+    /// Computes all windows which are complete, or all remaining windows if
+    /// `is_final`, and trims the buffer to the rows still needed afterwards.
+    fn flush(&mut self, is_final: bool, state: &ExecutionState) -> PolarsResult<DataFrame> {
+        let mut frames = Vec::with_capacity(self.new_frames.len() + 1);
+        frames.push(std::mem::take(&mut self.retained));
+        frames.append(&mut self.new_frames);
+        self.new_rows = 0;
+        let mut df = accumulate_dataframes_vertical_unchecked(frames);
+        if df.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&self.output_schema));
+        }
+        df.as_single_chunk_par();
+
+        let (keys, mut runs) = self.sort_by_key(&mut df, state)?;
+        let group_by = (!keys.is_empty()).then(|| {
+            runs.iter()
+                .map(|r| [r.start as IdxSize, (r.end - r.start) as IdxSize])
+                .collect::<Vec<_>>()
+        });
+        let (time_key, bounds, groups) = match &self.window {
+            TemporalWindow::Dynamic(options) => {
+                // We always need the boundaries to know which windows are complete.
+                let mut options = options.clone();
+                options.include_boundaries = true;
+                df.group_by_dynamic(group_by, &options)?
+            },
+            TemporalWindow::Rolling(options) => {
+                let (time_key, groups) = df.rolling(group_by, options)?;
+                (time_key, Vec::new(), groups)
+            },
+        };
+        let GroupsType::Slice { groups: slices, .. } = &*groups else {
+            unreachable!()
+        };
+        let (time, tu) = window_time_values(df.column(self.window.index_column())?)?;
+        let time = time.cont_slice()?;
+
+        // Select the windows which are complete and weren't emitted before. A
+        // window is complete if it lies before the last time seen for its key,
+        // as the input is sorted by time within each key.
+        let mut selected = Vec::new();
+        let mut selected_runs = Vec::new();
+        let mut run_idx = 0;
+        match &self.window {
+            TemporalWindow::Dynamic(options) => {
+                let (lower, _) = window_time_values(&bounds[0])?;
+                let (upper, _) = window_time_values(&bounds[1])?;
+                let (lower, upper) = (lower.cont_slice()?, upper.cont_slice()?);
+                for (i, [first, _]) in slices.iter().enumerate() {
+                    while *first as usize >= runs[run_idx].end {
+                        run_idx += 1;
+                    }
+                    let run = &mut runs[run_idx];
+                    let last_t = time[run.end - 1];
+                    let is_new = run.emitted_before.is_none_or(|e| lower[i] > e);
+                    let is_complete =
+                        is_final || window_ends_before(upper[i], last_t, options.closed_window);
+                    if is_new && is_complete {
+                        selected.push(i as IdxSize);
+                        selected_runs.push(run_idx);
+                        run.emitted_until = Some(lower[i]);
+                    }
+                }
+            },
+            TemporalWindow::Rolling(options) => {
+                for i in 0..slices.len() {
+                    while i >= runs[run_idx].end {
+                        run_idx += 1;
+                    }
+                    let run = &mut runs[run_idx];
+                    let last_t = time[run.end - 1];
+                    let t = time[i];
+                    let is_new = run.emitted_before.is_none_or(|e| t > e);
+                    let is_complete = is_final
+                        || t < last_t && {
+                            let lower = add_duration(&options.offset, t, tu)?;
+                            let upper = add_duration(&options.period, lower, tu)?;
+                            window_ends_before(upper, last_t, options.closed_window)
+                        };
+                    if is_new && is_complete {
+                        selected.push(i as IdxSize);
+                        selected_runs.push(run_idx);
+                        run.emitted_until = Some(t);
+                    }
+                }
+            },
+        }
+
+        // The aggregations are evaluated on all windows, as some kernels
+        // depend on the layout of the groups.
+        let out = if selected.is_empty() {
+            DataFrame::empty_with_schema(&self.output_schema)
+        } else {
+            let selected = IdxCa::from_vec(PlSmallStr::EMPTY, selected);
+
+            let mut columns = Vec::with_capacity(self.output_schema.len());
+            for key in &keys {
+                columns.push(match &self.window {
+                    TemporalWindow::Dynamic(_) => unsafe {
+                        key.agg_first(&groups).take_unchecked(&selected)
+                    },
+                    TemporalWindow::Rolling(_) => unsafe { key.take_unchecked(&selected) },
+                });
+            }
+            if let TemporalWindow::Dynamic(options) = &self.window {
+                if options.include_boundaries {
+                    for bound in &bounds {
+                        columns.push(unsafe { bound.take_unchecked(&selected) });
+                    }
+                }
+            }
+            columns.push(unsafe { time_key.take_unchecked(&selected) });
+            for agg in &self.aggs {
+                let agg = agg.evaluate_on_groups(&df, &groups, state)?.finalize();
+                polars_ensure!(agg.len() == groups.len(), agg_len = agg.len(), groups.len());
+                columns.push(unsafe { agg.take_unchecked(&selected) });
+            }
+            if let Some(name) = &self.key_order_name {
+                let key_order = BinaryChunked::from_iter_values(
+                    name.clone(),
+                    selected_runs.iter().map(|&r| runs[r].key.as_slice()),
+                );
+                columns.push(key_order.into_column());
+            }
+            DataFrame::new(columns)?
+        };
+
+        // Retain the rows that can still be part of a window which wasn't
+        // emitted yet. We remember what was emitted for every key, even if
+        // none of its rows are retained, as later windows can overlap it.
+        for run in &runs {
+            if let Some(emitted_until) = run.emitted_until {
+                self.emitted_until.insert(run.key.clone(), emitted_until);
+            }
+        }
+        if !is_final {
+            let mut retain = Vec::new();
+            for run in &runs {
+                let Some(emitted_until) = run.emitted_until else {
+                    retain.extend(run.start as IdxSize..run.end as IdxSize);
+                    continue;
+                };
+                let run_time = &time[run.start..run.end];
+                let num_unneeded = match &self.window {
+                    // Windows which weren't emitted yet start after the last
+                    // emitted one. We do keep the last row before that, as
+                    // the first window is derived from the first row, and
+                    // windows after it only exist if they follow that chain.
+                    TemporalWindow::Dynamic(_) => run_time
+                        .partition_point(|t| *t <= emitted_until)
+                        .saturating_sub(1),
+                    // Windows which weren't emitted yet belong to later rows,
+                    // so they start at or after the offset from the last
+                    // emitted row.
+                    TemporalWindow::Rolling(options) => {
+                        let lower = add_duration(&options.offset, emitted_until, tu)?;
+                        let cutoff = lower.min(emitted_until);
+                        run_time.partition_point(|t| *t < cutoff)
+                    },
+                };
+                retain.extend((run.start + num_unneeded) as IdxSize..run.end as IdxSize);
+            }
+            let retain = IdxCa::from_vec(PlSmallStr::EMPTY, retain);
+            self.retained = unsafe { df.take_unchecked(&retain) };
+        }
+
+        Ok(out)
+    }
+}
+
+impl ComputeNode for DynamicGroupByNode {
+    fn name(&self) -> &str {
+        match self.window {
+            TemporalWindow::Dynamic(_) => "dynamic-group-by",
+            TemporalWindow::Rolling(_) => "rolling-group-by",
+        }
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = DynamicGroupByState::Done;
+            },
+            // Input is done, flush the remaining windows.
+            DynamicGroupByState::Running if recv[0] == PortState::Done => {
+                let df = self.flush(true, &state.in_memory_exec_state)?;
+                let source = InMemorySourceNode::new(Arc::new(df), self.seq);
+                self.state = DynamicGroupByState::Source(source);
+            },
+            // Defer to source node implementation.
+            DynamicGroupByState::Source(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = DynamicGroupByState::Done;
+                }
+            },
+            // Nothing to change.
+            DynamicGroupByState::Running | DynamicGroupByState::Done => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            DynamicGroupByState::Running => {
+                recv.swap_with_slice(send);
+            },
+            DynamicGroupByState::Source(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            DynamicGroupByState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match self.state {
+            DynamicGroupByState::Running => {
+                let mut recv = recv_ports[0].take().unwrap().serial();
+                let mut send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(morsel) = recv.recv().await {
+                        let source_token = morsel.source_token().clone();
+                        self.push(morsel.into_df());
+                        if !self.should_flush() {
+                            continue;
+                        }
+
+                        let df = self.flush(false, &state.in_memory_exec_state)?;
+                        let morsel_size = get_ideal_morsel_size();
+                        let mut offset = 0;
+                        while offset < df.height() {
+                            let chunk = df.slice(offset as i64, morsel_size);
+                            offset += morsel_size;
+                            let morsel = Morsel::new(chunk, self.seq, source_token.clone());
+                            self.seq = self.seq.successor();
+                            if send.send(morsel).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            DynamicGroupByState::Source(ref mut source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            DynamicGroupByState::Done => unreachable!(),
+        }
+    }
+}
@@ -8,12 +8,12 @@ use polars_expr::groups::Grouper;
 use polars_expr::hash_keys::HashKeys;
 use polars_expr::hot_groups::{HotGrouper, new_hash_hot_grouper};
 use polars_expr::reduce::GroupedReduction;
-use polars_utils::IdxSize;
 use polars_utils::cardinality_sketch::CardinalitySketch;
 use polars_utils::hashing::HashPartitioner;
 use polars_utils::itertools::Itertools;
 use polars_utils::pl_str::PlSmallStr;
 use polars_utils::sparse_init_vec::SparseInitVec;
+use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
 use super::compute_node_prelude::*;
@@ -22,12 +22,18 @@ use crate::async_primitives::connector::Receiver;
 use crate::expression::StreamExpr;
 use crate::morsel::get_ideal_morsel_size;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::memory::MemoryReservation;
+use crate::utils::spill::{LazySpillDir, PartitionSpiller, SpilledPartition, can_spill};
 
 #[cfg(debug_assertions)]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4;
 #[cfg(not(debug_assertions))]
 const DEFAULT_HOT_TABLE_SIZE: usize = 4096;
 
+fn spill_key_name(i: usize) -> PlSmallStr {
+    format_pl_smallstr!("__POLARS_GB_SPILL_KEY_{i}")
+}
+
 struct LocalGroupBySinkState {
     hot_grouper: Box<dyn HotGrouper>,
     hot_grouped_reductions: Vec<Box<dyn GroupedReduction>>,
@@ -39,7 +45,12 @@ struct LocalGroupBySinkState {
     // for partition p, where start, stop are:
     // let start = morsel_idxs_offsets[i * num_partitions + p];
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
-    cold_morsels: Vec<(u64, HashKeys, DataFrame)>,
+    //
+    // If there are order-sensitive reductions the cold morsels also store the
+    // row of each cold row in its original morsel.
+    cold_morsels: Vec<(u64, HashKeys, DataFrame, Option<Vec<IdxSize>>)>,
+    // The bytes of the cold morsels held by the memory reservation.
+    cold_bytes: usize,
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
 
@@ -47,6 +58,11 @@ struct LocalGroupBySinkState {
     pre_aggs: Vec<(HashKeys, Vec<Box<dyn GroupedReduction>>)>,
     pre_agg_idxs_values_per_p: Vec<Vec<IdxSize>>,
     pre_agg_idxs_offsets_per_p: Vec<usize>,
+
+    // Once the memory budget is exceeded all rows are spilled per partition,
+    // together with their keys.
+    spilling: bool,
+    spiller: PartitionSpiller,
 }
 
 impl LocalGroupBySinkState {
@@ -64,12 +80,16 @@ impl LocalGroupBySinkState {
             sketch_per_p: vec![CardinalitySketch::new(); num_partitions],
 
             cold_morsels: Vec::new(),
+            cold_bytes: 0,
             morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
             morsel_idxs_offsets_per_p: vec![0; num_partitions],
 
             pre_aggs: Vec::new(),
             pre_agg_idxs_values_per_p: vec![Vec::new(); num_partitions],
             pre_agg_idxs_offsets_per_p: vec![0; num_partitions],
+
+            spilling: false,
+            spiller: PartitionSpiller::new(num_partitions),
         }
     }
 
@@ -110,6 +130,8 @@ struct GroupBySinkState {
     locals: Vec<LocalGroupBySinkState>,
     random_state: PlRandomState,
     partitioner: HashPartitioner,
+    reservation: MemoryReservation,
+    spill_dir: LazySpillDir,
 }
 
 impl GroupBySinkState {
@@ -120,16 +142,23 @@ impl GroupBySinkState {
         state: &'s StreamingExecutionState,
         join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
     ) {
+        let order_sensitive = self
+            .grouped_reductions
+            .iter()
+            .any(|r| r.is_order_sensitive());
         for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
             let key_selectors = &self.key_selectors;
             let uniq_grouped_reduction_cols = &self.uniq_grouped_reduction_cols;
             let grouped_reduction_cols = &self.grouped_reduction_cols;
             let random_state = &self.random_state;
             let partitioner = self.partitioner.clone();
+            let reservation = &self.reservation;
+            let spill_dir = &self.spill_dir;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
                 let mut hot_idxs = Vec::new();
                 let mut hot_group_idxs = Vec::new();
                 let mut cold_idxs = Vec::new();
+                let mut spill_idxs_per_p = vec![Vec::new(); partitioner.num_partitions()];
                 while let Ok(morsel) = recv.recv().await {
                     // Compute hot group indices from key.
                     let seq = morsel.seq().to_u64();
@@ -142,6 +171,52 @@ impl GroupBySinkState {
                     let keys = DataFrame::new_with_broadcast_len(key_columns, df.height())?;
                     let hash_keys = HashKeys::from_df(&keys, *random_state, true, false);
 
+                    // Drop columns not used for reductions (key-only columns).
+                    if uniq_grouped_reduction_cols.len() < grouped_reduction_cols.len() {
+                        df = df._select_impl(uniq_grouped_reduction_cols).unwrap();
+                    }
+
+                    if !local.spilling
+                        && reservation.should_spill()
+                        && can_spill(keys.schema())
+                        && can_spill(df.schema())
+                    {
+                        local.spilling = true;
+                    }
+
+                    if local.spilling {
+                        // Prepend the keys under reserved names, the spilled
+                        // rows are grouped once all input is received.
+                        let mut spill_columns = keys
+                            .take_columns()
+                            .into_iter()
+                            .enumerate()
+                            .map(|(i, c)| c.with_name(spill_key_name(i)))
+                            .collect_vec();
+                        spill_columns.extend(df.take_columns());
+                        let spill_df =
+                            DataFrame::new_with_broadcast_len(spill_columns, hash_keys.len())?;
+
+                        for idxs in &mut spill_idxs_per_p {
+                            idxs.clear();
+                        }
+                        hash_keys.gen_idxs_per_partition(
+                            &partitioner,
+                            &mut spill_idxs_per_p,
+                            &mut local.sketch_per_p,
+                            true,
+                        );
+                        for (p, idxs) in spill_idxs_per_p.iter().enumerate() {
+                            if !idxs.is_empty() {
+                                let p_df =
+                                    unsafe { spill_df.take_slice_unchecked_impl(idxs, false) };
+                                local.spiller.push(p, seq, p_df, reservation);
+                            }
+                        }
+                        local.spiller.maybe_flush(spill_dir, reservation).await?;
+                        continue;
+                    }
+
                     hot_idxs.clear();
                     hot_group_idxs.clear();
                     cold_idxs.clear();
@@ -152,10 +227,6 @@ impl GroupBySinkState {
                         &mut cold_idxs,
                     );
 
-                    // Drop columns not used for reductions (key-only columns).
-                    if uniq_grouped_reduction_cols.len() < grouped_reduction_cols.len() {
-                        df = df._select_impl(uniq_grouped_reduction_cols).unwrap();
-                    }
                     df.rechunk_mut(); // For gathers.
 
                     // Update hot reductions.
@@ -181,6 +252,15 @@ impl GroupBySinkState {
                         unsafe {
                             let cold_keys = hash_keys.gather_unchecked(&cold_idxs);
                             let cold_df = df.take_slice_unchecked_impl(&cold_idxs, false);
+                            let cold_key_bytes =
+                                keys.estimated_size() * cold_idxs.len() / keys.height().max(1);
+                            let cold_rows = order_sensitive.then(|| cold_idxs.clone());
+                            let cold_row_bytes =
+                                cold_rows.as_ref().map_or(0, |r| size_of_val(r.as_slice()));
+                            let cold_bytes =
+                                cold_df.estimated_size() + cold_key_bytes + cold_row_bytes;
+                            reservation.grow(cold_bytes);
+                            local.cold_bytes += cold_bytes;
 
                             cold_keys.gen_idxs_per_partition(
                                 &partitioner,
@@ -191,7 +271,9 @@ impl GroupBySinkState {
                             local
                                 .morsel_idxs_offsets_per_p
                                 .extend(local.morsel_idxs_values_per_p.iter().map(|vp| vp.len()));
-                            local.cold_morsels.push((seq, cold_keys, cold_df));
+                            local
+                                .cold_morsels
+                                .push((seq, cold_keys, cold_df, cold_rows));
                         }
                     }
 
@@ -242,6 +324,20 @@ impl GroupBySinkState {
         }
         let (drop_q_send, drop_q_recv) = async_channel::bounded(self.locals.len());
         let num_partitions = self.locals[0].sketch_per_p.len();
+        let mut spilled_per_p: Vec<Vec<SpilledPartition>> =
+            (0..num_partitions).map(|_| Vec::new()).collect();
+        for l in &mut self.locals {
+            let spiller = core::mem::take(&mut l.spiller);
+            for (p, spilled) in spiller
+                .into_partitions(&self.reservation)
+                .into_iter()
+                .enumerate()
+            {
+                spilled_per_p[p].push(spilled);
+            }
+        }
+        let num_keys = self.key_selectors.len();
+        let random_state = self.random_state;
         let output_per_partition: SparseInitVec<GroupByPartition> =
             SparseInitVec::with_capacity(num_partitions);
         let locals = &self.locals;
@@ -255,7 +351,7 @@ impl GroupBySinkState {
             let arc_morsels_per_local = Arc::new(morsels_per_local);
             let arc_pre_aggs_per_local = Arc::new(pre_aggs_per_local);
             let mut join_handles = Vec::new();
-            for p in 0..num_partitions {
+            for (p, spilled) in spilled_per_p.into_iter().enumerate() {
                 let arc_morsels_per_local = Arc::clone(&arc_morsels_per_local);
                 let arc_pre_aggs_per_local = Arc::clone(&arc_pre_aggs_per_local);
                 let drop_q_send = drop_q_send.clone();
@@ -295,7 +391,7 @@ impl GroupBySinkState {
                         }
 
                         for (i, morsel) in l_morsels.iter().enumerate() {
-                            let (seq_id, keys, cols) = morsel;
+                            let (seq_id, keys, cols, rows) = morsel;
                             unsafe {
                                 let p_morsel_idxs_start =
                                     l.morsel_idxs_offsets_per_p[i * num_partitions + p];
@@ -313,12 +409,22 @@ impl GroupBySinkState {
                                 for (c, r) in grouped_reduction_cols.iter().zip(&mut p_reductions) {
                                     let values = cols.column(c.as_str()).unwrap();
                                     r.resize(p_grouper.num_groups());
-                                    r.update_groups_subset(
-                                        values,
-                                        p_morsel_idxs,
-                                        &group_idxs,
-                                        *seq_id,
-                                    )?;
+                                    if let Some(rows) = rows {
+                                        r.update_groups_subset_gathered(
+                                            values,
+                                            rows,
+                                            p_morsel_idxs,
+                                            &group_idxs,
+                                            *seq_id,
+                                        )?;
+                                    } else {
+                                        r.update_groups_subset(
+                                            values,
+                                            p_morsel_idxs,
+                                            &group_idxs,
+                                            *seq_id,
+                                        )?;
+                                    }
                                 }
                             }
                         }
@@ -375,6 +481,32 @@ impl GroupBySinkState {
                         }
                     }
 
+                    // Insert spilled rows, the keys are the leading columns.
+                    let mut subset = Vec::new();
+                    for partition in spilled {
+                        partition.for_each_frame(|seq_id, df| {
+                            let keys = unsafe {
+                                DataFrame::new_no_checks(
+                                    df.height(),
+                                    df.get_columns()[..num_keys].to_vec(),
+                                )
+                            };
+                            let keys = HashKeys::from_df(&keys, random_state, true, false);
+                            subset.clear();
+                            subset.extend(0..df.height() as IdxSize);
+                            unsafe {
+                                group_idxs.clear();
+                                p_grouper.insert_keys_subset(&keys, &subset, Some(&mut group_idxs));
+                                for (c, r) in grouped_reduction_cols.iter().zip(&mut p_reductions) {
+                                    let values = df.column(c.as_str()).unwrap();
+                                    r.resize(p_grouper.num_groups());
+                                    r.update_groups_subset(values, &subset, &group_idxs, seq_id)?;
+                                }
+                            }
+                            Ok(())
+                        })?;
+                    }
+
                     // We're done, help others out by doing drops.
                     drop(drop_q_send); // So we don't deadlock trying to receive from ourselves.
                     while let Ok(to_drop) = drop_q_recv.recv().await {
@@ -413,6 +545,10 @@ impl GroupBySinkState {
             PolarsResult::Ok(())
         })?;
 
+        // The cold morsels are merged into the partitions.
+        self.reservation
+            .shrink(self.locals.iter().map(|l| l.cold_bytes).sum());
+
         // Drop remaining local state in parallel.
         POOL.install(|| {
             core::mem::take(&mut self.locals)
@@ -499,6 +635,8 @@ impl GroupByNode {
                 grouped_reduction_cols,
                 locals,
                 partitioner,
+                reservation: MemoryReservation::new(),
+                spill_dir: LazySpillDir::new("group-by"),
             }),
             key_schema,
             output_schema,
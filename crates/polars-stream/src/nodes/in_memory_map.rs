@@ -6,13 +6,17 @@ use polars_plan::plans::DataFrameUdf;
 use super::compute_node_prelude::*;
 use super::in_memory_sink::InMemorySinkNode;
 use super::in_memory_source::InMemorySourceNode;
+use crate::utils::memory::MemoryReservation;
 
+/// Sinks its input and maps it with the in-memory engine. The input is spilled
+/// while it is sunk if the memory budget is exceeded, the output counts
+/// towards the budget while it is sent.
 pub enum InMemoryMapNode {
     Sink {
         sink_node: InMemorySinkNode,
         map: Arc<dyn DataFrameUdf>,
     },
-    Source(InMemorySourceNode),
+    Source(InMemorySourceNode, MemoryReservation),
     Done,
 }
 
@@ -47,11 +51,11 @@ impl ComputeNode for InMemoryMapNode {
         if let Self::Sink { sink_node, map } = self {
             if recv[0] == PortState::Done {
                 let df = sink_node.get_output()?;
-                let source_node = InMemorySourceNode::new(
-                    Arc::new(map.call_udf(df.unwrap())?),
-                    MorselSeq::default(),
-                );
-                *self = Self::Source(source_node);
+                let out = map.call_udf(df.unwrap())?;
+                let reservation = MemoryReservation::new();
+                reservation.grow(out.estimated_size());
+                let source_node = InMemorySourceNode::new(Arc::new(out), MorselSeq::default());
+                *self = Self::Source(source_node, reservation);
             }
         }
 
@@ -60,7 +64,7 @@ impl ComputeNode for InMemoryMapNode {
                 sink_node.update_state(recv, &mut [], state)?;
                 send[0] = PortState::Blocked;
             },
-            Self::Source(source_node) => {
+            Self::Source(source_node, _) => {
                 recv[0] = PortState::Done;
                 source_node.update_state(&mut [], send, state)?;
             },
@@ -88,7 +92,9 @@ impl ComputeNode for InMemoryMapNode {
             Self::Sink { sink_node, .. } => {
                 sink_node.spawn(scope, recv_ports, &mut [], state, join_handles)
             },
-            Self::Source(source) => source.spawn(scope, &mut [], send_ports, state, join_handles),
+            Self::Source(source, _) => {
+                source.spawn(scope, &mut [], send_ports, state, join_handles)
+            },
             Self::Done => unreachable!(),
         }
     }
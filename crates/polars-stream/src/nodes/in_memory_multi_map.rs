@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use polars_core::schema::Schema;
+
+use super::compute_node_prelude::*;
+use super::in_memory_sink::InMemorySinkNode;
+use super::in_memory_source::InMemorySourceNode;
+use crate::utils::memory::MemoryReservation;
+
+type MultiMap = Arc<dyn Fn(Vec<DataFrame>) -> PolarsResult<DataFrame> + Send + Sync>;
+
+/// Like the [`InMemoryMapNode`](super::in_memory_map::InMemoryMapNode), but
+/// sinks any number of inputs, which may all have a different height.
+pub enum InMemoryMultiMapNode {
+    Sink {
+        sink_nodes: Vec<InMemorySinkNode>,
+        map: MultiMap,
+    },
+    Source(InMemorySourceNode, MemoryReservation),
+    Done,
+}
+
+impl InMemoryMultiMapNode {
+    pub fn new(input_schemas: Vec<Arc<Schema>>, map: MultiMap) -> Self {
+        Self::Sink {
+            sink_nodes: input_schemas
+                .into_iter()
+                .map(InMemorySinkNode::new)
+                .collect(),
+            map,
+        }
+    }
+}
+
+impl ComputeNode for InMemoryMultiMapNode {
+    fn name(&self) -> &str {
+        "in-memory-multi-map"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done && !matches!(self, Self::Done) {
+            *self = Self::Done;
+        }
+
+        // If all inputs are done, transition to being a source.
+        if let Self::Sink { sink_nodes, map } = self {
+            assert!(recv.len() == sink_nodes.len());
+            if recv.iter().all(|r| *r == PortState::Done) {
+                let dfs = sink_nodes
+                    .iter_mut()
+                    .map(|sink_node| Ok(sink_node.get_output()?.unwrap()))
+                    .collect::<PolarsResult<Vec<_>>>()?;
+                let out = map(dfs)?;
+                let reservation = MemoryReservation::new();
+                reservation.grow(out.estimated_size());
+                let source_node = InMemorySourceNode::new(Arc::new(out), MorselSeq::default());
+                *self = Self::Source(source_node, reservation);
+            }
+        }
+
+        match self {
+            Self::Sink { sink_nodes, .. } => {
+                for (i, sink_node) in sink_nodes.iter_mut().enumerate() {
+                    sink_node.update_state(&mut recv[i..i + 1], &mut [], state)?;
+                }
+                send[0] = PortState::Blocked;
+            },
+            Self::Source(source_node, _) => {
+                recv.fill(PortState::Done);
+                source_node.update_state(&mut [], send, state)?;
+            },
+            Self::Done => {
+                recv.fill(PortState::Done);
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self, Self::Sink { .. })
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1);
+        match self {
+            Self::Sink { sink_nodes, .. } => {
+                assert!(recv_ports.len() == sink_nodes.len());
+                for (i, sink_node) in sink_nodes.iter_mut().enumerate() {
+                    if recv_ports[i].is_some() {
+                        sink_node.spawn(
+                            scope,
+                            &mut recv_ports[i..i + 1],
+                            &mut [],
+                            state,
+                            join_handles,
+                        );
+                    }
+                }
+            },
+            Self::Source(source, _) => {
+                source.spawn(scope, &mut [], send_ports, state, join_handles)
+            },
+            Self::Done => unreachable!(),
+        }
+    }
+}
@@ -5,18 +5,30 @@ use polars_core::schema::Schema;
 use polars_core::utils::accumulate_dataframes_vertical_unchecked;
 
 use super::compute_node_prelude::*;
+use crate::morsel::SourceToken;
 use crate::utils::in_memory_linearize::linearize;
+use crate::utils::memory::MemoryReservation;
+use crate::utils::spill::{LazySpillDir, PartitionSpiller, can_spill};
 
+/// Collects all of its input into a single [`DataFrame`]. If the memory budget
+/// is exceeded the received morsels are spilled to disk until the output is
+/// requested.
 pub struct InMemorySinkNode {
-    morsels_per_pipe: Mutex<Vec<Vec<Morsel>>>,
+    spilled_per_pipe: Mutex<Vec<PartitionSpiller>>,
     schema: Arc<Schema>,
+    spillable: bool,
+    reservation: MemoryReservation,
+    spill_dir: LazySpillDir,
 }
 
 impl InMemorySinkNode {
     pub fn new(schema: Arc<Schema>) -> Self {
         Self {
-            morsels_per_pipe: Mutex::default(),
+            spilled_per_pipe: Mutex::default(),
+            spillable: can_spill(&schema),
             schema,
+            reservation: MemoryReservation::new(),
+            spill_dir: LazySpillDir::new("in-memory-sink"),
         }
     }
 }
@@ -61,20 +73,38 @@ impl ComputeNode for InMemorySinkNode {
         for mut recv in receivers {
             let slf = &*self;
             join_handles.push(scope.spawn_task(TaskPriority::High, async move {
-                let mut morsels = Vec::new();
+                let mut spiller = PartitionSpiller::new(1);
                 while let Ok(mut morsel) = recv.recv().await {
                     morsel.take_consume_token();
-                    morsels.push(morsel);
+                    let seq = morsel.seq().to_u64();
+                    spiller.push(0, seq, morsel.into_df(), &slf.reservation);
+                    if slf.spillable && slf.reservation.should_spill() {
+                        spiller
+                            .maybe_flush(&slf.spill_dir, &slf.reservation)
+                            .await?;
+                    }
                 }
 
-                slf.morsels_per_pipe.lock().push(morsels);
+                slf.spilled_per_pipe.lock().push(spiller);
                 Ok(())
             }));
         }
     }
 
     fn get_output(&mut self) -> PolarsResult<Option<DataFrame>> {
-        let morsels_per_pipe = core::mem::take(&mut *self.morsels_per_pipe.get_mut());
+        let spilled_per_pipe = core::mem::take(&mut *self.spilled_per_pipe.get_mut());
+        let mut morsels_per_pipe = Vec::with_capacity(spilled_per_pipe.len());
+        for spiller in spilled_per_pipe {
+            let mut morsels = Vec::new();
+            for partition in spiller.into_partitions(&self.reservation) {
+                partition.for_each_frame(|seq, df| {
+                    let seq = MorselSeq::new(seq / 2);
+                    morsels.push(Morsel::new(df, seq, SourceToken::new()));
+                    Ok(())
+                })?;
+            }
+            morsels_per_pipe.push(morsels);
+        }
         let dataframes = linearize(morsels_per_pipe);
         if dataframes.is_empty() {
             Ok(Some(DataFrame::empty_with_schema(&self.schema)))
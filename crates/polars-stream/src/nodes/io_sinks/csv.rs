@@ -88,9 +88,10 @@ impl SinkNode for CsvSinkNode {
                         let mut writer = CsvWriter::new(&mut buffer)
                             .include_bom(false) // Handled once in the IO task.
                             .include_header(false) // Handled once in the IO task.
-                            .with_separator(options.serialize_options.separator)
+                            .with_separator(options.serialize_options.separator.clone())
                             .with_line_terminator(options.serialize_options.line_terminator.clone())
                             .with_quote_char(options.serialize_options.quote_char)
+                            .with_escape_char(options.serialize_options.escape_char)
                             .with_datetime_format(options.serialize_options.datetime_format.clone())
                             .with_date_format(options.serialize_options.date_format.clone())
                             .with_time_format(options.serialize_options.time_format.clone())
@@ -98,6 +99,8 @@ impl SinkNode for CsvSinkNode {
                             .with_float_precision(options.serialize_options.float_precision)
                             .with_null_value(options.serialize_options.null.clone())
                             .with_quote_style(options.serialize_options.quote_style)
+                            .with_compression(options.compression) // One member per morsel.
+                            .with_encoding(options.encoding)
                             .n_threads(1) // Disable rayon parallelism
                             .batched(&schema)?;
 
@@ -136,9 +139,10 @@ impl SinkNode for CsvSinkNode {
                 let mut writer = CsvWriter::new(&mut *file)
                     .include_bom(options.include_bom)
                     .include_header(options.include_header)
-                    .with_separator(options.serialize_options.separator)
+                    .with_separator(options.serialize_options.separator.clone())
                     .with_line_terminator(options.serialize_options.line_terminator.clone())
                     .with_quote_char(options.serialize_options.quote_char)
+                    .with_escape_char(options.serialize_options.escape_char)
                     .with_datetime_format(options.serialize_options.datetime_format.clone())
                     .with_date_format(options.serialize_options.date_format.clone())
                     .with_time_format(options.serialize_options.time_format.clone())
@@ -146,6 +150,8 @@ impl SinkNode for CsvSinkNode {
                     .with_float_precision(options.serialize_options.float_precision)
                     .with_null_value(options.serialize_options.null.clone())
                     .with_quote_style(options.serialize_options.quote_style)
+                    .with_compression(options.compression)
+                    .with_encoding(options.encoding)
                     .n_threads(1) // Disable rayon parallelism
                     .batched(&schema)?;
                 writer.write_batch(&DataFrame::empty_with_schema(&schema))?;
@@ -2,7 +2,7 @@ use std::cmp::Reverse;
 
 use polars_error::PolarsResult;
 use polars_io::cloud::CloudOptions;
-use polars_io::json::BatchedWriter;
+use polars_io::json::{BatchedWriter, JsonWriterOptions};
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
 
@@ -17,17 +17,20 @@ use crate::nodes::{JoinHandle, TaskPriority};
 pub struct NDJsonSinkNode {
     target: SinkTarget,
     sink_options: SinkOptions,
+    write_options: JsonWriterOptions,
     cloud_options: Option<CloudOptions>,
 }
 impl NDJsonSinkNode {
     pub fn new(
         target: SinkTarget,
         sink_options: SinkOptions,
+        write_options: JsonWriterOptions,
         cloud_options: Option<CloudOptions>,
     ) -> Self {
         Self {
             target,
             sink_options,
+            write_options,
             cloud_options,
         }
     }
@@ -65,6 +68,8 @@ impl SinkNode for NDJsonSinkNode {
         //
         // Task encodes the columns into their corresponding JSON encoding.
         join_handles.extend(pass_rxs.into_iter().map(|mut pass_rx| {
+            let compression = self.write_options.compression;
+
             spawn(TaskPriority::High, async move {
                 // Amortize the allocations over time. If we see that we need to do way larger
                 // allocations, we adjust to that over time.
@@ -75,7 +80,9 @@ impl SinkNode for NDJsonSinkNode {
                         let (df, seq, _, consume_token) = morsel.into_inner();
 
                         let mut buffer = Vec::with_capacity(allocation_size);
-                        let mut writer = BatchedWriter::new(&mut buffer);
+                        // Every morsel is compressed separately.
+                        let mut writer =
+                            BatchedWriter::new(&mut buffer).with_compression(compression);
 
                         writer.write_batch(&df)?;
 
@@ -13,7 +13,7 @@ use polars_parquet::parquet::error::ParquetResult;
 use polars_parquet::read::ParquetError;
 use polars_parquet::write::{
     ColumnWriteOptions, CompressedPage, Compressor, FileWriter, SchemaDescriptor, Version,
-    WriteOptions, array_to_columns, to_parquet_schema,
+    WriteOptions, array_to_bloom_filters, array_to_columns, to_parquet_schema,
 };
 use polars_plan::dsl::{SinkOptions, SinkTarget};
 use polars_utils::priority::Priority;
@@ -99,7 +99,8 @@ impl SinkNode for ParquetSinkNode {
         let (mut lin_rx, lin_txs) =
             Linearizer::new(state.num_pipelines, *DEFAULT_SINK_LINEARIZER_BUFFER_SIZE);
         // Collect task -> IO task
-        let (mut io_tx, mut io_rx) = connector::<Vec<Vec<CompressedPage>>>();
+        let (mut io_tx, mut io_rx) =
+            connector::<(Vec<Vec<CompressedPage>>, Vec<Option<Vec<u8>>>)>();
 
         let write_options = &self.write_options;
 
@@ -145,14 +146,29 @@ impl SinkNode for ParquetSinkNode {
                             // @NOTE: Since one Polars column might contain multiple Parquet columns (when
                             // it has a struct datatype), we return a Vec<Vec<CompressedPage>>.
 
+                            // Array -> Bloom filters of the Parquet columns.
+                            let bloom_filters = column_options
+                                .has_bloom_filter()
+                                .then(|| {
+                                    array_to_bloom_filters(
+                                        array.as_ref(),
+                                        type_.clone(),
+                                        column_options,
+                                    )
+                                })
+                                .transpose()?;
+
                             // Array -> Parquet pages.
                             let encoded_columns =
                                 array_to_columns(array, type_.clone(), column_options, options)?;
 
                             // Compress the pages.
+                            let compressions =
+                                column_options.leaf_compressions(options.compression);
                             let compressed_pages = encoded_columns
                                 .into_iter()
-                                .map(|encoded_pages| {
+                                .zip(compressions)
+                                .map(|(encoded_pages, compression)| {
                                     Compressor::new_from_vec(
                                         encoded_pages.map(|result| {
                                             result.map_err(|e| {
@@ -161,15 +177,20 @@ impl SinkNode for ParquetSinkNode {
                                                 ))
                                             })
                                         }),
-                                        options.compression,
+                                        compression,
                                         vec![],
                                     )
                                     .collect::<ParquetResult<Vec<_>>>()
                                 })
                                 .collect::<ParquetResult<Vec<_>>>()?;
+                            let bloom_filters =
+                                bloom_filters.unwrap_or_else(|| vec![None; compressed_pages.len()]);
 
                             if lin_tx
-                                .insert(Priority(Reverse(rg_idx), (col_idx, compressed_pages)))
+                                .insert(Priority(
+                                    Reverse(rg_idx),
+                                    (col_idx, compressed_pages, bloom_filters),
+                                ))
                                 .await
                                 .is_err()
                             {
@@ -191,7 +212,7 @@ impl SinkNode for ParquetSinkNode {
             struct Current {
                 seq: usize,
                 num_columns_seen: usize,
-                columns: Vec<Option<Vec<Vec<CompressedPage>>>>,
+                columns: Vec<Option<(Vec<Vec<CompressedPage>>, Vec<Option<Vec<u8>>>)>>,
             }
 
             let mut current = Current {
@@ -201,14 +222,16 @@ impl SinkNode for ParquetSinkNode {
             };
 
             // Linearize from all the Encoder tasks.
-            while let Some(Priority(Reverse(seq), (i, compressed_pages))) = lin_rx.get().await {
+            while let Some(Priority(Reverse(seq), (i, compressed_pages, bloom_filters))) =
+                lin_rx.get().await
+            {
                 if current.num_columns_seen == 0 {
                     current.seq = seq;
                 }
 
                 debug_assert_eq!(current.seq, seq);
                 debug_assert!(current.columns[i].is_none());
-                current.columns[i] = Some(compressed_pages);
+                current.columns[i] = Some((compressed_pages, bloom_filters));
                 current.num_columns_seen += 1;
 
                 if current.num_columns_seen == input_schema.len() {
@@ -216,11 +239,18 @@ impl SinkNode for ParquetSinkNode {
                     // them.
                     let mut current_row_group: Vec<Vec<CompressedPage>> =
                         Vec::with_capacity(num_parquet_columns);
+                    let mut bloom_filters = Vec::with_capacity(num_parquet_columns);
                     for column in current.columns.iter_mut() {
-                        current_row_group.extend(column.take().unwrap());
+                        let (compressed_pages, column_bloom_filters) = column.take().unwrap();
+                        current_row_group.extend(compressed_pages);
+                        bloom_filters.extend(column_bloom_filters);
                     }
 
-                    if io_tx.send(current_row_group).await.is_err() {
+                    if io_tx
+                        .send((current_row_group, bloom_filters))
+                        .await
+                        .is_err()
+                    {
                         return Ok(());
                     }
                     current.num_columns_seen = 0;
@@ -269,11 +299,11 @@ impl SinkNode for ParquetSinkNode {
             );
 
             let num_parquet_columns = writer.parquet_schema().leaves().len();
-            while let Ok(current_row_group) = io_rx.recv().await {
+            while let Ok((current_row_group, bloom_filters)) = io_rx.recv().await {
                 // @TODO: At the moment this is a sync write, this is not ideal because we can only
                 // have so many blocking threads in the tokio threadpool.
                 assert_eq!(current_row_group.len(), num_parquet_columns);
-                writer.write_row_group(&current_row_group)?;
+                writer.write_row_group(&current_row_group, bloom_filters)?;
             }
 
             writer.finish()?;
@@ -0,0 +1,275 @@
+//! Reads from an [`AnonymousScan`].
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::SchemaRef;
+use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_plan::dsl::Expr;
+use polars_plan::plans::{AnonymousScan, AnonymousScanArgs};
+use polars_utils::IdxSize;
+use polars_utils::pl_str::PlSmallStr;
+use polars_utils::slice_enum::Slice;
+
+use crate::async_executor::{JoinHandle, TaskPriority, spawn};
+use crate::morsel::{Morsel, MorselSeq, SourceToken};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::{
+    FileReaderOutputRecv, FileReaderOutputSend,
+};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    BeginReadArgs, FileReader, FileReaderCallbacks,
+};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::schema::SchemaRef;
+    use polars_plan::dsl::Expr;
+    use polars_plan::plans::AnonymousScan;
+
+    use super::AnonymousScanReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    pub struct AnonymousScanReaderBuilder {
+        pub name: &'static str,
+        pub function: Arc<dyn AnonymousScan>,
+        pub file_schema: SchemaRef,
+        /// The predicate as it is passed to the scan function if the multiscan decides to push
+        /// it into this reader.
+        pub predicate: Option<Expr>,
+    }
+
+    impl FileReaderBuilder for AnonymousScanReaderBuilder {
+        fn reader_name(&self) -> &str {
+            self.name
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            let mut capabilities = RC::empty();
+
+            if self.function.allows_slice_pushdown() {
+                capabilities |= RC::PRE_SLICE;
+            }
+
+            // The scan function is expected to fully apply a predicate it accepts.
+            if self.function.allows_predicate_pushdown() && self.predicate.is_some() {
+                capabilities |= RC::PARTIAL_FILTER | RC::FULL_FILTER;
+            }
+
+            capabilities
+        }
+
+        fn build_file_reader(
+            &self,
+            _source: polars_plan::prelude::ScanSource,
+            _cloud_options: Option<Arc<polars_io::cloud::CloudOptions>>,
+            scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            assert_eq!(scan_source_idx, 0);
+
+            Box::new(AnonymousScanReader {
+                name: self.name,
+                function: self.function.clone(),
+                file_schema: self.file_schema.clone(),
+                predicate: self.predicate.clone(),
+                verbose: polars_core::config::verbose(),
+            }) as Box<dyn FileReader>
+        }
+    }
+
+    impl std::fmt::Debug for AnonymousScanReaderBuilder {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("AnonymousScanReaderBuilder: name: ")?;
+            f.write_str(self.name)?;
+
+            Ok(())
+        }
+    }
+}
+
+pub struct AnonymousScanReader {
+    name: &'static str,
+    function: Arc<dyn AnonymousScan>,
+    file_schema: SchemaRef,
+    predicate: Option<Expr>,
+    verbose: bool,
+}
+
+/// Calls the scan function for the next batch of data.
+///
+/// The scan is called only once, unless the function produces its data in
+/// batches.
+struct BatchIter {
+    function: Arc<dyn AnonymousScan>,
+    n_rows: Option<usize>,
+    with_columns: Option<Arc<[PlSmallStr]>>,
+    schema: SchemaRef,
+    output_schema: Option<SchemaRef>,
+    predicate: Option<Expr>,
+    finished: bool,
+}
+
+impl BatchIter {
+    fn next(&mut self) -> PolarsResult<Option<DataFrame>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let args = AnonymousScanArgs {
+            n_rows: self.n_rows,
+            with_columns: self.with_columns.clone(),
+            schema: self.schema.clone(),
+            output_schema: self.output_schema.clone(),
+            predicate: self.predicate.clone(),
+        };
+
+        if self.function.produces_batches() {
+            let out = self.function.next_batch(args)?;
+            self.finished = out.is_none();
+            Ok(out)
+        } else {
+            self.finished = true;
+            self.function.scan(args).map(Some)
+        }
+    }
+}
+
+#[async_trait]
+impl FileReader for AnonymousScanReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let BeginReadArgs {
+            projected_schema,
+            row_index: None,
+            pre_slice: pre_slice @ (None | Some(Slice::Positive { .. })),
+            predicate,
+            cast_columns_policy: _,
+            num_pipelines: _,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(self.file_schema.clone());
+        }
+
+        let projection: Arc<[PlSmallStr]> = projected_schema.iter_names_cloned().collect();
+        // Without projection pushdown the scan gives us all columns, and we project here.
+        let (with_columns, output_schema) =
+            if self.function.allows_projection_pushdown() && !projection.is_empty() {
+                (Some(projection.clone()), Some(projected_schema.clone()))
+            } else {
+                (None, None)
+            };
+
+        let (offset, len) = match pre_slice {
+            Some(Slice::Positive { offset, len }) => (offset, len),
+            _ => (0, usize::MAX),
+        };
+        let slice_end = offset.saturating_add(len);
+        // The full row count is only known if we read to the end, so the slice
+        // must not stop the scan early if it is requested.
+        let stop_at_slice_end = pre_slice.is_some() && n_rows_in_file_tx.is_none();
+
+        let mut batches = BatchIter {
+            function: self.function.clone(),
+            n_rows: stop_at_slice_end.then_some(slice_end),
+            with_columns,
+            schema: self.file_schema.clone(),
+            output_schema,
+            predicate: predicate.map(|_| self.predicate.clone().unwrap()),
+            finished: false,
+        };
+        let name = self.name;
+        let verbose = self.verbose;
+
+        if verbose {
+            eprintln!("[AnonymousScanReader]: name: {name}");
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        let handle = spawn(TaskPriority::Low, async move {
+            let mut seq: u64 = 0;
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            let mut n_rows_seen: usize = 0;
+            let mut output_closed = false;
+
+            while let Some(df) = batches.next()? {
+                let height = df.height();
+                let batch_offset = offset.saturating_sub(n_rows_seen);
+                let batch_len = slice_end.saturating_sub(n_rows_seen.max(offset));
+                n_rows_seen = n_rows_seen.saturating_add(height);
+
+                if !output_closed && batch_offset < height && batch_len > 0 {
+                    let df = df
+                        .select(projection.iter().cloned())?
+                        .slice(batch_offset as i64, batch_len);
+
+                    output_closed = morsel_sender
+                        .send_morsel(Morsel::new(df, MorselSeq::new(seq), source_token.clone()))
+                        .await
+                        .is_err();
+                    seq = seq.saturating_add(1);
+                }
+
+                // We keep counting the rows if the full row count is requested.
+                if n_rows_in_file_tx.is_none() && (output_closed || n_rows_seen >= slice_end) {
+                    break;
+                }
+            }
+
+            if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+                let n_rows_seen = n_rows_seen.min(slice_end);
+                let n_rows_seen = IdxSize::try_from(n_rows_seen)
+                    .map_err(|_| polars_err!(bigidx, ctx = "anonymous scan", size = n_rows_seen))?;
+
+                _ = row_position_on_end_tx.try_send(n_rows_seen)
+            }
+
+            if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+                let n_rows_seen = IdxSize::try_from(n_rows_seen)
+                    .map_err(|_| polars_err!(bigidx, ctx = "anonymous scan", size = n_rows_seen))?;
+
+                _ = n_rows_in_file_tx.try_send(n_rows_seen)
+            }
+
+            Ok(())
+        });
+
+        Ok((morsel_rx, handle))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<SchemaRef> {
+        Ok(self.file_schema.clone())
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        // Counting the rows would consume the scan function, which can only be
+        // read once.
+        polars_bail!(
+            ComputeError:
+            "anonymous scan '{}' does not support counting its rows before reading", self.name
+        )
+    }
+}
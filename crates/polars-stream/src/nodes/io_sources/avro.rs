@@ -0,0 +1,579 @@
+use std::cmp::Reverse;
+use std::io::Cursor;
+use std::ops::Range;
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use polars_core::frame::DataFrame;
+use polars_core::schema::{Schema, SchemaExt};
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_core::utils::arrow::datatypes::ArrowSchemaRef;
+use polars_core::utils::arrow::io::avro::avro_schema::file::FileMetadata;
+use polars_core::utils::arrow::io::avro::avro_schema::read::fallible_streaming_iterator::FallibleStreamingIterator;
+use polars_core::utils::arrow::io::avro::{avro_schema, read};
+use polars_error::{ErrString, PolarsError, PolarsResult, polars_err};
+use polars_io::RowIndex;
+use polars_io::cloud::CloudOptions;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
+use polars_utils::IdxSize;
+use polars_utils::mmap::MemSlice;
+use polars_utils::priority::Priority;
+use polars_utils::slice_enum::Slice;
+
+use super::multi_file_reader::reader_interface::output::FileReaderOutputRecv;
+use super::multi_file_reader::reader_interface::{BeginReadArgs, calc_row_position_after_slice};
+use crate::async_executor::{AbortOnDropHandle, JoinHandle, TaskPriority, spawn};
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::async_primitives::linearizer::Linearizer;
+use crate::morsel::{Morsel, MorselSeq, SourceToken, get_ideal_morsel_size};
+use crate::nodes::io_sources::multi_file_reader::reader_interface::output::FileReaderOutputSend;
+use crate::nodes::io_sources::multi_file_reader::reader_interface::{
+    FileReader, FileReaderCallbacks,
+};
+use crate::{DEFAULT_DISTRIBUTOR_BUFFER_SIZE, DEFAULT_LINEARIZER_BUFFER_SIZE};
+
+pub mod builder {
+    use std::sync::Arc;
+
+    use polars_core::config;
+    use polars_io::cloud::CloudOptions;
+    use polars_plan::dsl::ScanSource;
+
+    use super::AvroFileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::FileReader;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
+    use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
+
+    #[derive(Debug)]
+    pub struct AvroReaderBuilder {}
+
+    #[cfg(feature = "avro")]
+    impl FileReaderBuilder for AvroReaderBuilder {
+        fn reader_name(&self) -> &str {
+            "avro"
+        }
+
+        fn reader_capabilities(&self) -> ReaderCapabilities {
+            use ReaderCapabilities as RC;
+
+            RC::ROW_INDEX | RC::PRE_SLICE | RC::NEGATIVE_PRE_SLICE
+        }
+
+        fn build_file_reader(
+            &self,
+            source: ScanSource,
+            cloud_options: Option<Arc<CloudOptions>>,
+            _scan_source_idx: usize,
+        ) -> Box<dyn FileReader> {
+            let scan_source = source;
+            let verbose = config::verbose();
+
+            let reader = AvroFileReader {
+                scan_source,
+                cloud_options,
+                verbose,
+                init_data: None,
+            };
+
+            Box::new(reader) as Box<dyn FileReader>
+        }
+    }
+}
+
+const ROW_COUNT_OVERFLOW_ERR: PolarsError = PolarsError::ComputeError(ErrString::new_static(
+    "\
+Avro file produces more than 2^32 rows; \
+consider compiling with polars-bigidx feature (polars-u64-idx package on python)",
+));
+
+struct AvroFileReader {
+    scan_source: ScanSource,
+    cloud_options: Option<Arc<CloudOptions>>,
+    verbose: bool,
+
+    init_data: Option<InitializedState>,
+}
+
+#[derive(Clone)]
+struct InitializedState {
+    memslice: MemSlice,
+    metadata: Arc<FileMetadata>,
+    arrow_schema: ArrowSchemaRef,
+    /// Offset of the first block in the file.
+    data_offset: usize,
+    // Lazily initialized - getting this involves walking all block headers.
+    n_rows_in_file: Option<IdxSize>,
+}
+
+/// Move `slice` forward by `n` and return the slice until then.
+fn slice_take(slice: &mut Range<usize>, n: usize) -> Range<usize> {
+    let offset = slice.start;
+    let length = slice.len();
+
+    assert!(offset <= n);
+
+    let chunk_length = (n - offset).min(length);
+    let rng = offset..offset + chunk_length;
+    *slice = 0..length - chunk_length;
+
+    rng
+}
+
+/// Reads the header of the block at `offset`, returning its number of rows and the offset of the
+/// next block.
+fn next_block(
+    bytes: &[u8],
+    offset: usize,
+    marker: &[u8; 16],
+) -> PolarsResult<Option<(usize, usize)>> {
+    let mut data = &bytes[offset..];
+    let num_rows = read::skip_block(&mut data, marker)?;
+    Ok(num_rows.map(|num_rows| (num_rows, bytes.len() - data.len())))
+}
+
+#[async_trait]
+impl FileReader for AvroFileReader {
+    async fn initialize(&mut self) -> PolarsResult<()> {
+        if self.init_data.is_some() {
+            return Ok(());
+        }
+
+        // check_latest: IR resolution does not download Avro.
+        if let ScanSourceRef::Path(p) = self.scan_source.as_scan_source_ref() {
+            polars_io::file_cache::init_entries_from_uri_list(
+                &[Arc::from(p.to_str().unwrap())],
+                self.cloud_options.as_deref(),
+            )?;
+        }
+
+        let memslice = self
+            .scan_source
+            .as_scan_source_ref()
+            .to_memslice_async_check_latest(self.scan_source.run_async())?;
+
+        let mut cursor = Cursor::new(memslice.as_ref());
+        let metadata = avro_schema::read::read_metadata(&mut cursor)?;
+        let data_offset = usize::try_from(cursor.position()).unwrap();
+        let arrow_schema = Arc::new(read::infer_schema(&metadata.record)?);
+
+        self.init_data = Some(InitializedState {
+            memslice,
+            metadata: Arc::new(metadata),
+            arrow_schema,
+            data_offset,
+            n_rows_in_file: None,
+        });
+
+        Ok(())
+    }
+
+    fn begin_read(
+        &mut self,
+        args: BeginReadArgs,
+    ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
+        let verbose = self.verbose;
+
+        let InitializedState {
+            memslice,
+            metadata,
+            arrow_schema,
+            data_offset,
+            n_rows_in_file: _,
+        } = self.init_data.clone().unwrap();
+
+        let BeginReadArgs {
+            projected_schema,
+            row_index,
+            pre_slice: pre_slice_arg,
+            predicate: None,
+            cast_columns_policy: _,
+            num_pipelines,
+            callbacks:
+                FileReaderCallbacks {
+                    file_schema_tx,
+                    n_rows_in_file_tx,
+                    row_position_on_end_tx,
+                },
+        } = args
+        else {
+            panic!("unsupported args: {:?}", &args)
+        };
+
+        let normalized_pre_slice = if let Some(pre_slice) = pre_slice_arg.clone() {
+            Some(pre_slice.restrict_to_bounds(usize::try_from(self._n_rows_in_file()?).unwrap()))
+        } else {
+            None
+        };
+
+        if let Some(mut n_rows_in_file_tx) = n_rows_in_file_tx {
+            _ = n_rows_in_file_tx.try_send(self._n_rows_in_file()?);
+        }
+
+        if let Some(mut row_position_on_end_tx) = row_position_on_end_tx {
+            _ = row_position_on_end_tx
+                .try_send(self._row_position_after_slice(normalized_pre_slice.clone())?);
+        }
+
+        if let Some(mut file_schema_tx) = file_schema_tx {
+            _ = file_schema_tx.try_send(Arc::new(Schema::from_arrow_schema(&arrow_schema)));
+        }
+
+        if normalized_pre_slice.as_ref().is_some_and(|x| x.len() == 0) {
+            let (_, rx) = FileReaderOutputSend::new_serial();
+
+            if verbose {
+                eprintln!(
+                    "[AvroFileReader]: early return: \
+                    n_rows_in_file: {} \
+                    pre_slice: {:?} \
+                    resolved_pre_slice: {:?} \
+                    ",
+                    self._n_rows_in_file()?,
+                    pre_slice_arg,
+                    normalized_pre_slice
+                )
+            }
+
+            return Ok((rx, spawn(TaskPriority::Low, std::future::ready(Ok(())))));
+        }
+
+        // Prepare parameters for tasks
+
+        // Always create a slice. If no slice was given, just make the biggest slice possible.
+        let slice: Range<usize> = normalized_pre_slice
+            .clone()
+            .map_or(0..usize::MAX, Range::<usize>::from);
+
+        // Avro is row-oriented, so the values of unprojected columns are skipped over while
+        // decoding.
+        let projection: Arc<[bool]> = arrow_schema
+            .iter_names()
+            .map(|name| projected_schema.contains(name))
+            .collect();
+        let num_projected_columns = projection.iter().filter(|p| **p).count();
+
+        if verbose {
+            eprintln!(
+                "[AvroFileReader]: \
+                project: {} / {}, \
+                pre_slice: {:?}, \
+                resolved_pre_slice: {:?} \
+                ",
+                num_projected_columns,
+                arrow_schema.len(),
+                pre_slice_arg,
+                normalized_pre_slice
+            )
+        }
+
+        // Split size for morsels.
+        let max_morsel_size = get_ideal_morsel_size();
+
+        /// Messages sent from Walker task to Decoder tasks.
+        struct BatchMessage {
+            row_idx_offset: IdxSize,
+            slice: Range<usize>,
+            /// Byte range of the (non-empty) blocks of this batch.
+            byte_range: Range<usize>,
+            morsel_seq_base: u64,
+        }
+
+        let (mut morsel_sender, morsel_rx) = FileReaderOutputSend::new_serial();
+
+        // Walker task -> Decoder tasks.
+        let (mut batch_tx, batch_rxs) =
+            distributor_channel::<BatchMessage>(num_pipelines, *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+        // Decoder tasks -> Distributor task.
+        let (mut decoded_rx, decoded_tx) =
+            Linearizer::<Priority<Reverse<MorselSeq>, DataFrame>>::new(
+                num_pipelines,
+                *DEFAULT_LINEARIZER_BUFFER_SIZE,
+            );
+
+        // Explicitly linearize here to redistribute morsels from large batches over the output
+        // pipelines.
+        let distributor_handle = AbortOnDropHandle::new(spawn(TaskPriority::High, async move {
+            // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
+            let source_token = SourceToken::new();
+
+            while let Some(Priority(Reverse(seq), df)) = decoded_rx.get().await {
+                let morsel = Morsel::new(df, seq, source_token.clone());
+
+                if morsel_sender.send_morsel(morsel).await.is_err() {
+                    break;
+                }
+            }
+
+            PolarsResult::Ok(())
+        }));
+
+        // Decoder tasks.
+        //
+        // Decompresses and decodes the blocks of a batch into a DataFrame, and splits it into
+        // morsels.
+        let decoder_handles = decoded_tx
+            .into_iter()
+            .zip(batch_rxs)
+            .map(|(mut send, mut rx)| {
+                let memslice = memslice.clone();
+                let metadata = metadata.clone();
+                let arrow_schema = arrow_schema.clone();
+                let projection = projection.clone();
+                let row_index = row_index.clone();
+                AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+                    while let Ok(m) = rx.recv().await {
+                        let BatchMessage {
+                            row_idx_offset,
+                            slice,
+                            byte_range,
+                            morsel_seq_base,
+                        } = m;
+
+                        // If we don't project any columns there is nothing to decode, so we just
+                        // create an empty frame with the proper height.
+                        let mut df = if num_projected_columns == 0 {
+                            DataFrame::empty_with_height(slice.len())
+                        } else {
+                            let mut blocks = avro_schema::read::block_iterator(
+                                Cursor::new(&memslice[byte_range]),
+                                metadata.compression,
+                                metadata.marker,
+                            );
+
+                            let mut dfs = Vec::new();
+                            while let Some(block) = blocks.next()? {
+                                let record_batch = read::deserialize(
+                                    block,
+                                    &arrow_schema,
+                                    &metadata.record.fields,
+                                    &projection,
+                                )?;
+                                dfs.push(DataFrame::from(record_batch));
+                            }
+
+                            accumulate_dataframes_vertical_unchecked(dfs)
+                                .slice(slice.start as i64, slice.len())
+                        };
+
+                        if let Some(RowIndex { name, offset: _ }) = &row_index {
+                            let offset = row_idx_offset + slice.start as IdxSize;
+                            df = df.with_row_index(name.clone(), Some(offset))?;
+                        }
+
+                        for i in 0..df.height().div_ceil(max_morsel_size) {
+                            let morsel_df = df.slice((i * max_morsel_size) as i64, max_morsel_size);
+                            let seq = MorselSeq::new(morsel_seq_base + i as u64);
+                            if send
+                                .insert(Priority(Reverse(seq), morsel_df))
+                                .await
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+                    }
+
+                    PolarsResult::Ok(())
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        // Walker task.
+        //
+        // Walks the block headers of the file and supplies byte ranges of blocks to the decoder
+        // tasks. This does not need to decompress anything, the headers contain both the number of
+        // rows and the (compressed) size of every block.
+        let walker_handle = AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
+            let bytes = memslice.as_ref();
+            let marker = &metadata.marker;
+
+            let mut morsel_seq: u64 = 0;
+            let mut row_idx_offset: IdxSize = row_index.as_ref().map_or(0, |ri| ri.offset);
+            let mut slice: Range<usize> = slice;
+            let mut offset = data_offset;
+
+            struct Batch {
+                row_idx_offset: IdxSize,
+                byte_start: usize,
+                num_rows: usize,
+            }
+
+            // Batch completion parameters
+            let batch_size_limit = get_ideal_morsel_size();
+            let sliced_batch_size_limit = slice.len().div_ceil(num_pipelines);
+            let batch_byte_limit = (bytes.len() - data_offset).div_ceil(num_pipelines);
+
+            let add_rows = |row_idx_offset: IdxSize, num_rows: usize| {
+                IdxSize::try_from(num_rows)
+                    .ok()
+                    .and_then(|num_rows| row_idx_offset.checked_add(num_rows))
+                    .ok_or(ROW_COUNT_OVERFLOW_ERR)
+            };
+
+            // Skip over all blocks that the slice would skip anyway.
+            while slice.start > 0 {
+                match next_block(bytes, offset, marker)? {
+                    Some((num_rows, next_offset)) if num_rows <= slice.start => {
+                        row_idx_offset = add_rows(row_idx_offset, num_rows)?;
+                        slice = slice.start - num_rows..slice.end - num_rows;
+                        offset = next_offset;
+                    },
+                    _ => break,
+                }
+            }
+
+            'read: {
+                let mut batch = Batch {
+                    row_idx_offset,
+                    byte_start: offset,
+                    num_rows: 0,
+                };
+
+                // We don't yet want to commit these values to the state in case this batch gets
+                // cancelled.
+                let mut uncommitted_slice = slice.clone();
+                let mut uncommitted_row_idx_offset = row_idx_offset;
+                while !slice.is_empty() {
+                    let mut is_batch_complete = false;
+                    let mut empty_block_end = None;
+
+                    match next_block(bytes, offset, marker)? {
+                        None if batch.num_rows == 0 => break,
+
+                        // If we have no more blocks available, we want to send what is left.
+                        None => is_batch_complete = true,
+
+                        // The block decoder stops at empty blocks, so we end the batch before
+                        // them and skip over them.
+                        Some((0, next_offset)) if batch.num_rows == 0 => {
+                            offset = next_offset;
+                            batch.byte_start = next_offset;
+                            continue;
+                        },
+                        Some((0, next_offset)) => {
+                            is_batch_complete = true;
+                            empty_block_end = Some(next_offset);
+                        },
+
+                        Some((num_rows, next_offset)) => {
+                            batch.num_rows += num_rows;
+                            uncommitted_row_idx_offset =
+                                add_rows(uncommitted_row_idx_offset, num_rows)?;
+                            offset = next_offset;
+                        },
+                    }
+
+                    // Subdivide into batches for large files.
+                    is_batch_complete |= batch.num_rows >= batch_size_limit;
+                    // Subdivide into batches if the file is sliced.
+                    is_batch_complete |= batch.num_rows >= sliced_batch_size_limit;
+                    // Subdivide into batches for small files.
+                    is_batch_complete |= offset - batch.byte_start >= batch_byte_limit;
+
+                    // Batch blocks such that we send appropriately sized morsels. We guarantee a
+                    // lower bound here, but not an upper bound.
+                    if is_batch_complete {
+                        let batch_slice = slice_take(&mut uncommitted_slice, batch.num_rows);
+                        let batch_slice_len = batch_slice.len();
+
+                        let message = BatchMessage {
+                            row_idx_offset: batch.row_idx_offset,
+                            slice: batch_slice,
+                            byte_range: batch.byte_start..offset,
+                            morsel_seq_base: morsel_seq,
+                        };
+
+                        if batch_tx.send(message).await.is_err() {
+                            // This should only happen if the receiver of the decoder
+                            // has broken off, meaning no further input will be needed.
+                            break 'read;
+                        }
+
+                        // Commit the changes to the state.
+                        // Now, we know that the a decoder will process it.
+                        //
+                        // This might generate several morsels if the batch is very large.
+                        morsel_seq += batch_slice_len.div_ceil(max_morsel_size) as u64;
+                        slice = uncommitted_slice.clone();
+                        row_idx_offset = uncommitted_row_idx_offset;
+                        if let Some(next_offset) = empty_block_end {
+                            offset = next_offset;
+                        }
+
+                        batch = Batch {
+                            row_idx_offset,
+                            byte_start: offset,
+                            num_rows: 0,
+                        };
+                    }
+                }
+            } // 'read
+
+            PolarsResult::Ok(())
+        }));
+
+        Ok((
+            morsel_rx,
+            spawn(TaskPriority::Low, async move {
+                distributor_handle.await?;
+
+                for handle in decoder_handles {
+                    handle.await?;
+                }
+
+                walker_handle.await?;
+                Ok(())
+            }),
+        ))
+    }
+
+    async fn file_schema(&mut self) -> PolarsResult<polars_core::schema::SchemaRef> {
+        let arrow_schema = &self.init_data.as_ref().unwrap().arrow_schema;
+        Ok(Arc::new(Schema::from_arrow_schema(arrow_schema)))
+    }
+
+    async fn n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        self._n_rows_in_file()
+    }
+
+    async fn row_position_after_slice(
+        &mut self,
+        pre_slice: Option<Slice>,
+    ) -> PolarsResult<IdxSize> {
+        self._row_position_after_slice(pre_slice)
+    }
+}
+
+impl AvroFileReader {
+    fn _n_rows_in_file(&mut self) -> PolarsResult<IdxSize> {
+        let InitializedState {
+            memslice,
+            metadata,
+            data_offset,
+            n_rows_in_file,
+            ..
+        } = self.init_data.as_mut().unwrap();
+
+        if n_rows_in_file.is_none() {
+            let mut data = &memslice[*data_offset..];
+            let mut n_rows: usize = 0;
+            while let Some(num_rows) = read::skip_block(&mut data, &metadata.marker)? {
+                n_rows += num_rows;
+            }
+
+            let n_rows = IdxSize::try_from(n_rows)
+                .map_err(|_| polars_err!(bigidx, ctx = "avro file", size = n_rows))?;
+
+            *n_rows_in_file = Some(n_rows);
+        }
+
+        Ok(n_rows_in_file.unwrap())
+    }
+
+    fn _row_position_after_slice(&mut self, pre_slice: Option<Slice>) -> PolarsResult<IdxSize> {
+        Ok(calc_row_position_after_slice(
+            self._n_rows_in_file()?,
+            pre_slice,
+        ))
+    }
+}
@@ -1,25 +1,26 @@
 use std::ops::Range;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use async_trait::async_trait;
 #[cfg(feature = "dtype-categorical")]
 use polars_core::StringCacheHolder;
 use polars_core::prelude::{Column, Field};
 use polars_core::schema::{SchemaExt, SchemaRef};
-use polars_error::{PolarsResult, polars_bail, polars_err};
+use polars_error::{PolarsResult, polars_bail, polars_ensure, polars_err};
 use polars_io::RowIndex;
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::_csv_read_internal::{
-    CountLines, NullValuesCompiled, cast_columns, find_starting_point, prepare_csv_schema,
-    read_chunk,
+    ChunkRejects, CountLines, NullValuesCompiled, cast_columns, concat_rejects, count_eol_chars,
+    find_starting_point, n_lines_for_schema_inference, prepare_csv_schema, read_chunk,
 };
 use polars_io::prelude::buffer::validate_utf8;
 use polars_io::prelude::{
-    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, count_rows_from_slice,
+    CommentPrefix, CsvEncoding, CsvParseOptions, CsvReadOptions, CsvRejectsWriter,
+    count_rows_from_slice, streaming_utf8_decoder,
 };
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::StreamingDecompressor;
 use polars_io::utils::slice::SplitSlicePosition;
-use polars_plan::dsl::ScanSource;
+use polars_plan::dsl::{ScanSource, ScanSourceRef};
 use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
 use polars_utils::slice_enum::Slice;
@@ -39,7 +40,7 @@ pub mod builder {
 
     use polars_core::config;
     use polars_io::cloud::CloudOptions;
-    use polars_io::prelude::CsvReadOptions;
+    use polars_io::prelude::{CsvReadOptions, CsvRejectsWriter};
     use polars_plan::dsl::ScanSource;
 
     use super::CsvFileReader;
@@ -47,7 +48,28 @@ pub mod builder {
     use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
     use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
 
-    impl FileReaderBuilder for Arc<CsvReadOptions> {
+    #[derive(Debug)]
+    pub struct CsvReaderBuilder {
+        pub options: Arc<CsvReadOptions>,
+        /// Writes the rejected rows of all files.
+        pub rejects_writer: Option<Arc<CsvRejectsWriter>>,
+    }
+
+    impl CsvReaderBuilder {
+        pub fn new(options: Arc<CsvReadOptions>) -> Self {
+            let rejects_writer = options
+                .rejects_path
+                .clone()
+                .map(|path| Arc::new(CsvRejectsWriter::new(path)));
+
+            Self {
+                options,
+                rejects_writer,
+            }
+        }
+    }
+
+    impl FileReaderBuilder for CsvReaderBuilder {
         fn reader_name(&self) -> &str {
             "csv"
         }
@@ -55,7 +77,9 @@ pub mod builder {
         fn reader_capabilities(&self) -> ReaderCapabilities {
             use ReaderCapabilities as RC;
 
-            if self.parse_options.comment_prefix.is_some() {
+            // Rejected rows are only known after parsing, so lines cannot be sliced before.
+            if self.options.parse_options.comment_prefix.is_some() || self.rejects_writer.is_some()
+            {
                 RC::empty()
             } else {
                 RC::PRE_SLICE
@@ -70,12 +94,13 @@ pub mod builder {
         ) -> Box<dyn FileReader> {
             let scan_source = source;
             let verbose = config::verbose();
-            let options = self.clone();
+            let options = self.options.clone();
 
             let reader = CsvFileReader {
                 scan_source,
                 cloud_options,
                 options,
+                rejects_writer: self.rejects_writer.clone(),
                 verbose,
                 cached_bytes: None,
             };
@@ -92,12 +117,13 @@ const NO_SLICE: (usize, usize) = (0, usize::MAX);
 const SLICE_ENDED: (usize, usize) = (usize::MAX, 0);
 
 struct LineBatch {
-    // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-    bytes: &'static [u8],
+    bytes: MemSlice,
     n_lines: usize,
     slice: (usize, usize),
     /// Position of this chunk relative to the start of the file according to CountLines.
     row_offset: usize,
+    /// Line number of the start of this chunk, only counted if rows are rejected.
+    first_line: usize,
     morsel_seq: MorselSeq,
 }
 
@@ -106,7 +132,9 @@ struct CsvFileReader {
     #[expect(unused)] // Will be used when implementing cloud streaming.
     cloud_options: Option<Arc<CloudOptions>>,
     options: Arc<CsvReadOptions>,
-    // Cached on first access - we may be called multiple times e.g. on negative slice.
+    rejects_writer: Option<Arc<CsvRejectsWriter>>,
+    // Cached on first access - we may be called multiple times e.g. on negative slice. Note that
+    // these are the raw bytes, compressed files are decompressed while reading.
     cached_bytes: Option<MemSlice>,
     verbose: bool,
 }
@@ -131,8 +159,6 @@ impl FileReader for CsvFileReader {
     ) -> PolarsResult<(FileReaderOutputRecv, JoinHandle<PolarsResult<()>>)> {
         let verbose = self.verbose;
 
-        let memslice = self.get_bytes_maybe_decompress()?;
-
         let BeginReadArgs {
             projected_schema,
             // Because we currently only support PRE_SLICE we don't need to handle row index here.
@@ -170,6 +196,10 @@ impl FileReader for CsvFileReader {
             self.options.infer_schema_length
         };
 
+        // For compressed files this is only the first decompressed chunk, it contains at least the
+        // lines needed to infer the schema.
+        let (memslice, decompressor) = self.get_bytes_maybe_decompress(infer_schema_length)?;
+
         let (mut inferred_schema, ..) = polars_io::csv::read::infer_file_schema(
             &polars_io::mmap::ReaderBytes::Owned(memslice.clone()),
             &self.options.parse_options,
@@ -248,13 +278,19 @@ impl FileReader for CsvFileReader {
         // Only used on empty projection, or if we need the exact row count.
         let alt_count_lines: Option<Arc<CountLinesWithComments>> =
             CountLinesWithComments::opt_new(&self.options.parse_options).map(Arc::new);
+        let collect_rejects = self.rejects_writer.is_some();
         let chunk_reader = Arc::new(ChunkReader::try_new(
             self.options.clone(),
             inferred_schema.clone(),
             projection,
             row_index,
             alt_count_lines.clone(),
+            collect_rejects.then(|| match self.scan_source.as_scan_source_ref() {
+                ScanSourceRef::Path(path) => Some(path.to_string_lossy().into_owned()),
+                _ => None,
+            }),
         )?);
+        let rejects = Arc::new(Mutex::new(vec![]));
 
         let needs_full_row_count = n_rows_in_file_tx.is_some();
 
@@ -264,16 +300,19 @@ impl FileReader for CsvFileReader {
         let line_batch_source_handle = AbortOnDropHandle::new(spawn(
             TaskPriority::Low,
             LineBatchSource {
-                memslice: memslice.clone(),
+                memslice,
+                decompressor,
                 line_counter: CountLines::new(
                     self.options.parse_options.quote_char,
                     self.options.parse_options.eol_char,
-                ),
+                )
+                .with_escape_char(self.options.parse_options.escape_char),
                 line_batch_tx,
                 options: self.options.clone(),
                 file_schema_len: inferred_schema.len(),
                 pre_slice,
                 needs_full_row_count,
+                count_lines: collect_rejects,
                 num_pipelines,
                 verbose,
             }
@@ -289,8 +328,6 @@ impl FileReader for CsvFileReader {
             .zip(morsel_senders)
             .enumerate()
             .map(|(worker_idx, (mut line_batch_rx, mut morsel_tx))| {
-                // Hold a ref as we are receiving `&'static [u8]`s pointing to this.
-                let global_memslice = memslice.clone();
                 // Only verbose log from the last worker to avoid flooding output.
                 let verbose = verbose && worker_idx == n_workers - 1;
                 let mut n_rows_processed: usize = 0;
@@ -298,6 +335,7 @@ impl FileReader for CsvFileReader {
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
                 let alt_count_lines = alt_count_lines.clone();
+                let rejects = rejects.clone();
 
                 AbortOnDropHandle::new(spawn(TaskPriority::Low, async move {
                     while let Ok(LineBatch {
@@ -305,22 +343,26 @@ impl FileReader for CsvFileReader {
                         n_lines,
                         slice,
                         row_offset,
+                        first_line,
                         morsel_seq,
                     }) = line_batch_rx.recv().await
                     {
-                        debug_assert!(bytes.as_ptr() as usize >= global_memslice.as_ptr() as usize);
-                        debug_assert!(
-                            bytes.as_ptr() as usize + bytes.len()
-                                <= global_memslice.as_ptr() as usize + global_memslice.len()
-                        );
-
                         let (offset, len) = match slice {
                             SLICE_ENDED => (0, 1),
                             v => v,
                         };
 
-                        let (df, n_rows_in_chunk) =
-                            chunk_reader.read_chunk(bytes, n_lines, (offset, len), row_offset)?;
+                        let (df, n_rows_in_chunk, rejected) = chunk_reader.read_chunk(
+                            &bytes,
+                            n_lines,
+                            (offset, len),
+                            row_offset,
+                            first_line,
+                        )?;
+
+                        if let Some(rejected) = rejected.filter(|df| df.height() > 0) {
+                            rejects.lock().unwrap().push(rejected);
+                        }
 
                         n_rows_processed = n_rows_processed.saturating_add(n_rows_in_chunk);
 
@@ -349,13 +391,14 @@ impl FileReader for CsvFileReader {
                             n_lines,
                             slice,
                             row_offset: _,
+                            first_line: _,
                             morsel_seq: _,
                         }) = line_batch_rx.recv().await
                         {
                             assert_eq!(slice, SLICE_ENDED);
 
                             let n_lines = if let Some(v) = alt_count_lines.as_deref() {
-                                v.count_lines(bytes)?
+                                v.count_lines(&bytes)?
                             } else {
                                 n_lines
                             };
@@ -369,6 +412,8 @@ impl FileReader for CsvFileReader {
             })
             .collect::<Vec<_>>();
 
+        let rejects_writer = self.rejects_writer.clone();
+
         Ok((
             rx,
             spawn(TaskPriority::Low, async move {
@@ -379,6 +424,11 @@ impl FileReader for CsvFileReader {
                     row_position = row_position.saturating_add(rows_processed);
                 }
 
+                if let Some(rejects_writer) = rejects_writer {
+                    let rejects = std::mem::take(&mut *rejects.lock().unwrap());
+                    rejects_writer.write(&concat_rejects(rejects)?)?;
+                }
+
                 row_position = {
                     let rows_skipped = line_batch_source_handle.await?;
                     row_position.saturating_add(rows_skipped)
@@ -403,28 +453,58 @@ impl FileReader for CsvFileReader {
 }
 
 impl CsvFileReader {
+    /// Returns the bytes of the file, or for compressed files and files that are not encoded in
+    /// UTF-8 the first decoded chunk along with the decoder for the remaining data. The first
+    /// chunk contains at least the lines needed to infer the schema from `infer_schema_length`
+    /// rows.
+    ///
     /// # Panics
     /// Panics if `self.cached_bytes` is None.
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
-        let mut out = vec![];
-        maybe_decompress_bytes(self.cached_bytes.as_deref().unwrap(), &mut out)?;
+    fn get_bytes_maybe_decompress(
+        &self,
+        infer_schema_length: Option<usize>,
+    ) -> PolarsResult<(MemSlice, Option<StreamingDecompressor>)> {
+        let memslice = self.cached_bytes.clone().unwrap();
+        let parse_options = self.options.parse_options.as_ref();
 
-        if !out.is_empty() {
-            self.cached_bytes = Some(MemSlice::from_vec(out));
+        let Some(mut decompressor) =
+            streaming_utf8_decoder(memslice.clone(), parse_options.encoding)?
+        else {
+            return Ok((memslice, None));
+        };
+
+        if self.verbose {
+            eprintln!(
+                "[CsvFileReader]: streaming decompression (encoding: {:?})",
+                parse_options.encoding
+            );
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        let line_counter = CountLines::new(parse_options.quote_char, parse_options.eol_char)
+            .with_escape_char(parse_options.escape_char);
+        let min_lines = n_lines_for_schema_inference(&self.options, infer_schema_length);
+
+        let first_chunk = decompressor
+            .next_chunk(|bytes| line_counter.find_last_line_end(bytes, min_lines?))?
+            .unwrap_or_default();
+
+        Ok((first_chunk, Some(decompressor)))
     }
 }
 
 struct LineBatchSource {
     memslice: MemSlice,
+    /// Decodes the data after `memslice` for compressed files and files that are not encoded in
+    /// UTF-8.
+    decompressor: Option<StreamingDecompressor>,
     line_counter: CountLines,
     line_batch_tx: distributor_channel::Sender<LineBatch>,
     options: Arc<CsvReadOptions>,
     file_schema_len: usize,
     pre_slice: Option<Slice>,
     needs_full_row_count: bool,
+    /// Count the lines for the line numbers of rejected rows.
+    count_lines: bool,
     num_pipelines: usize,
     verbose: bool,
 }
@@ -434,12 +514,14 @@ impl LineBatchSource {
     async fn run(self) -> PolarsResult<usize> {
         let LineBatchSource {
             memslice,
+            mut decompressor,
             line_counter,
             mut line_batch_tx,
             options,
             file_schema_len,
             pre_slice,
             needs_full_row_count,
+            count_lines,
             num_pipelines,
             verbose,
         } = self;
@@ -464,14 +546,12 @@ impl LineBatchSource {
             eprintln!("[CsvSource]: Start line splitting",);
         }
 
-        let global_bytes: &[u8] = memslice.as_ref();
-        let global_bytes: &'static [u8] = unsafe { std::mem::transmute(global_bytes) };
-
         let i = {
             let parse_options = options.parse_options.as_ref();
 
             let quote_char = parse_options.quote_char;
             let eol_char = parse_options.eol_char;
+            let escape_char = parse_options.escape_char;
 
             let skip_lines = options.skip_lines;
             let skip_rows_before_header = options.skip_rows;
@@ -480,9 +560,10 @@ impl LineBatchSource {
             let has_header = options.has_header;
 
             find_starting_point(
-                global_bytes,
+                memslice.as_ref(),
                 quote_char,
                 eol_char,
+                escape_char,
                 file_schema_len,
                 skip_lines,
                 skip_rows_before_header,
@@ -492,14 +573,12 @@ impl LineBatchSource {
             )?
         };
 
-        let mut bytes = &global_bytes[i..];
-
         let mut chunk_size = {
             let max_chunk_size = 16 * 1024 * 1024;
             let chunk_size = if global_slice.is_some() {
                 max_chunk_size
             } else {
-                std::cmp::min(bytes.len() / (16 * num_pipelines), max_chunk_size)
+                std::cmp::min((memslice.len() - i) / (16 * num_pipelines), max_chunk_size)
             };
 
             // Use a small min chunk size to catch failures in tests.
@@ -510,69 +589,106 @@ impl LineBatchSource {
             std::cmp::max(chunk_size, min_chunk_size)
         };
 
-        loop {
-            if bytes.is_empty() {
-                break;
-            }
+        // For compressed files, `global_bytes` is a decompressed chunk that ends at a line end
+        // (except for the last chunk). We split every chunk into line batches before decompressing
+        // the next one.
+        let mut global_bytes = memslice;
+        let mut offset = i;
+        let mut current_line = 1;
+        if count_lines {
+            current_line +=
+                count_eol_chars(&global_bytes[..offset], options.parse_options.eol_char);
+        }
 
-            let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
-            let (count, position) = if count == 0 {
-                (1, bytes.len())
-            } else {
-                let pos = (position + 1).min(bytes.len()); // +1 for '\n'
-                (count, pos)
-            };
+        'chunks: loop {
+            let mut bytes = &global_bytes[offset..];
 
-            let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
-
-            bytes = &bytes[position..];
-
-            let current_row_offset = *current_row_offset_ref;
-            *current_row_offset_ref += count;
-
-            let slice = if let Some(global_slice) = &global_slice {
-                match SplitSlicePosition::split_slice_at_file(
-                    current_row_offset,
-                    count,
-                    global_slice.clone(),
-                ) {
-                    // Note that we don't check that the skipped line batches actually contain this many
-                    // lines.
-                    SplitSlicePosition::Before => {
-                        n_rows_skipped = n_rows_skipped.saturating_add(count);
-                        continue;
-                    },
-                    SplitSlicePosition::Overlapping(offset, len) => (offset, len),
-                    SplitSlicePosition::After => {
-                        if needs_full_row_count {
-                            // If we need to know the unrestricted row count, we need
-                            // to go until the end.
-                            SLICE_ENDED
-                        } else {
-                            break;
-                        }
-                    },
+            loop {
+                if bytes.is_empty() {
+                    break;
                 }
-            } else {
-                NO_SLICE
-            };
 
-            let bytes_this_chunk = &global_bytes[slice_start..slice_start + position];
+                let (count, position) = line_counter.find_next(bytes, &mut chunk_size);
+                let (count, position) = if count == 0 {
+                    (1, bytes.len())
+                } else {
+                    let pos = (position + 1).min(bytes.len()); // +1 for '\n'
+                    (count, pos)
+                };
 
-            let morsel_seq = *morsel_seq_ref;
-            *morsel_seq_ref = morsel_seq.successor();
+                let slice_start = bytes.as_ptr() as usize - global_bytes.as_ptr() as usize;
 
-            let batch = LineBatch {
-                bytes: bytes_this_chunk,
-                n_lines: count,
-                slice,
-                row_offset: current_row_offset,
-                morsel_seq,
+                bytes = &bytes[position..];
+
+                let current_row_offset = *current_row_offset_ref;
+                *current_row_offset_ref += count;
+
+                let first_line = current_line;
+                if count_lines {
+                    current_line += count_eol_chars(
+                        &global_bytes[slice_start..slice_start + position],
+                        options.parse_options.eol_char,
+                    );
+                }
+
+                let slice = if let Some(global_slice) = &global_slice {
+                    match SplitSlicePosition::split_slice_at_file(
+                        current_row_offset,
+                        count,
+                        global_slice.clone(),
+                    ) {
+                        // Note that we don't check that the skipped line batches actually contain this many
+                        // lines.
+                        SplitSlicePosition::Before => {
+                            n_rows_skipped = n_rows_skipped.saturating_add(count);
+                            continue;
+                        },
+                        SplitSlicePosition::Overlapping(offset, len) => (offset, len),
+                        SplitSlicePosition::After => {
+                            if needs_full_row_count {
+                                // If we need to know the unrestricted row count, we need
+                                // to go until the end.
+                                SLICE_ENDED
+                            } else {
+                                break 'chunks;
+                            }
+                        },
+                    }
+                } else {
+                    NO_SLICE
+                };
+
+                let bytes_this_chunk = global_bytes.slice(slice_start..slice_start + position);
+
+                let morsel_seq = *morsel_seq_ref;
+                *morsel_seq_ref = morsel_seq.successor();
+
+                let batch = LineBatch {
+                    bytes: bytes_this_chunk,
+                    n_lines: count,
+                    slice,
+                    row_offset: current_row_offset,
+                    first_line,
+                    morsel_seq,
+                };
+
+                if line_batch_tx.send(batch).await.is_err() {
+                    break 'chunks;
+                }
+            }
+
+            let Some(decompressor) = decompressor.as_mut() else {
+                break;
             };
 
-            if line_batch_tx.send(batch).await.is_err() {
+            let Some(next_chunk) =
+                decompressor.next_chunk(|bytes| line_counter.find_last_line_end(bytes, 1))?
+            else {
                 break;
-            }
+            };
+
+            global_bytes = next_chunk;
+            offset = 0;
         }
 
         Ok(n_rows_skipped)
@@ -593,6 +709,8 @@ struct ChunkReader {
     row_index: Option<RowIndex>,
     // Alternate line counter when there are comments. This is used on empty projection.
     alt_count_lines: Option<Arc<CountLinesWithComments>>,
+    /// Set if rows that fail to parse are rejected, contains the path of the file.
+    rejects_file: Option<Option<String>>,
 }
 
 impl ChunkReader {
@@ -602,7 +720,13 @@ impl ChunkReader {
         projection: Vec<usize>,
         row_index: Option<RowIndex>,
         alt_count_lines: Option<Arc<CountLinesWithComments>>,
+        rejects_file: Option<Option<String>>,
     ) -> PolarsResult<Self> {
+        polars_ensure!(
+            !(rejects_file.is_some() && options.ignore_errors),
+            InvalidOperation: "rejected rows cannot be captured when 'ignore_errors' is set"
+        );
+
         let mut fields_to_cast: Vec<Field> = options.fields_to_cast.clone();
         let has_categorical = prepare_csv_schema(&mut reader_schema, &mut fields_to_cast)?;
 
@@ -634,10 +758,12 @@ impl ChunkReader {
             validate_utf8,
             row_index,
             alt_count_lines,
+            rejects_file,
         })
     }
 
-    /// The 2nd return value indicates how many rows exist in the chunk.
+    /// The 2nd return value indicates how many rows exist in the chunk, the 3rd contains the
+    /// rejected rows if rows that fail to parse are rejected.
     fn read_chunk(
         &self,
         chunk: &[u8],
@@ -645,13 +771,54 @@ impl ChunkReader {
         n_lines: usize,
         slice: (usize, usize),
         chunk_row_offset: usize,
-    ) -> PolarsResult<(DataFrame, usize)> {
-        if self.validate_utf8 && !validate_utf8(chunk) {
+        first_line: usize,
+    ) -> PolarsResult<(DataFrame, usize, Option<DataFrame>)> {
+        let is_valid_utf8 = !self.validate_utf8 || validate_utf8(chunk);
+        if !is_valid_utf8 && self.rejects_file.is_none() {
             polars_bail!(ComputeError: "invalid utf-8 sequence")
         }
 
-        // If projection is empty create a DataFrame with the correct height by counting the lines.
-        let mut df = if self.projection.is_empty() {
+        let mut rejected = None;
+
+        let mut df = if let Some(rejects_file) = &self.rejects_file {
+            // All columns are parsed to find the rows that fail to parse.
+            let mut rejects = ChunkRejects::new(!is_valid_utf8);
+            let all_columns: Vec<usize> = (0..self.reader_schema.len()).collect();
+            let mut df = read_chunk(
+                chunk,
+                &self.parse_options,
+                &self.reader_schema,
+                self.ignore_errors,
+                &all_columns,
+                0,       // bytes_offset_thread
+                n_lines, // capacity
+                self.null_values.as_ref(),
+                usize::MAX,  // chunk_size
+                chunk.len(), // stop_at_nbytes
+                Some(0),     // starting_point_offset
+                Some(&mut rejects),
+            )?;
+
+            rejected = Some(rejects.finish(
+                &mut df,
+                &self.fields_to_cast,
+                chunk,
+                self.parse_options.eol_char,
+                first_line,
+                rejects_file.as_deref(),
+            )?);
+
+            if self.projection.is_empty() {
+                DataFrame::empty_with_height(df.height())
+            } else {
+                df.select(
+                    self.projection
+                        .iter()
+                        .map(|&i| self.reader_schema.get_at_index(i).unwrap().0.clone()),
+                )?
+            }
+        } else if self.projection.is_empty() {
+            // If projection is empty create a DataFrame with the correct height by counting the lines.
             let h = if let Some(v) = &self.alt_count_lines {
                 v.count_lines(chunk)?
             } else {
@@ -672,6 +839,7 @@ impl ChunkReader {
                 usize::MAX,  // chunk_size
                 chunk.len(), // stop_at_nbytes
                 Some(0),     // starting_point_offset
+                None,        // rejects
             )?
         };
 
@@ -685,7 +853,10 @@ impl ChunkReader {
             df = df.slice(i64::try_from(slice.0).unwrap(), slice.1);
         }
 
-        cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+        // Rejected rows have been cast already.
+        if rejected.is_none() {
+            cast_columns(&mut df, &self.fields_to_cast, false, self.ignore_errors)?;
+        }
 
         if let Some(ri) = &self.row_index {
             assert!(n_lines_is_correct);
@@ -700,13 +871,14 @@ impl ChunkReader {
             }
         }
 
-        Ok((df, height))
+        Ok((df, height, rejected))
     }
 }
 
 struct CountLinesWithComments {
     quote_char: Option<u8>,
     eol_char: u8,
+    escape_char: Option<u8>,
     comment_prefix: CommentPrefix,
 }
 
@@ -718,6 +890,7 @@ impl CountLinesWithComments {
             .map(|comment_prefix| CountLinesWithComments {
                 quote_char: parse_options.quote_char,
                 eol_char: parse_options.eol_char,
+                escape_char: parse_options.escape_char,
                 comment_prefix,
             })
     }
@@ -729,6 +902,7 @@ impl CountLinesWithComments {
             Some(&self.comment_prefix),
             self.eol_char,
             false, // has_header
+            self.escape_char,
         )
     }
 }
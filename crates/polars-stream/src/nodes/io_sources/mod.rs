@@ -1,5 +1,8 @@
 pub mod multi_file_reader;
 
+pub mod anonymous;
+#[cfg(feature = "avro")]
+pub mod avro;
 pub mod batch;
 #[cfg(feature = "csv")]
 pub mod csv;
@@ -1,6 +1,7 @@
 use polars_core::config;
 use polars_error::PolarsResult;
 use polars_io::prelude::json_lines;
+use polars_io::utils::compression::StreamingDecompressor;
 use polars_utils::idx_mapper::IdxMapper;
 use polars_utils::mmap::MemSlice;
 
@@ -9,6 +10,9 @@ use crate::async_primitives::distributor_channel;
 
 pub(super) struct LineBatchDistributor {
     pub(super) global_bytes: MemSlice,
+    /// Decompresses the data after `global_bytes` for compressed files. This is never set when
+    /// `reverse` is true.
+    pub(super) decompressor: Option<StreamingDecompressor>,
     pub(super) chunk_size: usize,
     pub(super) n_rows_to_skip: usize,
     pub(super) reverse: bool,
@@ -19,123 +23,178 @@ impl LineBatchDistributor {
     /// Returns the number of rows skipped (i.e. were not sent to LineBatchProcessors).
     pub(super) async fn run(self) -> PolarsResult<usize> {
         let LineBatchDistributor {
-            global_bytes: global_bytes_mem_slice,
+            global_bytes,
+            mut decompressor,
             chunk_size,
             n_rows_to_skip,
             reverse,
             mut line_batch_distribute_tx,
         } = self;
 
-        // Safety: All receivers (LineBatchProcessors) hold a MemSlice ref to this.
-        let global_bytes: &'static [u8] =
-            unsafe { std::mem::transmute(global_bytes_mem_slice.as_ref()) };
-        let n_chunks = global_bytes.len().div_ceil(chunk_size);
+        assert!(!(reverse && decompressor.is_some()));
+
         let verbose = config::verbose();
 
-        if verbose {
-            eprintln!(
-                "\
-                [NDJSON LineBatchDistributor]: \
-                global_bytes.len(): {} \
-                chunk_size: {} \
-                n_chunks: {} \
-                n_rows_to_skip: {} \
-                reverse: {} \
-                ",
-                global_bytes.len(),
+        let mut row_skipper = RowSkipper {
+            remaining_rows_to_skip: n_rows_to_skip,
+            reverse,
+        };
+
+        // For compressed files, `global_bytes` is a decompressed chunk that ends at a line end
+        // (except for the last chunk). Every chunk is distributed before decompressing the next
+        // one.
+        let mut global_bytes = global_bytes;
+        let mut chunk_idx_offset: usize = 0;
+
+        loop {
+            let Some(n_chunks) = distribute_chunks(
+                &global_bytes,
                 chunk_size,
-                n_chunks,
-                n_rows_to_skip,
-                reverse
+                chunk_idx_offset,
+                reverse,
+                &mut row_skipper,
+                &mut line_batch_distribute_tx,
+                verbose,
             )
+            .await
+            else {
+                break;
+            };
+
+            chunk_idx_offset += n_chunks;
+
+            let Some(decompressor) = decompressor.as_mut() else {
+                break;
+            };
+
+            let Some(next_chunk) = decompressor
+                .next_chunk(|bytes| bytes.iter().rposition(|&c| c == b'\n').map(|i| i + 1))?
+            else {
+                break;
+            };
+
+            global_bytes = next_chunk;
         }
 
-        // The logic below processes in fixed chunks with remainder handling so that in the future
-        // we can handle receiving data in a batched manner.
+        if verbose {
+            eprintln!("[NDJSON LineBatchDistributor]: returning");
+        }
 
-        let mut prev_remainder: &'static [u8] = &[];
+        let n_rows_skipped = n_rows_to_skip - row_skipper.remaining_rows_to_skip;
 
-        let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
+        Ok(n_rows_skipped)
+    }
+}
 
-        let mut row_skipper = RowSkipper {
-            remaining_rows_to_skip: n_rows_to_skip,
-            reverse,
+/// Splits `global_bytes_mem_slice` into line batches and sends them. Returns the number of chunks,
+/// or `None` if the receivers have disconnected.
+async fn distribute_chunks(
+    global_bytes_mem_slice: &MemSlice,
+    chunk_size: usize,
+    chunk_idx_offset: usize,
+    reverse: bool,
+    row_skipper: &mut RowSkipper,
+    line_batch_distribute_tx: &mut distributor_channel::Sender<LineBatch>,
+    verbose: bool,
+) -> Option<usize> {
+    let global_bytes: &[u8] = global_bytes_mem_slice.as_ref();
+    let n_chunks = global_bytes.len().div_ceil(chunk_size);
+
+    if verbose {
+        eprintln!(
+            "\
+            [NDJSON LineBatchDistributor]: \
+            global_bytes.len(): {} \
+            chunk_size: {} \
+            n_chunks: {} \
+            n_rows_to_skip: {} \
+            reverse: {} \
+            ",
+            global_bytes.len(),
+            chunk_size,
+            n_chunks,
+            row_skipper.remaining_rows_to_skip,
+            reverse
+        )
+    }
+
+    // The logic below processes in fixed chunks with remainder handling so that in the future
+    // we can handle receiving data in a batched manner.
+
+    let mut prev_remainder: &[u8] = &[];
+
+    let global_idx_map = IdxMapper::new(global_bytes.len(), reverse);
+
+    for chunk_idx in 0..n_chunks {
+        let offset = chunk_idx.saturating_mul(chunk_size);
+        let range = offset..offset.saturating_add(chunk_size).min(global_bytes.len());
+        let range = global_idx_map.map_range(range);
+
+        let chunk = &global_bytes[range];
+
+        // Split off the chunk occurring after the last newline char.
+        let chunk_remainder = if chunk_idx == n_chunks - 1 {
+            // Last chunk, send everything.
+            &[]
+        } else if reverse {
+            // Remainder is on the left because we are parsing lines in reverse:
+            // chunk:     ---\n---------
+            // remainder: ---
+            &chunk[..chunk.split(|&c| c == b'\n').next().unwrap().len()]
+        } else {
+            // chunk:     ---------\n---
+            // remainder:            ---
+            &chunk[chunk.len() - chunk.rsplit(|&c| c == b'\n').next().unwrap().len()..]
         };
 
-        for chunk_idx in 0..n_chunks {
-            let offset = chunk_idx.saturating_mul(chunk_size);
-            let range = offset..offset.saturating_add(chunk_size).min(global_bytes.len());
-            let range = global_idx_map.map_range(range);
+        let n_chars_without_remainder = chunk.len() - chunk_remainder.len();
 
-            let chunk = &global_bytes[range];
+        if n_chars_without_remainder > 0 {
+            let range = 0..n_chars_without_remainder;
+            let range = IdxMapper::new(chunk.len(), reverse).map_range(range);
 
-            // Split off the chunk occurring after the last newline char.
-            let chunk_remainder = if chunk_idx == n_chunks - 1 {
-                // Last chunk, send everything.
-                &[]
+            let full_chunk = &chunk[range];
+
+            let mut full_chunk = if prev_remainder.is_empty() {
+                full_chunk
             } else if reverse {
-                // Remainder is on the left because we are parsing lines in reverse:
-                // chunk:     ---\n---------
-                // remainder: ---
-                &chunk[..chunk.split(|&c| c == b'\n').next().unwrap().len()]
+                unsafe { merge_adjacent_non_empty_slices(full_chunk, prev_remainder) }
             } else {
-                // chunk:     ---------\n---
-                // remainder:            ---
-                &chunk[chunk.len() - chunk.rsplit(|&c| c == b'\n').next().unwrap().len()..]
+                unsafe { merge_adjacent_non_empty_slices(prev_remainder, full_chunk) }
             };
 
-            let n_chars_without_remainder = chunk.len() - chunk_remainder.len();
-
-            if n_chars_without_remainder > 0 {
-                let range = 0..n_chars_without_remainder;
-                let range = IdxMapper::new(chunk.len(), reverse).map_range(range);
-
-                let full_chunk = &chunk[range];
-
-                let mut full_chunk = if prev_remainder.is_empty() {
-                    full_chunk
-                } else if reverse {
-                    unsafe { merge_adjacent_non_empty_slices(full_chunk, prev_remainder) }
-                } else {
-                    unsafe { merge_adjacent_non_empty_slices(prev_remainder, full_chunk) }
-                };
-
-                prev_remainder = &[];
-                row_skipper.skip_rows(&mut full_chunk);
-
-                if !full_chunk.is_empty()
-                    && line_batch_distribute_tx
-                        .send(LineBatch {
-                            bytes: full_chunk,
-                            chunk_idx,
-                        })
-                        .await
-                        .is_err()
+            prev_remainder = &[];
+            row_skipper.skip_rows(&mut full_chunk);
+
+            if !full_chunk.is_empty() {
+                let start = full_chunk.as_ptr() as usize - global_bytes.as_ptr() as usize;
+
+                if line_batch_distribute_tx
+                    .send(LineBatch {
+                        bytes: global_bytes_mem_slice.slice(start..start + full_chunk.len()),
+                        chunk_idx: chunk_idx_offset + chunk_idx,
+                    })
+                    .await
+                    .is_err()
                 {
-                    break;
+                    return None;
                 }
             }
-
-            // Note: If `prev_remainder` is non-empty at this point, it means the entire current
-            // chunk does not contain a newline.
-            prev_remainder = if prev_remainder.is_empty() {
-                chunk_remainder
-            } else if reverse {
-                // Current chunk comes before the previous remainder in memory when reversed.
-                unsafe { merge_adjacent_non_empty_slices(chunk_remainder, prev_remainder) }
-            } else {
-                unsafe { merge_adjacent_non_empty_slices(prev_remainder, chunk_remainder) }
-            };
-        }
-
-        if verbose {
-            eprintln!("[NDJSON LineBatchDistributor]: returning");
         }
 
-        let n_rows_skipped = n_rows_to_skip - row_skipper.remaining_rows_to_skip;
-
-        Ok(n_rows_skipped)
+        // Note: If `prev_remainder` is non-empty at this point, it means the entire current
+        // chunk does not contain a newline.
+        prev_remainder = if prev_remainder.is_empty() {
+            chunk_remainder
+        } else if reverse {
+            // Current chunk comes before the previous remainder in memory when reversed.
+            unsafe { merge_adjacent_non_empty_slices(chunk_remainder, prev_remainder) }
+        } else {
+            unsafe { merge_adjacent_non_empty_slices(prev_remainder, chunk_remainder) }
+        };
     }
+
+    Some(n_chunks)
 }
 
 struct RowSkipper {
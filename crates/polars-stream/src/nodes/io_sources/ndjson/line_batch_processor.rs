@@ -19,9 +19,6 @@ pub(super) struct LineBatchProcessor {
     /// Mainly for logging
     pub(super) worker_idx: usize,
 
-    /// We need to hold a ref to this as `LineBatch` we receive contains `&[u8]`
-    /// references to it.
-    pub(super) global_bytes: MemSlice,
     pub(super) chunk_reader: Arc<ChunkReader>,
 
     // Input
@@ -41,7 +38,6 @@ impl LineBatchProcessor {
     pub(super) async fn run(self) -> PolarsResult<usize> {
         let LineBatchProcessor {
             worker_idx,
-            global_bytes: _global_bytes,
             chunk_reader,
             mut line_batch_rx,
             mut output_port,
@@ -60,7 +56,7 @@ impl LineBatchProcessor {
         let mut n_rows_processed: usize = 0;
 
         while let Ok(LineBatch { bytes, chunk_idx }) = line_batch_rx.recv().await {
-            let df = chunk_reader.read_chunk(bytes)?;
+            let df = chunk_reader.read_chunk(&bytes)?;
 
             n_rows_processed = n_rows_processed.saturating_add(df.height());
 
@@ -81,7 +77,7 @@ impl LineBatchProcessor {
                 chunk_idx: _,
             }) = line_batch_rx.recv().await
             {
-                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(bytes));
+                n_rows_processed = n_rows_processed.saturating_add(ndjson::count_rows(&bytes));
             }
         }
 
@@ -95,8 +91,7 @@ impl LineBatchProcessor {
 
 /// Represents a complete chunk of NDJSON data (i.e. no partial lines).
 pub(super) struct LineBatch {
-    /// Safety: This is sent between 2 places that both hold a reference to the underlying MemSlice.
-    pub(super) bytes: &'static [u8],
+    pub(super) bytes: MemSlice,
     pub(super) chunk_idx: usize,
 }
 
@@ -12,7 +12,7 @@ use polars_core::schema::SchemaRef;
 use polars_error::{PolarsResult, polars_bail, polars_err};
 use polars_io::cloud::CloudOptions;
 use polars_io::prelude::estimate_n_lines_in_file;
-use polars_io::utils::compression::maybe_decompress_bytes;
+use polars_io::utils::compression::{StreamingDecompressor, maybe_decompress_bytes};
 use polars_plan::dsl::{NDJsonReadOptions, ScanSource};
 use polars_utils::IdxSize;
 use polars_utils::mem::prefetch::get_memory_prefetch_func;
@@ -79,9 +79,14 @@ impl FileReader for NDJsonFileReader {
             panic!("unsupported args: {:?}", &args)
         };
 
-        // TODO: This currently downloads and decompresses everything upfront in a blocking manner.
-        // Ideally we have a streaming download/decompression.
-        let global_bytes = self.get_bytes_maybe_decompress()?;
+        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
+
+        // TODO: This currently downloads everything upfront in a blocking manner. Ideally we have
+        // a streaming download.
+        //
+        // For compressed files this is only the first decompressed chunk, unless we have a negative
+        // slice which needs to read the lines in reverse.
+        let (global_bytes, decompressor) = self.get_bytes_maybe_decompress(!is_negative_slice)?;
 
         // NDJSON: We just use the projected schema - the parser will automatically append NULL if
         // the field is not found.
@@ -96,8 +101,6 @@ impl FileReader for NDJsonFileReader {
             _ = tx.try_send(schema.clone())
         }
 
-        let is_negative_slice = matches!(pre_slice, Some(Slice::Negative { .. }));
-
         // Convert (offset, len) to Range
         // Note: This is converted to right-to-left for negative slice (i.e. range.start is position
         // from end).
@@ -307,7 +310,6 @@ impl FileReader for NDJsonFileReader {
             .enumerate()
             .rev()
             .map(|(worker_idx, line_batch_rx)| {
-                let global_bytes = global_bytes.clone();
                 let chunk_reader = chunk_reader.clone();
                 // Note: We don't use this (it is handled by the bridge). But morsels require a source token.
                 let source_token = SourceToken::new();
@@ -317,7 +319,6 @@ impl FileReader for NDJsonFileReader {
                     LineBatchProcessor {
                         worker_idx,
 
-                        global_bytes,
                         chunk_reader,
 
                         line_batch_rx,
@@ -345,6 +346,7 @@ impl FileReader for NDJsonFileReader {
             TaskPriority::Low,
             line_batch_distributor::LineBatchDistributor {
                 global_bytes,
+                decompressor,
                 chunk_size,
                 n_rows_to_skip,
                 reverse: is_negative_slice,
@@ -438,7 +440,12 @@ impl NDJsonFileReader {
         ChunkReader::try_new(&self.options, schema)
     }
 
-    fn get_bytes_maybe_decompress(&mut self) -> PolarsResult<MemSlice> {
+    /// Returns the bytes of the file. For compressed files with `streaming_decompression`, this
+    /// is the first decompressed chunk along with the decompressor for the remaining data.
+    fn get_bytes_maybe_decompress(
+        &mut self,
+        streaming_decompression: bool,
+    ) -> PolarsResult<(MemSlice, Option<StreamingDecompressor>)> {
         if self.cached_bytes.is_none() {
             let run_async = self.scan_source.run_async();
             let source = self
@@ -446,20 +453,33 @@ impl NDJsonFileReader {
                 .as_scan_source_ref()
                 .to_memslice_async_assume_latest(run_async)?;
 
-            let memslice = {
-                let mut out = vec![];
-                maybe_decompress_bytes(&source, &mut out)?;
+            self.cached_bytes = Some(source);
+        }
 
-                if out.is_empty() {
-                    source
-                } else {
-                    MemSlice::from_vec(out)
+        let source = self.cached_bytes.clone().unwrap();
+
+        if streaming_decompression {
+            if let Some(mut decompressor) = StreamingDecompressor::try_new(source.clone())? {
+                if self.verbose {
+                    eprintln!("[NDJsonFileReader]: streaming decompression");
                 }
-            };
 
-            self.cached_bytes = Some(memslice);
+                let first_chunk = decompressor
+                    .next_chunk(|bytes| bytes.iter().rposition(|&c| c == b'\n').map(|i| i + 1))?
+                    .unwrap_or_default();
+
+                return Ok((first_chunk, Some(decompressor)));
+            }
+        }
+
+        let mut out = vec![];
+        maybe_decompress_bytes(&source, &mut out)?;
+
+        if !out.is_empty() {
+            // Cache the decompressed bytes, we may be called again.
+            self.cached_bytes = Some(MemSlice::from_vec(out));
         }
 
-        Ok(self.cached_bytes.clone().unwrap())
+        Ok((self.cached_bytes.clone().unwrap(), None))
     }
 }
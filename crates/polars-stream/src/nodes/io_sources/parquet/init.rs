@@ -4,15 +4,21 @@ use std::sync::Arc;
 
 use arrow::datatypes::ArrowDataType;
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{Column, DataType, IDX_DTYPE, IntoColumn};
+use polars_core::prelude::{Column, DataType, IDX_DTYPE, IntoColumn, PlHashMap};
 use polars_core::series::Series;
-use polars_core::utils::arrow::bitmap::Bitmap;
-use polars_core::utils::arrow::datatypes::ArrowSchemaRef;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
+use polars_core::utils::arrow::datatypes::{ArrowSchema, ArrowSchemaRef};
 use polars_error::{PolarsResult, polars_ensure};
 use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
-use polars_io::prelude::_internal::{PrefilterMaskSetting, collect_statistics_with_live_columns};
+use polars_io::prelude::_internal::{
+    PrefilterMaskSetting, bloom_filter_hashes, bloom_filter_may_contain,
+    collect_statistics_with_live_columns, single_column_chunk,
+};
 use polars_io::prelude::{FileMetadata, ParallelStrategy};
+use polars_io::utils::byte_source::{ByteSource, DynByteSource};
+use polars_parquet::parquet::bloom_filter::deserialize_header;
+use polars_utils::pl_str::PlSmallStr;
 use polars_utils::{IdxSize, format_pl_smallstr};
 
 use super::row_group_data_fetch::RowGroupDataFetcher;
@@ -146,6 +152,138 @@ async fn calculate_row_group_pred_pushdown_skip_mask(
     Ok(Some(skip_row_group_mask))
 }
 
+/// Upper bound on the size of a bloom filter header. Used to read the header of bloom filters
+/// whose length is not stored in the metadata.
+const BLOOM_FILTER_HEADER_SIZE_ESTIMATE: usize = 64;
+
+/// Number of bloom filters that are fetched at once.
+const BLOOM_FILTER_FETCH_BATCH_SIZE: usize = 64;
+
+/// Extends `skip_row_group_mask` with the row groups whose bloom filters show that they contain
+/// none of the values of an equality or `is_in` predicate.
+#[allow(clippy::too_many_arguments)]
+async fn calculate_row_group_bloom_filter_skip_mask(
+    row_group_slice: Range<usize>,
+    use_statistics: bool,
+    predicate: Option<&ScanIOPredicate>,
+    metadata: &FileMetadata,
+    reader_schema: &ArrowSchema,
+    byte_source: &DynByteSource,
+    skip_row_group_mask: Option<Bitmap>,
+    verbose: bool,
+) -> PolarsResult<Option<Bitmap>> {
+    let Some(predicate) = predicate.filter(|_| use_statistics) else {
+        return Ok(skip_row_group_mask);
+    };
+    let equality_sets = &predicate.column_predicates.equality_sets;
+    if equality_sets.is_empty() {
+        return Ok(skip_row_group_mask);
+    }
+
+    let num_row_groups = row_group_slice.len();
+    let row_groups = &metadata.row_groups[row_group_slice];
+
+    // The hashes only depend on the type of the column, so they are computed once per column.
+    let mut column_hashes = PlHashMap::<&PlSmallStr, Option<Vec<u64>>>::default();
+    // (row group, column, bloom filter offset, bloom filter length)
+    let mut probes = Vec::new();
+    for (i, rg) in row_groups.iter().enumerate() {
+        if skip_row_group_mask.as_ref().is_some_and(|m| m.get_bit(i)) {
+            continue;
+        }
+
+        for (name, values) in equality_sets.iter() {
+            let Some(field) = reader_schema.get(name) else {
+                continue;
+            };
+            let Some(column) = single_column_chunk(rg, name) else {
+                continue;
+            };
+            let Some(offset) = column.metadata().bloom_filter_offset else {
+                continue;
+            };
+
+            if !column_hashes.contains_key(name) {
+                column_hashes.insert(name, bloom_filter_hashes(values, field, column)?);
+            }
+            if column_hashes[name].is_some() {
+                let length = column.metadata().bloom_filter_length;
+                probes.push((i, name, offset as usize, length.map(|l| l as usize)));
+            }
+        }
+    }
+
+    if probes.is_empty() {
+        return Ok(skip_row_group_mask);
+    }
+
+    let mut skip_mask = skip_row_group_mask.map_or_else(
+        || MutableBitmap::from_len_zeroed(num_row_groups),
+        Bitmap::make_mut,
+    );
+    let num_skipped_by_statistics = skip_mask.set_bits();
+
+    let mut file_size = None;
+    for probes in probes.chunks_mut(BLOOM_FILTER_FETCH_BATCH_SIZE) {
+        // Read the headers of the bloom filters without a stored length to find their length.
+        let mut header_ranges = Vec::new();
+        for (_, _, offset, length) in probes.iter() {
+            if length.is_none() {
+                let file_size = match file_size {
+                    Some(file_size) => file_size,
+                    None => *file_size.insert(byte_source.get_size().await?),
+                };
+                header_ranges
+                    .push(*offset..(*offset + BLOOM_FILTER_HEADER_SIZE_ESTIMATE).min(file_size));
+            }
+        }
+        if !header_ranges.is_empty() {
+            let headers = byte_source.get_ranges(&mut header_ranges).await?;
+            for (_, _, offset, length) in probes.iter_mut() {
+                if length.is_none() {
+                    if let Some((header_len, num_bytes)) = deserialize_header(&headers[offset])? {
+                        *length = Some(header_len + num_bytes);
+                    }
+                }
+            }
+        }
+
+        let mut ranges = probes
+            .iter()
+            .filter_map(|(_, _, offset, length)| Some(*offset..*offset + (*length)?))
+            .collect::<Vec<_>>();
+        let bloom_filters = byte_source.get_ranges(&mut ranges).await?;
+
+        for (i, name, offset, _) in probes.iter() {
+            let Some(bytes) = bloom_filters.get(offset) else {
+                continue;
+            };
+            let Some((header_len, num_bytes)) = deserialize_header(bytes)? else {
+                continue;
+            };
+            let Some(bitset) = bytes.get(header_len..header_len + num_bytes) else {
+                continue;
+            };
+
+            let hashes = column_hashes[name].as_deref().unwrap();
+            if !bloom_filter_may_contain(bitset, hashes) {
+                skip_mask.set(*i, true);
+            }
+        }
+    }
+
+    if verbose {
+        eprintln!(
+            "[ParquetFileReader]: Bloom filter pushdown: \
+                                skipping {} / {} row groups",
+            skip_mask.set_bits() - num_skipped_by_statistics,
+            num_row_groups - num_skipped_by_statistics,
+        );
+    }
+
+    Ok(Some(skip_mask.freeze()))
+}
+
 impl ParquetReadImpl {
     /// Constructs the task that distributes morsels across the engine pipelines.
     #[allow(clippy::type_complexity)]
@@ -254,14 +392,30 @@ impl ParquetReadImpl {
                 predicate.as_ref(),
                 &metadata,
                 &reader_schema,
-                row_index,
+                row_index.clone(),
+                verbose,
+            )
+            .await?;
+
+            let row_group_mask = calculate_row_group_bloom_filter_skip_mask(
+                row_group_slice.clone(),
+                use_statistics,
+                predicate.as_ref(),
+                &metadata,
+                &reader_schema,
+                &byte_source,
+                row_group_mask,
                 verbose,
             )
             .await?;
 
             let mut row_group_data_fetcher = RowGroupDataFetcher {
                 projection,
+                reader_schema,
                 predicate,
+                use_statistics,
+                row_index,
+                verbose,
                 slice_range,
                 memory_prefetch_func,
                 metadata,
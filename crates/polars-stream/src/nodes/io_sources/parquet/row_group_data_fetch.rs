@@ -1,15 +1,24 @@
 use std::ops::Range;
 use std::sync::Arc;
 
-use arrow::datatypes::ArrowSchemaRef;
-use polars_core::prelude::PlHashMap;
+use arrow::datatypes::{ArrowSchema, ArrowSchemaRef};
+use polars_core::prelude::{PlHashMap, PlHashSet};
 use polars_core::series::IsSorted;
-use polars_core::utils::arrow::bitmap::Bitmap;
+use polars_core::utils::arrow::bitmap::{Bitmap, MutableBitmap};
 use polars_error::PolarsResult;
+use polars_io::RowIndex;
 use polars_io::predicates::ScanIOPredicate;
+use polars_io::prelude::_internal::{
+    ColumnPageIndex, calculate_page_row_ranges, single_column_chunk,
+};
 use polars_io::prelude::{FileMetadata, create_sorting_map};
 use polars_io::utils::byte_source::{ByteSource, DynByteSource};
-use polars_parquet::read::RowGroupMetadata;
+use polars_parquet::read::indexes::{
+    OffsetIndex, deserialize_column_index, deserialize_offset_index, page_byte_ranges,
+    page_row_ranges,
+};
+use polars_parquet::read::{PageMetaData, RowGroupMetadata};
+use polars_utils::IdxSize;
 use polars_utils::mmap::MemSlice;
 use polars_utils::pl_str::PlSmallStr;
 
@@ -22,12 +31,33 @@ pub(super) struct RowGroupData {
     pub(super) slice: Option<(usize, usize)>,
     pub(super) row_group_metadata: RowGroupMetadata,
     pub(super) sorting_map: PlHashMap<usize, IsSorted>,
+    pub(super) page_selection: Option<PageSelection>,
+}
+
+/// The rows of a row group that are left after evaluating the predicate on the page index.
+pub(super) struct PageSelection {
+    /// Rows of the row group that may match the predicate.
+    pub(super) rows: Bitmap,
+    /// Column chunks of which only the pages holding selected rows were fetched, keyed by the
+    /// start of the column chunk in the file.
+    pub(super) pruned_columns: PlHashMap<u64, PrunedColumnChunk>,
+}
+
+/// The dictionary page and the selected data pages of a column chunk.
+pub(super) struct PrunedColumnChunk {
+    pub(super) page_meta: PageMetaData,
+    pub(super) bytes: MemSlice,
+    /// Selected rows out of the rows in the fetched data pages.
+    pub(super) rows: Bitmap,
 }
 
 pub(super) struct RowGroupDataFetcher {
     pub(super) projection: Option<ArrowSchemaRef>,
-    #[allow(unused)] // TODO: Fix!
+    pub(super) reader_schema: ArrowSchemaRef,
     pub(super) predicate: Option<ScanIOPredicate>,
+    pub(super) use_statistics: bool,
+    pub(super) row_index: Option<RowIndex>,
+    pub(super) verbose: bool,
     pub(super) slice_range: Option<Range<usize>>,
     pub(super) memory_prefetch_func: fn(&[u8]) -> (),
     pub(super) metadata: Arc<FileMetadata>,
@@ -76,6 +106,21 @@ impl RowGroupDataFetcher {
                 }
             }
 
+            // Pages can only be skipped if the whole row group is read.
+            let page_predicate = self.predicate.clone().filter(|p| {
+                self.use_statistics
+                    && p.skip_batch_predicate.is_some()
+                    && slice.is_none_or(|(offset, len)| offset == 0 && len >= num_rows)
+            });
+            let row_index = self.row_index.as_ref().map(|ri| {
+                let offset = ri
+                    .offset
+                    .saturating_add(IdxSize::try_from(current_row_offset).unwrap_or(IdxSize::MAX));
+                (ri.name.clone(), offset)
+            });
+            let reader_schema = self.reader_schema.clone();
+            let verbose = self.verbose;
+
             let metadata = self.metadata.clone();
             let current_byte_source = self.byte_source.clone();
             let projection = self.projection.clone();
@@ -84,6 +129,33 @@ impl RowGroupDataFetcher {
 
             let handle = io_runtime.spawn(async move {
                 let row_group_metadata = &metadata.row_groups[idx];
+
+                let page_selection = if let Some(predicate) = page_predicate {
+                    fetch_page_selection(
+                        current_byte_source.as_ref(),
+                        row_group_metadata,
+                        projection.as_deref().unwrap_or(reader_schema.as_ref()),
+                        reader_schema.as_ref(),
+                        &predicate,
+                        row_index.as_ref().map(|(name, offset)| (name, *offset)),
+                    )
+                    .await?
+                } else {
+                    None
+                };
+
+                if verbose {
+                    if let Some(page_selection) = &page_selection {
+                        eprintln!(
+                            "[ParquetFileReader]: Page index pushdown: \
+                                reading {} / {} rows of row group {}",
+                            page_selection.rows.set_bits(),
+                            num_rows,
+                            idx
+                        );
+                    }
+                }
+
                 let fetched_bytes =
                     if let DynByteSource::MemSlice(mem_slice) = current_byte_source.as_ref() {
                         // Skip byte range calculation for `no_prefetch`.
@@ -115,6 +187,24 @@ impl RowGroupDataFetcher {
                             offset: 0,
                             mem_slice,
                         }
+                    } else if let Some(page_selection) = page_selection.as_ref() {
+                        // The pruned column chunks already hold their bytes.
+                        let columns = projection.as_deref().unwrap_or(reader_schema.as_ref());
+                        let mut ranges = get_row_group_byte_ranges_for_projection(
+                            row_group_metadata,
+                            &mut columns.iter_names(),
+                        )
+                        .filter(|range| {
+                            page_selection.rows.set_bits() > 0
+                                && !page_selection
+                                    .pruned_columns
+                                    .contains_key(&(range.start as u64))
+                        })
+                        .collect::<Vec<_>>();
+
+                        let bytes_map = current_byte_source.get_ranges(&mut ranges).await?;
+
+                        FetchedBytes::BytesMap(bytes_map)
                     } else if let Some(columns) = projection.as_ref() {
                         let mut ranges = get_row_group_byte_ranges_for_projection(
                             row_group_metadata,
@@ -156,6 +246,7 @@ impl RowGroupDataFetcher {
                     // @TODO: Remove clone
                     row_group_metadata: row_group_metadata.clone(),
                     sorting_map,
+                    page_selection,
                 })
             });
 
@@ -206,3 +297,214 @@ fn get_row_group_byte_ranges_for_projection<'a>(
             })
     })
 }
+
+/// Evaluates the predicate on the page index of the row group and fetches the pages that hold
+/// rows that may match. Returns `None` if no pages can be skipped.
+async fn fetch_page_selection(
+    byte_source: &DynByteSource,
+    row_group_metadata: &RowGroupMetadata,
+    projection: &ArrowSchema,
+    reader_schema: &ArrowSchema,
+    predicate: &ScanIOPredicate,
+    row_index: Option<(&PlSmallStr, IdxSize)>,
+) -> PolarsResult<Option<PageSelection>> {
+    let num_rows = row_group_metadata.num_rows();
+
+    // Fetch the column indexes of the live columns and the offset indexes of both the live and
+    // the projected columns.
+    let mut index_ranges = PlHashSet::default();
+    for name in predicate.live_columns.iter() {
+        if let Some(column) = single_column_chunk(row_group_metadata, name) {
+            if let (Some(ci), Some(oi)) = (
+                column.column_index_byte_range(),
+                column.offset_index_byte_range(),
+            ) {
+                index_ranges.insert(ci.start as usize..ci.end as usize);
+                index_ranges.insert(oi.start as usize..oi.end as usize);
+            }
+        }
+    }
+    if index_ranges.is_empty() {
+        return Ok(None);
+    }
+    for name in projection.iter_names() {
+        if let Some(oi) = single_column_chunk(row_group_metadata, name)
+            .and_then(|column| column.offset_index_byte_range())
+        {
+            index_ranges.insert(oi.start as usize..oi.end as usize);
+        }
+    }
+
+    let mut ranges = index_ranges.into_iter().collect::<Vec<_>>();
+    let index_bytes = byte_source.get_ranges(&mut ranges).await?;
+    let get_index_bytes = |range: std::ops::Range<u64>| &index_bytes[&(range.start as usize)];
+
+    let mut page_indexes = PlHashMap::default();
+    for name in predicate.live_columns.iter() {
+        let Some(column) = single_column_chunk(row_group_metadata, name) else {
+            continue;
+        };
+        let (Some(ci), Some(oi)) = (
+            column.column_index_byte_range(),
+            column.offset_index_byte_range(),
+        ) else {
+            continue;
+        };
+
+        page_indexes.insert(
+            name.clone(),
+            ColumnPageIndex {
+                column_index: deserialize_column_index(get_index_bytes(ci))?,
+                offset_index: deserialize_offset_index(get_index_bytes(oi))?,
+            },
+        );
+    }
+
+    let Some(row_ranges) = calculate_page_row_ranges(
+        row_group_metadata,
+        reader_schema,
+        &predicate.live_columns,
+        &page_indexes,
+        row_index,
+        predicate.skip_batch_predicate.as_deref().unwrap(),
+    )?
+    else {
+        return Ok(None);
+    };
+
+    let mut rows = MutableBitmap::with_capacity(num_rows);
+    for range in row_ranges.iter() {
+        rows.extend_constant(range.start - rows.len(), false);
+        rows.extend_constant(range.len(), true);
+    }
+    rows.extend_constant(num_rows - rows.len(), false);
+    let rows = rows.freeze();
+
+    // Determine which pages to fetch for every projected column that has an offset index. The
+    // bytes before the first data page hold the dictionary page.
+    let mut pruned_columns = Vec::new();
+    let mut page_ranges = Vec::new();
+    if rows.set_bits() > 0 {
+        for name in projection.iter_names() {
+            let Some(column) = single_column_chunk(row_group_metadata, name) else {
+                continue;
+            };
+            let Some(oi) = column.offset_index_byte_range() else {
+                continue;
+            };
+            let offset_index = match page_indexes.get(name) {
+                Some(page_index) => page_index.offset_index.clone(),
+                None => deserialize_offset_index(get_index_bytes(oi))?,
+            };
+            let Some(pruned) = select_pages(column.byte_range(), &offset_index, num_rows, &rows)
+            else {
+                continue;
+            };
+
+            let start = page_ranges.len();
+            page_ranges.extend(pruned.byte_ranges.iter().cloned());
+            pruned_columns.push((column, pruned, start..page_ranges.len()));
+        }
+    }
+
+    let page_bytes = if page_ranges.is_empty() {
+        PlHashMap::default()
+    } else {
+        byte_source.get_ranges(&mut page_ranges.clone()).await?
+    };
+
+    let pruned_columns = pruned_columns
+        .into_iter()
+        .map(|(column, pruned, range_idxs)| {
+            let mut buffers = page_ranges[range_idxs]
+                .iter()
+                .map(|range| page_bytes[&range.start].clone());
+
+            let bytes = if buffers.len() == 1 {
+                buffers.next().unwrap()
+            } else {
+                let mut bytes = Vec::with_capacity(buffers.clone().map(|b| b.len()).sum());
+                buffers.for_each(|b| bytes.extend_from_slice(b.as_ref()));
+                MemSlice::from_vec(bytes)
+            };
+
+            let page_meta = PageMetaData::new(
+                column.byte_range().start,
+                pruned.num_values as i64,
+                column.compression(),
+                column.descriptor().descriptor.clone(),
+            );
+
+            (
+                column.byte_range().start,
+                PrunedColumnChunk {
+                    page_meta,
+                    bytes,
+                    rows: pruned.rows,
+                },
+            )
+        })
+        .collect();
+
+    Ok(Some(PageSelection {
+        rows,
+        pruned_columns,
+    }))
+}
+
+struct SelectedPages {
+    /// Coalesced byte ranges of the dictionary page and the selected data pages.
+    byte_ranges: Vec<std::ops::Range<usize>>,
+    num_values: usize,
+    rows: Bitmap,
+}
+
+/// Selects the data pages of a column chunk that hold any of the `rows`. Returns `None` if all
+/// pages are needed.
+fn select_pages(
+    column_byte_range: std::ops::Range<u64>,
+    offset_index: &OffsetIndex,
+    num_rows: usize,
+    rows: &Bitmap,
+) -> Option<SelectedPages> {
+    let first_page_start = offset_index.page_locations.first()?.offset as u64;
+    if first_page_start < column_byte_range.start {
+        return None;
+    }
+
+    let mut byte_ranges: Vec<std::ops::Range<usize>> = Vec::new();
+    let mut push_range = |range: std::ops::Range<u64>| {
+        let range = range.start as usize..range.end as usize;
+        match byte_ranges.last_mut() {
+            Some(last) if last.end == range.start => last.end = range.end,
+            _ => byte_ranges.push(range),
+        }
+    };
+
+    if first_page_start > column_byte_range.start {
+        push_range(column_byte_range.start..first_page_start);
+    }
+
+    let mut num_values = 0;
+    let mut selected_rows = MutableBitmap::new();
+    let mut num_skipped_pages = 0;
+    for (row_range, byte_range) in
+        page_row_ranges(offset_index, num_rows).zip(page_byte_ranges(offset_index))
+    {
+        let page_rows = rows.clone().sliced(row_range.start, row_range.len());
+        if page_rows.set_bits() == 0 {
+            num_skipped_pages += 1;
+            continue;
+        }
+
+        push_range(byte_range);
+        num_values += row_range.len();
+        selected_rows.extend_from_bitmap(&page_rows);
+    }
+
+    (num_skipped_pages > 0).then(|| SelectedPages {
+        byte_ranges,
+        num_values,
+        rows: selected_rows.freeze(),
+    })
+}
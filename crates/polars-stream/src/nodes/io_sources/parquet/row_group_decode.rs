@@ -13,7 +13,7 @@ use polars_io::predicates::{
     ColumnPredicateExpr, ColumnPredicates, ScanIOPredicate, SpecializedColumnPredicateExpr,
 };
 pub use polars_io::prelude::_internal::PrefilterMaskSetting;
-use polars_io::prelude::_internal::calc_prefilter_cost;
+use polars_io::prelude::_internal::{calc_prefilter_cost, single_column_chunk};
 use polars_io::prelude::try_set_sorted_flag;
 use polars_parquet::read::{Filter, ParquetType, PredicateFilter, PrimitiveLogicalType};
 use polars_utils::IdxSize;
@@ -49,6 +49,7 @@ impl RowGroupDecoder {
 
         if self.use_prefiltered.is_some()
             && row_group_data.slice.is_none()
+            && row_group_data.page_selection.is_none()
             && !self.predicate_arrow_field_indices.is_empty()
         {
             self.row_group_data_to_df_prefiltered(row_group_data).await
@@ -74,19 +75,36 @@ impl RowGroupDecoder {
 
         assert!(slice_range.end <= row_group_data.row_group_metadata.num_rows());
 
+        // Only the rows that are left after evaluating the predicate on the page index are
+        // decoded.
+        let (filter, projection_height) = match &row_group_data.page_selection {
+            None => (Filter::Range(slice_range.clone()), slice_range.len()),
+            Some(page_selection) => (
+                Filter::Mask(page_selection.rows.clone()),
+                page_selection.rows.set_bits(),
+            ),
+        };
+
         if let Some(s) = self.materialize_row_index(row_group_data.as_ref(), slice_range.clone())? {
+            let s = match &row_group_data.page_selection {
+                None => s,
+                Some(page_selection) => s.filter(&BooleanChunked::from_bitmap(
+                    PlSmallStr::EMPTY,
+                    page_selection.rows.clone(),
+                ))?,
+            };
             out_columns.push(s);
         }
 
         let mut decoded_cols = Vec::with_capacity(row_group_data.row_group_metadata.n_columns());
-        self.decode_projected_columns(
-            &mut decoded_cols,
-            &row_group_data,
-            Some(polars_parquet::read::Filter::Range(slice_range.clone())),
-        )
-        .await?;
-
-        let projection_height = slice_range.len();
+        if projection_height > 0 {
+            self.decode_projected_columns(&mut decoded_cols, &row_group_data, Some(filter))
+                .await?;
+        } else {
+            decoded_cols.extend(self.projected_arrow_schema.iter_values().map(|field| {
+                Column::full_null(field.name.clone(), 0, &DataType::from_arrow_field(field))
+            }));
+        }
 
         out_columns.extend(decoded_cols);
 
@@ -242,6 +260,37 @@ fn decode_column(
         ));
     };
 
+    let pruned = row_group_data
+        .page_selection
+        .as_ref()
+        .and_then(|page_selection| {
+            let col_md =
+                single_column_chunk(&row_group_data.row_group_metadata, &arrow_field.name)?;
+            page_selection
+                .pruned_columns
+                .get(&col_md.byte_range().start)
+        });
+    if let Some(pruned) = pruned {
+        // Only the pages that hold selected rows were fetched for this column.
+        let (array, pred_true_mask) =
+            polars_io::prelude::_internal::to_deserializer_with_page_meta(
+                vec![(pruned.page_meta.clone(), pruned.bytes.clone())],
+                arrow_field.clone(),
+                Some(Filter::Mask(pruned.rows.clone())),
+            )?;
+        assert_eq!(array.len(), expected_num_rows);
+
+        let mut series = Series::try_from((arrow_field, array))?;
+        if let Some(col_idxs) = row_group_data
+            .row_group_metadata
+            .columns_idxs_under_root_iter(&arrow_field.name)
+        {
+            try_set_sorted_flag(&mut series, col_idxs[0], &row_group_data.sorting_map);
+        }
+
+        return Ok((series.into_column(), pred_true_mask));
+    }
+
     let columns_to_deserialize = iter
         .map(|col_md| {
             let byte_range = col_md.byte_range();
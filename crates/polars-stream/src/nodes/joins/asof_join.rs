@@ -0,0 +1,602 @@
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+use polars_core::chunked_array::ops::row_encode::encode_rows_vertical_par_unordered;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::arrow::temporal_conversions::MILLISECONDS_IN_DAY;
+use polars_ops::frame::{AsOfOptions, AsofStrategy, DataFrameJoinOps, JoinArgs, JoinType};
+use polars_ops::series::SeriesMethods;
+
+use crate::DEFAULT_DISTRIBUTOR_BUFFER_SIZE;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::distributor_channel::distributor_channel;
+use crate::expression::StreamExpr;
+use crate::morsel::SourceToken;
+use crate::nodes::compute_node_prelude::*;
+
+/// Converts a tolerance given as a duration string to the unit of the key.
+fn resolve_tolerance(options: &mut AsOfOptions, key_dtype: &DataType) -> PolarsResult<()> {
+    let Some(tol) = &options.tolerance_str else {
+        return Ok(());
+    };
+    let duration = polars_time::Duration::try_parse(tol)?;
+    polars_ensure!(
+        duration.months() == 0,
+        ComputeError: "cannot use month offset in timedelta of an asof join; \
+        consider using 4 weeks"
+    );
+    options.tolerance = Some(match key_dtype {
+        DataType::Datetime(tu, _) | DataType::Duration(tu) => AnyValue::from(match tu {
+            TimeUnit::Nanoseconds => duration.duration_ns(),
+            TimeUnit::Microseconds => duration.duration_us(),
+            TimeUnit::Milliseconds => duration.duration_ms(),
+        }),
+        DataType::Date => AnyValue::from((duration.duration_ms() / MILLISECONDS_IN_DAY) as i32),
+        DataType::Time => AnyValue::from(duration.duration_ns()),
+        dt => polars_bail!(
+            InvalidOperation: "can only use timedelta string language with Date/Datetime/Duration/Time dtypes, got {}", dt
+        ),
+    });
+    Ok(())
+}
+
+fn encode_groups(df: &DataFrame, by: &[PlSmallStr]) -> PolarsResult<BinaryOffsetChunked> {
+    encode_rows_vertical_par_unordered(&df.select_columns(by.iter().cloned())?)
+}
+
+/// Checks that the keys of a side are sorted, also with respect to the
+/// previous morsel of that side.
+fn check_sorted(key: &Series, last: &mut Option<Series>) -> PolarsResult<()> {
+    let mut keys = last.clone().unwrap_or_else(|| key.clear());
+    keys.append(key)?;
+    keys.ensure_sorted_arg("asof_join")?;
+    if !key.is_empty() {
+        *last = Some(key.tail(Some(1)));
+    }
+    Ok(())
+}
+
+struct AsOfJoinParams {
+    left_key_selector: StreamExpr,
+    right_key_selector: StreamExpr,
+    /// The arguments of the in-memory join of a left morsel with the right
+    /// rows it can be matched with.
+    args: JoinArgs,
+    strategy: AsofStrategy,
+    left_by: Option<Vec<PlSmallStr>>,
+    right_by: Option<Vec<PlSmallStr>>,
+    /// The tolerance in the physical unit of the key.
+    tolerance: Option<f64>,
+    check_sortedness: bool,
+}
+
+/// The last two distinct keys seen on the right side for a group.
+struct GroupKeys {
+    last: AnyValue<'static>,
+    prev: Option<AnyValue<'static>>,
+}
+
+/// The data we buffer, which is kept between phases.
+struct AsOfJoinBuffers {
+    /// Left morsels and their keys, waiting for the right side to catch up.
+    left: VecDeque<(DataFrame, Series)>,
+    /// The right rows which can still be matched, and their keys and encoded
+    /// groups.
+    right: DataFrame,
+    right_key: Series,
+    right_groups: Option<BinaryOffsetChunked>,
+    /// The last keys per encoded group of the right rows. Without `by` all
+    /// rows are in the empty group.
+    right_group_keys: PlHashMap<Vec<u8>, GroupKeys>,
+    /// The maximum key per encoded group of the left rows dispatched so far.
+    left_group_max: PlHashMap<Vec<u8>, AnyValue<'static>>,
+    left_last: Option<Series>,
+    right_last: Option<Series>,
+}
+
+impl AsOfJoinParams {
+    async fn push_left(
+        &self,
+        df: DataFrame,
+        buffers: &mut AsOfJoinBuffers,
+        state: &ExecutionState,
+    ) -> PolarsResult<()> {
+        let key = self.left_key_selector.evaluate(&df, state).await?;
+        let key = key.take_materialized_series();
+        if self.check_sortedness {
+            check_sorted(&key, &mut buffers.left_last)?;
+        }
+        buffers.left.push_back((df, key));
+        Ok(())
+    }
+
+    async fn push_right(
+        &self,
+        df: DataFrame,
+        buffers: &mut AsOfJoinBuffers,
+        state: &ExecutionState,
+    ) -> PolarsResult<()> {
+        let key = self.right_key_selector.evaluate(&df, state).await?;
+        let key = key.take_materialized_series();
+        if self.check_sortedness {
+            check_sorted(&key, &mut buffers.right_last)?;
+        }
+
+        // Only the new rows are encoded, the index of the buffered rows is
+        // maintained incrementally.
+        let groups = match &self.right_by {
+            Some(right_by) => Some(encode_groups(&df, right_by)?),
+            None => None,
+        };
+        let key = key.rechunk();
+        let mut update = |group: &[u8], value: AnyValue| {
+            if value.is_null() {
+                return;
+            }
+            match buffers.right_group_keys.get_mut(group) {
+                Some(keys) => {
+                    if keys.last != value {
+                        let last = std::mem::replace(&mut keys.last, value.into_static());
+                        keys.prev = Some(last);
+                    }
+                },
+                None => {
+                    let keys = GroupKeys {
+                        last: value.into_static(),
+                        prev: None,
+                    };
+                    buffers.right_group_keys.insert(group.to_vec(), keys);
+                },
+            }
+        };
+        match &groups {
+            Some(groups) => {
+                for (group, value) in groups.into_no_null_iter().zip(key.iter()) {
+                    update(group, value);
+                }
+            },
+            None => {
+                for value in key.iter() {
+                    update(&[], value);
+                }
+            },
+        }
+
+        buffers.right.vstack_mut_owned(df)?;
+        buffers.right_key.append_owned(key)?;
+        if let (Some(right_groups), Some(groups)) = (&mut buffers.right_groups, groups) {
+            right_groups.append_owned(groups)?;
+        }
+        Ok(())
+    }
+
+    /// Calls `f` with the encoded group and the key of every left row, until
+    /// it returns false.
+    fn all_left_rows(
+        &self,
+        left: &DataFrame,
+        left_key: &Series,
+        mut f: impl FnMut(&[u8], AnyValue) -> bool,
+    ) -> PolarsResult<bool> {
+        let left_key = left_key.rechunk();
+        let Some(left_by) = &self.left_by else {
+            return Ok(left_key.iter().all(|key| f(&[], key)));
+        };
+        let left_groups = encode_groups(left, left_by)?;
+        Ok(left_groups
+            .into_no_null_iter()
+            .zip(left_key.iter())
+            .all(|(group, key)| f(group, key)))
+    }
+
+    /// Returns whether the buffered right rows contain all rows the given left
+    /// morsel can be matched with, assuming the right side isn't done yet.
+    ///
+    /// The keys only have to be sorted within each group, so a left row is
+    /// only ready based on the right keys seen for its own group.
+    fn is_ready(
+        &self,
+        left: &DataFrame,
+        left_key: &Series,
+        buffers: &AsOfJoinBuffers,
+    ) -> PolarsResult<bool> {
+        let is_row_ready = |group: &[u8], key: AnyValue| {
+            if key.is_null() {
+                return true;
+            }
+            // Without right rows for the group, any of the rows still to come
+            // may match.
+            let Some(keys) = buffers.right_group_keys.get(group) else {
+                return false;
+            };
+
+            // The rows of the group still to come have a key of at least its
+            // last key, so once that is larger we have all backward matches.
+            let last = &keys.last;
+            if *last <= key {
+                return false;
+            }
+            if let Some(tolerance) = self.tolerance {
+                if let (Some(key), Some(last)) = (key.extract::<f64>(), last.extract::<f64>()) {
+                    if last - key > tolerance {
+                        return true;
+                    }
+                }
+            }
+
+            // A forward match is known once there is a key after it which
+            // isn't the last key, as more rows equal to that may still come. A
+            // nearest match is also known once the previous key is closer than
+            // any key still to come.
+            match (self.strategy, &keys.prev) {
+                (AsofStrategy::Backward, _) => true,
+                (_, Some(prev)) if *prev >= key => true,
+                (AsofStrategy::Nearest, Some(prev)) => {
+                    match (
+                        key.extract::<f64>(),
+                        prev.extract::<f64>(),
+                        last.extract::<f64>(),
+                    ) {
+                        (Some(key), Some(prev), Some(last)) => key - prev < last - key,
+                        _ => false,
+                    }
+                },
+                _ => false,
+            }
+        };
+        self.all_left_rows(left, left_key, is_row_ready)
+    }
+
+    /// Updates the maximum left key per group with a dispatched left morsel.
+    fn update_left_max(
+        &self,
+        left: &DataFrame,
+        left_key: &Series,
+        buffers: &mut AsOfJoinBuffers,
+    ) -> PolarsResult<()> {
+        let left_group_max = &mut buffers.left_group_max;
+        self.all_left_rows(left, left_key, |group, key| {
+            if key.is_null() {
+                return true;
+            }
+            match left_group_max.get_mut(group) {
+                Some(max) if *max < key => *max = key.into_static(),
+                Some(_) => {},
+                None => {
+                    left_group_max.insert(group.to_vec(), key.into_static());
+                },
+            }
+            true
+        })?;
+        Ok(())
+    }
+
+    /// Drops the right rows which can't be matched by any left row still to
+    /// come, which have a key of at least the maximum dispatched left key of
+    /// their group.
+    fn prune(&self, buffers: &mut AsOfJoinBuffers) -> PolarsResult<()> {
+        if buffers.left_group_max.is_empty() {
+            return Ok(());
+        }
+
+        let key = buffers.right_key.rechunk();
+        let groups: Box<dyn Iterator<Item = &[u8]>> = match &buffers.right_groups {
+            Some(groups) => Box::new(groups.into_no_null_iter()),
+            None => Box::new(std::iter::repeat_n(&[][..], key.len())),
+        };
+        let mut keep = vec![true; key.len()];
+        // The last row before the left keys of a group can still be matched
+        // backward.
+        let mut last_before = PlHashMap::new();
+        for (idx, (group, value)) in groups.zip(key.iter()).enumerate() {
+            let Some(left_max) = buffers.left_group_max.get(group) else {
+                continue;
+            };
+            if value.is_null() {
+                keep[idx] = false;
+            } else if value < *left_max {
+                keep[idx] = false;
+                last_before.insert(group, idx);
+            }
+        }
+        for idx in last_before.into_values() {
+            keep[idx] = true;
+        }
+
+        if keep.iter().all(|k| *k) {
+            return Ok(());
+        }
+        let keep = BooleanChunked::from_slice(PlSmallStr::EMPTY, &keep);
+        buffers.right = buffers.right.filter(&keep)?;
+        buffers.right_key = buffers.right_key.filter(&keep)?;
+        if let Some(groups) = &mut buffers.right_groups {
+            *groups = groups.filter(&keep)?;
+        }
+        Ok(())
+    }
+
+    /// Buffers the remaining morsels of a port, after requesting its source to
+    /// stop.
+    async fn buffer_remaining(
+        &self,
+        port: &mut Receiver<Morsel>,
+        is_left: bool,
+        buffers: &mut AsOfJoinBuffers,
+        state: &ExecutionState,
+    ) -> PolarsResult<()> {
+        let Ok(morsel) = port.recv().await else {
+            return Ok(());
+        };
+        morsel.source_token().stop();
+        let mut morsel = Some(morsel);
+        while let Some(m) = morsel {
+            if is_left {
+                self.push_left(m.into_df(), buffers, state).await?;
+            } else {
+                self.push_right(m.into_df(), buffers, state).await?;
+            }
+            morsel = port.recv().await.ok();
+        }
+        Ok(())
+    }
+}
+
+/// An as-of join of inputs which are sorted by their key, within each `by`
+/// group if given.
+///
+/// The left morsels are joined in order with the right rows they can be
+/// matched with, which are buffered as the right side advances.
+pub struct AsOfJoinNode {
+    params: AsOfJoinParams,
+    buffers: AsOfJoinBuffers,
+    right_done: bool,
+    seq: MorselSeq,
+}
+
+impl AsOfJoinNode {
+    pub fn new(
+        right_input_schema: Arc<Schema>,
+        left_key_schema: Arc<Schema>,
+        right_key_schema: Arc<Schema>,
+        left_key_selector: StreamExpr,
+        right_key_selector: StreamExpr,
+        args: &JoinArgs,
+    ) -> PolarsResult<Self> {
+        let JoinType::AsOf(options) = &args.how else {
+            unreachable!()
+        };
+        let mut options = options.clone();
+        let (_, left_key_dtype) = left_key_schema.get_at_index(0).unwrap();
+        resolve_tolerance(&mut options, left_key_dtype)?;
+
+        let tolerance = match &options.tolerance {
+            Some(tolerance) => Some(tolerance.extract::<f64>().ok_or_else(
+                || polars_err!(InvalidOperation: "invalid tolerance {} for an asof join", tolerance),
+            )?),
+            None => None,
+        };
+        let has_by = options.left_by.is_some();
+        if options.check_sortedness && has_by {
+            polars_warn!("Sortedness of columns cannot be checked when 'by' groups provided");
+        }
+        let params = AsOfJoinParams {
+            left_key_selector,
+            right_key_selector,
+            args: JoinArgs {
+                how: JoinType::AsOf(AsOfOptions {
+                    check_sortedness: false,
+                    ..options.clone()
+                }),
+                slice: None,
+                ..args.clone()
+            },
+            strategy: options.strategy,
+            left_by: options.left_by,
+            right_by: options.right_by,
+            tolerance,
+            check_sortedness: options.check_sortedness && !has_by,
+        };
+
+        let (right_key_name, right_key_dtype) = right_key_schema.get_at_index(0).unwrap();
+        let buffers = AsOfJoinBuffers {
+            left: VecDeque::new(),
+            right: DataFrame::empty_with_schema(&right_input_schema),
+            right_key: Series::new_empty(right_key_name.clone(), right_key_dtype),
+            right_groups: has_by.then(BinaryOffsetChunked::default),
+            right_group_keys: PlHashMap::new(),
+            left_group_max: PlHashMap::new(),
+            left_last: None,
+            right_last: None,
+        };
+
+        Ok(Self {
+            params,
+            buffers,
+            right_done: false,
+            seq: MorselSeq::default(),
+        })
+    }
+}
+
+impl ComputeNode for AsOfJoinNode {
+    fn name(&self) -> &str {
+        "asof-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        _state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // Without left rows there is nothing to join anymore.
+        let left_done = recv[0] == PortState::Done && self.buffers.left.is_empty();
+        if send[0] == PortState::Done || left_done {
+            recv[0] = PortState::Done;
+            recv[1] = PortState::Done;
+            send[0] = PortState::Done;
+            return Ok(());
+        }
+        self.right_done = recv[1] == PortState::Done;
+
+        let send_blocked = send[0] == PortState::Blocked;
+        let left_blocked = recv[0] == PortState::Blocked && self.buffers.left.is_empty();
+        let right_blocked = recv[1] == PortState::Blocked;
+        send[0] = if left_blocked || right_blocked {
+            PortState::Blocked
+        } else {
+            PortState::Ready
+        };
+        if recv[0] != PortState::Done {
+            recv[0] = if send_blocked || right_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        if recv[1] != PortState::Done {
+            recv[1] = if send_blocked || left_blocked {
+                PortState::Blocked
+            } else {
+                PortState::Ready
+            };
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2 && send_ports.len() == 1);
+
+        let senders = send_ports[0].take().unwrap().parallel();
+        let (mut distributor, dist_recv) =
+            distributor_channel(senders.len(), *DEFAULT_DISTRIBUTOR_BUFFER_SIZE);
+
+        let mut left = recv_ports[0].take().map(|p| p.serial());
+        let mut right = recv_ports[1].take().map(|p| p.serial());
+        let right_done = self.right_done;
+        let params = &self.params;
+        let buffers = &mut self.buffers;
+        let seq = &mut self.seq;
+
+        // The left morsels are matched in order by a single task, the joins
+        // themselves are done in parallel.
+        join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+            let exec_state = &state.in_memory_exec_state;
+            let source_token = SourceToken::new();
+
+            loop {
+                while let Some((df, key)) = buffers.left.front() {
+                    if !right_done && !params.is_ready(df, key, buffers)? {
+                        break;
+                    }
+
+                    let (df, key) = buffers.left.pop_front().unwrap();
+                    params.update_left_max(&df, &key, buffers)?;
+                    if buffers.right_key.n_chunks() > 1 {
+                        buffers.right.as_single_chunk_par();
+                        buffers.right_key = buffers.right_key.rechunk();
+                        if let Some(groups) = &mut buffers.right_groups {
+                            *groups = groups.rechunk().into_owned();
+                        }
+                    }
+                    let morsel = Morsel::new(df, *seq, source_token.clone());
+                    *seq = seq.successor();
+                    let right = (buffers.right.clone(), buffers.right_key.clone());
+                    if distributor.send((morsel, key, right)).await.is_err() {
+                        return Ok(());
+                    }
+
+                    params.prune(buffers)?;
+                }
+
+                if source_token.stop_requested() {
+                    if let Some(port) = &mut left {
+                        params
+                            .buffer_remaining(port, true, buffers, exec_state)
+                            .await?;
+                    }
+                    if let Some(port) = &mut right {
+                        params
+                            .buffer_remaining(port, false, buffers, exec_state)
+                            .await?;
+                    }
+                    break;
+                }
+
+                // Get a new left morsel if none is waiting, otherwise advance
+                // the right side.
+                if buffers.left.is_empty() {
+                    let Some(port) = &mut left else {
+                        break;
+                    };
+                    let Ok(morsel) = port.recv().await else {
+                        if let Some(port) = &mut right {
+                            params
+                                .buffer_remaining(port, false, buffers, exec_state)
+                                .await?;
+                        }
+                        break;
+                    };
+                    params
+                        .push_left(morsel.into_df(), buffers, exec_state)
+                        .await?;
+                } else {
+                    let Some(port) = &mut right else {
+                        break;
+                    };
+                    let Ok(morsel) = port.recv().await else {
+                        if let Some(port) = &mut left {
+                            params
+                                .buffer_remaining(port, true, buffers, exec_state)
+                                .await?;
+                        }
+                        break;
+                    };
+                    params
+                        .push_right(morsel.into_df(), buffers, exec_state)
+                        .await?;
+                }
+            }
+
+            Ok(())
+        }));
+
+        let args = &self.params.args;
+        join_handles.extend(
+            dist_recv
+                .into_iter()
+                .zip(senders)
+                .map(|(mut recv, mut send)| {
+                    scope.spawn_task(TaskPriority::High, async move {
+                        while let Ok((morsel, left_key, (right, right_key))) = recv.recv().await {
+                            let morsel = morsel.try_map(|df| {
+                                df._join_impl(
+                                    &right,
+                                    vec![left_key],
+                                    vec![right_key],
+                                    args.clone(),
+                                    None,
+                                    true,
+                                    false,
+                                )
+                            })?;
+                            if send.send(morsel).await.is_err() {
+                                break;
+                            }
+                        }
+                        Ok(())
+                    })
+                }),
+        );
+    }
+}
@@ -4,6 +4,7 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 
 use arrow::array::builder::ShareStrategy;
+use parking_lot::Mutex;
 use polars_core::frame::builder::DataFrameBuilder;
 use polars_core::prelude::*;
 use polars_core::schema::{Schema, SchemaExt};
@@ -22,14 +23,18 @@ use polars_utils::sparse_init_vec::SparseInitVec;
 use polars_utils::{IdxSize, format_pl_smallstr};
 use rayon::prelude::*;
 
-use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR};
+use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR, sample_sink};
 use crate::async_executor;
-use crate::async_primitives::connector::{Receiver, Sender};
+use crate::async_primitives::connector::{Receiver, Sender, connector};
 use crate::async_primitives::wait_group::WaitGroup;
 use crate::expression::StreamExpr;
 use crate::morsel::{SourceToken, get_ideal_morsel_size};
 use crate::nodes::compute_node_prelude::*;
 use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::memory::MemoryReservation;
+use crate::utils::spill::{
+    LazySpillDir, PartitionSpiller, SpilledFrames, SpilledPartition, can_spill,
+};
 
 struct EquiJoinParams {
     left_is_build: Option<bool>,
@@ -46,6 +51,10 @@ struct EquiJoinParams {
     right_payload_schema: Arc<Schema>,
     args: JoinArgs,
     random_state: PlRandomState,
+    // Whether the build side may be spilled once the memory budget is
+    // exceeded, in which case the probe side is spilled as well.
+    may_spill: bool,
+    spill_dir: LazySpillDir,
 }
 
 impl EquiJoinParams {
@@ -239,31 +248,6 @@ struct SampleState {
 }
 
 impl SampleState {
-    async fn sink(
-        mut recv: Receiver<Morsel>,
-        morsels: &mut Vec<Morsel>,
-        len: &mut usize,
-        this_final_len: Arc<AtomicUsize>,
-        other_final_len: Arc<AtomicUsize>,
-    ) -> PolarsResult<()> {
-        while let Ok(mut morsel) = recv.recv().await {
-            *len += morsel.df().height();
-            if *len >= *JOIN_SAMPLE_LIMIT
-                || *len
-                    >= other_final_len
-                        .load(Ordering::Relaxed)
-                        .saturating_mul(LOPSIDED_SAMPLE_FACTOR)
-            {
-                morsel.source_token().stop();
-            }
-
-            drop(morsel.take_consume_token());
-            morsels.push(morsel);
-        }
-        this_final_len.store(*len, Ordering::Relaxed);
-        Ok(())
-    }
-
     fn try_transition_to_build(
         &mut self,
         recv: &[PortState],
@@ -359,6 +343,7 @@ impl SampleState {
                     .reinsert(state.num_pipelines, None, scope, &mut join_handles)
                     .unwrap();
 
+                let reservation = &build_state.reservation;
                 for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
@@ -367,6 +352,7 @@ impl SampleState {
                             local_builder,
                             partitioner.clone(),
                             params,
+                            reservation,
                             state,
                         ),
                     ));
@@ -399,11 +385,19 @@ struct LocalBuilder {
     // let stop = morsel_idxs_offsets[(i + 1) * num_partitions + p];
     morsel_idxs_values_per_p: Vec<Vec<IdxSize>>,
     morsel_idxs_offsets_per_p: Vec<usize>,
+
+    // Once the memory budget is exceeded the received morsels are spilled per
+    // partition instead, and the partitions are joined one at a time.
+    spilling: bool,
+    spiller: PartitionSpiller,
 }
 
 struct BuildState {
     local_builders: Vec<LocalBuilder>,
     sampled_probe_morsels: BufferedStream,
+    // Holds the buffered morsels until the hash tables built from them are
+    // dropped.
+    reservation: MemoryReservation,
 }
 
 impl BuildState {
@@ -418,11 +412,14 @@ impl BuildState {
                 sketch_per_p: vec![CardinalitySketch::default(); num_partitions],
                 morsel_idxs_values_per_p: vec![Vec::new(); num_partitions],
                 morsel_idxs_offsets_per_p: vec![0; num_partitions],
+                spilling: false,
+                spiller: PartitionSpiller::new(num_partitions),
             })
             .collect();
         Self {
             local_builders,
             sampled_probe_morsels,
+            reservation: MemoryReservation::new(),
         }
     }
 
@@ -431,6 +428,7 @@ impl BuildState {
         local: &mut LocalBuilder,
         partitioner: HashPartitioner,
         params: &EquiJoinParams,
+        reservation: &MemoryReservation,
         state: &StreamingExecutionState,
     ) -> PolarsResult<()> {
         let track_unmatchable = params.emit_unmatched_build();
@@ -443,6 +441,7 @@ impl BuildState {
             key_selectors = &params.right_key_selectors;
         };
 
+        let mut spill_idxs_per_p = vec![Vec::new(); partitioner.num_partitions()];
         while let Ok(morsel) = recv.recv().await {
             // Compute hashed keys and payload. We must rechunk the payload for
             // later gathers.
@@ -453,8 +452,40 @@ impl BuildState {
                 &state.in_memory_exec_state,
             )
             .await?;
+
+            if params.may_spill && !local.spilling && reservation.should_spill() {
+                local.spilling = true;
+            }
+            if local.spilling {
+                // The morsel is spilled as-is, its keys and payload are
+                // selected again once its partition is joined.
+                for idxs in &mut spill_idxs_per_p {
+                    idxs.clear();
+                }
+                hash_keys.gen_idxs_per_partition(
+                    &partitioner,
+                    &mut spill_idxs_per_p,
+                    &mut [],
+                    track_unmatchable,
+                );
+                let seq = morsel.seq().to_u64();
+                let df = morsel.into_df();
+                for (p, idxs) in spill_idxs_per_p.iter().enumerate() {
+                    if !idxs.is_empty() {
+                        let p_df = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+                        local.spiller.push(p, seq, p_df, reservation);
+                    }
+                }
+                local
+                    .spiller
+                    .maybe_flush(&params.spill_dir, reservation)
+                    .await?;
+                continue;
+            }
+
             let mut payload = select_payload(morsel.df().clone(), payload_selector);
             payload.rechunk_mut();
+            reservation.grow(payload.estimated_size());
 
             hash_keys.gen_idxs_per_partition(
                 &partitioner,
@@ -566,6 +597,9 @@ impl BuildState {
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
+            spilled_build: None,
+            probe_spillers: Vec::new(),
+            reservation: core::mem::take(&mut self.reservation),
         }
     }
 
@@ -695,12 +729,48 @@ impl BuildState {
             });
         });
 
+        let spilled_build = self.take_spilled_partitions();
+        let probe_spillers = if spilled_build.is_some() {
+            (0..self.local_builders.len())
+                .map(|_| PartitionSpiller::new(num_partitions))
+                .collect()
+        } else {
+            Vec::new()
+        };
         ProbeState {
             table_per_partition: probe_tables.try_assume_init().ok().unwrap(),
             max_seq_sent: MorselSeq::default(),
             sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
             unordered_morsel_seq: AtomicU64::new(0),
+            spilled_build,
+            probe_spillers,
+            reservation: core::mem::take(&mut self.reservation),
+        }
+    }
+
+    /// Returns the spilled morsels of all builders per partition, if any
+    /// builder spilled.
+    fn take_spilled_partitions(&mut self) -> Option<Vec<Vec<SpilledPartition>>> {
+        if !self.local_builders.iter().any(|l| l.spilling) {
+            return None;
+        }
+
+        let num_partitions = self.local_builders[0].sketch_per_p.len();
+        let mut spilled_per_p = (0..num_partitions).map(|_| Vec::new()).collect_vec();
+        for l in &mut self.local_builders {
+            let spiller = core::mem::take(&mut l.spiller);
+            for (p, spilled) in spiller
+                .into_partitions(&self.reservation)
+                .into_iter()
+                .enumerate()
+            {
+                // The morsels that weren't flushed stay in memory until their
+                // partition is joined.
+                self.reservation.grow(spilled.buffered_bytes());
+                spilled_per_p[p].push(spilled);
+            }
         }
+        Some(spilled_per_p)
     }
 }
 
@@ -717,9 +787,157 @@ struct ProbeState {
 
     // For unordered joins we relabel output morsels to speed up the linearizer.
     unordered_morsel_seq: AtomicU64,
+
+    // If the build side spilled, the probe side is spilled per partition as
+    // well, and the partitions are joined one at a time once it is done.
+    spilled_build: Option<Vec<Vec<SpilledPartition>>>,
+    probe_spillers: Vec<PartitionSpiller>,
+
+    // Holds the build side until the hash tables are dropped.
+    reservation: MemoryReservation,
 }
 
 impl ProbeState {
+    /// Partitions the probe side like the build side and spills it, to be
+    /// joined one partition at a time once it is done.
+    async fn partition_and_spill(
+        mut recv: Receiver<Morsel>,
+        spiller: &mut PartitionSpiller,
+        partitioner: HashPartitioner,
+        params: &EquiJoinParams,
+        reservation: &MemoryReservation,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let mut idxs_per_p = vec![Vec::new(); partitioner.num_partitions()];
+        while let Ok(mut morsel) = recv.recv().await {
+            morsel.take_consume_token();
+            Self::spill_probe_df(
+                morsel.into_df(),
+                spiller,
+                &mut idxs_per_p,
+                &partitioner,
+                params,
+                reservation,
+                state,
+            )
+            .await?;
+        }
+        Ok(())
+    }
+
+    async fn spill_probe_df(
+        df: DataFrame,
+        spiller: &mut PartitionSpiller,
+        idxs_per_p: &mut [Vec<IdxSize>],
+        partitioner: &HashPartitioner,
+        params: &EquiJoinParams,
+        reservation: &MemoryReservation,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let key_selectors = if params.left_is_build.unwrap() {
+            &params.right_key_selectors
+        } else {
+            &params.left_key_selectors
+        };
+        let hash_keys =
+            select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+
+        for idxs in idxs_per_p.iter_mut() {
+            idxs.clear();
+        }
+        hash_keys.gen_idxs_per_partition(
+            partitioner,
+            idxs_per_p,
+            &mut [],
+            params.emit_unmatched_probe(),
+        );
+        for (p, idxs) in idxs_per_p.iter().enumerate() {
+            if !idxs.is_empty() {
+                let p_df = unsafe { df.take_slice_unchecked_impl(idxs, false) };
+                spiller.push(p, 0, p_df, reservation);
+            }
+        }
+
+        if reservation.should_spill() {
+            spiller.maybe_flush(&params.spill_dir, reservation).await?;
+        }
+        Ok(())
+    }
+
+    /// Spills the sampled probe morsels, which would otherwise only be
+    /// reinserted into the probe tasks.
+    fn spill_sampled_probe_morsels(
+        &mut self,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let partitioner = HashPartitioner::new(self.table_per_partition.len(), 0);
+        let mut idxs_per_p = vec![Vec::new(); partitioner.num_partitions()];
+        let spiller = &mut self.probe_spillers[0];
+        let sampled_probe_morsels = &self.sampled_probe_morsels;
+        let reservation = &self.reservation;
+        get_runtime().block_on(async {
+            while let Some(morsel) = sampled_probe_morsels.pop() {
+                Self::spill_probe_df(
+                    morsel.into_df(),
+                    spiller,
+                    &mut idxs_per_p,
+                    &partitioner,
+                    params,
+                    reservation,
+                    state,
+                )
+                .await?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Hands the hash tables and the spilled partitions of both sides over to
+    /// be joined one partition at a time.
+    fn into_spilled_join(&mut self, spilled_build: Vec<Vec<SpilledPartition>>) -> SpilledJoinState {
+        let num_partitions = self.table_per_partition.len();
+        let mut spilled_probe = (0..num_partitions).map(|_| Vec::new()).collect_vec();
+        for spiller in self.probe_spillers.drain(..) {
+            for (p, spilled) in spiller
+                .into_partitions(&self.reservation)
+                .into_iter()
+                .enumerate()
+            {
+                spilled_probe[p].push(spilled);
+            }
+        }
+
+        // From now on the bytes are tracked per partition, such that they can
+        // be returned as soon as a partition is joined.
+        let buffered_bytes = |spilled: &[SpilledPartition]| {
+            spilled.iter().map(|s| s.buffered_bytes()).sum::<usize>()
+        };
+        let bytes_per_partition = (0..num_partitions)
+            .map(|p| {
+                self.table_per_partition[p].payload.estimated_size()
+                    + buffered_bytes(&spilled_build[p])
+                    + buffered_bytes(&spilled_probe[p])
+            })
+            .collect_vec();
+        self.reservation.release();
+        self.reservation.grow(bytes_per_partition.iter().sum());
+
+        SpilledJoinState {
+            table_per_partition: core::mem::take(&mut self.table_per_partition)
+                .into_iter()
+                .map(Some)
+                .collect(),
+            spilled_build,
+            spilled_probe,
+            bytes_per_partition,
+            active_partition_idx: 0,
+            phase: SpilledJoinPhase::Load,
+            unordered_morsel_seq: AtomicU64::new(0),
+            reservation: core::mem::take(&mut self.reservation),
+        }
+    }
+
     /// Returns the max morsel sequence sent.
     async fn partition_and_probe(
         mut recv: Receiver<Morsel>,
@@ -1060,6 +1278,8 @@ struct EmitUnmatchedState {
     active_partition_idx: usize,
     offset_in_active_p: usize,
     morsel_seq: MorselSeq,
+    // Holds the partitions until they're dropped.
+    reservation: MemoryReservation,
 }
 
 impl EmitUnmatchedState {
@@ -1131,10 +1351,166 @@ impl EmitUnmatchedState {
     }
 }
 
+enum SpilledJoinPhase {
+    Load,
+    Probe(Mutex<Vec<SpilledFrames>>),
+    EmitUnmatched(EmitUnmatchedState),
+}
+
+/// Joins the partitions one at a time once the build side spilled, such that
+/// only a single partition of the build side has to be in memory.
+struct SpilledJoinState {
+    // The hash tables built from the morsels received before spilling.
+    table_per_partition: Vec<Option<ProbeTable>>,
+    spilled_build: Vec<Vec<SpilledPartition>>,
+    spilled_probe: Vec<Vec<SpilledPartition>>,
+    bytes_per_partition: Vec<usize>,
+    active_partition_idx: usize,
+    phase: SpilledJoinPhase,
+    unordered_morsel_seq: AtomicU64,
+    reservation: MemoryReservation,
+}
+
+impl SpilledJoinState {
+    /// Moves on to the next phase or partition once the current one is done.
+    /// Returns false once all partitions are joined.
+    fn advance(
+        &mut self,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<bool> {
+        loop {
+            let p = self.active_partition_idx;
+            match &mut self.phase {
+                SpilledJoinPhase::Load => {
+                    if p == self.table_per_partition.len() {
+                        return Ok(false);
+                    }
+                    self.load_partition(params, state)?;
+                    let frames = core::mem::take(&mut self.spilled_probe[p])
+                        .into_iter()
+                        .map(SpilledPartition::into_frames)
+                        .collect();
+                    self.phase = SpilledJoinPhase::Probe(Mutex::new(frames));
+                },
+                SpilledJoinPhase::Probe(frames) => {
+                    if !frames.get_mut().is_empty() {
+                        return Ok(true);
+                    }
+                    if params.emit_unmatched_build() {
+                        let seq = self.unordered_morsel_seq.load(Ordering::Relaxed);
+                        self.phase = SpilledJoinPhase::EmitUnmatched(EmitUnmatchedState {
+                            partitions: vec![self.table_per_partition[p].take().unwrap()],
+                            active_partition_idx: 0,
+                            offset_in_active_p: 0,
+                            morsel_seq: MorselSeq::new(seq),
+                            // The table is held by the bytes of its partition.
+                            reservation: MemoryReservation::default(),
+                        });
+                    } else {
+                        self.finish_partition();
+                    }
+                },
+                SpilledJoinPhase::EmitUnmatched(emit_state) => {
+                    if emit_state.active_partition_idx < emit_state.partitions.len() {
+                        return Ok(true);
+                    }
+                    self.finish_partition();
+                },
+            }
+        }
+    }
+
+    /// Adds the spilled build morsels of the active partition to its hash
+    /// table.
+    fn load_partition(
+        &mut self,
+        params: &EquiJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        let track_unmatchable = params.emit_unmatched_build();
+        let (key_selectors, payload_selector, payload_schema);
+        if params.left_is_build.unwrap() {
+            key_selectors = &params.left_key_selectors;
+            payload_selector = &params.left_payload_select;
+            payload_schema = &params.left_payload_schema;
+        } else {
+            key_selectors = &params.right_key_selectors;
+            payload_selector = &params.right_payload_select;
+            payload_schema = &params.right_payload_schema;
+        };
+
+        let p = self.active_partition_idx;
+        let spilled = core::mem::take(&mut self.spilled_build[p]);
+        let table = self.table_per_partition[p].as_mut().unwrap();
+        let mut payload_builder = DataFrameBuilder::new(payload_schema.clone());
+        let height = table.payload.height();
+        payload_builder.subslice_extend(&table.payload, 0, height, ShareStrategy::Never);
+        let mut subset = Vec::new();
+        get_runtime().block_on(async {
+            for frame in spilled.into_iter().flat_map(SpilledPartition::into_frames) {
+                let (_seq, df) = frame?;
+                let hash_keys =
+                    select_keys(&df, key_selectors, params, &state.in_memory_exec_state).await?;
+                let payload = select_payload(df, payload_selector);
+
+                // The table assigns the keys consecutive indices, matching
+                // the order in which the payload is appended.
+                subset.clear();
+                subset.extend(0..payload.height() as IdxSize);
+                unsafe {
+                    table
+                        .hash_table
+                        .insert_keys_subset(&hash_keys, &subset, track_unmatchable)
+                };
+                payload_builder.subslice_extend(
+                    &payload,
+                    0,
+                    payload.height(),
+                    ShareStrategy::Never,
+                );
+            }
+            PolarsResult::Ok(())
+        })?;
+        table.payload = payload_builder.freeze();
+
+        let bytes = table.payload.estimated_size()
+            + self.spilled_probe[p]
+                .iter()
+                .map(|s| s.buffered_bytes())
+                .sum::<usize>();
+        self.reservation.grow(bytes);
+        self.reservation
+            .shrink(core::mem::replace(&mut self.bytes_per_partition[p], bytes));
+        Ok(())
+    }
+
+    fn finish_partition(&mut self) {
+        let p = self.active_partition_idx;
+        self.phase = SpilledJoinPhase::Load;
+        self.table_per_partition[p] = None;
+        self.reservation
+            .shrink(core::mem::take(&mut self.bytes_per_partition[p]));
+        self.active_partition_idx += 1;
+    }
+
+    fn next_probe_frame(frames: &Mutex<Vec<SpilledFrames>>) -> PolarsResult<Option<DataFrame>> {
+        let mut frames = frames.lock();
+        while let Some(active) = frames.last_mut() {
+            if let Some(frame) = active.next() {
+                return Ok(Some(frame?.1));
+            }
+            frames.pop();
+        }
+        Ok(None)
+    }
+}
+
 enum EquiJoinState {
     Sample(SampleState),
     Build(BuildState),
     Probe(ProbeState),
+    JoinSpilled(SpilledJoinState),
     EmitUnmatchedBuild(EmitUnmatchedState),
     EmitUnmatchedBuildInOrder(InMemorySourceNode),
     Done,
@@ -1176,6 +1552,11 @@ impl EquiJoinNode {
             args.maintain_order,
             MaintainOrderJoin::LeftRight | MaintainOrderJoin::RightLeft
         );
+        // Only unordered joins spill, as the partitions are joined one at a
+        // time.
+        let may_spill = args.maintain_order == MaintainOrderJoin::None
+            && can_spill(&left_input_schema)
+            && can_spill(&right_input_schema);
 
         let left_payload_select = compute_payload_selector(
             &left_input_schema,
@@ -1221,6 +1602,8 @@ impl EquiJoinNode {
                 right_payload_schema,
                 args,
                 random_state: PlRandomState::default(),
+                may_spill,
+                spill_dir: LazySpillDir::new("equi-join"),
             },
             table: new_idx_table(unique_key_schema),
         })
@@ -1264,11 +1647,14 @@ impl ComputeNode for EquiJoinNode {
         // If we are building and the build input is done, transition to probing.
         if let EquiJoinState::Build(build_state) = &mut self.state {
             if recv[build_idx] == PortState::Done {
-                let probe_state = if self.params.preserve_order_build {
+                let mut probe_state = if self.params.preserve_order_build {
                     build_state.finalize_ordered(&self.params, &*self.table)
                 } else {
                     build_state.finalize_unordered(&self.params, &*self.table)
                 };
+                if probe_state.spilled_build.is_some() {
+                    probe_state.spill_sampled_probe_morsels(&self.params, state)?;
+                }
                 self.state = EquiJoinState::Probe(probe_state);
             }
         }
@@ -1278,7 +1664,10 @@ impl ComputeNode for EquiJoinNode {
         if let EquiJoinState::Probe(probe_state) = &mut self.state {
             let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
             if samples_consumed && recv[probe_idx] == PortState::Done {
-                if self.params.emit_unmatched_build() {
+                if let Some(spilled_build) = probe_state.spilled_build.take() {
+                    let spilled_state = probe_state.into_spilled_join(spilled_build);
+                    self.state = EquiJoinState::JoinSpilled(spilled_state);
+                } else if self.params.emit_unmatched_build() {
                     if self.params.preserve_order_build {
                         let unmatched = probe_state.ordered_unmatched(&self.params);
                         let src = InMemorySourceNode::new(
@@ -1292,6 +1681,7 @@ impl ComputeNode for EquiJoinNode {
                             active_partition_idx: 0,
                             offset_in_active_p: 0,
                             morsel_seq: probe_state.max_seq_sent.successor(),
+                            reservation: core::mem::take(&mut probe_state.reservation),
                         });
                     }
                 } else {
@@ -1300,6 +1690,14 @@ impl ComputeNode for EquiJoinNode {
             }
         }
 
+        // If we are joining the spilled partitions, move on to the next one
+        // once the active one is joined.
+        if let EquiJoinState::JoinSpilled(spilled_state) = &mut self.state {
+            if !spilled_state.advance(&self.params, state)? {
+                self.state = EquiJoinState::Done;
+            }
+        }
+
         // Finally, check if we are done emitting unmatched keys.
         if let EquiJoinState::EmitUnmatchedBuild(emit_state) = &mut self.state {
             if emit_state.active_partition_idx >= emit_state.partitions.len() {
@@ -1334,6 +1732,14 @@ impl ComputeNode for EquiJoinNode {
                     recv[probe_idx] = PortState::Blocked;
                 }
             },
+            EquiJoinState::Probe(probe_state) if probe_state.spilled_build.is_some() => {
+                // The probe side is spilled, nothing is sent until it is done.
+                send[0] = PortState::Blocked;
+                if recv[probe_idx] != PortState::Done {
+                    recv[probe_idx] = PortState::Ready;
+                }
+                recv[build_idx] = PortState::Done;
+            },
             EquiJoinState::Probe(probe_state) => {
                 if recv[probe_idx] != PortState::Done {
                     core::mem::swap(&mut send[0], &mut recv[probe_idx]);
@@ -1347,7 +1753,7 @@ impl ComputeNode for EquiJoinNode {
                 }
                 recv[build_idx] = PortState::Done;
             },
-            EquiJoinState::EmitUnmatchedBuild(_) => {
+            EquiJoinState::JoinSpilled(_) | EquiJoinState::EmitUnmatchedBuild(_) => {
                 send[0] = PortState::Ready;
                 recv[build_idx] = PortState::Done;
                 recv[probe_idx] = PortState::Done;
@@ -1370,10 +1776,11 @@ impl ComputeNode for EquiJoinNode {
     }
 
     fn is_memory_intensive_pipeline_blocker(&self) -> bool {
-        matches!(
-            self.state,
-            EquiJoinState::Sample { .. } | EquiJoinState::Build { .. }
-        )
+        match &self.state {
+            EquiJoinState::Sample { .. } | EquiJoinState::Build { .. } => true,
+            EquiJoinState::Probe(probe_state) => probe_state.spilled_build.is_some(),
+            _ => false,
+        }
     }
 
     fn spawn<'env, 's>(
@@ -1411,7 +1818,7 @@ impl ComputeNode for EquiJoinNode {
                 if let Some(left_recv) = recv_ports[0].take() {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
-                        SampleState::sink(
+                        sample_sink(
                             left_recv.serial(),
                             &mut sample_state.left,
                             &mut sample_state.left_len,
@@ -1423,7 +1830,7 @@ impl ComputeNode for EquiJoinNode {
                 if let Some(right_recv) = recv_ports[1].take() {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
-                        SampleState::sink(
+                        sample_sink(
                             right_recv.serial(),
                             &mut sample_state.right,
                             &mut sample_state.right_len,
@@ -1439,6 +1846,7 @@ impl ComputeNode for EquiJoinNode {
                 let receivers = recv_ports[build_idx].take().unwrap().parallel();
 
                 let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+                let reservation = &build_state.reservation;
                 for (local_builder, recv) in build_state.local_builders.iter_mut().zip(receivers) {
                     join_handles.push(scope.spawn_task(
                         TaskPriority::High,
@@ -1447,6 +1855,28 @@ impl ComputeNode for EquiJoinNode {
                             local_builder,
                             partitioner.clone(),
                             &self.params,
+                            reservation,
+                            state,
+                        ),
+                    ));
+                }
+            },
+            EquiJoinState::Probe(probe_state) if probe_state.spilled_build.is_some() => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[build_idx].is_none());
+                let receivers = recv_ports[probe_idx].take().unwrap().parallel();
+
+                let partitioner = HashPartitioner::new(state.num_pipelines, 0);
+                let reservation = &probe_state.reservation;
+                for (spiller, recv) in probe_state.probe_spillers.iter_mut().zip(receivers) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        ProbeState::partition_and_spill(
+                            recv,
+                            spiller,
+                            partitioner.clone(),
+                            &self.params,
+                            reservation,
                             state,
                         ),
                     ));
@@ -1493,6 +1923,63 @@ impl ComputeNode for EquiJoinNode {
                     Ok(())
                 }));
             },
+            EquiJoinState::JoinSpilled(spilled_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let p = spilled_state.active_partition_idx;
+                match &mut spilled_state.phase {
+                    SpilledJoinPhase::Load => unreachable!(),
+                    SpilledJoinPhase::Probe(frames) => {
+                        let senders = send_ports[0].take().unwrap().parallel();
+                        let frames = &*frames;
+                        let table = spilled_state.table_per_partition[p].as_ref().unwrap();
+                        let unordered_morsel_seq = &spilled_state.unordered_morsel_seq;
+                        let params = &self.params;
+                        for send in senders {
+                            // Feed the spilled probe morsels of this partition
+                            // to the probe task like a source would.
+                            let (mut feed_send, feed_recv) = connector();
+                            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                                let wait_group = WaitGroup::default();
+                                let source_token = SourceToken::new();
+                                while let Some(df) = SpilledJoinState::next_probe_frame(frames)? {
+                                    let mut morsel =
+                                        Morsel::new(df, MorselSeq::default(), source_token.clone());
+                                    morsel.set_consume_token(wait_group.token());
+                                    if feed_send.send(morsel).await.is_err() {
+                                        break;
+                                    }
+                                    wait_group.wait().await;
+                                    if source_token.stop_requested() {
+                                        break;
+                                    }
+                                }
+                                Ok(())
+                            }));
+                            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                                ProbeState::partition_and_probe(
+                                    feed_recv,
+                                    send,
+                                    std::slice::from_ref(table),
+                                    unordered_morsel_seq,
+                                    HashPartitioner::new(1, 0),
+                                    params,
+                                    state,
+                                )
+                                .await?;
+                                Ok(())
+                            }));
+                        }
+                    },
+                    SpilledJoinPhase::EmitUnmatched(emit_state) => {
+                        let send = send_ports[0].take().unwrap().serial();
+                        join_handles.push(scope.spawn_task(
+                            TaskPriority::Low,
+                            emit_state.emit_unmatched(send, &self.params, state.num_pipelines),
+                        ));
+                    },
+                }
+            },
             EquiJoinState::EmitUnmatchedBuild(emit_state) => {
                 assert!(recv_ports[build_idx].is_none());
                 assert!(recv_ports[probe_idx].is_none());
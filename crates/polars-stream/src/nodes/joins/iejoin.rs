@@ -0,0 +1,511 @@
+use std::sync::Arc;
+use std::sync::atomic::AtomicUsize;
+
+use polars_core::config;
+use polars_core::prelude::*;
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_ops::frame::{IEJoinOptions, JoinArgs, iejoin_block, iejoin_l1_order};
+use polars_utils::format_pl_smallstr;
+use polars_utils::itertools::Itertools;
+use polars_utils::pl_str::PlSmallStr;
+
+use super::{BufferedStream, JOIN_SAMPLE_LIMIT, LOPSIDED_SAMPLE_FACTOR, sample_sink};
+use crate::async_primitives::connector::{Receiver, Sender};
+use crate::expression::StreamExpr;
+use crate::morsel::get_ideal_morsel_size;
+use crate::nodes::compute_node_prelude::*;
+
+struct IEJoinParams {
+    left_is_build: Option<bool>,
+    left_key_selectors: Vec<StreamExpr>,
+    right_key_selectors: Vec<StreamExpr>,
+    left_input_schema: Arc<Schema>,
+    right_input_schema: Arc<Schema>,
+    right_rename: Vec<Option<PlSmallStr>>,
+    options: IEJoinOptions,
+}
+
+impl IEJoinParams {
+    fn build_key_selectors(&self) -> &[StreamExpr] {
+        if self.left_is_build.unwrap() {
+            &self.left_key_selectors
+        } else {
+            &self.right_key_selectors
+        }
+    }
+
+    fn probe_key_selectors(&self) -> &[StreamExpr] {
+        if self.left_is_build.unwrap() {
+            &self.right_key_selectors
+        } else {
+            &self.left_key_selectors
+        }
+    }
+}
+
+async fn select_keys(
+    df: &DataFrame,
+    key_selectors: &[StreamExpr],
+    state: &ExecutionState,
+) -> PolarsResult<Vec<Series>> {
+    let mut keys = Vec::with_capacity(key_selectors.len());
+    for selector in key_selectors {
+        let key = selector.evaluate(df, state).await?;
+        keys.push(key.take_materialized_series().rechunk());
+    }
+    Ok(keys)
+}
+
+#[derive(Default)]
+struct SampleState {
+    left: Vec<Morsel>,
+    left_len: usize,
+    right: Vec<Morsel>,
+    right_len: usize,
+}
+
+impl SampleState {
+    fn try_transition_to_build(
+        &mut self,
+        recv: &[PortState],
+        params: &mut IEJoinParams,
+    ) -> Option<BuildState> {
+        let left_saturated = self.left_len >= *JOIN_SAMPLE_LIMIT;
+        let right_saturated = self.right_len >= *JOIN_SAMPLE_LIMIT;
+        let left_done = recv[0] == PortState::Done || left_saturated;
+        let right_done = recv[1] == PortState::Done || right_saturated;
+        #[expect(clippy::nonminimal_bool)]
+        let stop_sampling = (left_done && right_done)
+            || (left_done && self.right_len >= LOPSIDED_SAMPLE_FACTOR * self.left_len)
+            || (right_done && self.left_len >= LOPSIDED_SAMPLE_FACTOR * self.right_len);
+        if !stop_sampling {
+            return None;
+        }
+
+        // Unlike for an equi-join the keys don't matter, we simply buffer the
+        // smaller side.
+        let left_is_build = match (left_saturated, right_saturated) {
+            (false, false) => self.left_len < self.right_len,
+            (false, true) => true,
+            (true, false) | (true, true) => false,
+        };
+
+        if config::verbose() {
+            eprintln!(
+                "ie-join build side chosen: {}, sample lengths are: {} vs. {}",
+                if left_is_build { "left" } else { "right" },
+                self.left_len,
+                self.right_len
+            );
+        }
+
+        params.left_is_build = Some(left_is_build);
+        let mut sampled_build_morsels = core::mem::take(&mut self.left);
+        let mut sampled_probe_morsels = core::mem::take(&mut self.right);
+        if !left_is_build {
+            core::mem::swap(&mut sampled_build_morsels, &mut sampled_probe_morsels);
+        }
+
+        Some(BuildState {
+            frames: vec![
+                sampled_build_morsels
+                    .into_iter()
+                    .map(|m| m.into_df())
+                    .collect(),
+            ],
+            sampled_probe_morsels: BufferedStream::new(sampled_probe_morsels, MorselSeq::default()),
+        })
+    }
+}
+
+struct BuildState {
+    /// The buffered frames of the build side, per pipeline.
+    frames: Vec<Vec<DataFrame>>,
+    sampled_probe_morsels: BufferedStream,
+}
+
+impl BuildState {
+    async fn sink(mut recv: Receiver<Morsel>, frames: &mut Vec<DataFrame>) -> PolarsResult<()> {
+        while let Ok(morsel) = recv.recv().await {
+            frames.push(morsel.into_df());
+        }
+        Ok(())
+    }
+
+    fn finalize(
+        &mut self,
+        params: &IEJoinParams,
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<ProbeState> {
+        let frames = core::mem::take(&mut self.frames)
+            .into_iter()
+            .flatten()
+            .collect_vec();
+        let mut df = if frames.is_empty() {
+            let schema = if params.left_is_build.unwrap() {
+                &params.left_input_schema
+            } else {
+                &params.right_input_schema
+            };
+            DataFrame::empty_with_schema(schema)
+        } else {
+            accumulate_dataframes_vertical_unchecked(frames)
+        };
+        df.as_single_chunk_par();
+
+        let keys = params
+            .build_key_selectors()
+            .iter()
+            .map(|selector| {
+                let key = selector.evaluate_blocking(&df, &state.in_memory_exec_state)?;
+                PolarsResult::Ok(key.take_materialized_series().rechunk())
+            })
+            .try_collect_vec()?;
+
+        // Sort the build side once, and split it in blocks which each probe
+        // morsel is joined with.
+        let l1_order = iejoin_l1_order(&keys[0], &params.options);
+        let block_size = get_ideal_morsel_size().max(1);
+        let blocks = (0..l1_order.len())
+            .step_by(block_size)
+            .map(|offset| l1_order.slice(offset as i64, block_size))
+            .collect();
+
+        Ok(ProbeState {
+            df,
+            keys,
+            blocks,
+            sampled_probe_morsels: core::mem::take(&mut self.sampled_probe_morsels),
+        })
+    }
+}
+
+struct ProbeState {
+    /// The build side and its keys.
+    df: DataFrame,
+    keys: Vec<Series>,
+    /// Blocks of the build side's row indices, in order of its first key.
+    blocks: Vec<IdxCa>,
+    sampled_probe_morsels: BufferedStream,
+}
+
+impl ProbeState {
+    async fn probe(
+        &self,
+        mut recv: Receiver<Morsel>,
+        mut send: Sender<Morsel>,
+        params: &IEJoinParams,
+        state: &ExecutionState,
+    ) -> PolarsResult<()> {
+        let left_is_build = params.left_is_build.unwrap();
+        while let Ok(morsel) = recv.recv().await {
+            let (df, seq, source_token, consume_token) = morsel.into_inner();
+            let keys = select_keys(&df, params.probe_key_selectors(), state).await?;
+            let l1_order = iejoin_l1_order(&keys[0], &params.options);
+
+            for block in &self.blocks {
+                let matches = if left_is_build {
+                    iejoin_block(block, &l1_order, &self.keys, &keys, &params.options)?
+                        .map(|(build_idx, probe_idx)| (probe_idx, build_idx))
+                } else {
+                    iejoin_block(&l1_order, block, &keys, &self.keys, &params.options)?
+                };
+                let Some((probe_idx, build_idx)) = matches else {
+                    continue;
+                };
+
+                let out = unsafe {
+                    let mut left_df = self.df.take_unchecked(&build_idx);
+                    let mut right_df = df.take_unchecked(&probe_idx);
+                    if !left_is_build {
+                        core::mem::swap(&mut left_df, &mut right_df);
+                    }
+
+                    for (col, opt_rename) in right_df
+                        .get_columns_mut()
+                        .iter_mut()
+                        .zip(&params.right_rename)
+                    {
+                        if let Some(rename) = opt_rename {
+                            col.rename(rename.clone());
+                        }
+                    }
+
+                    left_df.hstack_mut_unchecked(right_df.get_columns());
+                    left_df
+                };
+
+                if send
+                    .send(Morsel::new(out, seq, source_token.clone()))
+                    .await
+                    .is_err()
+                {
+                    return Ok(());
+                }
+            }
+
+            drop(consume_token);
+        }
+        Ok(())
+    }
+}
+
+enum IEJoinState {
+    Sample(SampleState),
+    Build(BuildState),
+    Probe(ProbeState),
+    Done,
+}
+
+/// An inequality join, which buffers the smaller side and sorts it once, and
+/// then joins each morsel of the other side with it.
+pub struct IEJoinNode {
+    state: IEJoinState,
+    params: IEJoinParams,
+}
+
+impl IEJoinNode {
+    pub fn new(
+        left_input_schema: Arc<Schema>,
+        right_input_schema: Arc<Schema>,
+        left_key_selectors: Vec<StreamExpr>,
+        right_key_selectors: Vec<StreamExpr>,
+        args: &JoinArgs,
+        options: IEJoinOptions,
+    ) -> Self {
+        let left_is_build = (*JOIN_SAMPLE_LIMIT == 0).then_some(false);
+        let state = if left_is_build.is_some() {
+            IEJoinState::Build(BuildState {
+                frames: Vec::new(),
+                sampled_probe_morsels: BufferedStream::default(),
+            })
+        } else {
+            IEJoinState::Sample(SampleState::default())
+        };
+
+        let right_rename = right_input_schema
+            .iter_names()
+            .map(|rname| {
+                if left_input_schema.contains(rname) {
+                    Some(format_pl_smallstr!("{}{}", rname, args.suffix()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        Self {
+            state,
+            params: IEJoinParams {
+                left_is_build,
+                left_key_selectors,
+                right_key_selectors,
+                left_input_schema,
+                right_input_schema,
+                right_rename,
+                options,
+            },
+        }
+    }
+}
+
+impl ComputeNode for IEJoinNode {
+    fn name(&self) -> &str {
+        "ie-join"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 2 && send.len() == 1);
+
+        // If the output doesn't want any more data, transition to being done.
+        if send[0] == PortState::Done {
+            self.state = IEJoinState::Done;
+        }
+
+        // If we are sampling and both sides are done/filled, transition to building.
+        if let IEJoinState::Sample(sample_state) = &mut self.state {
+            if let Some(build_state) = sample_state.try_transition_to_build(recv, &mut self.params)
+            {
+                self.state = IEJoinState::Build(build_state);
+            }
+        }
+
+        let build_idx = if self.params.left_is_build == Some(true) {
+            0
+        } else {
+            1
+        };
+        let probe_idx = 1 - build_idx;
+
+        // If we are building and the build input is done, transition to probing.
+        if let IEJoinState::Build(build_state) = &mut self.state {
+            if recv[build_idx] == PortState::Done {
+                let probe_state = build_state.finalize(&self.params, state)?;
+                self.state = if probe_state.df.is_empty() {
+                    IEJoinState::Done
+                } else {
+                    IEJoinState::Probe(probe_state)
+                };
+            }
+        }
+
+        // If we are probing and the probe input is done, we're done.
+        if let IEJoinState::Probe(probe_state) = &mut self.state {
+            let samples_consumed = probe_state.sampled_probe_morsels.is_empty();
+            if samples_consumed && recv[probe_idx] == PortState::Done {
+                self.state = IEJoinState::Done;
+            }
+        }
+
+        match &mut self.state {
+            IEJoinState::Sample(sample_state) => {
+                send[0] = PortState::Blocked;
+                if recv[0] != PortState::Done {
+                    recv[0] = if sample_state.left_len < *JOIN_SAMPLE_LIMIT {
+                        PortState::Ready
+                    } else {
+                        PortState::Blocked
+                    };
+                }
+                if recv[1] != PortState::Done {
+                    recv[1] = if sample_state.right_len < *JOIN_SAMPLE_LIMIT {
+                        PortState::Ready
+                    } else {
+                        PortState::Blocked
+                    };
+                }
+            },
+            IEJoinState::Build(_) => {
+                send[0] = PortState::Blocked;
+                if recv[build_idx] != PortState::Done {
+                    recv[build_idx] = PortState::Ready;
+                }
+                if recv[probe_idx] != PortState::Done {
+                    recv[probe_idx] = PortState::Blocked;
+                }
+            },
+            IEJoinState::Probe(_) => {
+                if recv[probe_idx] != PortState::Done {
+                    core::mem::swap(&mut send[0], &mut recv[probe_idx]);
+                } else {
+                    send[0] = PortState::Ready;
+                }
+                recv[build_idx] = PortState::Done;
+            },
+            IEJoinState::Done => {
+                send[0] = PortState::Done;
+                recv[0] = PortState::Done;
+                recv[1] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(
+            self.state,
+            IEJoinState::Sample { .. } | IEJoinState::Build { .. }
+        )
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 2);
+        assert!(send_ports.len() == 1);
+
+        let build_idx = if self.params.left_is_build == Some(true) {
+            0
+        } else {
+            1
+        };
+        let probe_idx = 1 - build_idx;
+
+        match &mut self.state {
+            IEJoinState::Sample(sample_state) => {
+                assert!(send_ports[0].is_none());
+                let left_final_len = Arc::new(AtomicUsize::new(if recv_ports[0].is_none() {
+                    sample_state.left_len
+                } else {
+                    usize::MAX
+                }));
+                let right_final_len = Arc::new(AtomicUsize::new(if recv_ports[1].is_none() {
+                    sample_state.right_len
+                } else {
+                    usize::MAX
+                }));
+
+                if let Some(left_recv) = recv_ports[0].take() {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        sample_sink(
+                            left_recv.serial(),
+                            &mut sample_state.left,
+                            &mut sample_state.left_len,
+                            left_final_len.clone(),
+                            right_final_len.clone(),
+                        ),
+                    ));
+                }
+                if let Some(right_recv) = recv_ports[1].take() {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        sample_sink(
+                            right_recv.serial(),
+                            &mut sample_state.right,
+                            &mut sample_state.right_len,
+                            right_final_len,
+                            left_final_len,
+                        ),
+                    ));
+                }
+            },
+            IEJoinState::Build(build_state) => {
+                assert!(send_ports[0].is_none());
+                assert!(recv_ports[probe_idx].is_none());
+                let receivers = recv_ports[build_idx].take().unwrap().parallel();
+
+                if build_state.frames.len() < receivers.len() {
+                    build_state.frames.resize_with(receivers.len(), Vec::new);
+                }
+                for (frames, recv) in build_state.frames.iter_mut().zip(receivers) {
+                    join_handles
+                        .push(scope.spawn_task(TaskPriority::High, BuildState::sink(recv, frames)));
+                }
+            },
+            IEJoinState::Probe(probe_state) => {
+                assert!(recv_ports[build_idx].is_none());
+                let senders = send_ports[0].take().unwrap().parallel();
+                let receivers = probe_state
+                    .sampled_probe_morsels
+                    .reinsert(
+                        state.num_pipelines,
+                        recv_ports[probe_idx].take(),
+                        scope,
+                        join_handles,
+                    )
+                    .unwrap();
+
+                let probe_state = &*probe_state;
+                for (recv, send) in receivers.into_iter().zip(senders) {
+                    join_handles.push(scope.spawn_task(
+                        TaskPriority::High,
+                        probe_state.probe(recv, send, &self.params, &state.in_memory_exec_state),
+                    ));
+                }
+            },
+            IEJoinState::Done => unreachable!(),
+        }
+    }
+}
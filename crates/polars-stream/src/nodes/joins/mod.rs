@@ -1,4 +1,5 @@
-use std::sync::LazyLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, LazyLock};
 
 use crossbeam_queue::ArrayQueue;
 use polars_core::POOL;
@@ -12,8 +13,12 @@ use crate::async_primitives::wait_group::WaitGroup;
 use crate::morsel::{Morsel, MorselSeq, SourceToken};
 use crate::pipe::RecvPort;
 
+#[cfg(feature = "asof_join")]
+pub mod asof_join;
 pub mod cross_join;
 pub mod equi_join;
+#[cfg(feature = "iejoin")]
+pub mod iejoin;
 pub mod in_memory;
 #[cfg(feature = "semi_anti_join")]
 pub mod semi_anti_join;
@@ -28,6 +33,33 @@ static JOIN_SAMPLE_LIMIT: LazyLock<usize> = LazyLock::new(|| {
 // smaller side as the build side without checking cardinalities.
 const LOPSIDED_SAMPLE_FACTOR: usize = 10;
 
+/// Buffers the morsels of one side of a join while sampling, until we have
+/// seen enough or this side is much bigger than the other side.
+async fn sample_sink(
+    mut recv: Receiver<Morsel>,
+    morsels: &mut Vec<Morsel>,
+    len: &mut usize,
+    this_final_len: Arc<AtomicUsize>,
+    other_final_len: Arc<AtomicUsize>,
+) -> PolarsResult<()> {
+    while let Ok(mut morsel) = recv.recv().await {
+        *len += morsel.df().height();
+        if *len >= *JOIN_SAMPLE_LIMIT
+            || *len
+                >= other_final_len
+                    .load(Ordering::Relaxed)
+                    .saturating_mul(LOPSIDED_SAMPLE_FACTOR)
+        {
+            morsel.source_token().stop();
+        }
+
+        drop(morsel.take_consume_token());
+        morsels.push(morsel);
+    }
+    this_final_len.store(*len, Ordering::Relaxed);
+    Ok(())
+}
+
 // TODO: improve, generalize this, and move it away from here.
 struct BufferedStream {
     morsels: ArrayQueue<Morsel>,
@@ -55,6 +87,12 @@ impl BufferedStream {
         self.morsels.is_empty()
     }
 
+    /// Takes the next buffered morsel, for consumers that don't need them as
+    /// a stream.
+    pub fn pop(&self) -> Option<Morsel> {
+        self.morsels.pop()
+    }
+
     #[allow(clippy::needless_lifetimes)]
     pub fn reinsert<'s, 'env>(
         &'s self,
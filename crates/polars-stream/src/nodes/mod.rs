@@ -1,6 +1,9 @@
+#[cfg(feature = "dynamic_group_by")]
+pub mod dynamic_group_by;
 pub mod filter;
 pub mod group_by;
 pub mod in_memory_map;
+pub mod in_memory_multi_map;
 pub mod in_memory_sink;
 pub mod in_memory_source;
 pub mod input_independent_select;
@@ -16,6 +19,8 @@ pub mod ordered_union;
 pub mod reduce;
 pub mod select;
 pub mod simple_projection;
+pub mod sort;
+pub mod stateful_scan;
 pub mod streaming_slice;
 pub mod with_row_index;
 pub mod zip;
@@ -0,0 +1,625 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+
+use arrow::array::BinaryViewArray;
+use polars_core::POOL;
+use polars_core::chunked_array::ops::row_encode::_get_rows_encoded;
+use polars_core::prelude::{
+    BinaryChunked, Column, DataType, IdxCa, IntoColumn, Scalar, SortMultipleOptions,
+};
+use polars_core::schema::Schema;
+use polars_core::utils::accumulate_dataframes_vertical_unchecked;
+use polars_plan::plans::DataFrameUdf;
+use polars_utils::IdxSize;
+use polars_utils::itertools::Itertools;
+use polars_utils::pl_str::PlSmallStr;
+use rayon::prelude::*;
+
+use super::compute_node_prelude::*;
+use crate::async_primitives::connector::Receiver;
+use crate::async_primitives::wait_group::WaitGroup;
+use crate::expression::StreamExpr;
+use crate::morsel::{SourceToken, get_ideal_morsel_size};
+use crate::nodes::in_memory_source::InMemorySourceNode;
+use crate::utils::in_memory_linearize::linearize;
+use crate::utils::memory::MemoryReservation;
+use crate::utils::spill::{LazySpillDir, SpillDir, SpillReader, SpilledFile, can_spill};
+
+/// Name of the row-encoded sort key column that is appended to sorted runs.
+const SORT_KEY_NAME: PlSmallStr = PlSmallStr::from_static("__POLARS_SORT_KEY");
+
+/// Parameters shared by all the tasks building sorted runs.
+struct SortParams {
+    key_selectors: Vec<StreamExpr>,
+    descending: Vec<bool>,
+    nulls_last: Vec<bool>,
+    maintain_order: bool,
+    /// Non-negative slice (offset, length) fused into this sort.
+    slice: Option<(usize, usize)>,
+    run_schema: Arc<Schema>,
+    spillable: bool,
+}
+
+impl SortParams {
+    /// The maximum number of rows of a run that can end up in the output.
+    fn row_limit(&self) -> Option<usize> {
+        self.slice.map(|(offset, len)| offset.saturating_add(len))
+    }
+
+    /// Sorts the given frames into a single run with the row-encoded key
+    /// appended as the last column.
+    async fn build_run(
+        &self,
+        frames: Vec<(u64, DataFrame)>,
+        state: &ExecutionState,
+    ) -> PolarsResult<DataFrame> {
+        let mut payloads = Vec::with_capacity(frames.len());
+        let mut keys = Vec::with_capacity(frames.len());
+        for (seq, df) in frames {
+            let mut key_columns = Vec::with_capacity(self.key_selectors.len() + 2);
+            for (i, selector) in self.key_selectors.iter().enumerate() {
+                let key = selector.evaluate(&df, state).await?;
+                key_columns.push(key.with_name(format!("_POLARS_SORT_COLUMN_{i}").into()));
+            }
+
+            // Equal keys are ordered by their position in the input stream.
+            if self.maintain_order {
+                key_columns.push(Column::new_scalar(
+                    PlSmallStr::from_static("__POLARS_SORT_SEQ"),
+                    Scalar::from(seq),
+                    df.height(),
+                ));
+                key_columns.push(
+                    IdxCa::from_vec(
+                        PlSmallStr::from_static("__POLARS_SORT_ROW"),
+                        (0..df.height() as IdxSize).collect(),
+                    )
+                    .into_column(),
+                );
+            }
+
+            keys.push(DataFrame::new_with_broadcast_len(key_columns, df.height())?);
+            payloads.push(df);
+        }
+
+        let mut payload = accumulate_dataframes_vertical_unchecked(payloads);
+        let keys = accumulate_dataframes_vertical_unchecked(keys);
+        payload.rechunk_mut();
+
+        let mut descending = self.descending.clone();
+        let mut nulls_last = self.nulls_last.clone();
+        descending.resize(keys.width(), false);
+        nulls_last.resize(keys.width(), false);
+        let rows = _get_rows_encoded(keys.get_columns(), &descending, &nulls_last)?;
+
+        let mut idx = (0..payload.height() as IdxSize).collect_vec();
+        {
+            let encoded = rows.iter().collect_vec();
+            POOL.install(|| idx.par_sort_unstable_by_key(|i| encoded[*i as usize]));
+        }
+        if let Some(limit) = self.row_limit() {
+            idx.truncate(limit);
+        }
+
+        let key = BinaryChunked::with_chunk(SORT_KEY_NAME, rows.into_binview());
+        unsafe {
+            payload.with_column_unchecked(key.into_column());
+            Ok(payload.take_slice_unchecked(&idx))
+        }
+    }
+}
+
+#[derive(Default)]
+struct LocalSortSinkState {
+    buffer: Vec<(u64, DataFrame)>,
+    buffer_rows: usize,
+    buffer_bytes: usize,
+    spilled_runs: Vec<SpilledFile>,
+}
+
+impl LocalSortSinkState {
+    fn take_buffer(&mut self, reservation: &MemoryReservation) -> Vec<(u64, DataFrame)> {
+        reservation.shrink(self.buffer_bytes);
+        self.buffer_rows = 0;
+        self.buffer_bytes = 0;
+        core::mem::take(&mut self.buffer)
+    }
+
+    fn push(&mut self, seq: u64, df: DataFrame, reservation: &MemoryReservation) {
+        let bytes = df.estimated_size();
+        reservation.grow(bytes);
+        self.buffer_rows += df.height();
+        self.buffer_bytes += bytes;
+        self.buffer.push((seq, df));
+    }
+}
+
+struct SortSinkState {
+    params: Arc<SortParams>,
+    locals: Vec<LocalSortSinkState>,
+    reservation: MemoryReservation,
+    spill_dir: LazySpillDir,
+}
+
+impl SortSinkState {
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        receivers: Vec<Receiver<Morsel>>,
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        for (mut recv, local) in receivers.into_iter().zip(&mut self.locals) {
+            let params = &self.params;
+            let reservation = &self.reservation;
+            let spill_dir = &self.spill_dir;
+            join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                let min_run_rows = get_ideal_morsel_size();
+                while let Ok(mut morsel) = recv.recv().await {
+                    morsel.take_consume_token();
+                    let seq = morsel.seq().to_u64();
+                    local.push(seq, morsel.into_df(), reservation);
+
+                    // For a top-k we only ever need to keep the first rows, so
+                    // we periodically compact the buffer instead of spilling.
+                    if let Some(limit) = params.row_limit() {
+                        if !params.maintain_order
+                            && local.buffer_rows >= limit.saturating_mul(2).max(min_run_rows)
+                        {
+                            let buffer = local.take_buffer(reservation);
+                            let mut run = params
+                                .build_run(buffer, &state.in_memory_exec_state)
+                                .await?;
+                            drop(run.drop_in_place(SORT_KEY_NAME.as_str())?);
+                            local.push(seq, run, reservation);
+                            continue;
+                        }
+                    }
+
+                    if params.spillable
+                        && reservation.should_spill()
+                        && local.buffer_rows >= min_run_rows
+                    {
+                        let buffer = local.take_buffer(reservation);
+                        let run = params
+                            .build_run(buffer, &state.in_memory_exec_state)
+                            .await?;
+                        let spill_dir = spill_dir.get()?;
+                        let run_schema = params.run_schema.clone();
+                        let spilled = polars_io::pl_async::get_runtime()
+                            .spawn_blocking(move || {
+                                // Write in morsel-sized batches so the merge can read
+                                // the run back incrementally.
+                                let batches = (0..run.height())
+                                    .step_by(min_run_rows)
+                                    .map(|offset| run.slice(offset as i64, min_run_rows));
+                                spill_dir.spill(&run_schema, batches)
+                            })
+                            .await
+                            .unwrap()?;
+                        local.spilled_runs.push(spilled);
+                    }
+                }
+
+                Ok(())
+            }));
+        }
+    }
+
+    fn has_spilled(&self) -> bool {
+        self.locals.iter().any(|l| !l.spilled_runs.is_empty())
+    }
+
+    /// Sorts everything in memory, used if nothing was spilled.
+    fn finalize_in_memory(&mut self, in_memory_sort: &dyn DataFrameUdf) -> PolarsResult<DataFrame> {
+        let morsels_per_pipe = self
+            .locals
+            .iter_mut()
+            .map(|l| {
+                l.take_buffer(&self.reservation)
+                    .into_iter()
+                    .map(|(seq, df)| Morsel::new(df, MorselSeq::new(seq / 2), SourceToken::new()))
+                    .collect_vec()
+            })
+            .collect_vec();
+        let dataframes = linearize(morsels_per_pipe);
+        let df = if dataframes.is_empty() {
+            DataFrame::empty_with_schema(&self.params.run_schema)
+                .drop(SORT_KEY_NAME.as_str())
+                .unwrap()
+        } else {
+            accumulate_dataframes_vertical_unchecked(dataframes)
+        };
+        in_memory_sort.call_udf(df)
+    }
+
+    /// Turns the remaining buffers into sorted runs and prepares the k-way
+    /// merge of all runs.
+    fn finalize_merge(&mut self, state: &ExecutionState) -> PolarsResult<SortMergeSource> {
+        let buffers = self
+            .locals
+            .iter_mut()
+            .map(|l| l.take_buffer(&self.reservation))
+            .filter(|b| !b.is_empty())
+            .collect_vec();
+        let params = &self.params;
+        let in_memory_runs = POOL.install(|| {
+            buffers
+                .into_par_iter()
+                .map(|buffer| {
+                    polars_io::pl_async::get_runtime().block_on(params.build_run(buffer, state))
+                })
+                .collect::<PolarsResult<Vec<_>>>()
+        })?;
+
+        let mut runs = Vec::new();
+        for run in in_memory_runs {
+            runs.push(RunCursor::new(run, None)?);
+        }
+        for local in &mut self.locals {
+            for spilled in local.spilled_runs.drain(..) {
+                let mut reader = spilled.reader()?;
+                if let Some(df) = reader.next().transpose()? {
+                    runs.push(RunCursor::new(df, Some((reader, spilled)))?);
+                }
+            }
+        }
+
+        let (offset, length) = params.slice.unwrap_or((0, usize::MAX));
+        let mut heap = BinaryHeap::with_capacity(runs.len());
+        for (i, run) in runs.iter().enumerate() {
+            if let Some(key) = run.current_key() {
+                heap.push(Reverse((key.to_vec(), i)));
+            }
+        }
+        Ok(SortMergeSource {
+            runs,
+            heap,
+            offset,
+            remaining: length,
+            seq: MorselSeq::default(),
+            // Keep the spill directory alive until the merge is done.
+            _spill_dir: self.spill_dir.take(),
+        })
+    }
+}
+
+/// A cursor into a sorted run, which is either fully in memory or is read
+/// back from disk one batch at a time.
+struct RunCursor {
+    df: DataFrame,
+    keys: BinaryViewArray,
+    pos: usize,
+    spilled: Option<(SpillReader, SpilledFile)>,
+}
+
+impl RunCursor {
+    fn new(df: DataFrame, spilled: Option<(SpillReader, SpilledFile)>) -> PolarsResult<Self> {
+        let mut slf = Self {
+            df: DataFrame::empty(),
+            keys: BinaryViewArray::new_empty(arrow::datatypes::ArrowDataType::BinaryView),
+            pos: 0,
+            spilled,
+        };
+        slf.set_batch(df)?;
+        Ok(slf)
+    }
+
+    fn set_batch(&mut self, mut df: DataFrame) -> PolarsResult<()> {
+        df.rechunk_mut();
+        let keys = df.column(SORT_KEY_NAME.as_str())?.binary()?;
+        self.keys = keys.downcast_as_array().clone();
+        self.df = df;
+        self.pos = 0;
+        Ok(())
+    }
+
+    fn current_key(&self) -> Option<&[u8]> {
+        (self.pos < self.keys.len()).then(|| unsafe { self.keys.value_unchecked(self.pos) })
+    }
+
+    /// Moves to the next batch if the current batch is exhausted. Returns
+    /// false if the run is exhausted.
+    fn advance(&mut self) -> PolarsResult<bool> {
+        while self.pos >= self.df.height() {
+            let Some((reader, _)) = &mut self.spilled else {
+                return Ok(false);
+            };
+            match reader.next().transpose()? {
+                Some(df) => self.set_batch(df)?,
+                None => {
+                    self.spilled = None;
+                    return Ok(false);
+                },
+            }
+        }
+        Ok(true)
+    }
+
+    /// The end of the rows in the current batch which are less than or equal
+    /// to the given key.
+    fn end_of_rows_le(&self, key: &[u8]) -> usize {
+        let mut lo = self.pos;
+        let mut hi = self.keys.len();
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if unsafe { self.keys.value_unchecked(mid) } <= key {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        lo
+    }
+}
+
+/// K-way merges sorted runs.
+struct SortMergeSource {
+    runs: Vec<RunCursor>,
+    heap: BinaryHeap<Reverse<(Vec<u8>, usize)>>,
+    /// Rows still to skip before output starts.
+    offset: usize,
+    /// Rows still to output.
+    remaining: usize,
+    seq: MorselSeq,
+    _spill_dir: Option<Arc<SpillDir>>,
+}
+
+impl SortMergeSource {
+    fn is_exhausted(&self) -> bool {
+        self.heap.is_empty() || self.remaining == 0
+    }
+
+    fn next_morsel(&mut self, morsel_size: usize) -> PolarsResult<Option<DataFrame>> {
+        // Rows taken from each run batch touched in this morsel, and for each
+        // output row the (batch, index into the taken rows) pair.
+        let mut batches: Vec<(DataFrame, Vec<IdxSize>)> = Vec::new();
+        let mut batch_of_run: Vec<Option<usize>> = vec![None; self.runs.len()];
+        let mut order: Vec<(usize, IdxSize)> = Vec::new();
+
+        while order.len() < morsel_size && self.remaining > 0 {
+            let Some(Reverse((_, r))) = self.heap.pop() else {
+                break;
+            };
+            let run = &mut self.runs[r];
+            let end = match self.heap.peek() {
+                Some(Reverse((next_key, _))) => run.end_of_rows_le(next_key),
+                None => run.df.height(),
+            };
+
+            let available = end - run.pos;
+            let skip = available.min(self.offset);
+            self.offset -= skip;
+            let emit = (available - skip)
+                .min(morsel_size - order.len())
+                .min(self.remaining);
+            self.remaining -= emit;
+
+            if emit > 0 {
+                let b = *batch_of_run[r].get_or_insert_with(|| {
+                    batches.push((run.df.clone(), Vec::new()));
+                    batches.len() - 1
+                });
+                let taken = &mut batches[b].1;
+                for i in run.pos + skip..run.pos + skip + emit {
+                    order.push((b, taken.len() as IdxSize));
+                    taken.push(i as IdxSize);
+                }
+            }
+            run.pos += skip + emit;
+
+            if run.pos >= run.df.height() {
+                // The next batch of this run is a different frame.
+                batch_of_run[r] = None;
+            }
+            if run.advance()? {
+                self.heap
+                    .push(Reverse((run.current_key().unwrap().to_vec(), r)));
+            }
+        }
+
+        if order.is_empty() {
+            return Ok(None);
+        }
+
+        let mut offsets = Vec::with_capacity(batches.len());
+        let mut total = 0;
+        let taken = batches
+            .into_iter()
+            .map(|(df, idx)| {
+                offsets.push(total);
+                total += idx.len() as IdxSize;
+                unsafe { df.take_slice_unchecked(&idx) }
+            })
+            .collect_vec();
+        let mut df = accumulate_dataframes_vertical_unchecked(taken);
+        df.rechunk_mut();
+        let gather = order.into_iter().map(|(b, i)| offsets[b] + i).collect_vec();
+        let mut df = unsafe { df.take_slice_unchecked(&gather) };
+        drop(df.drop_in_place(SORT_KEY_NAME.as_str())?);
+        Ok(Some(df))
+    }
+}
+
+enum SortState {
+    Sink(SortSinkState),
+    Source(InMemorySourceNode),
+    Merge(SortMergeSource),
+    Done,
+}
+
+/// A streaming sort that sorts each pipeline's input in runs, spilling those
+/// runs to disk if the memory budget is exceeded, and k-way
+/// merges the runs once all input is received. If nothing was spilled the
+/// in-memory sort is used instead.
+pub struct SortNode {
+    state: SortState,
+    in_memory_sort: Arc<dyn DataFrameUdf>,
+}
+
+impl SortNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        key_selectors: Vec<StreamExpr>,
+        sort_options: &SortMultipleOptions,
+        slice: Option<(usize, usize)>,
+        in_memory_sort: Arc<dyn DataFrameUdf>,
+        num_pipelines: usize,
+    ) -> Self {
+        let num_keys = key_selectors.len();
+        let broadcast = |v: &Vec<bool>| {
+            if v.len() == 1 {
+                vec![v[0]; num_keys]
+            } else {
+                v.clone()
+            }
+        };
+
+        let mut run_schema = input_schema.as_ref().clone();
+        run_schema.insert(SORT_KEY_NAME, DataType::Binary);
+
+        let params = SortParams {
+            key_selectors,
+            descending: broadcast(&sort_options.descending),
+            nulls_last: broadcast(&sort_options.nulls_last),
+            maintain_order: sort_options.maintain_order,
+            slice,
+            spillable: can_spill(&run_schema),
+            run_schema: Arc::new(run_schema),
+        };
+
+        Self {
+            state: SortState::Sink(SortSinkState {
+                params: Arc::new(params),
+                locals: (0..num_pipelines)
+                    .map(|_| LocalSortSinkState::default())
+                    .collect(),
+                reservation: MemoryReservation::new(),
+                spill_dir: LazySpillDir::new("sort"),
+            }),
+            in_memory_sort,
+        }
+    }
+}
+
+impl ComputeNode for SortNode {
+    fn name(&self) -> &str {
+        "sort"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = SortState::Done;
+            },
+            // Input is done, transition to being a source.
+            SortState::Sink(sink) if matches!(recv[0], PortState::Done) => {
+                let force_ooc = std::env::var("POLARS_FORCE_OOC").is_ok();
+                if sink.has_spilled() || force_ooc {
+                    let merge = sink.finalize_merge(&state.in_memory_exec_state)?;
+                    self.state = SortState::Merge(merge);
+                } else {
+                    let df = sink.finalize_in_memory(self.in_memory_sort.as_ref())?;
+                    let source = InMemorySourceNode::new(Arc::new(df), MorselSeq::default());
+                    self.state = SortState::Source(source);
+                }
+            },
+            // Nothing to change.
+            SortState::Source(_) | SortState::Merge(_) | SortState::Done | SortState::Sink(_) => {},
+        }
+
+        // Communicate our state.
+        match &mut self.state {
+            SortState::Sink(_) => {
+                send[0] = PortState::Blocked;
+                recv[0] = PortState::Ready;
+            },
+            SortState::Source(src) => {
+                recv[0] = PortState::Done;
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = SortState::Done;
+                }
+            },
+            SortState::Merge(merge) => {
+                recv[0] = PortState::Done;
+                if merge.is_exhausted() {
+                    send[0] = PortState::Done;
+                    self.state = SortState::Done;
+                } else {
+                    send[0] = PortState::Ready;
+                }
+            },
+            SortState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn is_memory_intensive_pipeline_blocker(&self) -> bool {
+        matches!(self.state, SortState::Sink(_))
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(send_ports.len() == 1 && recv_ports.len() == 1);
+        match &mut self.state {
+            SortState::Sink(sink) => {
+                assert!(send_ports[0].is_none());
+                sink.spawn(
+                    scope,
+                    recv_ports[0].take().unwrap().parallel(),
+                    state,
+                    join_handles,
+                )
+            },
+            SortState::Source(source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            SortState::Merge(merge) => {
+                assert!(recv_ports[0].is_none());
+                let mut send = send_ports[0].take().unwrap().serial();
+                join_handles.push(scope.spawn_task(TaskPriority::Low, async move {
+                    let source_token = SourceToken::new();
+                    let wait_group = WaitGroup::default();
+                    let morsel_size = get_ideal_morsel_size();
+                    while let Some(df) = merge.next_morsel(morsel_size)? {
+                        let mut morsel = Morsel::new(df, merge.seq, source_token.clone());
+                        merge.seq = merge.seq.successor();
+                        morsel.set_consume_token(wait_group.token());
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+
+                        wait_group.wait().await;
+                        if source_token.stop_requested() {
+                            break;
+                        }
+                    }
+
+                    Ok(())
+                }));
+            },
+            SortState::Done => unreachable!(),
+        }
+    }
+}
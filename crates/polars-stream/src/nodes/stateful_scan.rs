@@ -0,0 +1,240 @@
+use std::sync::Arc;
+
+use polars_core::prelude::*;
+use polars_core::utils::last_non_null;
+
+use super::compute_node_prelude::*;
+use crate::expression::StreamExpr;
+use crate::nodes::in_memory_source::InMemorySourceNode;
+
+/// What a [`StatefulScanNode`] carries over from one morsel to the next.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ScanCarry {
+    /// The last `n` input rows, for functions which look back at most `n`
+    /// rows, e.g. `shift(n)`, `diff(n)` or a forward fill with a limit.
+    Lookback(usize),
+    /// The last non-null output, for cumulative functions which continue
+    /// from their previous output, e.g. `cum_sum` or a forward fill.
+    LastValue,
+    /// The number of values counted so far, for `cum_count`.
+    Count,
+    /// The trailing null inputs which can still be filled by a later value,
+    /// for a backward fill with an optional limit. The output of these rows
+    /// is held back until that value arrives.
+    Lookahead(Option<usize>),
+}
+
+fn trailing_null_count(s: &Series) -> usize {
+    let validities = s.chunks().iter().map(|arr| arr.validity());
+    s.len() - last_non_null(validities, s.len()).map_or(0, |i| i + 1)
+}
+
+/// Evaluates an order-dependent, length-preserving function on a single
+/// input column, one morsel at a time in order.
+///
+/// Each morsel is extended with the rows carried over from the previous
+/// morsels, such that the in-memory kernel of the function computes the
+/// same output as it would on the full column.
+pub struct StatefulScanNode {
+    state: StatefulScanState,
+    function: StreamExpr,
+    carry: ScanCarry,
+    output_schema: Arc<Schema>,
+    /// The rows carried over, named like the input column.
+    context: Series,
+    count: IdxSize,
+    seq: MorselSeq,
+}
+
+enum StatefulScanState {
+    Running,
+    Flush(InMemorySourceNode),
+    Done,
+}
+
+impl StatefulScanNode {
+    pub fn new(
+        input_schema: Arc<Schema>,
+        function: StreamExpr,
+        carry: ScanCarry,
+        output_schema: Arc<Schema>,
+    ) -> Self {
+        assert!(input_schema.len() == 1 && output_schema.len() == 1);
+        let (name, in_dtype) = input_schema.get_at_index(0).unwrap();
+        // We continue from the last output, so the input has to be of the
+        // same type.
+        let dtype = match carry {
+            ScanCarry::LastValue => output_schema.get_at_index(0).unwrap().1,
+            _ => in_dtype,
+        };
+        Self {
+            state: StatefulScanState::Running,
+            function,
+            carry,
+            context: Series::new_empty(name.clone(), dtype),
+            output_schema,
+            count: 0,
+            seq: MorselSeq::default(),
+        }
+    }
+
+    async fn evaluate(&self, input: Series, state: &ExecutionState) -> PolarsResult<Series> {
+        let out = self.function.evaluate(&input.into_frame(), state).await?;
+        let name = self.output_schema.get_at_index(0).unwrap().0;
+        Ok(out.take_materialized_series().with_name(name.clone()))
+    }
+
+    async fn scan(&mut self, df: DataFrame, state: &ExecutionState) -> PolarsResult<DataFrame> {
+        let mut input = df.take_columns().pop().unwrap().take_materialized_series();
+        let len = input.len();
+        if self.carry == ScanCarry::Count {
+            let out = self.evaluate(input.clone(), state).await?;
+            let out = &out + self.count;
+            self.count += (len - input.null_count()) as IdxSize;
+            return Ok(out.into_frame());
+        }
+
+        if input.dtype() != self.context.dtype() {
+            input = input.cast(self.context.dtype())?;
+        }
+        let num_context = self.context.len();
+        let mut combined = self.context.clone();
+        combined.append(&input)?;
+        let out = self.evaluate(combined.clone(), state).await?;
+
+        let out = match self.carry {
+            ScanCarry::Lookback(n) => {
+                let offset = combined.len().saturating_sub(n);
+                self.context = combined.slice(offset as i64, n);
+                out.slice(num_context as i64, len)
+            },
+            ScanCarry::LastValue => {
+                let validities = out.chunks().iter().map(|arr| arr.validity());
+                if let Some(idx) = last_non_null(validities, out.len()) {
+                    self.context = out
+                        .slice(idx as i64, 1)
+                        .with_name(self.context.name().clone());
+                }
+                out.slice(num_context as i64, len)
+            },
+            ScanCarry::Lookahead(limit) => {
+                let trailing_nulls = trailing_null_count(&combined);
+                let held = limit.map_or(trailing_nulls, |l| trailing_nulls.min(l));
+                let emitted = combined.len() - held;
+                self.context = combined.slice(emitted as i64, held);
+                out.slice(0, emitted)
+            },
+            ScanCarry::Count => unreachable!(),
+        };
+        Ok(out.into_frame())
+    }
+
+    /// Returns the output which is still held back after the input is done.
+    fn flush(&mut self, state: &ExecutionState) -> PolarsResult<DataFrame> {
+        let context = std::mem::take(&mut self.context);
+        if !matches!(self.carry, ScanCarry::Lookahead(_)) || context.is_empty() {
+            return Ok(DataFrame::empty_with_schema(&self.output_schema));
+        }
+        let out = self
+            .function
+            .evaluate_blocking(&context.into_frame(), state)?;
+        let name = self.output_schema.get_at_index(0).unwrap().0;
+        Ok(out.with_name(name.clone()).into_frame())
+    }
+}
+
+impl ComputeNode for StatefulScanNode {
+    fn name(&self) -> &str {
+        "stateful-scan"
+    }
+
+    fn update_state(
+        &mut self,
+        recv: &mut [PortState],
+        send: &mut [PortState],
+        state: &StreamingExecutionState,
+    ) -> PolarsResult<()> {
+        assert!(recv.len() == 1 && send.len() == 1);
+
+        // State transitions.
+        match &mut self.state {
+            // If the output doesn't want any more data, transition to being done.
+            _ if send[0] == PortState::Done => {
+                self.state = StatefulScanState::Done;
+            },
+            // Input is done, flush the held back output.
+            StatefulScanState::Running if recv[0] == PortState::Done => {
+                let df = self.flush(&state.in_memory_exec_state)?;
+                self.state = if df.is_empty() {
+                    StatefulScanState::Done
+                } else {
+                    StatefulScanState::Flush(InMemorySourceNode::new(Arc::new(df), self.seq))
+                };
+            },
+            // Defer to source node implementation.
+            StatefulScanState::Flush(src) => {
+                src.update_state(&mut [], send, state)?;
+                if send[0] == PortState::Done {
+                    self.state = StatefulScanState::Done;
+                }
+            },
+            // Nothing to change.
+            StatefulScanState::Running | StatefulScanState::Done => {},
+        }
+
+        // Communicate our state.
+        match &self.state {
+            StatefulScanState::Running => {
+                recv.swap_with_slice(send);
+            },
+            StatefulScanState::Flush(..) => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Ready;
+            },
+            StatefulScanState::Done => {
+                recv[0] = PortState::Done;
+                send[0] = PortState::Done;
+            },
+        }
+        Ok(())
+    }
+
+    fn spawn<'env, 's>(
+        &'env mut self,
+        scope: &'s TaskScope<'s, 'env>,
+        recv_ports: &mut [Option<RecvPort<'_>>],
+        send_ports: &mut [Option<SendPort<'_>>],
+        state: &'s StreamingExecutionState,
+        join_handles: &mut Vec<JoinHandle<PolarsResult<()>>>,
+    ) {
+        assert!(recv_ports.len() == 1 && send_ports.len() == 1);
+        match self.state {
+            StatefulScanState::Running => {
+                let mut recv = recv_ports[0].take().unwrap().serial();
+                let mut send = send_ports[0].take().unwrap().serial();
+
+                // The state depends on all previous morsels, so we have to be serial.
+                join_handles.push(scope.spawn_task(TaskPriority::High, async move {
+                    while let Ok(morsel) = recv.recv().await {
+                        let (df, seq, source_token, consume_token) = morsel.into_inner();
+                        let out = self.scan(df, &state.in_memory_exec_state).await?;
+                        self.seq = seq.successor();
+                        let mut morsel = Morsel::new(out, seq, source_token);
+                        if let Some(token) = consume_token {
+                            morsel.set_consume_token(token);
+                        }
+                        if send.send(morsel).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(())
+                }));
+            },
+            StatefulScanState::Flush(ref mut source) => {
+                assert!(recv_ports[0].is_none());
+                source.spawn(scope, &mut [], send_ports, state, join_handles);
+            },
+            StatefulScanState::Done => unreachable!(),
+        }
+    }
+}
@@ -8,7 +8,7 @@ use polars_utils::arena::Arena;
 use polars_utils::slice_enum::Slice;
 use slotmap::{Key, SecondaryMap, SlotMap};
 
-use super::{PhysNode, PhysNodeKey, PhysNodeKind};
+use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream};
 
 /// A style of a graph node.
 enum NodeStyle {
@@ -25,7 +25,7 @@ impl NodeStyle {
     pub fn for_node_kind(kind: &PhysNodeKind) -> Self {
         use PhysNodeKind as K;
         match kind {
-            K::InMemoryMap { .. } => Self::InMemoryFallback,
+            K::InMemoryMap { .. } | K::InMemoryExtContext { .. } => Self::InMemoryFallback,
             K::InMemorySource { .. }
             | K::InputIndependentSelect { .. }
             | K::NegativeSlice { .. }
@@ -38,6 +38,10 @@ impl NodeStyle {
             | K::Multiplexer { .. } => Self::MemoryIntensive,
             #[cfg(feature = "merge_sorted")]
             K::MergeSorted { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "dynamic_group_by")]
+            K::DynamicGroupBy { .. } => Self::MemoryIntensive,
+            #[cfg(feature = "iejoin")]
+            K::IEJoin { .. } => Self::MemoryIntensive,
             _ => Self::Generic,
         }
     }
@@ -161,6 +165,7 @@ fn visualize_plan_rec(
     let kind = &phys_sm[node_key].kind;
 
     use std::slice::from_ref;
+    let ext_context_inputs: Vec<PhysStream>;
     let (label, inputs) = match kind {
         PhysNodeKind::InMemorySource { df } => (
             format!(
@@ -256,6 +261,8 @@ fn visualize_plan_rec(
             FileType::Csv(_) => ("csv-sink".to_string(), from_ref(input)),
             #[cfg(feature = "json")]
             FileType::Json(_) => ("ndjson-sink".to_string(), from_ref(input)),
+            #[cfg(feature = "avro")]
+            FileType::Avro(_) => ("avro-sink".to_string(), from_ref(input)),
             #[allow(unreachable_patterns)]
             _ => todo!(),
         },
@@ -280,6 +287,8 @@ fn visualize_plan_rec(
                 FileType::Csv(_) => (format!("{variant}[csv]"), from_ref(input)),
                 #[cfg(feature = "json")]
                 FileType::Json(_) => (format!("{variant}[ndjson]"), from_ref(input)),
+                #[cfg(feature = "avro")]
+                FileType::Avro(_) => (format!("{variant}[avro]"), from_ref(input)),
                 #[allow(unreachable_patterns)]
                 _ => todo!(),
             }
@@ -299,6 +308,25 @@ fn visualize_plan_rec(
             }
             (label, from_ref(input))
         },
+        PhysNodeKind::InMemoryExtContext {
+            input,
+            contexts,
+            format_str,
+            ..
+        } => {
+            let mut label = String::new();
+            label.push_str("in-memory-ext-context");
+            if let Some(format_str) = format_str {
+                label.push('\n');
+
+                let mut f = EscapeLabel(&mut label);
+                write!(f, "{format_str}").unwrap();
+            }
+            ext_context_inputs = std::iter::once(*input)
+                .chain(contexts.iter().copied())
+                .collect();
+            (label, ext_context_inputs.as_slice())
+        },
         PhysNodeKind::Map { input, map: _ } => ("map".to_string(), from_ref(input)),
         PhysNodeKind::Sort {
             input,
@@ -312,6 +340,17 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        PhysNodeKind::StatefulScan {
+            input,
+            function,
+            carry,
+        } => (
+            format!(
+                "stateful-scan\\n{}\\ncarry: {carry:?}",
+                fmt_exprs_to_label(from_ref(function), expr_arena, FormatExprStyle::Select)
+            ),
+            from_ref(input),
+        ),
         PhysNodeKind::OrderedUnion { inputs } => ("ordered-union".to_string(), inputs.as_slice()),
         PhysNodeKind::Zip {
             inputs,
@@ -403,6 +442,28 @@ fn visualize_plan_rec(
             ),
             from_ref(input),
         ),
+        #[cfg(feature = "dynamic_group_by")]
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            key,
+            aggs,
+            window,
+            key_order_name: _,
+        } => {
+            use crate::nodes::dynamic_group_by::TemporalWindow;
+            let name = match window {
+                TemporalWindow::Dynamic(_) => "dynamic-group-by",
+                TemporalWindow::Rolling(_) => "rolling-group-by",
+            };
+            let mut out = format!(
+                "{name}\\nkey:\\n{}\\naggs:\\n{}",
+                fmt_exprs_to_label(key, expr_arena, FormatExprStyle::Select),
+                fmt_exprs_to_label(aggs, expr_arena, FormatExprStyle::Select)
+            );
+            let mut f = EscapeLabel(&mut out);
+            write!(f, "\nindex column: {}", window.index_column()).unwrap();
+            (out, from_ref(input))
+        },
         PhysNodeKind::InMemoryJoin {
             input_left,
             input_right,
@@ -475,6 +536,82 @@ fn visualize_plan_rec(
             input_right,
             args: _,
         } => ("cross-join".to_string(), &[*input_left, *input_right][..]),
+        #[cfg(feature = "asof_join")]
+        PhysNodeKind::AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let mut label = "asof-join".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(from_ref(left_on), expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(from_ref(right_on), expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            if let polars_ops::frame::JoinType::AsOf(options) = &args.how {
+                write!(
+                    label,
+                    r"\nstrategy: {}",
+                    escape_graphviz(&format!("{:?}", options.strategy))
+                )
+                .unwrap();
+                if let Some(left_by) = &options.left_by {
+                    write!(
+                        label,
+                        r"\nleft_by: {}",
+                        escape_graphviz(&left_by.join(", "))
+                    )
+                    .unwrap();
+                }
+                if let Some(right_by) = &options.right_by {
+                    write!(
+                        label,
+                        r"\nright_by: {}",
+                        escape_graphviz(&right_by.join(", "))
+                    )
+                    .unwrap();
+                }
+            }
+            (label, &[*input_left, *input_right][..])
+        },
+        #[cfg(feature = "iejoin")]
+        PhysNodeKind::IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args: _,
+            options,
+        } => {
+            let mut label = "ie-join".to_string();
+            write!(
+                label,
+                r"\nleft_on:\n{}",
+                fmt_exprs_to_label(left_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            write!(
+                label,
+                r"\nright_on:\n{}",
+                fmt_exprs_to_label(right_on, expr_arena, FormatExprStyle::NoAliases)
+            )
+            .unwrap();
+            let operators = std::iter::once(options.operator1)
+                .chain(options.operator2)
+                .map(|op| format!("{op:?}"))
+                .collect::<Vec<_>>();
+            write!(label, r"\noperators: {}", operators.join(", ")).unwrap();
+            (label, &[*input_left, *input_right][..])
+        },
         #[cfg(feature = "merge_sorted")]
         PhysNodeKind::MergeSorted {
             input_left,
@@ -1,12 +1,12 @@
 use std::sync::Arc;
 
 use polars_core::frame::DataFrame;
-use polars_core::prelude::{DataType, Field, InitHashMaps, PlHashMap, PlHashSet};
+use polars_core::prelude::{DataType, Field, FillNullStrategy, InitHashMaps, PlHashMap, PlHashSet};
 use polars_core::schema::{Schema, SchemaExt};
 use polars_error::PolarsResult;
 use polars_expr::state::ExecutionState;
 use polars_expr::{ExpressionConversionState, create_physical_expr};
-use polars_ops::frame::{JoinArgs, JoinType};
+use polars_ops::frame::{JoinArgs, JoinCoalesce, JoinType, MaintainOrderJoin};
 use polars_plan::plans::AExpr;
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::prelude::*;
@@ -18,6 +18,7 @@ use slotmap::SlotMap;
 
 use super::fmt::fmt_exprs;
 use super::{PhysNode, PhysNodeKey, PhysNodeKind, PhysStream, StreamingLowerIRContext};
+use crate::nodes::stateful_scan::ScanCarry;
 use crate::physical_plan::lower_group_by::build_group_by_stream;
 
 type ExprNodeKey = Node;
@@ -865,6 +866,13 @@ fn lower_exprs_with_ctx(
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
             AExpr::Agg(mut agg) => match agg {
+                // The streaming quantile needs to know the quantile upfront.
+                IRAggExpr::Quantile { quantile, .. } if !matches!(ctx.expr_arena.get(quantile), AExpr::Literal(lv) if lv.is_scalar()) =>
+                {
+                    let out_name = unique_column_name();
+                    fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
+                    transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
+                },
                 // Change agg mutably so we can share the codepath for all of these.
                 IRAggExpr::Min {
                     input: ref mut inner,
@@ -880,7 +888,13 @@ fn lower_exprs_with_ctx(
                 | IRAggExpr::Mean(ref mut inner)
                 | IRAggExpr::Var(ref mut inner, _ /* ddof */)
                 | IRAggExpr::Std(ref mut inner, _ /* ddof */)
-                | IRAggExpr::Count(ref mut inner, _ /* count_nulls */) => {
+                | IRAggExpr::Count(ref mut inner, _ /* count_nulls */)
+                | IRAggExpr::Median(ref mut inner)
+                | IRAggExpr::Implode(ref mut inner)
+                | IRAggExpr::Quantile {
+                    expr: ref mut inner,
+                    ..
+                } => {
                     let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &[*inner], ctx)?;
                     *inner = trans_exprs[0];
 
@@ -939,10 +953,7 @@ fn lower_exprs_with_ctx(
                     input_streams.insert(PhysStream::first(reduce_node_key));
                     transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(tmp_name)));
                 },
-                IRAggExpr::Median(_)
-                | IRAggExpr::Implode(_)
-                | IRAggExpr::Quantile { .. }
-                | IRAggExpr::AggGroups(_) => {
+                IRAggExpr::AggGroups(_) => {
                     let out_name = unique_column_name();
                     fallback_subset.push(ExprIR::new(expr, OutputName::Alias(out_name.clone())));
                     transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
@@ -960,6 +971,27 @@ fn lower_exprs_with_ctx(
                 input_streams.insert(PhysStream::first(reduce_node_key));
                 transformed_exprs.push(ctx.expr_arena.add(AExpr::Column(out_name)));
             },
+            AExpr::Window {
+                function,
+                ref partition_by,
+                order_by: None,
+                options: WindowType::Over(mapping),
+            } if is_streamable_window(function, partition_by, ctx) => {
+                let (trans_input, trans_expr) =
+                    lower_window_expr(input, function, partition_by, mapping, ctx)?;
+                input_streams.insert(trans_input);
+                transformed_exprs.push(trans_expr);
+            },
+            AExpr::Function {
+                input: ref inner_exprs,
+                ref function,
+                options: _,
+            } if stateful_scan_carry(function, inner_exprs, ctx.expr_arena).is_some() => {
+                let carry = stateful_scan_carry(function, inner_exprs, ctx.expr_arena).unwrap();
+                let (trans_input, trans_expr) = lower_stateful_scan(input, expr, carry, ctx)?;
+                input_streams.insert(trans_input);
+                transformed_exprs.push(trans_expr);
+            },
             AExpr::AnonymousFunction { .. }
             | AExpr::Function { .. }
             | AExpr::Slice { .. }
@@ -1002,6 +1034,228 @@ fn lower_exprs_with_ctx(
     Ok((PhysStream::first(zip_node), transformed_exprs))
 }
 
+/// Whether a window expression can be lowered to a group by on the partition
+/// keys, which requires the function to either be elementwise or give a single
+/// value per group.
+fn is_streamable_window(function: Node, partition_by: &[Node], ctx: &mut LowerExprContext) -> bool {
+    !partition_by.is_empty()
+        && partition_by
+            .iter()
+            .all(|key| is_elementwise_rec_cached(*key, ctx.expr_arena, ctx.cache))
+        && !is_input_independent_ctx(function, ctx)
+        && (is_elementwise_rec_cached(function, ctx.expr_arena, ctx.cache)
+            || is_scalar_ae(function, ctx.expr_arena))
+}
+
+/// Lowers `function.over(partition_by)` to a group by on the partition keys,
+/// which is joined back onto the original rows in their original order.
+fn lower_window_expr(
+    input: PhysStream,
+    function: Node,
+    partition_by: &[Node],
+    mapping: WindowMapping,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<(PhysStream, Node)> {
+    let is_elementwise = is_elementwise_rec_cached(function, ctx.expr_arena, ctx.cache);
+    if is_elementwise && mapping == WindowMapping::GroupsToRows {
+        // Mapping the groups back to their rows gives the function itself.
+        let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &[function], ctx)?;
+        return Ok((trans_input, trans_exprs[0]));
+    }
+
+    let key_names = partition_by
+        .iter()
+        .map(|_| unique_column_name())
+        .collect_vec();
+    let keys = partition_by
+        .iter()
+        .zip(&key_names)
+        .map(|(key, name)| ExprIR::new(*key, OutputName::Alias(name.clone())))
+        .collect_vec();
+
+    // Non-aggregated values are gathered per group.
+    let agg_name = unique_column_name();
+    let agg_node = if is_elementwise {
+        ctx.expr_arena.add(AExpr::Agg(IRAggExpr::Implode(function)))
+    } else {
+        function
+    };
+    let agg = ExprIR::new(agg_node, OutputName::Alias(agg_name.clone()));
+    let group_by_output_schema = schema_for_select(
+        input,
+        &[keys.as_slice(), std::slice::from_ref(&agg)].concat(),
+        ctx,
+    )?;
+    let maintain_order = mapping == WindowMapping::Explode;
+    let group_by_stream = build_group_by_stream(
+        input,
+        &keys,
+        std::slice::from_ref(&agg),
+        group_by_output_schema.clone(),
+        maintain_order,
+        Arc::new(GroupbyOptions::default()),
+        None,
+        ctx.expr_arena,
+        ctx.phys_sm,
+        ctx.cache,
+        StreamingLowerIRContext::from(&*ctx),
+    )?;
+
+    let agg_col = ctx.expr_arena.add(AExpr::Column(agg_name.clone()));
+    if mapping == WindowMapping::Explode {
+        // The groups are flattened in the order they first occur.
+        let trans_expr = if is_elementwise {
+            ctx.expr_arena.add(AExpr::Explode {
+                expr: agg_col,
+                skip_empty: false,
+            })
+        } else {
+            agg_col
+        };
+        return Ok((group_by_stream, trans_expr));
+    }
+
+    let left_stream = build_select_stream_with_ctx(input, &keys, ctx)?;
+    let key_cols = key_names
+        .iter()
+        .map(|name| {
+            let node = ctx.expr_arena.add(AExpr::Column(name.clone()));
+            ExprIR::new(node, OutputName::Alias(name.clone()))
+        })
+        .collect_vec();
+    let mut output_schema = ctx.phys_sm[left_stream.node].output_schema.as_ref().clone();
+    output_schema.insert(
+        agg_name.clone(),
+        group_by_output_schema.get(&agg_name).unwrap().clone(),
+    );
+    let kind = PhysNodeKind::EquiJoin {
+        input_left: left_stream,
+        input_right: group_by_stream,
+        left_on: key_cols.clone(),
+        right_on: key_cols,
+        args: JoinArgs {
+            how: JoinType::Left,
+            validation: Default::default(),
+            suffix: None,
+            slice: None,
+            nulls_equal: true,
+            coalesce: JoinCoalesce::CoalesceColumns,
+            maintain_order: MaintainOrderJoin::Left,
+        },
+    };
+    let join_node = ctx
+        .phys_sm
+        .insert(PhysNode::new(Arc::new(output_schema), kind));
+    Ok((PhysStream::first(join_node), agg_col))
+}
+
+/// Returns what has to be carried over from one morsel to the next to evaluate
+/// this order-dependent function in a stateful scan, if that is possible.
+fn stateful_scan_carry(
+    function: &FunctionExpr,
+    inputs: &[ExprIR],
+    expr_arena: &Arena<AExpr>,
+) -> Option<ScanCarry> {
+    // The other inputs are parameters which we need to know upfront.
+    let literal_n = || {
+        let AExpr::Literal(lv) = expr_arena.get(inputs.get(1)?.node()) else {
+            return None;
+        };
+        let n = lv.to_any_value()?.extract::<i64>()?;
+        usize::try_from(n).ok()
+    };
+    let is_scalar_literal =
+        |i: usize| matches!(expr_arena.get(inputs[i].node()), AExpr::Literal(lv) if lv.is_scalar());
+
+    use FunctionExpr as F;
+    match function {
+        #[cfg(feature = "cum_agg")]
+        F::CumSum { reverse: false }
+        | F::CumProd { reverse: false }
+        | F::CumMin { reverse: false }
+        | F::CumMax { reverse: false } => Some(ScanCarry::LastValue),
+        #[cfg(feature = "cum_agg")]
+        F::CumCount { reverse: false } => Some(ScanCarry::Count),
+        F::Shift => Some(ScanCarry::Lookback(literal_n()?)),
+        F::ShiftAndFill if is_scalar_literal(2) => Some(ScanCarry::Lookback(literal_n()?)),
+        #[cfg(feature = "diff")]
+        F::Diff(polars_core::series::ops::NullBehavior::Ignore) => {
+            Some(ScanCarry::Lookback(literal_n()?))
+        },
+        #[cfg(feature = "pct_change")]
+        F::PctChange => Some(ScanCarry::Lookback(literal_n()?)),
+        F::FillNullWithStrategy(FillNullStrategy::Forward(None)) => Some(ScanCarry::LastValue),
+        F::FillNullWithStrategy(FillNullStrategy::Forward(Some(limit))) => {
+            Some(ScanCarry::Lookback(*limit as usize))
+        },
+        F::FillNullWithStrategy(FillNullStrategy::Backward(limit)) => {
+            Some(ScanCarry::Lookahead(limit.map(|l| l as usize)))
+        },
+        _ => None,
+    }
+}
+
+/// Lowers an order-dependent function to a stateful scan over its first
+/// input, evaluating the function with its in-memory kernel on each morsel.
+fn lower_stateful_scan(
+    input: PhysStream,
+    expr: Node,
+    carry: ScanCarry,
+    ctx: &mut LowerExprContext,
+) -> PolarsResult<(PhysStream, Node)> {
+    let AExpr::Function {
+        input: mut inner_exprs,
+        function,
+        options,
+    } = ctx.expr_arena.get(expr).clone()
+    else {
+        unreachable!()
+    };
+
+    #[allow(unused_mut)]
+    let mut scan_input = inner_exprs[0].node();
+    // The percentage change is taken over the forward filled values, which
+    // depend on more than the last n rows, so we forward fill in a scan first.
+    #[cfg(feature = "pct_change")]
+    if matches!(function, FunctionExpr::PctChange) {
+        let fill = FunctionExpr::FillNullWithStrategy(FillNullStrategy::Forward(None));
+        scan_input = ctx.expr_arena.add(AExpr::Function {
+            input: vec![inner_exprs[0].clone()],
+            options: fill.function_options(),
+            function: fill,
+        });
+    }
+
+    let (trans_input, trans_exprs) = lower_exprs_with_ctx(input, &[scan_input], ctx)?;
+    let in_name = unique_column_name();
+    let in_expr = ExprIR::new(trans_exprs[0], OutputName::Alias(in_name.clone()));
+    let select_stream = build_select_stream_with_ctx(trans_input, &[in_expr], ctx)?;
+
+    let out_name = unique_column_name();
+    inner_exprs[0] = ExprIR::new(
+        ctx.expr_arena.add(AExpr::Column(in_name.clone())),
+        OutputName::ColumnLhs(in_name),
+    );
+    let function_node = ctx.expr_arena.add(AExpr::Function {
+        input: inner_exprs,
+        function,
+        options,
+    });
+    let function_expr = ExprIR::new(function_node, OutputName::Alias(out_name.clone()));
+    let output_schema =
+        schema_for_select(select_stream, std::slice::from_ref(&function_expr), ctx)?;
+    let kind = PhysNodeKind::StatefulScan {
+        input: select_stream,
+        function: function_expr,
+        carry,
+    };
+    let node_key = ctx.phys_sm.insert(PhysNode::new(output_schema, kind));
+    Ok((
+        PhysStream::first(node_key),
+        ctx.expr_arena.add(AExpr::Column(out_name)),
+    ))
+}
+
 /// Computes the schema that selecting the given expressions on the input schema
 /// would result in.
 pub fn compute_output_schema(
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use parking_lot::Mutex;
-use polars_core::prelude::{InitHashMaps, PlIndexMap};
+use polars_core::prelude::{DataType, InitHashMaps, PlIndexMap, SortMultipleOptions};
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_err};
 use polars_expr::state::ExecutionState;
@@ -72,6 +72,46 @@ fn build_group_by_fallback(
     Ok(PhysStream::first(phys_sm.insert(group_by_node)))
 }
 
+/// Adds a row index column with the given name to the start of the stream.
+pub fn build_row_index_stream(
+    input: PhysStream,
+    name: PlSmallStr,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+) -> PhysStream {
+    let mut output_schema = phys_sm[input.node].output_schema.as_ref().clone();
+    output_schema
+        .insert_at_index(0, name.clone(), DataType::IDX_DTYPE)
+        .unwrap();
+    let kind = PhysNodeKind::WithRowIndex {
+        input,
+        name,
+        offset: None,
+    };
+    PhysStream::first(phys_sm.insert(PhysNode::new(Arc::new(output_schema), kind)))
+}
+
+/// Sorts the stream by the given column, which is used to restore the
+/// original order of the groups after an order-agnostic group by.
+pub fn build_sort_by_column_stream(
+    input: PhysStream,
+    name: PlSmallStr,
+    expr_arena: &mut Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+) -> PhysStream {
+    let output_schema = phys_sm[input.node].output_schema.clone();
+    let by_column = ExprIR::new(
+        expr_arena.add(AExpr::Column(name.clone())),
+        OutputName::ColumnLhs(name),
+    );
+    let kind = PhysNodeKind::Sort {
+        input,
+        by_column: vec![by_column],
+        slice: None,
+        sort_options: SortMultipleOptions::default(),
+    };
+    PhysStream::first(phys_sm.insert(PhysNode::new(output_schema, kind)))
+}
+
 /// Tries to lower an expression as a 'elementwise scalar agg expression'.
 ///
 /// Such an expression is defined as the elementwise combination of scalar
@@ -206,7 +246,18 @@ fn try_lower_elementwise_scalar_agg_expr(
                 | IRAggExpr::Sum(input)
                 | IRAggExpr::Var(input, ..)
                 | IRAggExpr::Std(input, ..)
-                | IRAggExpr::Count(input, ..) => {
+                | IRAggExpr::Count(input, ..)
+                | IRAggExpr::Median(input)
+                | IRAggExpr::Implode(input)
+                | IRAggExpr::Quantile { expr: input, .. } => {
+                    if let IRAggExpr::Quantile { quantile, .. } = agg {
+                        // The streaming quantile needs to know the quantile upfront.
+                        if !matches!(expr_arena.get(*quantile), AExpr::Literal(lv) if lv.is_scalar())
+                        {
+                            return None;
+                        }
+                    }
+
                     if is_input_independent(*input, expr_arena, expr_cache) {
                         // TODO: we could simply return expr here, but we first need an is_scalar function, because if
                         // it is not a scalar we need to return expr.implode().
@@ -237,11 +288,7 @@ fn try_lower_elementwise_scalar_agg_expr(
                     agg_exprs.push(agg_expr);
                     Some(result_node)
                 },
-                IRAggExpr::Median(..)
-                | IRAggExpr::NUnique(..)
-                | IRAggExpr::Implode(..)
-                | IRAggExpr::Quantile { .. }
-                | IRAggExpr::AggGroups(..) => None, // TODO: allow all aggregates,
+                IRAggExpr::NUnique(..) | IRAggExpr::AggGroups(..) => None, // TODO: allow all aggregates,
             }
         },
         AExpr::Len => {
@@ -257,11 +304,103 @@ fn try_lower_elementwise_scalar_agg_expr(
     }
 }
 
+/// Tries to lower a `group_by_dynamic` or `rolling` to a streaming
+/// [`PhysNodeKind::DynamicGroupBy`].
+#[cfg(feature = "dynamic_group_by")]
+#[allow(clippy::too_many_arguments)]
+fn try_build_streaming_dynamic_group_by(
+    input: PhysStream,
+    keys: &[ExprIR],
+    aggs: &[ExprIR],
+    output_schema: &Arc<Schema>,
+    options: &GroupbyOptions,
+    expr_arena: &mut Arena<AExpr>,
+    phys_sm: &mut SlotMap<PhysNodeKey, PhysNode>,
+    expr_cache: &mut ExprCache,
+) -> Option<PolarsResult<PhysStream>> {
+    use polars_time::prelude::StartBy;
+
+    use crate::nodes::dynamic_group_by::TemporalWindow;
+
+    // The keys are evaluated on the buffered rows, so they have to be
+    // elementwise.
+    if !keys
+        .iter()
+        .all(|key| is_elementwise_rec_cached(key.node(), expr_arena, expr_cache))
+    {
+        return None;
+    }
+
+    let input_schema = &phys_sm[input.node].output_schema;
+    let window = if let Some(dynamic) = &options.dynamic {
+        // Other start points depend on the first row of each key, which we
+        // can't know for windows that were already trimmed from the buffer.
+        if dynamic.start_by != StartBy::WindowBound {
+            return None;
+        }
+        TemporalWindow::Dynamic(dynamic.clone())
+    } else {
+        let rolling = options.rolling.clone().unwrap();
+        let index_dtype = input_schema.get(&rolling.index_column);
+        if matches!(index_dtype, Some(DataType::Datetime(_, Some(_)))) {
+            return None;
+        }
+        TemporalWindow::Rolling(rolling)
+    };
+
+    // The windows of different keys are emitted as they complete, so we sort
+    // them by key afterwards to get the order of the in-memory engine. The sort
+    // is stable, which keeps the windows of each key in time order.
+    let key_order_name = (!keys.is_empty()).then(unique_column_name);
+    let mut group_by_schema = output_schema.as_ref().clone();
+    if let Some(name) = &key_order_name {
+        group_by_schema.insert(name.clone(), DataType::Binary);
+    }
+    let group_by_node = phys_sm.insert(PhysNode::new(
+        Arc::new(group_by_schema),
+        PhysNodeKind::DynamicGroupBy {
+            input,
+            key: keys.to_vec(),
+            aggs: aggs.to_vec(),
+            window,
+            key_order_name: key_order_name.clone(),
+        },
+    ));
+    let mut out = PhysStream::first(group_by_node);
+    if let Some(name) = key_order_name {
+        let by_column = ExprIR::new(
+            expr_arena.add(AExpr::Column(name.clone())),
+            OutputName::ColumnLhs(name),
+        );
+        let sorted = phys_sm.insert(PhysNode::new(
+            phys_sm[out.node].output_schema.clone(),
+            PhysNodeKind::Sort {
+                input: out,
+                by_column: vec![by_column],
+                slice: None,
+                sort_options: SortMultipleOptions::default().with_maintain_order(true),
+            },
+        ));
+        out = PhysStream::first(phys_sm.insert(PhysNode::new(
+            output_schema.clone(),
+            PhysNodeKind::SimpleProjection {
+                input: PhysStream::first(sorted),
+                columns: output_schema.iter_names_cloned().collect(),
+            },
+        )));
+    }
+    if let Some((offset, len)) = options.slice {
+        out = build_slice_stream(out, offset, len, phys_sm);
+    }
+    Some(Ok(out))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn try_build_streaming_group_by(
     input: PhysStream,
     keys: &[ExprIR],
     aggs: &[ExprIR],
+    #[cfg_attr(not(feature = "dynamic_group_by"), allow(unused))] output_schema: &Arc<Schema>,
     maintain_order: bool,
     options: Arc<GroupbyOptions>,
     apply: Option<Arc<dyn DataFrameUdf>>,
@@ -270,13 +409,22 @@ fn try_build_streaming_group_by(
     expr_cache: &mut ExprCache,
     ctx: StreamingLowerIRContext,
 ) -> Option<PolarsResult<PhysStream>> {
-    if apply.is_some() || maintain_order {
+    if apply.is_some() {
         return None; // TODO
     }
 
     #[cfg(feature = "dynamic_group_by")]
     if options.dynamic.is_some() || options.rolling.is_some() {
-        return None; // TODO
+        return try_build_streaming_dynamic_group_by(
+            input,
+            keys,
+            aggs,
+            output_schema,
+            &options,
+            expr_arena,
+            phys_sm,
+            expr_cache,
+        );
     }
 
     if keys.is_empty() {
@@ -293,12 +441,54 @@ fn try_build_streaming_group_by(
         return None;
     }
 
+    let mut has_agg_groups = false;
+    for agg in aggs {
+        if let AExpr::Agg(IRAggExpr::AggGroups(input)) = expr_arena.get(agg.node()) {
+            if !is_elementwise_rec_cached(*input, expr_arena, expr_cache) {
+                return None;
+            }
+            has_agg_groups = true;
+        }
+    }
+
+    let mut aggs = aggs.to_vec();
+    let row_index_name = (maintain_order || has_agg_groups).then(unique_column_name);
+    if let Some(row_index_name) = row_index_name.as_ref().filter(|_| has_agg_groups) {
+        // The groups of an aggregation are the row indices of its rows.
+        let row_index_node = expr_arena.add(AExpr::Column(row_index_name.clone()));
+        for agg in &mut aggs {
+            if let AExpr::Agg(IRAggExpr::AggGroups(_)) = expr_arena.get(agg.node()) {
+                let implode_node = expr_arena.add(AExpr::Agg(IRAggExpr::Implode(row_index_node)));
+                *agg = ExprIR::new(implode_node, OutputName::Alias(agg.output_name().clone()));
+            }
+        }
+    }
+
+    // To maintain the order we track the first row index of each group, and
+    // sort the groups by it afterwards.
+    let order_name = row_index_name
+        .as_ref()
+        .filter(|_| maintain_order)
+        .map(|row_index_name| {
+            let order_name = unique_column_name();
+            let row_index_node = expr_arena.add(AExpr::Column(row_index_name.clone()));
+            let first_idx_node = expr_arena.add(AExpr::Agg(IRAggExpr::Min {
+                input: row_index_node,
+                propagate_nans: false,
+            }));
+            aggs.push(ExprIR::new(
+                first_idx_node,
+                OutputName::Alias(order_name.clone()),
+            ));
+            order_name
+        });
+
     // Fill all expressions into the merger, letting us extract common subexpressions later.
     let mut expr_merger = NaiveExprMerger::default();
     for key in keys {
         expr_merger.add_expr(key.node(), expr_arena);
     }
-    for agg in aggs {
+    for agg in &aggs {
         expr_merger.add_expr(agg.node(), expr_arena);
     }
 
@@ -326,7 +516,7 @@ fn try_build_streaming_group_by(
         let trans_output_node = expr_arena.add(AExpr::Column(uniq_name));
         trans_output_exprs.push(ExprIR::new(trans_output_node, output_name));
     }
-    for agg in aggs {
+    for agg in &aggs {
         let trans_node = try_lower_elementwise_scalar_agg_expr(
             agg.node(),
             Some(agg.output_name().clone()),
@@ -347,6 +537,10 @@ fn try_build_streaming_group_by(
         input_exprs.push(ExprIR::new(node, OutputName::Alias(name.clone())));
     }
 
+    let input = match row_index_name {
+        Some(row_index_name) => build_row_index_stream(input, row_index_name, phys_sm),
+        None => input,
+    };
     let pre_select =
         build_select_stream(input, &input_exprs, expr_arena, phys_sm, expr_cache, ctx).ok()?;
 
@@ -366,7 +560,7 @@ fn try_build_streaming_group_by(
         },
     ));
 
-    let post_select = build_select_stream(
+    let mut post_select = build_select_stream(
         PhysStream::first(agg_node),
         &trans_output_exprs,
         expr_arena,
@@ -374,6 +568,22 @@ fn try_build_streaming_group_by(
         expr_cache,
         ctx,
     );
+    if let Some(order_name) = order_name {
+        post_select = post_select.map(|stream| {
+            let sorted =
+                build_sort_by_column_stream(stream, order_name.clone(), expr_arena, phys_sm);
+            let mut output_schema = phys_sm[sorted.node].output_schema.as_ref().clone();
+            output_schema.shift_remove(&order_name);
+            let columns = output_schema.iter_names_cloned().collect();
+            PhysStream::first(phys_sm.insert(PhysNode::new(
+                Arc::new(output_schema),
+                PhysNodeKind::SimpleProjection {
+                    input: sorted,
+                    columns,
+                },
+            )))
+        });
+    }
     let out = if let Some((offset, len)) = options.slice {
         post_select.map(|s| build_slice_stream(s, offset, len, phys_sm))
     } else {
@@ -400,6 +610,7 @@ pub fn build_group_by_stream(
         input,
         keys,
         aggs,
+        &output_schema,
         maintain_order,
         options.clone(),
         apply.clone(),
@@ -1,15 +1,17 @@
 use std::sync::Arc;
 
-use parking_lot::Mutex;
 use polars_core::config;
 use polars_core::frame::{DataFrame, UniqueKeepStrategy};
 use polars_core::prelude::{DataType, InitHashMaps, PlHashMap, PlHashSet, PlIndexMap};
 use polars_core::schema::Schema;
 use polars_error::{PolarsResult, polars_bail};
-use polars_expr::state::ExecutionState;
-use polars_mem_engine::create_physical_plan;
+#[cfg(feature = "asof_join")]
+use polars_ops::frame::JoinType;
+#[cfg(feature = "iejoin")]
+use polars_plan::dsl::JoinTypeOptionsIR;
 use polars_plan::dsl::{
-    ExtraColumnsPolicy, FileScan, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR, SinkTypeIR,
+    ExtraColumnsPolicy, FileScan, FileSinkType, PartitionSinkTypeIR, PartitionVariantIR,
+    ScanSources, SinkTypeIR,
 };
 use polars_plan::plans::expr_ir::{ExprIR, OutputName};
 use polars_plan::plans::{
@@ -18,6 +20,7 @@ use polars_plan::plans::{
 use polars_plan::prelude::GroupbyOptions;
 use polars_utils::arena::{Arena, Node};
 use polars_utils::itertools::Itertools;
+use polars_utils::mmap::MemSlice;
 use polars_utils::slice_enum::Slice;
 use polars_utils::{IdxSize, unique_column_name};
 use slotmap::SlotMap;
@@ -29,8 +32,9 @@ use crate::physical_plan::lower_expr::{
     ExprCache, build_length_preserving_select_stream, build_select_stream,
     is_elementwise_rec_cached, lower_exprs,
 };
-use crate::physical_plan::lower_group_by::build_group_by_stream;
-use crate::utils::late_materialized_df::LateMaterializedDataFrame;
+use crate::physical_plan::lower_group_by::{
+    build_group_by_stream, build_row_index_stream, build_sort_by_column_stream,
+};
 
 /// Creates a new PhysStream which outputs a slice of the input stream.
 pub fn build_slice_stream(
@@ -110,6 +114,17 @@ fn build_filter_stream(
     )
 }
 
+/// Returns the input of the node if it is an ExtContext whose columns are used
+/// by the expressions of the node.
+fn ext_context_input(node: Node, ir_arena: &Arena<IR>) -> Option<Node> {
+    let ir = ir_arena.get(node);
+    let [input] = ir.get_inputs()[..] else {
+        return None;
+    };
+    let has_exprs = !ir.get_exprs().is_empty();
+    (has_exprs && matches!(ir_arena.get(input), IR::ExtContext { .. })).then_some(input)
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct StreamingLowerIRContext {
     pub prepare_visualization: bool,
@@ -143,8 +158,52 @@ pub fn lower_ir(
         };
     }
 
-    let ir_node = ir_arena.get(node);
     let output_schema = IR::schema_with_cache(node, ir_arena, schema_cache);
+
+    // Expressions look up the columns missing from their input in the external
+    // contexts, which can have a different height than the input. A stream
+    // can't hold such columns, so the input and the contexts are streamed into
+    // memory, after which the in-memory engine evaluates the node consuming the
+    // ExtContext.
+    if let Some(ext_context) = ext_context_input(node, ir_arena) {
+        let IR::ExtContext {
+            input,
+            contexts,
+            schema,
+        } = ir_arena.get(ext_context).clone()
+        else {
+            unreachable!()
+        };
+        let phys_input = lower_ir!(input)?;
+        let phys_contexts = contexts
+            .into_iter()
+            .map(|context| lower_ir!(context))
+            .collect::<PolarsResult<Vec<_>>>()?;
+        let format_str = ctx.prepare_visualization.then(|| {
+            let mut buffer = String::new();
+            write_ir_non_recursive(
+                &mut buffer,
+                ir_arena.get(node),
+                expr_arena,
+                schema.as_ref(),
+                0,
+            )
+            .unwrap();
+            buffer
+        });
+
+        let node_kind = PhysNodeKind::InMemoryExtContext {
+            input: phys_input,
+            contexts: phys_contexts,
+            consumer: ir_arena.get(node).clone(),
+            ext_context_schema: schema,
+            format_str,
+        };
+        let node_key = phys_sm.insert(PhysNode::new(output_schema, node_kind));
+        return Ok(PhysStream::first(node_key));
+    }
+
+    let ir_node = ir_arena.get(node);
     let node_kind = match ir_node {
         IR::SimpleProjection { input, columns } => {
             let columns = columns.iter_names_cloned().collect::<Vec<_>>();
@@ -478,6 +537,15 @@ pub fn lower_ir(
                 unreachable!();
             };
 
+            // Anonymous scans don't have sources, but the multiscan creates a reader per
+            // source. The anonymous reader doesn't read its source, so we give it an empty
+            // in-memory buffer.
+            let scan_sources = if matches!(&*scan_type, FileScan::Anonymous { .. }) {
+                ScanSources::Buffers(Arc::from([MemSlice::EMPTY]))
+            } else {
+                scan_sources
+            };
+
             if scan_sources.is_empty()
                 || unified_scan_args
                     .pre_slice
@@ -514,9 +582,17 @@ pub fn lower_ir(
                         first_metadata: first_metadata.clone(),
                     }) as Arc<dyn FileReaderBuilder>,
 
+                    #[cfg(feature = "avro")]
+                    FileScan::Avro { options: _ } => {
+                        Arc::new(crate::nodes::io_sources::avro::builder::AvroReaderBuilder {})
+                            as Arc<dyn FileReaderBuilder>
+                    },
+
                     #[cfg(feature = "csv")]
                     FileScan::Csv { options } => {
-                        Arc::new(Arc::new(options.clone())) as Arc<dyn FileReaderBuilder>
+                        use crate::nodes::io_sources::csv::builder::CsvReaderBuilder;
+                        Arc::new(CsvReaderBuilder::new(Arc::new(options.clone())))
+                            as Arc<dyn FileReaderBuilder>
                     },
 
                     #[cfg(feature = "json")]
@@ -545,7 +621,15 @@ pub fn lower_ir(
                         )
                     },
 
-                    FileScan::Anonymous { .. } => todo!("unimplemented: AnonymousScan"),
+                    FileScan::Anonymous { function, options } => Arc::new(
+                        crate::nodes::io_sources::anonymous::builder::AnonymousScanReaderBuilder {
+                            name: options.fmt_str,
+                            function: function.clone(),
+                            file_schema: file_info.schema.clone(),
+                            predicate: predicate.as_ref().map(|p| p.to_expr(expr_arena)),
+                        },
+                    )
+                        as Arc<dyn FileReaderBuilder>,
                 };
 
                 {
@@ -830,7 +914,23 @@ pub fn lower_ir(
             let options = options.options.clone();
             let phys_left = lower_ir!(input_left)?;
             let phys_right = lower_ir!(input_right)?;
-            if (args.how.is_equi() || args.how.is_semi_anti()) && !args.validation.needs_checks() {
+            let is_ie = cfg!(feature = "iejoin") && args.how.is_ie();
+            // The streaming as-of join matches on a single key, with both or
+            // none of the sides grouped.
+            #[cfg(feature = "asof_join")]
+            let is_asof = match &args.how {
+                JoinType::AsOf(options) => {
+                    left_on.len() == 1
+                        && right_on.len() == 1
+                        && options.left_by.is_some() == options.right_by.is_some()
+                },
+                _ => false,
+            };
+            #[cfg(not(feature = "asof_join"))]
+            let is_asof = false;
+            if (args.how.is_equi() || args.how.is_semi_anti() || is_ie || is_asof)
+                && !args.validation.needs_checks()
+            {
                 // When lowering the expressions for the keys we need to ensure we keep around the
                 // payload columns, otherwise the input nodes can get replaced by input-independent
                 // nodes since the lowering code does not see we access any non-literal expressions.
@@ -864,30 +964,59 @@ pub fn lower_ir(
                 trans_left_on.drain(left_on.len()..);
                 trans_right_on.drain(right_on.len()..);
 
-                let node = if args.how.is_equi() {
-                    phys_sm.insert(PhysNode::new(
-                        output_schema,
-                        PhysNodeKind::EquiJoin {
+                let kind = if args.how.is_equi() {
+                    PhysNodeKind::EquiJoin {
+                        input_left: trans_input_left,
+                        input_right: trans_input_right,
+                        left_on: trans_left_on,
+                        right_on: trans_right_on,
+                        args: args.clone(),
+                    }
+                } else if is_asof {
+                    #[cfg(feature = "asof_join")]
+                    {
+                        PhysNodeKind::AsOfJoin {
                             input_left: trans_input_left,
                             input_right: trans_input_right,
-                            left_on: trans_left_on,
-                            right_on: trans_right_on,
+                            left_on: trans_left_on.pop().unwrap(),
+                            right_on: trans_right_on.pop().unwrap(),
                             args: args.clone(),
-                        },
-                    ))
+                        }
+                    }
+                    #[cfg(not(feature = "asof_join"))]
+                    {
+                        unreachable!()
+                    }
+                } else if args.how.is_semi_anti() {
+                    PhysNodeKind::SemiAntiJoin {
+                        input_left: trans_input_left,
+                        input_right: trans_input_right,
+                        left_on: trans_left_on,
+                        right_on: trans_right_on,
+                        args: args.clone(),
+                        output_bool: false,
+                    }
                 } else {
-                    phys_sm.insert(PhysNode::new(
-                        output_schema,
-                        PhysNodeKind::SemiAntiJoin {
+                    #[cfg(feature = "iejoin")]
+                    {
+                        let Some(JoinTypeOptionsIR::IEJoin(options)) = options else {
+                            unreachable!()
+                        };
+                        PhysNodeKind::IEJoin {
                             input_left: trans_input_left,
                             input_right: trans_input_right,
                             left_on: trans_left_on,
                             right_on: trans_right_on,
                             args: args.clone(),
-                            output_bool: false,
-                        },
-                    ))
+                            options,
+                        }
+                    }
+                    #[cfg(not(feature = "iejoin"))]
+                    {
+                        unreachable!()
+                    }
                 };
+                let node = phys_sm.insert(PhysNode::new(output_schema, kind));
                 let mut stream = PhysStream::first(node);
                 if let Some((offset, len)) = args.slice {
                     stream = build_slice_stream(stream, offset, len, phys_sm);
@@ -931,52 +1060,6 @@ pub fn lower_ir(
                 return Ok(phys_input);
             }
 
-            if options.maintain_order && options.keep_strategy == UniqueKeepStrategy::Last {
-                // Unfortunately the order-preserving groupby always orders by the first occurrence
-                // of the group so we can't lower this and have to fallback.
-                let input_schema = phys_sm[phys_input.node].output_schema.clone();
-                let lmdf = Arc::new(LateMaterializedDataFrame::default());
-                let mut lp_arena = Arena::default();
-                let input_lp_node = lp_arena.add(lmdf.clone().as_ir_node(input_schema.clone()));
-                let distinct_lp_node = lp_arena.add(IR::Distinct {
-                    input: input_lp_node,
-                    options,
-                });
-                let executor = Mutex::new(create_physical_plan(
-                    distinct_lp_node,
-                    &mut lp_arena,
-                    expr_arena,
-                    None,
-                )?);
-
-                let format_str = ctx.prepare_visualization.then(|| {
-                    let mut buffer = String::new();
-                    write_ir_non_recursive(
-                        &mut buffer,
-                        ir_arena.get(node),
-                        expr_arena,
-                        phys_sm.get(phys_input.node).unwrap().output_schema.as_ref(),
-                        0,
-                    )
-                    .unwrap();
-                    buffer
-                });
-                let distinct_node = PhysNode {
-                    output_schema,
-                    kind: PhysNodeKind::InMemoryMap {
-                        input: phys_input,
-                        map: Arc::new(move |df| {
-                            lmdf.set_materialized_dataframe(df);
-                            let mut state = ExecutionState::new();
-                            executor.lock().execute(&mut state)
-                        }),
-                        format_str,
-                    },
-                };
-
-                return Ok(PhysStream::first(phys_sm.insert(distinct_node)));
-            }
-
             // Create the key and aggregate expressions.
             let all_col_names = input_schema.iter_names().cloned().collect_vec();
             let key_names = if let Some(subset) = options.subset {
@@ -1025,12 +1108,34 @@ pub fn lower_ir(
                 ));
             }
 
+            // The order-preserving group by orders by the first occurrence of
+            // each group, so for keep = "last" we instead track the index of
+            // the last occurrence and sort on that after the group by.
+            let mut phys_input = phys_input;
+            let mut last_order_name = None;
+            if options.maintain_order && options.keep_strategy == UniqueKeepStrategy::Last {
+                let row_index_name = unique_column_name();
+                let order_name = unique_column_name();
+                phys_input = build_row_index_stream(phys_input, row_index_name.clone(), phys_sm);
+                let row_index_node = expr_arena.add(AExpr::Column(row_index_name));
+                let last_idx_node = expr_arena.add(AExpr::Agg(IRAggExpr::Max {
+                    input: row_index_node,
+                    propagate_nans: false,
+                }));
+                group_by_output_schema.insert(order_name.clone(), DataType::IDX_DTYPE);
+                aggs.push(ExprIR::new(
+                    last_idx_node,
+                    OutputName::Alias(order_name.clone()),
+                ));
+                last_order_name = Some(order_name);
+            }
+
             let mut stream = build_group_by_stream(
                 phys_input,
                 &keys,
                 &aggs,
                 Arc::new(group_by_output_schema),
-                options.maintain_order,
+                options.maintain_order && last_order_name.is_none(),
                 Arc::new(GroupbyOptions::default()),
                 None,
                 expr_arena,
@@ -1055,7 +1160,11 @@ pub fn lower_ir(
                     build_filter_stream(stream, predicate, expr_arena, phys_sm, expr_cache, ctx)?;
             }
 
-            // Restore column order and drop the temporary length column if any.
+            if let Some(order_name) = last_order_name {
+                stream = build_sort_by_column_stream(stream, order_name, expr_arena, phys_sm);
+            }
+
+            // Restore column order and drop the temporary columns if any.
             let exprs = all_col_names
                 .iter()
                 .map(|name| {
@@ -1073,7 +1182,12 @@ pub fn lower_ir(
 
             return Ok(stream);
         },
-        IR::ExtContext { .. } => todo!(),
+        IR::ExtContext { input, .. } => {
+            // The contexts are only visible to the expressions of the node
+            // consuming this one, which is handled above. The output is just
+            // the input.
+            return lower_ir!(*input);
+        },
         IR::Invalid => unreachable!(),
     };
 
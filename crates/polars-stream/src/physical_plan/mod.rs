@@ -171,6 +171,23 @@ pub enum PhysNodeKind {
         format_str: Option<String>,
     },
 
+    /// Evaluates a node whose expressions look up columns in external
+    /// contexts. The input and the contexts are streamed, but fully sunk to
+    /// in-memory data frames, after which the in-memory engine evaluates the
+    /// consuming node.
+    InMemoryExtContext {
+        input: PhysStream,
+        contexts: Vec<PhysStream>,
+
+        /// The node consuming the ExtContext, its input is replaced by the
+        /// ExtContext when building the in-memory plan.
+        consumer: IR,
+        ext_context_schema: SchemaRef,
+
+        /// A formatted explain of the consuming node.
+        format_str: Option<String>,
+    },
+
     Map {
         input: PhysStream,
         map: Arc<dyn DataFrameUdf>,
@@ -183,6 +200,14 @@ pub enum PhysNodeKind {
         sort_options: SortMultipleOptions,
     },
 
+    /// Evaluates an order-dependent function on the single input column,
+    /// carrying state from one morsel to the next.
+    StatefulScan {
+        input: PhysStream,
+        function: ExprIR,
+        carry: crate::nodes::stateful_scan::ScanCarry,
+    },
+
     OrderedUnion {
         inputs: Vec<PhysStream>,
     },
@@ -237,6 +262,18 @@ pub enum PhysNodeKind {
         aggs: Vec<ExprIR>,
     },
 
+    /// Group-by on temporal windows (`group_by_dynamic` or `rolling`), the
+    /// input must be sorted by the index column within each key.
+    #[cfg(feature = "dynamic_group_by")]
+    DynamicGroupBy {
+        input: PhysStream,
+        key: Vec<ExprIR>,
+        aggs: Vec<ExprIR>,
+        window: crate::nodes::dynamic_group_by::TemporalWindow,
+        /// The name of the row-encoded key column to add to the output, if any.
+        key_order_name: Option<PlSmallStr>,
+    },
+
     EquiJoin {
         input_left: PhysStream,
         input_right: PhysStream,
@@ -260,6 +297,25 @@ pub enum PhysNodeKind {
         args: JoinArgs,
     },
 
+    #[cfg(feature = "asof_join")]
+    AsOfJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: ExprIR,
+        right_on: ExprIR,
+        args: JoinArgs,
+    },
+
+    #[cfg(feature = "iejoin")]
+    IEJoin {
+        input_left: PhysStream,
+        input_right: PhysStream,
+        left_on: Vec<ExprIR>,
+        right_on: Vec<ExprIR>,
+        args: JoinArgs,
+        options: polars_ops::frame::IEJoinOptions,
+    },
+
     /// Generic fallback for (as-of-yet) unsupported streaming joins.
     /// Fully sinks all data to in-memory data frames and uses the in-memory
     /// engine to perform the join.
@@ -317,12 +373,19 @@ fn visit_node_inputs_mut(
             | PhysNodeKind::InMemoryMap { input, .. }
             | PhysNodeKind::Map { input, .. }
             | PhysNodeKind::Sort { input, .. }
+            | PhysNodeKind::StatefulScan { input, .. }
             | PhysNodeKind::Multiplexer { input }
             | PhysNodeKind::GroupBy { input, .. } => {
                 rec!(input.node);
                 visit(input);
             },
 
+            #[cfg(feature = "dynamic_group_by")]
+            PhysNodeKind::DynamicGroupBy { input, .. } => {
+                rec!(input.node);
+                visit(input);
+            },
+
             PhysNodeKind::InMemoryJoin {
                 input_left,
                 input_right,
@@ -349,6 +412,30 @@ fn visit_node_inputs_mut(
                 visit(input_right);
             },
 
+            #[cfg(feature = "asof_join")]
+            PhysNodeKind::AsOfJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
+            #[cfg(feature = "iejoin")]
+            PhysNodeKind::IEJoin {
+                input_left,
+                input_right,
+                ..
+            } => {
+                rec!(input_left.node);
+                rec!(input_right.node);
+                visit(input_left);
+                visit(input_right);
+            },
+
             #[cfg(feature = "merge_sorted")]
             PhysNodeKind::MergeSorted {
                 input_left,
@@ -361,6 +448,17 @@ fn visit_node_inputs_mut(
                 visit(input_right);
             },
 
+            PhysNodeKind::InMemoryExtContext {
+                input, contexts, ..
+            } => {
+                rec!(input.node);
+                visit(input);
+                for context in contexts {
+                    rec!(context.node);
+                    visit(context);
+                }
+            },
+
             PhysNodeKind::OrderedUnion { inputs } | PhysNodeKind::Zip { inputs, .. } => {
                 for input in inputs {
                     rec!(input.node);
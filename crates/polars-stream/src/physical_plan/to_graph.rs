@@ -14,7 +14,7 @@ use polars_expr::state::ExecutionState;
 use polars_mem_engine::{create_physical_plan, create_scan_predicate};
 use polars_plan::dsl::{JoinOptions, PartitionVariantIR, ScanSources};
 use polars_plan::plans::expr_ir::ExprIR;
-use polars_plan::plans::{AExpr, ArenaExprIter, Context, IR};
+use polars_plan::plans::{AExpr, ArenaExprIter, Context, DataFrameUdf, IR};
 use polars_plan::prelude::{FileType, FunctionFlags};
 use polars_utils::arena::{Arena, Node};
 use polars_utils::format_pl_smallstr;
@@ -33,7 +33,9 @@ use crate::nodes::io_sinks::SinkComputeNode;
 use crate::nodes::io_sources::multi_file_reader::MultiFileReaderConfig;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::builder::FileReaderBuilder;
 use crate::nodes::io_sources::multi_file_reader::reader_interface::capabilities::ReaderCapabilities;
-use crate::physical_plan::lower_expr::compute_output_schema;
+use crate::physical_plan::lower_expr::{
+    ExprCache, compute_output_schema, is_elementwise_rec_cached,
+};
 use crate::utils::late_materialized_df::LateMaterializedDataFrame;
 
 fn has_potential_recurring_entrance(node: Node, arena: &Arena<AExpr>) -> bool {
@@ -264,10 +266,11 @@ fn to_graph_rec<'a>(
                     [(input_key, input.port)],
                 ),
                 #[cfg(feature = "json")]
-                FileType::Json(_) => ctx.graph.add_node(
+                FileType::Json(json_writer_options) => ctx.graph.add_node(
                     SinkComputeNode::from(nodes::io_sinks::json::NDJsonSinkNode::new(
                         target.clone(),
                         sink_options,
+                        *json_writer_options,
                         cloud_options.clone(),
                     )),
                     [(input_key, input.port)],
@@ -294,11 +297,23 @@ fn to_graph_rec<'a>(
                     )),
                     [(input_key, input.port)],
                 ),
+                #[cfg(feature = "avro")]
+                FileType::Avro(avro_writer_options) => ctx.graph.add_node(
+                    SinkComputeNode::from(nodes::io_sinks::avro::AvroSinkNode::new(
+                        target.clone(),
+                        input_schema,
+                        sink_options,
+                        avro_writer_options,
+                        cloud_options.clone(),
+                    )?),
+                    [(input_key, input.port)],
+                ),
                 #[cfg(not(any(
                     feature = "csv",
                     feature = "parquet",
                     feature = "json",
-                    feature = "ipc"
+                    feature = "ipc",
+                    feature = "avro"
                 )))]
                 _ => {
                     panic!("activate source feature")
@@ -394,6 +409,58 @@ fn to_graph_rec<'a>(
             )
         },
 
+        InMemoryExtContext {
+            input,
+            contexts,
+            consumer,
+            ext_context_schema,
+            format_str: _,
+        } => {
+            let mut input_keys = Vec::with_capacity(contexts.len() + 1);
+            let mut input_schemas = Vec::with_capacity(contexts.len() + 1);
+            let mut lmdfs = Vec::with_capacity(contexts.len() + 1);
+            let mut lp_arena = Arena::default();
+            let mut lp_nodes = Vec::with_capacity(contexts.len() + 1);
+            for stream in std::iter::once(input).chain(contexts) {
+                let schema = ctx.phys_sm[stream.node].output_schema.clone();
+                let lmdf = Arc::new(LateMaterializedDataFrame::default());
+                input_keys.push((to_graph_rec(stream.node, ctx)?, stream.port));
+                lp_nodes.push(lp_arena.add(lmdf.clone().as_ir_node(schema.clone())));
+                input_schemas.push(schema);
+                lmdfs.push(lmdf);
+            }
+
+            let ext_context_node = lp_arena.add(IR::ExtContext {
+                input: lp_nodes[0],
+                contexts: lp_nodes[1..].to_vec(),
+                schema: ext_context_schema.clone(),
+            });
+            let consumer =
+                consumer.with_exprs_and_input(consumer.get_exprs(), vec![ext_context_node]);
+            let consumer_node = lp_arena.add(consumer);
+
+            let executor = Mutex::new(create_physical_plan(
+                consumer_node,
+                &mut lp_arena,
+                ctx.expr_arena,
+                None,
+            )?);
+
+            ctx.graph.add_node(
+                nodes::in_memory_multi_map::InMemoryMultiMapNode::new(
+                    input_schemas,
+                    Arc::new(move |dfs| {
+                        for (lmdf, df) in lmdfs.iter().zip(dfs) {
+                            lmdf.set_materialized_dataframe(df);
+                        }
+                        let mut state = ExecutionState::new();
+                        executor.lock().execute(&mut state)
+                    }),
+                ),
+                input_keys,
+            )
+        },
+
         Map { input, map } => {
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
@@ -425,15 +492,60 @@ fn to_graph_rec<'a>(
                 None,
             )?);
 
+            let in_memory_sort: Arc<dyn DataFrameUdf> = Arc::new(move |df| {
+                lmdf.set_materialized_dataframe(df);
+                let mut state = ExecutionState::new();
+                executor.lock().execute(&mut state)
+            });
+
+            // The streaming sort evaluates the keys per morsel and spills the
+            // data to IPC, so that must be supported by the keys and input.
+            let mut expr_cache = ExprCache::with_capacity(by_column.len());
+            let streamable = slice.is_none_or(|(offset, _)| offset >= 0)
+                && by_column
+                    .iter()
+                    .all(|e| is_elementwise_rec_cached(e.node(), ctx.expr_arena, &mut expr_cache))
+                && !input_schema.iter_values().any(|dt| dt.contains_objects());
+
+            let input_key = to_graph_rec(input.node, ctx)?;
+            if streamable {
+                let key_selectors = by_column
+                    .iter()
+                    .map(|e| create_stream_expr(e, ctx, &input_schema))
+                    .try_collect_vec()?;
+                ctx.graph.add_node(
+                    nodes::sort::SortNode::new(
+                        input_schema,
+                        key_selectors,
+                        sort_options,
+                        slice.map(|(offset, len)| (offset as usize, len)),
+                        in_memory_sort,
+                        ctx.num_pipelines,
+                    ),
+                    [(input_key, input.port)],
+                )
+            } else {
+                ctx.graph.add_node(
+                    nodes::in_memory_map::InMemoryMapNode::new(input_schema, in_memory_sort),
+                    [(input_key, input.port)],
+                )
+            }
+        },
+
+        StatefulScan {
+            input,
+            function,
+            carry,
+        } => {
+            let input_schema = ctx.phys_sm[input.node].output_schema.clone();
+            let function = create_stream_expr(function, ctx, &input_schema)?;
             let input_key = to_graph_rec(input.node, ctx)?;
             ctx.graph.add_node(
-                nodes::in_memory_map::InMemoryMapNode::new(
+                nodes::stateful_scan::StatefulScanNode::new(
                     input_schema,
-                    Arc::new(move |df| {
-                        lmdf.set_materialized_dataframe(df);
-                        let mut state = ExecutionState::new();
-                        executor.lock().execute(&mut state)
-                    }),
+                    function,
+                    *carry,
+                    node.output_schema.clone(),
                 ),
                 [(input_key, input.port)],
             )
@@ -594,6 +706,47 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "dynamic_group_by")]
+        DynamicGroupBy {
+            input,
+            key,
+            aggs,
+            window,
+            key_order_name,
+        } => {
+            let input_key = to_graph_rec(input.node, ctx)?;
+
+            let input_schema = &ctx.phys_sm[input.node].output_schema;
+            let key_selectors = key
+                .iter()
+                .map(|e| create_stream_expr(e, ctx, input_schema))
+                .try_collect_vec()?;
+            let aggs = aggs
+                .iter()
+                .map(|e| {
+                    create_physical_expr(
+                        e,
+                        Context::Aggregation,
+                        ctx.expr_arena,
+                        input_schema,
+                        &mut ctx.expr_conversion_state,
+                    )
+                })
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::dynamic_group_by::DynamicGroupByNode::new(
+                    input_schema.clone(),
+                    key_selectors,
+                    aggs,
+                    window.clone(),
+                    key_order_name.clone(),
+                    node.output_schema.clone(),
+                ),
+                [(input_key, input.port)],
+            )
+        },
+
         InMemoryJoin {
             input_left,
             input_right,
@@ -778,6 +931,99 @@ fn to_graph_rec<'a>(
             )
         },
 
+        #[cfg(feature = "asof_join")]
+        AsOfJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+        } => {
+            let args = args.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            let left_key_schema = compute_output_schema(
+                &left_input_schema,
+                std::slice::from_ref(left_on),
+                ctx.expr_arena,
+            )?;
+            let right_key_schema = compute_output_schema(
+                &right_input_schema,
+                std::slice::from_ref(right_on),
+                ctx.expr_arena,
+            )?;
+            let left_key_selector = create_stream_expr(left_on, ctx, &left_input_schema)?;
+            let right_key_selector = create_stream_expr(right_on, ctx, &right_input_schema)?;
+
+            ctx.graph.add_node(
+                nodes::joins::asof_join::AsOfJoinNode::new(
+                    right_input_schema,
+                    left_key_schema,
+                    right_key_schema,
+                    left_key_selector,
+                    right_key_selector,
+                    &args,
+                )?,
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
+        #[cfg(feature = "iejoin")]
+        IEJoin {
+            input_left,
+            input_right,
+            left_on,
+            right_on,
+            args,
+            options,
+        } => {
+            let args = args.clone();
+            let options = options.clone();
+            let left_input_key = to_graph_rec(input_left.node, ctx)?;
+            let right_input_key = to_graph_rec(input_right.node, ctx)?;
+            let left_input_schema = ctx.phys_sm[input_left.node].output_schema.clone();
+            let right_input_schema = ctx.phys_sm[input_right.node].output_schema.clone();
+
+            // Like for the equi-join, the keys are used by position.
+            let left_key_selectors = left_on
+                .iter()
+                .enumerate()
+                .map(|(i, expr)| {
+                    let expr = expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&expr, ctx, &left_input_schema)
+                })
+                .try_collect_vec()?;
+            let right_key_selectors = right_on
+                .iter()
+                .enumerate()
+                .map(|(i, expr)| {
+                    let expr = expr.with_alias(format_pl_smallstr!("__POLARS_KEYCOL_{i}"));
+                    create_stream_expr(&expr, ctx, &right_input_schema)
+                })
+                .try_collect_vec()?;
+
+            ctx.graph.add_node(
+                nodes::joins::iejoin::IEJoinNode::new(
+                    left_input_schema,
+                    right_input_schema,
+                    left_key_selectors,
+                    right_key_selectors,
+                    &args,
+                    options,
+                ),
+                [
+                    (left_input_key, input_left.port),
+                    (right_input_key, input_right.port),
+                ],
+            )
+        },
+
         #[cfg(feature = "merge_sorted")]
         MergeSorted {
             input_left,
@@ -0,0 +1,88 @@
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use polars_core::config;
+use polars_utils::sys::MEMINFO;
+
+/// The number of bytes currently buffered by all stateful nodes of all running
+/// streaming queries.
+static MEMORY_USED: AtomicUsize = AtomicUsize::new(0);
+
+static MEMORY_LIMIT_OVERRIDE: OnceLock<Option<usize>> = OnceLock::new();
+
+/// The memory limit in bytes set with `POLARS_STREAMING_MEMORY_LIMIT`. An
+/// invalid value is ignored.
+fn memory_limit_override() -> Option<usize> {
+    *MEMORY_LIMIT_OVERRIDE.get_or_init(|| {
+        let limit = std::env::var("POLARS_STREAMING_MEMORY_LIMIT").ok()?;
+        let parsed = limit.trim().parse().ok();
+        if parsed.is_none() && config::verbose() {
+            eprintln!(
+                "ignoring invalid POLARS_STREAMING_MEMORY_LIMIT '{limit}', expected a number of bytes"
+            );
+        }
+        parsed
+    })
+}
+
+/// The memory budget that stateful nodes draw from before they start spilling.
+///
+/// It can be set in bytes with `POLARS_STREAMING_MEMORY_LIMIT`, setting
+/// `POLARS_FORCE_OOC` makes it zero. By default it is half of the memory that
+/// is free when the node is created.
+fn memory_limit() -> usize {
+    if std::env::var("POLARS_FORCE_OOC").is_ok() {
+        return 0;
+    }
+    memory_limit_override().unwrap_or_else(|| MEMINFO.free() as usize / 2)
+}
+
+/// The part of the shared memory budget held by a single node. Whatever is
+/// still held is returned to the budget when this is dropped.
+pub struct MemoryReservation {
+    limit: usize,
+    bytes: AtomicUsize,
+}
+
+impl MemoryReservation {
+    pub fn new() -> Self {
+        Self {
+            limit: memory_limit(),
+            bytes: AtomicUsize::new(0),
+        }
+    }
+
+    pub fn grow(&self, bytes: usize) {
+        self.bytes.fetch_add(bytes, Ordering::Relaxed);
+        MEMORY_USED.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    pub fn shrink(&self, bytes: usize) {
+        self.bytes.fetch_sub(bytes, Ordering::Relaxed);
+        MEMORY_USED.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    /// Returns everything held by this reservation to the budget.
+    pub fn release(&self) {
+        let bytes = self.bytes.swap(0, Ordering::Relaxed);
+        MEMORY_USED.fetch_sub(bytes, Ordering::Relaxed);
+    }
+
+    /// Whether the nodes together buffer more than the budget allows, in which
+    /// case the node holding this reservation should spill.
+    pub fn should_spill(&self) -> bool {
+        MEMORY_USED.load(Ordering::Relaxed) > self.limit
+    }
+}
+
+impl Default for MemoryReservation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        MEMORY_USED.fetch_sub(*self.bytes.get_mut(), Ordering::Relaxed);
+    }
+}
@@ -1,3 +1,5 @@
 pub mod in_memory_linearize;
 pub mod late_materialized_df;
+pub mod memory;
+pub mod spill;
 pub mod task_handles_ext;
@@ -0,0 +1,324 @@
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use arrow::io::ipc::read::{FileReader, read_file_metadata};
+use parking_lot::Mutex;
+use polars_core::config;
+use polars_core::frame::DataFrame;
+use polars_core::schema::Schema;
+use polars_error::{PolarsResult, polars_err};
+use polars_io::SerWriter;
+use polars_io::ipc::IpcWriter;
+use polars_io::path_utils::{POLARS_TEMP_DIR_BASE_PATH, ensure_temp_dir};
+
+use super::memory::MemoryReservation;
+use crate::morsel::get_ideal_morsel_size;
+
+/// Whether frames with the given schema survive a round-trip through a spill
+/// file. Categoricals come back with a new local mapping, so they can't be
+/// mixed with the frames that stayed in memory.
+pub fn can_spill(schema: &Schema) -> bool {
+    schema
+        .iter_values()
+        .all(|dtype| !dtype.contains_categoricals() && !dtype.contains_objects())
+}
+
+/// The directory in which spill directories are created.
+///
+/// `POLARS_TEMP_DIR` is resolved when spilling starts (rather than through the
+/// process-wide temporary directory, which is fixed on first use), so that it
+/// can be changed between queries. It gets the same permission checks as the
+/// process-wide temporary directory.
+fn spill_base_path() -> PolarsResult<PathBuf> {
+    match std::env::var_os("POLARS_TEMP_DIR") {
+        Some(path) => {
+            let path = PathBuf::from(path);
+            ensure_temp_dir(&path)?;
+            Ok(path)
+        },
+        None => Ok(POLARS_TEMP_DIR_BASE_PATH.to_path_buf()),
+    }
+}
+
+/// A directory inside `POLARS_TEMP_DIR` to which a single operator can spill
+/// data frames in the IPC format. The directory and everything in it is
+/// removed when this is dropped.
+pub struct SpillDir {
+    path: PathBuf,
+    next_file_idx: AtomicUsize,
+}
+
+impl SpillDir {
+    pub fn new(operation_name: &str) -> PolarsResult<Self> {
+        let id: u64 = rand::random();
+        let path = spill_base_path()?
+            .join("spill")
+            .join(format!("{operation_name}-{}-{id:016x}", std::process::id()));
+        std::fs::create_dir_all(&path).map_err(|err| {
+            polars_err!(ComputeError: "failed to create spill directory {}: {err}", path.display())
+        })?;
+
+        if config::verbose() {
+            eprintln!("[{operation_name}]: spilling to {}", path.display());
+        }
+
+        Ok(Self {
+            path,
+            next_file_idx: AtomicUsize::new(0),
+        })
+    }
+
+    /// Writes the given frames to a new IPC file in this directory. Every frame
+    /// is written as a single record batch, so they can be read back one at a
+    /// time.
+    pub fn spill(
+        &self,
+        schema: &Schema,
+        frames: impl IntoIterator<Item = DataFrame>,
+    ) -> PolarsResult<SpilledFile> {
+        let idx = self.next_file_idx.fetch_add(1, Ordering::Relaxed);
+        let path = self.path.join(format!("{idx}.ipc"));
+        let file = File::create_new(&path)?;
+
+        let mut writer = IpcWriter::new(BufWriter::new(file)).batched(schema)?;
+        for mut df in frames {
+            df.rechunk_mut();
+            writer.write_batch(&df)?;
+        }
+        writer.finish()?;
+
+        Ok(SpilledFile { path })
+    }
+}
+
+impl Drop for SpillDir {
+    fn drop(&mut self) {
+        // Best-effort, the OS will eventually clean up the temporary directory.
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+/// A [`SpillDir`] that is only created once something is spilled.
+pub struct LazySpillDir {
+    operation_name: &'static str,
+    dir: Mutex<Option<Arc<SpillDir>>>,
+}
+
+impl LazySpillDir {
+    pub fn new(operation_name: &'static str) -> Self {
+        Self {
+            operation_name,
+            dir: Mutex::new(None),
+        }
+    }
+
+    pub fn get(&self) -> PolarsResult<Arc<SpillDir>> {
+        let mut dir = self.dir.lock();
+        if dir.is_none() {
+            *dir = Some(Arc::new(SpillDir::new(self.operation_name)?));
+        }
+        Ok(dir.clone().unwrap())
+    }
+
+    pub fn take(&self) -> Option<Arc<SpillDir>> {
+        self.dir.lock().take()
+    }
+}
+
+/// A file written by [`SpillDir::spill`]. The file is removed when this is
+/// dropped.
+pub struct SpilledFile {
+    path: PathBuf,
+}
+
+impl SpilledFile {
+    /// Returns an iterator over the spilled frames in the order they were
+    /// written.
+    pub fn reader(&self) -> PolarsResult<SpillReader> {
+        let mut file = BufReader::new(File::open(&self.path)?);
+        let metadata = read_file_metadata(&mut file)?;
+        Ok(SpillReader {
+            reader: FileReader::new(file, metadata, None, None),
+        })
+    }
+}
+
+impl Drop for SpilledFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+pub struct SpillReader {
+    reader: FileReader<BufReader<File>>,
+}
+
+impl Iterator for SpillReader {
+    type Item = PolarsResult<DataFrame>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.next().map(|batch| batch.map(DataFrame::from))
+    }
+}
+
+/// Buffers the hash partitions of the frames received by a single pipeline,
+/// spilling every partition to a file of its own once enough rows are
+/// buffered. The sequence id of each frame is kept in memory.
+#[derive(Default)]
+pub struct PartitionSpiller {
+    buffers: Vec<Vec<(u64, DataFrame)>>,
+    buffered_rows: usize,
+    buffered_bytes: usize,
+    spilled: Vec<Vec<(SpilledFile, Vec<u64>)>>,
+}
+
+impl PartitionSpiller {
+    pub fn new(num_partitions: usize) -> Self {
+        Self {
+            buffers: (0..num_partitions).map(|_| Vec::new()).collect(),
+            buffered_rows: 0,
+            buffered_bytes: 0,
+            spilled: (0..num_partitions).map(|_| Vec::new()).collect(),
+        }
+    }
+
+    pub fn push(&mut self, p: usize, seq: u64, df: DataFrame, reservation: &MemoryReservation) {
+        let bytes = df.estimated_size();
+        reservation.grow(bytes);
+        self.buffered_rows += df.height();
+        self.buffered_bytes += bytes;
+        self.buffers[p].push((seq, df));
+    }
+
+    /// Spills the buffered partitions if there are enough rows to make it
+    /// worthwhile.
+    pub async fn maybe_flush(
+        &mut self,
+        spill_dir: &LazySpillDir,
+        reservation: &MemoryReservation,
+    ) -> PolarsResult<()> {
+        if self.buffered_rows < get_ideal_morsel_size() {
+            return Ok(());
+        }
+
+        let buffers = self
+            .buffers
+            .iter_mut()
+            .map(core::mem::take)
+            .collect::<Vec<_>>();
+        reservation.shrink(self.buffered_bytes);
+        self.buffered_rows = 0;
+        self.buffered_bytes = 0;
+
+        let spill_dir = spill_dir.get()?;
+        let spilled = polars_io::pl_async::get_runtime()
+            .spawn_blocking(move || {
+                buffers
+                    .into_iter()
+                    .map(|frames| {
+                        if frames.is_empty() {
+                            return Ok(None);
+                        }
+                        let schema = frames[0].1.schema().clone();
+                        let (seqs, frames): (Vec<_>, Vec<_>) = frames.into_iter().unzip();
+                        let file = spill_dir.spill(&schema, frames)?;
+                        Ok(Some((file, seqs)))
+                    })
+                    .collect::<PolarsResult<Vec<_>>>()
+            })
+            .await
+            .unwrap()?;
+
+        for (p, spilled) in spilled.into_iter().enumerate() {
+            self.spilled[p].extend(spilled);
+        }
+        Ok(())
+    }
+
+    /// Hands out the partitions, the buffered frames no longer count towards
+    /// the reservation.
+    pub fn into_partitions(self, reservation: &MemoryReservation) -> Vec<SpilledPartition> {
+        reservation.shrink(self.buffered_bytes);
+        self.spilled
+            .into_iter()
+            .zip(self.buffers)
+            .map(|(files, buffered)| SpilledPartition { files, buffered })
+            .collect()
+    }
+}
+
+/// The frames of a single partition of a [`PartitionSpiller`].
+#[derive(Default)]
+pub struct SpilledPartition {
+    files: Vec<(SpilledFile, Vec<u64>)>,
+    buffered: Vec<(u64, DataFrame)>,
+}
+
+impl SpilledPartition {
+    /// The estimated size of the frames of this partition that were never
+    /// spilled.
+    pub fn buffered_bytes(&self) -> usize {
+        self.buffered
+            .iter()
+            .map(|(_, df)| df.estimated_size())
+            .sum()
+    }
+
+    /// Reads back the frames of this partition with their sequence ids, one
+    /// spill file at a time.
+    pub fn into_frames(self) -> SpilledFrames {
+        SpilledFrames {
+            files: self.files.into_iter(),
+            active: None,
+            buffered: self.buffered.into_iter(),
+        }
+    }
+
+    /// Reads back the frames of this partition with their sequence ids, the
+    /// spill files are removed as soon as they're read.
+    pub fn for_each_frame(
+        self,
+        mut f: impl FnMut(u64, DataFrame) -> PolarsResult<()>,
+    ) -> PolarsResult<()> {
+        for frame in self.into_frames() {
+            let (seq, df) = frame?;
+            f(seq, df)?;
+        }
+        Ok(())
+    }
+}
+
+/// An iterator over the frames of a [`SpilledPartition`]. A spill file is
+/// removed once all of its frames are read.
+pub struct SpilledFrames {
+    files: std::vec::IntoIter<(SpilledFile, Vec<u64>)>,
+    active: Option<(SpilledFile, std::vec::IntoIter<u64>, SpillReader)>,
+    buffered: std::vec::IntoIter<(u64, DataFrame)>,
+}
+
+impl Iterator for SpilledFrames {
+    type Item = PolarsResult<(u64, DataFrame)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((_file, seqs, reader)) = &mut self.active {
+                if let (Some(seq), Some(df)) = (seqs.next(), reader.next()) {
+                    return Some(df.map(|df| (seq, df)));
+                }
+                self.active = None;
+            }
+
+            let Some((file, seqs)) = self.files.next() else {
+                break;
+            };
+            match file.reader() {
+                Ok(reader) => self.active = Some((file, seqs.into_iter(), reader)),
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        self.buffered.next().map(Ok)
+    }
+}
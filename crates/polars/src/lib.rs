@@ -209,6 +209,10 @@
 //!          - gzip
 //!          - zlib
 //!          - zstd
+//!          - bzip2
+//!          - xz
+//!          - lz4 (frame format)
+//!     - `compress` - Write gzip or zstd compressed CSV and NDJSON files.
 //!
 //! [`StringChunked`]: crate::datatypes::StringChunked
 //! [column selection]: polars_lazy::dsl::col
@@ -432,6 +432,29 @@ fn test_write_and_read_with_compression() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_write_multiple_chunks() -> PolarsResult<()> {
+    // every chunk is written as a block, the header must only be written once
+    let mut df = df!("i64" => &[1, 2], "string" => &["a", "b"])?;
+    df.vstack_mut(&df!("i64" => &[3], "string" => &["c"])?)?;
+    df.vstack_mut(&df!("i64" => &[4, 5], "string" => &["d", "e"])?)?;
+    assert_eq!(df.first_col_n_chunks(), 3);
+
+    for compression in [None, Some(Compression::Deflate), Some(Compression::Snappy)] {
+        let mut buf: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+
+        AvroWriter::new(&mut buf)
+            .with_compression(compression)
+            .finish(&mut df)?;
+        buf.set_position(0);
+
+        let read_df = AvroReader::new(buf).finish()?;
+        assert!(df.equals(&read_df));
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_with_projection() -> PolarsResult<()> {
     let mut df = df!(
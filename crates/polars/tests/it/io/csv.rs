@@ -1065,6 +1065,66 @@ fn test_comma_separated_field_in_tsv() -> PolarsResult<()> {
     Ok(())
 }
 
+#[test]
+fn test_multi_byte_separator() -> PolarsResult<()> {
+    let csv = "a||b\t|\tc\n1||x|y\t|\tq\n2||z\t|\t\n";
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_separator("||"))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    assert_eq!(df.get_column_names(), &["a", "b\t|\tc"]);
+    assert_eq!(df.dtypes(), &[DataType::Int64, DataType::String]);
+
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_separator("\t|\t"))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    let expected = df![
+        "a||b" => ["1||x|y", "2||z"],
+        "c" => [Some("q"), None],
+    ]?;
+    assert!(df.equals_missing(&expected));
+    Ok(())
+}
+
+#[test]
+fn test_escape_char() -> PolarsResult<()> {
+    let csv = "a,b\n\"say \\\"hi\\\"\",x\\,y\nline\\\nbreak,\\\\\n";
+    let file = Cursor::new(csv);
+    let df = CsvReadOptions::default()
+        .map_parse_options(|parse_options| parse_options.with_escape_char(Some(b'\\')))
+        .into_reader_with_file_handle(file)
+        .finish()?;
+    let expected = df![
+        "a" => ["say \"hi\"", "line\nbreak"],
+        "b" => ["x,y", "\\"],
+    ]?;
+    assert!(df.equals(&expected));
+
+    let mut buf = Vec::new();
+    let mut df = df;
+    CsvWriter::new(&mut buf)
+        .with_separator("||")
+        .with_escape_char(Some(b'\\'))
+        .finish(&mut df)?;
+    assert_eq!(
+        std::str::from_utf8(&buf).unwrap(),
+        "a||b\nsay \\\"hi\\\"||x,y\n\"line\nbreak\"||\\\\\n"
+    );
+    let df_read = CsvReadOptions::default()
+        .map_parse_options(|parse_options| {
+            parse_options
+                .with_separator("||")
+                .with_escape_char(Some(b'\\'))
+        })
+        .into_reader_with_file_handle(Cursor::new(buf))
+        .finish()?;
+    assert!(df_read.equals(&expected));
+    Ok(())
+}
+
 #[test]
 fn test_quoted_projection() -> PolarsResult<()> {
     let csv = r#"c1,c2,c3,c4,c5
@@ -105,7 +105,10 @@ Ambiguous: TypeAlias = Literal["earliest", "latest", "raise", "null"]
 AvroCompression: TypeAlias = Literal["uncompressed", "snappy", "deflate"]
 CsvQuoteStyle: TypeAlias = Literal["necessary", "always", "non_numeric", "never"]
 CategoricalOrdering: TypeAlias = Literal["physical", "lexical"]
-CsvEncoding: TypeAlias = Literal["utf8", "utf8-lossy"]
+CsvEncoding: TypeAlias = Literal[
+    "utf8", "utf8-lossy", "latin1", "windows-1252", "utf-16-le", "utf-16-be"
+]
+ExternalCompression: TypeAlias = Literal["uncompressed", "gzip", "zstd"]
 FillNullStrategy: TypeAlias = Literal[
     "forward", "backward", "min", "max", "mean", "zero", "one"
 ]
@@ -125,11 +128,19 @@ ParallelStrategy: TypeAlias = Literal[
 ParquetCompression: TypeAlias = Literal[
     "lz4", "uncompressed", "snappy", "gzip", "lzo", "brotli", "zstd"
 ]
+ParquetEncoding: TypeAlias = Literal[
+    "plain",
+    "rle",
+    "delta_binary_packed",
+    "delta_length_byte_array",
+    "delta_byte_array",
+    "byte_stream_split",
+]
 PivotAgg: TypeAlias = Literal[
     "min", "max", "first", "last", "sum", "mean", "median", "len"
 ]
 QuantileMethod: TypeAlias = Literal[
-    "nearest", "higher", "lower", "midpoint", "linear", "equiprobable"
+    "nearest", "higher", "lower", "midpoint", "linear", "equiprobable", "tdigest"
 ]
 RankMethod: TypeAlias = Literal["average", "min", "max", "dense", "ordinal", "random"]
 Roll: TypeAlias = Literal["raise", "forward", "backward"]
@@ -381,6 +392,7 @@ __all__ = [
     "EpochTimeUnit",
     "ExcelSpreadsheetEngine",
     "ExplainFormat",
+    "ExternalCompression",
     "FileSource",
     "FillNullStrategy",
     "FloatFmt",
@@ -411,6 +423,7 @@ __all__ = [
     "ParallelStrategy",
     "ParametricProfileNames",
     "ParquetCompression",
+    "ParquetEncoding",
     "PartitioningScheme",
     "PivotAgg",
     "PolarsDataType",
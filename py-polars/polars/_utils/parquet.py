@@ -1,3 +1,5 @@
+from __future__ import annotations
+
 from typing import Any, Callable
 
 from polars._typing import ParquetMetadataContext, ParquetMetadataFn
@@ -13,3 +15,20 @@ def wrap_parquet_metadata_callback(
         return list(fn(ctx_py).items())
 
     return pyo3_compatible_callback
+
+
+def parquet_statistics_to_dict(
+    statistics: bool | str | dict[str, bool],
+) -> dict[str, bool]:
+    """Normalize the `statistics` parameter of the parquet writers to a dict."""
+    if isinstance(statistics, bool):
+        if not statistics:
+            return {}
+        return {"min": True, "max": True, "distinct_count": False, "null_count": True}
+    elif statistics == "full":
+        return {"min": True, "max": True, "distinct_count": True, "null_count": True}
+    elif isinstance(statistics, dict):
+        return statistics
+
+    msg = f"invalid parquet statistics: {statistics!r}"
+    raise ValueError(msg)
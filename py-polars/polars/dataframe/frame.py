@@ -143,9 +143,11 @@ if TYPE_CHECKING:
         ComparisonOperator,
         ConditionalFormatDict,
         ConnectionOrCursor,
+        CsvEncoding,
         CsvQuoteStyle,
         DbWriteEngine,
         EngineType,
+        ExternalCompression,
         FillNullStrategy,
         FrameInitTypes,
         IndexOrder,
@@ -182,6 +184,7 @@ if TYPE_CHECKING:
     from polars._utils.various import NoDefault
     from polars.interchange.dataframe import PolarsDataFrame
     from polars.io.cloud import CredentialProviderFunction
+    from polars.io.parquet import ParquetFieldOverwrites
     from polars.ml.torch import PolarsDataset
 
     if sys.version_info >= (3, 10):
@@ -2737,13 +2740,29 @@ class DataFrame:
             return None
 
     @overload
-    def write_ndjson(self, file: None = None) -> str: ...
+    def write_ndjson(
+        self,
+        file: None = None,
+        *,
+        compression: ExternalCompression = ...,
+        compression_level: int | None = ...,
+    ) -> str: ...
 
     @overload
-    def write_ndjson(self, file: str | Path | IO[bytes] | IO[str]) -> None: ...
+    def write_ndjson(
+        self,
+        file: str | Path | IO[bytes] | IO[str],
+        *,
+        compression: ExternalCompression = ...,
+        compression_level: int | None = ...,
+    ) -> None: ...
 
     def write_ndjson(
-        self, file: str | Path | IO[bytes] | IO[str] | None = None
+        self,
+        file: str | Path | IO[bytes] | IO[str] | None = None,
+        *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
     ) -> str | None:
         r"""
         Serialize to newline delimited JSON representation.
@@ -2753,6 +2772,14 @@ class DataFrame:
         file
             File path or writable file-like object to which the result will be written.
             If set to `None` (default), the output is returned as a string instead.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. Requires `file` to be set.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9.
+            - "zstd" : min-level: 1, max-level: 22.
 
         Examples
         --------
@@ -2765,6 +2792,10 @@ class DataFrame:
         >>> df.write_ndjson()
         '{"foo":1,"bar":6}\n{"foo":2,"bar":7}\n{"foo":3,"bar":8}\n'
         """
+        if file is None and compression != "uncompressed":
+            msg = "writing compressed output requires a `file`"
+            raise ValueError(msg)
+
         should_return_buffer = False
         target: str | Path | IO[bytes] | IO[str]
         if file is None:
@@ -2781,6 +2812,8 @@ class DataFrame:
 
         self.lazy().sink_ndjson(
             target,
+            compression=compression,
+            compression_level=compression_level,
             optimizations=QueryOptFlags._eager(),
             engine=engine,
         )
@@ -2800,6 +2833,7 @@ class DataFrame:
         separator: str = ...,
         line_terminator: str = ...,
         quote_char: str = ...,
+        escape_char: str | None = ...,
         batch_size: int = ...,
         datetime_format: str | None = ...,
         date_format: str | None = ...,
@@ -2808,6 +2842,9 @@ class DataFrame:
         float_precision: int | None = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        compression: ExternalCompression = ...,
+        compression_level: int | None = ...,
+        encoding: CsvEncoding = ...,
         storage_options: dict[str, Any] | None = ...,
         credential_provider: CredentialProviderFunction | Literal["auto"] | None = ...,
         retries: int = ...,
@@ -2823,6 +2860,7 @@ class DataFrame:
         separator: str = ...,
         line_terminator: str = ...,
         quote_char: str = ...,
+        escape_char: str | None = ...,
         batch_size: int = ...,
         datetime_format: str | None = ...,
         date_format: str | None = ...,
@@ -2831,6 +2869,9 @@ class DataFrame:
         float_precision: int | None = ...,
         null_value: str | None = ...,
         quote_style: CsvQuoteStyle | None = ...,
+        compression: ExternalCompression = ...,
+        compression_level: int | None = ...,
+        encoding: CsvEncoding = ...,
         storage_options: dict[str, Any] | None = ...,
         credential_provider: CredentialProviderFunction | Literal["auto"] | None = ...,
         retries: int = ...,
@@ -2845,6 +2886,7 @@ class DataFrame:
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -2853,6 +2895,9 @@ class DataFrame:
         float_precision: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
+        encoding: CsvEncoding = "utf8",
         storage_options: dict[str, Any] | None = None,
         credential_provider: (
             CredentialProviderFunction | Literal["auto"] | None
@@ -2872,11 +2917,14 @@ class DataFrame:
         include_header
             Whether to include header in the CSV output.
         separator
-            Separate CSV fields with this symbol.
+            Separate CSV fields with this symbol, e.g. `,` or `||`.
         line_terminator
             String used to end each row.
         quote_char
             Byte to use as quoting character.
+        escape_char
+            Byte to use as escape character. If set, quote characters inside fields
+            are escaped with it instead of being doubled.
         batch_size
             Number of rows that will be processed per thread.
         datetime_format
@@ -2917,6 +2965,18 @@ class DataFrame:
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. Requires `file` to be set.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9.
+            - "zstd" : min-level: 1, max-level: 22.
+        encoding : {'utf8', 'latin1', 'windows-1252', 'utf-16-le', 'utf-16-be'}
+            Encode the CSV output in this encoding. Characters that cannot be
+            represented in the encoding raise an error. A BOM can only be included
+            for `utf8` and the UTF-16 encodings.
         storage_options
             Options that indicate how to connect to a cloud provider.
 
@@ -2956,13 +3016,21 @@ class DataFrame:
         >>> path: pathlib.Path = dirpath / "new_file.csv"
         >>> df.write_csv(path, separator=",")
         """
-        from polars.io.csv._utils import _check_arg_is_1byte
+        from polars.io.csv._utils import _check_arg_is_1byte, _check_separator
 
-        _check_arg_is_1byte("separator", separator, can_be_empty=False)
+        _check_separator(separator)
         _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
+        _check_arg_is_1byte("escape_char", escape_char, can_be_empty=True)
         if not null_value:
             null_value = None
 
+        if file is None and compression != "uncompressed":
+            msg = "writing compressed output requires a `file`"
+            raise ValueError(msg)
+        if file is None and encoding not in ("utf8", "utf8-lossy"):
+            msg = "writing output in another encoding than utf8 requires a `file`"
+            raise ValueError(msg)
+
         should_return_buffer = False
         target: str | Path | IO[bytes] | IO[str]
         if file is None:
@@ -2984,6 +3052,7 @@ class DataFrame:
             separator=separator,
             line_terminator=line_terminator,
             quote_char=quote_char,
+            escape_char=escape_char,
             batch_size=batch_size,
             datetime_format=datetime_format,
             date_format=date_format,
@@ -2992,6 +3061,9 @@ class DataFrame:
             float_precision=float_precision,
             null_value=null_value,
             quote_style=quote_style,
+            compression=compression,
+            compression_level=compression_level,
+            encoding=encoding,
             storage_options=storage_options,
             credential_provider=credential_provider,
             retries=retries,
@@ -3840,6 +3912,10 @@ class DataFrame:
         ) = "auto",
         retries: int = 2,
         metadata: ParquetMetadata | None = None,
+        field_overwrites: ParquetFieldOverwrites
+        | Sequence[ParquetFieldOverwrites]
+        | Mapping[str, ParquetFieldOverwrites]
+        | None = None,
     ) -> None:
         """
         Write to Apache Parquet file.
@@ -3932,6 +4008,14 @@ class DataFrame:
             .. warning::
                 This functionality is considered **experimental**. It may be removed or
                 changed at any point without it being considered a breaking change.
+        field_overwrites
+            Property overwrites for individual Parquet fields, such as their encoding,
+            compression, dictionary encoding and statistics. Cannot be combined with
+            `use_pyarrow`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
 
         Examples
         --------
@@ -3977,6 +4061,9 @@ class DataFrame:
             if metadata is not None:
                 msg = "write_parquet with `use_pyarrow=True` cannot be combined with `metadata`"
                 raise ValueError(msg)
+            if field_overwrites is not None:
+                msg = "write_parquet with `use_pyarrow=True` cannot be combined with `field_overwrites`"
+                raise ValueError(msg)
 
             tbl = self.to_arrow()
             data = {}
@@ -4045,6 +4132,7 @@ class DataFrame:
             credential_provider=credential_provider,
             retries=retries,
             metadata=metadata,
+            field_overwrites=field_overwrites,
             engine=engine,
             mkdir=mkdir,
             optimizations=QueryOptFlags._eager(),
@@ -5370,7 +5458,7 @@ class DataFrame:
             One or more percentiles to include in the summary statistics.
             All values must be in the range `[0, 1]`.
 
-        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable', 'tdigest'}
             Interpolation method used when calculating percentiles.
 
         Notes
@@ -10407,7 +10495,7 @@ class DataFrame:
         ----------
         quantile
             Quantile between 0.0 and 1.0.
-        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable', 'tdigest'}
             Interpolation method.
 
         Examples
@@ -3877,8 +3877,11 @@ class Expr:
         ----------
         quantile
             Quantile between 0.0 and 1.0.
-        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
-            Interpolation method.
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable', 'tdigest'}
+            Interpolation method. `'tdigest'` approximates the quantile with a
+            t-digest sketch, which lets the streaming engine compute it in
+            bounded memory; the other methods are exact. Rolling quantiles
+            compute `'tdigest'` exactly with linear interpolation.
 
         Examples
         --------
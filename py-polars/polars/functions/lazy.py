@@ -1678,7 +1678,7 @@ def quantile(
         Column name.
     quantile
         Quantile between 0.0 and 1.0.
-    interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
+    interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable', 'tdigest'}
         Interpolation method.
     """
     return F.col(column).quantile(quantile, interpolation)
@@ -1,6 +1,6 @@
 """Functions for reading data."""
 
-from polars.io.avro import read_avro
+from polars.io.avro import read_avro, scan_avro
 from polars.io.cast_options import ScanCastOptions
 from polars.io.clipboard import read_clipboard
 from polars.io.csv import read_csv, read_csv_batched, scan_csv
@@ -54,6 +54,7 @@ __all__ = [
     "read_parquet",
     "read_parquet_metadata",
     "read_parquet_schema",
+    "scan_avro",
     "scan_csv",
     "scan_delta",
     "scan_iceberg",
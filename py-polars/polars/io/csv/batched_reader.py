@@ -57,6 +57,7 @@ class BatchedCsvReader:
         raise_if_empty: bool = True,
         truncate_ragged_lines: bool = False,
         decimal_comma: bool = False,
+        escape_char: str | None = None,
     ) -> None:
         path = normalize_filepath(source, check_not_directory=False)
 
@@ -105,6 +106,7 @@ class BatchedCsvReader:
             raise_if_empty=raise_if_empty,
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
+            escape_char=escape_char,
         )
         self.new_columns = new_columns
 
@@ -3,12 +3,13 @@ from __future__ import annotations
 import contextlib
 import os
 from collections.abc import Sequence
-from io import BytesIO, StringIO
+from io import BytesIO, StringIO, TextIOBase
 from pathlib import Path
-from typing import IO, TYPE_CHECKING, Any, Callable, Literal
+from typing import IO, TYPE_CHECKING, Any, Callable, Literal, get_args
 
 import polars._reexport as pl
 import polars.functions as F
+from polars._typing import CsvEncoding
 from polars._utils.deprecation import deprecate_renamed_parameter
 from polars._utils.various import (
     _process_null_values,
@@ -28,7 +29,11 @@ from polars.io._utils import (
 from polars.io.cloud.credential_provider._builder import (
     _init_credential_provider_builder,
 )
-from polars.io.csv._utils import _check_arg_is_1byte, _update_columns
+from polars.io.csv._utils import (
+    _check_arg_is_1byte,
+    _check_separator,
+    _update_columns,
+)
 from polars.io.csv.batched_reader import BatchedCsvReader
 
 with contextlib.suppress(ImportError):  # Module not available when building docs
@@ -38,7 +43,7 @@ if TYPE_CHECKING:
     from collections.abc import Mapping
 
     from polars import DataFrame, LazyFrame
-    from polars._typing import CsvEncoding, PolarsDataType, SchemaDict
+    from polars._typing import PolarsDataType, SchemaDict
     from polars.io.cloud import CredentialProviderFunction
     from polars.io.cloud.credential_provider._builder import CredentialProviderBuilder
 
@@ -84,6 +89,8 @@ def read_csv(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
+    rejects_path: str | Path | None = None,
+    escape_char: str | None = None,
 ) -> DataFrame:
     r"""
     Read a CSV file into a DataFrame.
@@ -114,13 +121,16 @@ def read_csv(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file, e.g. `,` or `||`.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
     quote_char
         Single byte character used for csv quoting, default = `"`.
         Set to None to turn off special handling and escaping of quotes.
+    escape_char
+        Single byte character used to escape the character following it, e.g. `\`.
+        An escaped quote, separator or end of line character is read literally.
     skip_rows
         Start reading after ``skip_rows`` rows. The header will be parsed at this
         offset. Note that we respect CSV escaping/comments when skipping rows.
@@ -178,11 +188,11 @@ def read_csv(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', 'windows-1252', 'windows-1252-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf-16-le', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. `latin1`, `windows-1252`, `utf-16-le` and `utf-16-be` are
+        transcoded to utf8 while the file is read. When using other encodings,
+        the input is first decoded in memory with python. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -226,6 +236,11 @@ def read_csv(
         Parse floats using a comma as the decimal separator instead of a period.
     glob
         Expand path given via globbing rules.
+    rejects_path
+        Instead of raising an error, write the rows that fail to parse or cast to a CSV
+        file at this path and leave them out of the result. The file has the columns
+        `file`, `line`, `raw_line` and `error`; it only contains a header if all rows
+        are valid. Cannot be combined with `ignore_errors`.
 
     Returns
     -------
@@ -273,8 +288,9 @@ def read_csv(
     │ 3   ┆ Charlie ┆ 2002-03-08 │
     └─────┴─────────┴────────────┘
     """
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_separator(separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
+    _check_arg_is_1byte("escape_char", escape_char, can_be_empty=True)
     _check_arg_is_1byte("eol_char", eol_char, can_be_empty=False)
 
     projection, columns = parse_columns_arg(columns)
@@ -302,6 +318,8 @@ def read_csv(
         and n_threads is None
         and not low_memory
         and null_values is None
+        and rejects_path is None
+        and len(separator) == 1
     ):
         include_columns: Sequence[str] | None = None
         if columns:
@@ -340,6 +358,7 @@ def read_csv(
                         delimiter=separator,
                         quote_char=quote_char if quote_char else False,
                         double_quote=quote_char is not None and quote_char == '"',
+                        escape_char=escape_char if escape_char else False,
                     ),
                     pa.csv.ConvertOptions(
                         column_types=None,
@@ -457,7 +476,8 @@ def read_csv(
 
     # TODO: scan_csv doesn't support a "dtype slice" (i.e. list[DataType])
     schema_overrides_is_list = isinstance(schema_overrides, Sequence)
-    encoding_supported_in_lazy = encoding in {"utf8", "utf8-lossy"}
+    # Other encodings are decoded in Python before the data is parsed.
+    encoding_supported_in_lazy = encoding in get_args(CsvEncoding)
 
     new_streaming = (
         os.getenv("POLARS_FORCE_NEW_STREAMING") == "1"
@@ -526,6 +546,8 @@ def read_csv(
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
             glob=glob,
+            rejects_path=rejects_path,
+            escape_char=escape_char,
         )
 
         if columns:
@@ -536,9 +558,15 @@ def read_csv(
         df = lf.collect()
 
     else:
+        # Text sources are decoded already.
+        reader_encoding = (
+            encoding
+            if encoding_supported_in_lazy and not isinstance(source, TextIOBase)
+            else "utf8"
+        )
         with prepare_file_arg(
             source,
-            encoding=encoding,
+            encoding=None if encoding_supported_in_lazy else encoding,
             use_pyarrow=False,
             raise_if_empty=raise_if_empty,
             storage_options=storage_options,
@@ -562,7 +590,7 @@ def read_csv(
                 infer_schema_length=infer_schema_length,
                 batch_size=batch_size,
                 n_rows=n_rows,
-                encoding=encoding if encoding == "utf8-lossy" else "utf8",
+                encoding=reader_encoding,
                 low_memory=low_memory,
                 rechunk=rechunk,
                 skip_rows_after_header=skip_rows_after_header,
@@ -573,6 +601,8 @@ def read_csv(
                 truncate_ragged_lines=truncate_ragged_lines,
                 decimal_comma=decimal_comma,
                 glob=glob,
+                rejects_path=rejects_path,
+                escape_char=escape_char,
             )
 
     if new_columns:
@@ -612,6 +642,8 @@ def _read_csv_impl(
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
     glob: bool = True,
+    rejects_path: str | Path | None = None,
+    escape_char: str | None = None,
 ) -> DataFrame:
     path: str | None
     if isinstance(source, (str, Path)):
@@ -677,6 +709,8 @@ def _read_csv_impl(
             truncate_ragged_lines=truncate_ragged_lines,
             decimal_comma=decimal_comma,
             glob=glob,
+            rejects_path=rejects_path,
+            escape_char=escape_char,
         )
         if columns is None:
             return scan.collect()
@@ -689,6 +723,9 @@ def _read_csv_impl(
             )
             raise ValueError(msg)
 
+    if rejects_path is not None:
+        rejects_path = normalize_filepath(rejects_path)
+
     projection, columns = parse_columns_arg(columns)
 
     pydf = PyDataFrame.read_csv(
@@ -722,6 +759,8 @@ def _read_csv_impl(
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
         schema=schema,
+        rejects_path=rejects_path,
+        escape_char=escape_char,
     )
     return wrap_df(pydf)
 
@@ -761,6 +800,7 @@ def read_csv_batched(
     raise_if_empty: bool = True,
     truncate_ragged_lines: bool = False,
     decimal_comma: bool = False,
+    escape_char: str | None = None,
 ) -> BatchedCsvReader:
     r"""
     Read a CSV file in batches.
@@ -795,13 +835,16 @@ def read_csv_batched(
         list is shorter than the width of the DataFrame the remaining
         columns will have their original name.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file, e.g. `,` or `||`.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
     quote_char
         Single byte character used for csv quoting, default = `"`.
         Set to None to turn off special handling and escaping of quotes.
+    escape_char
+        Single byte character used to escape the character following it, e.g. `\`.
+        An escaped quote, separator or end of line character is read literally.
     skip_rows
         Start reading after `skip_rows` lines.
     schema_overrides
@@ -840,11 +883,10 @@ def read_csv_batched(
         Stop reading from CSV file after reading `n_rows`.
         During multi-threaded parsing, an upper bound of `n_rows`
         rows cannot be guaranteed.
-    encoding : {'utf8', 'utf8-lossy', ...}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf-16-le', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. When using other encodings than `utf8` or
-        `utf8-lossy`, the input is first decoded in memory with
-        python. Defaults to `utf8`.
+        characters. `latin1`, `windows-1252`, `utf-16-le` and `utf-16-be` are
+        transcoded to utf8 while the file is read. Defaults to `utf8`.
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1025,7 +1067,7 @@ def read_csv_batched(
         infer_schema_length=infer_schema_length,
         batch_size=batch_size,
         n_rows=n_rows,
-        encoding=encoding if encoding == "utf8-lossy" else "utf8",
+        encoding=encoding if encoding in get_args(CsvEncoding) else "utf8",
         low_memory=low_memory,
         rechunk=rechunk,
         skip_rows_after_header=skip_rows_after_header,
@@ -1036,6 +1078,7 @@ def read_csv_batched(
         raise_if_empty=raise_if_empty,
         truncate_ragged_lines=truncate_ragged_lines,
         decimal_comma=decimal_comma,
+        escape_char=escape_char,
     )
 
 
@@ -1090,6 +1133,8 @@ def scan_csv(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    rejects_path: str | Path | None = None,
+    escape_char: str | None = None,
 ) -> LazyFrame:
     r"""
     Lazily read from a CSV file or multiple files via glob patterns.
@@ -1115,13 +1160,16 @@ def scan_csv(
         column names will be autogenerated in the following format: `column_x`, with
         `x` being an enumeration over every column in the dataset, starting at 1.
     separator
-        Single byte character to use as separator in the file.
+        Character or string to use as separator in the file, e.g. `,` or `||`.
     comment_prefix
         A string used to indicate the start of a comment line. Comment lines are skipped
         during parsing. Common examples of comment prefixes are `#` and `//`.
     quote_char
         Single byte character used for csv quoting, default = `"`.
         Set to None to turn off special handling and escaping of quotes.
+    escape_char
+        Single byte character used to escape the character following it, e.g. `\`.
+        An escaped quote, separator or end of line character is read literally.
     skip_rows
         Start reading after ``skip_rows`` rows. The header will be parsed at this
         offset. Note that we respect CSV escaping/comments when skipping rows.
@@ -1170,9 +1218,11 @@ def scan_csv(
         Set `infer_schema=False` to read all columns as `pl.String`.
     n_rows
         Stop reading from CSV file after reading `n_rows`.
-    encoding : {'utf8', 'utf8-lossy'}
+    encoding : {'utf8', 'utf8-lossy', 'latin1', 'windows-1252', 'utf-16-le', ...}
         Lossy means that invalid utf8 values are replaced with `�`
-        characters. Defaults to "utf8".
+        characters. `latin1`, `windows-1252`, `utf-16-le` and `utf-16-be` are
+        transcoded to utf8 while the file is read, bytes that do not form a
+        valid character are replaced with `�`. Defaults to "utf8".
     low_memory
         Reduce memory pressure at the expense of performance.
     rechunk
@@ -1235,6 +1285,11 @@ def scan_csv(
         (which defaults to 1 hour) if not given.
     include_file_paths
         Include the path of the source file(s) as a column with this name.
+    rejects_path
+        Instead of raising an error, write the rows that fail to parse or cast to a CSV
+        file at this path and leave them out of the result. The file is written when
+        the query is executed and has the columns `file`, `line`, `raw_line` and
+        `error`. Cannot be combined with `ignore_errors`.
 
     Returns
     -------
@@ -1325,8 +1380,9 @@ def scan_csv(
             else:
                 return new_columns  # type: ignore[return-value]
 
-    _check_arg_is_1byte("separator", separator, can_be_empty=False)
+    _check_separator(separator)
     _check_arg_is_1byte("quote_char", quote_char, can_be_empty=True)
+    _check_arg_is_1byte("escape_char", escape_char, can_be_empty=True)
 
     if isinstance(source, (str, Path)):
         source = normalize_filepath(source, check_not_directory=False)
@@ -1377,6 +1433,8 @@ def scan_csv(
         credential_provider=credential_provider_builder,
         file_cache_ttl=file_cache_ttl,
         include_file_paths=include_file_paths,
+        rejects_path=rejects_path,
+        escape_char=escape_char,
     )
 
 
@@ -1423,6 +1481,8 @@ def _scan_csv_impl(
     retries: int = 2,
     file_cache_ttl: int | None = None,
     include_file_paths: str | None = None,
+    rejects_path: str | Path | None = None,
+    escape_char: str | None = None,
 ) -> LazyFrame:
     dtype_list: list[tuple[str, PolarsDataType]] | None = None
     if schema_overrides is not None:
@@ -1446,6 +1506,9 @@ def _scan_csv_impl(
         # Handle empty dict input
         storage_options = None
 
+    if rejects_path is not None:
+        rejects_path = normalize_filepath(rejects_path)
+
     pylf = PyLazyFrame.new_from_csv(
         source,
         sources,
@@ -1480,5 +1543,7 @@ def _scan_csv_impl(
         retries=retries,
         file_cache_ttl=file_cache_ttl,
         include_file_paths=include_file_paths,
+        rejects_path=rejects_path,
+        escape_char=escape_char,
     )
     return wrap_ldf(pylf)
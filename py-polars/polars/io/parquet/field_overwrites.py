@@ -1,7 +1,12 @@
 from __future__ import annotations
 
 from collections.abc import Mapping, Sequence
-from typing import Any
+from typing import TYPE_CHECKING, Any
+
+from polars._utils.parquet import parquet_statistics_to_dict
+
+if TYPE_CHECKING:
+    from polars._typing import ParquetCompression, ParquetEncoding
 
 
 def _parquet_field_overwrites_dict_to_dict_list(
@@ -43,6 +48,23 @@ def _parquet_field_overwrites_to_dict(pqo: ParquetFieldOverwrites) -> dict[str,
     if pqo.metadata is not None:
         d["metadata"] = list(pqo.metadata.items())
 
+    if pqo.bloom_filter:
+        d["bloom_filter"] = True
+
+    # Encoding and compression
+    if pqo.encoding is not None:
+        d["encoding"] = pqo.encoding
+    if pqo.dictionary is not None:
+        d["dictionary"] = pqo.dictionary
+    if pqo.compression is not None:
+        d["compression"] = pqo.compression
+        if pqo.compression_level is not None:
+            d["compression_level"] = pqo.compression_level
+
+    # Statistics
+    if pqo.statistics is not None:
+        d["statistics"] = parquet_statistics_to_dict(pqo.statistics)
+
     return d
 
 
@@ -60,6 +82,8 @@ class ParquetFieldOverwrites:
     >>> lf = pl.LazyFrame(
     ...     {
     ...         "a": [None, 2, 3, 4],
+    ...         "id": ["x1", "x2", "x3", "x4"],
+    ...         "x": [0.5, 1.25, 3.0, 0.75],
     ...         "b": [[1, 2, 3], [42], [13], [37]],
     ...         "c": [
     ...             {"x": "a", "y": 42},
@@ -77,6 +101,10 @@ class ParquetFieldOverwrites:
     ...             children=ParquetFieldOverwrites(metadata={"listitem": "yes"}),
     ...             metadata={"list": "true"},
     ...         ),
+    ...         "id": ParquetFieldOverwrites(bloom_filter=True),
+    ...         "x": ParquetFieldOverwrites(
+    ...             encoding="byte_stream_split", compression="zstd"
+    ...         ),
     ...         "c": ParquetFieldOverwrites(
     ...             children=[
     ...                 ParquetFieldOverwrites(name="x", metadata={"md": "yes"}),
@@ -104,6 +132,18 @@ class ParquetFieldOverwrites:
     metadata: (
         dict[str, None | str] | None
     )  #: Arrow metadata added to the field before writing
+    bloom_filter: bool = False  #: Write a bloom filter for every row group
+    encoding: ParquetEncoding | None = None  #: Encoding of the data pages
+    dictionary: bool | None = None  #: Whether to dictionary encode the field
+    #
+    # By default, setting an `encoding` disables dictionary encoding. If `dictionary`
+    # is `True`, the `encoding` is used when the field cannot be dictionary encoded.
+
+    compression: ParquetCompression | None = None  #: Compression of the data pages
+    compression_level: int | None = None  #: Level of the `compression`
+    statistics: (
+        bool | str | dict[str, bool] | None
+    ) = None  #: Statistics to write, as in `sink_parquet`
 
     def __init__(
         self,
@@ -117,6 +157,12 @@ class ParquetFieldOverwrites:
         ) = None,
         field_id: int | None = None,
         metadata: Mapping[str, None | str] | None = None,
+        bloom_filter: bool = False,
+        encoding: ParquetEncoding | None = None,
+        dictionary: bool | None = None,
+        compression: ParquetCompression | None = None,
+        compression_level: int | None = None,
+        statistics: bool | str | dict[str, bool] | None = None,
     ) -> None:
         self.name = name
 
@@ -132,3 +178,9 @@ class ParquetFieldOverwrites:
             self.metadata = dict(metadata)
         else:
             self.metadata = metadata
+        self.bloom_filter = bloom_filter
+        self.encoding = encoding
+        self.dictionary = dictionary
+        self.compression = compression
+        self.compression_level = compression_level
+        self.statistics = statistics
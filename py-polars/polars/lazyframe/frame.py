@@ -34,7 +34,10 @@ from polars._utils.deprecation import (
     deprecated,
     issue_deprecation_warning,
 )
-from polars._utils.parquet import wrap_parquet_metadata_callback
+from polars._utils.parquet import (
+    parquet_statistics_to_dict,
+    wrap_parquet_metadata_callback,
+)
 from polars._utils.parse import (
     parse_into_expression,
     parse_into_list_of_expressions,
@@ -118,11 +121,14 @@ if TYPE_CHECKING:
     from polars import DataFrame, DataType, Expr
     from polars._typing import (
         AsofJoinStrategy,
+        AvroCompression,
         ClosedInterval,
         ColumnNameOrSelector,
+        CsvEncoding,
         CsvQuoteStyle,
         EngineType,
         ExplainFormat,
+        ExternalCompression,
         FillNullStrategy,
         FrameInitTypes,
         IntoExpr,
@@ -917,7 +923,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             One or more percentiles to include in the summary statistics.
             All values must be in the range `[0, 1]`.
 
-        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable', 'tdigest'}
             Interpolation method used when calculating percentiles.
 
         Returns
@@ -2680,22 +2686,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             msg = "`metadata` parameter is considered experimental"
             issue_unstable_warning(msg)
 
-        if isinstance(statistics, bool) and statistics:
-            statistics = {
-                "min": True,
-                "max": True,
-                "distinct_count": False,
-                "null_count": True,
-            }
-        elif isinstance(statistics, bool) and not statistics:
-            statistics = {}
-        elif statistics == "full":
-            statistics = {
-                "min": True,
-                "max": True,
-                "distinct_count": True,
-                "null_count": True,
-            }
+        statistics = parquet_statistics_to_dict(statistics)
 
         from polars.io.cloud.credential_provider._builder import (
             _init_credential_provider_builder,
@@ -2981,6 +2972,201 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             return None
         return LazyFrame._from_pyldf(ldf)
 
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[False] = ...,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> None: ...
+
+    @overload
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: Literal[True],
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame: ...
+
+    def sink_avro(
+        self,
+        path: str | Path | IO[bytes] | PartitioningScheme,
+        *,
+        compression: AvroCompression = "uncompressed",
+        name: str = "",
+        maintain_order: bool = True,
+        storage_options: dict[str, Any] | None = None,
+        credential_provider: CredentialProviderFunction
+        | Literal["auto"]
+        | None = "auto",
+        retries: int = 2,
+        sync_on_close: SyncOnCloseMethod | None = None,
+        mkdir: bool = False,
+        lazy: bool = False,
+        engine: EngineType = "auto",
+        optimizations: QueryOptFlags = DEFAULT_QUERY_OPT_FLAGS,
+    ) -> LazyFrame | None:
+        """
+        Evaluate the query in streaming mode and write to an Apache Avro file.
+
+        This allows streaming results that are larger than RAM to be written to disk.
+        The data is written as an object container file, block by block.
+
+        Parameters
+        ----------
+        path
+            File path to which the file should be written.
+        compression : {'uncompressed', 'snappy', 'deflate'}
+            Compression method of the blocks.
+        name
+            Schema name. Defaults to empty string.
+        maintain_order
+            Maintain the order in which data is processed.
+            Setting this to `False` will be slightly faster.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        storage_options
+            Options that indicate how to connect to a cloud provider.
+
+            The cloud providers currently supported are AWS, GCP, and Azure.
+            See supported keys here:
+
+            * `aws <https://docs.rs/object_store/latest/object_store/aws/enum.AmazonS3ConfigKey.html>`_
+            * `gcp <https://docs.rs/object_store/latest/object_store/gcp/enum.GoogleConfigKey.html>`_
+            * `azure <https://docs.rs/object_store/latest/object_store/azure/enum.AzureConfigKey.html>`_
+            * Hugging Face (`hf://`): Accepts an API key under the `token` parameter: \
+            `{'token': '...'}`, or by setting the `HF_TOKEN` environment variable.
+
+            If `storage_options` is not provided, Polars will try to infer the
+            information from environment variables.
+        credential_provider
+            Provide a function that can be called to provide cloud storage
+            credentials. The function is expected to return a dictionary of
+            credential keys along with an optional credential expiry time.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+        retries
+            Number of retries if accessing a cloud instance fails.
+        sync_on_close: { None, 'data', 'all' }
+            Sync to disk when before closing a file.
+
+            * `None` does not sync.
+            * `data` syncs the file contents.
+            * `all` syncs the file contents and metadata.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        mkdir: bool
+            Recursively create all the directories in the path.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        lazy: bool
+            Wait to start execution until `collect` is called.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed at any
+                point without it being considered a breaking change.
+        engine
+            Select the engine used to process the query, optional.
+            At the moment, if set to `"auto"` (default), the query is run
+            using the polars streaming engine. Polars will also
+            attempt to use the engine set by the `POLARS_ENGINE_AFFINITY`
+            environment variable. If it cannot run the query using the
+            selected engine, the query is run using the polars streaming
+            engine.
+
+            .. note::
+               The GPU engine is currently not supported.
+        optimizations
+            The optimization passes done during query optimization.
+
+            This has no effect if `lazy` is set to `True`.
+
+            .. warning::
+                This functionality is considered **unstable**. It may be changed
+                at any point without it being considered a breaking change.
+
+        Returns
+        -------
+        DataFrame
+
+        Examples
+        --------
+        >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
+        >>> lf.sink_avro("out.avro", compression="snappy")  # doctest: +SKIP
+        """
+        engine = _select_engine(engine)
+
+        from polars.io.cloud.credential_provider._builder import (
+            _init_credential_provider_builder,
+        )
+
+        credential_provider_builder = _init_credential_provider_builder(
+            credential_provider, path, storage_options, "sink_avro"
+        )
+        del credential_provider
+
+        if storage_options:
+            storage_options = list(storage_options.items())  # type: ignore[assignment]
+        else:
+            # Handle empty dict input
+            storage_options = None
+
+        target = _to_sink_target(path)
+        sink_options = {
+            "sync_on_close": sync_on_close or "none",
+            "maintain_order": maintain_order,
+            "mkdir": mkdir,
+        }
+
+        ldf = self._ldf.sink_avro(
+            target=target,
+            compression=compression,
+            name=name,
+            cloud_options=storage_options,
+            credential_provider=credential_provider_builder,
+            retries=retries,
+            sink_options=sink_options,
+        )
+
+        if not lazy:
+            ldf = ldf.with_optimizations(optimizations._pyoptflags)
+            ldf = LazyFrame._from_pyldf(ldf)
+            ldf.collect(engine=engine)
+            return None
+        return LazyFrame._from_pyldf(ldf)
+
     @overload
     def sink_csv(
         self,
@@ -2991,6 +3177,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -2999,6 +3186,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         float_precision: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3022,6 +3212,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -3030,6 +3221,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         float_precision: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3052,6 +3246,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         separator: str = ",",
         line_terminator: str = "\n",
         quote_char: str = '"',
+        escape_char: str | None = None,
         batch_size: int = 1024,
         datetime_format: str | None = None,
         date_format: str | None = None,
@@ -3060,6 +3255,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         float_precision: int | None = None,
         null_value: str | None = None,
         quote_style: CsvQuoteStyle | None = None,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
+        encoding: CsvEncoding = "utf8",
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3086,11 +3284,14 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         include_header
             Whether to include header in the CSV output.
         separator
-            Separate CSV fields with this symbol.
+            Separate CSV fields with this symbol, e.g. `,` or `||`.
         line_terminator
             String used to end each row.
         quote_char
             Byte to use as quoting character.
+        escape_char
+            Byte to use as escape character. If set, quote characters inside fields
+            are escaped with it instead of being doubled.
         batch_size
             Number of rows that will be processed per thread.
         datetime_format
@@ -3132,6 +3333,20 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
               Namely, when writing a field that does not parse as a valid float
               or integer, then quotes will be used even if they aren`t strictly
               necessary.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. The data is compressed in
+            parts that are written as consecutive gzip members or zstd frames, which
+            decompress as a single file.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9.
+            - "zstd" : min-level: 1, max-level: 22.
+        encoding : {'utf8', 'latin1', 'windows-1252', 'utf-16-le', 'utf-16-be'}
+            Encode the CSV output in this encoding. Characters that cannot be
+            represented in the encoding raise an error. A BOM can only be included
+            for `utf8` and the UTF-16 encodings.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3211,10 +3426,11 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         >>> lf = pl.scan_csv("/path/to/my_larger_than_ram_file.csv")  # doctest: +SKIP
         >>> lf.sink_csv("out.csv")  # doctest: +SKIP
         """
-        from polars.io.csv._utils import _check_arg_is_1byte
+        from polars.io.csv._utils import _check_arg_is_1byte, _check_separator
 
-        _check_arg_is_1byte("separator", separator, can_be_empty=False)
+        _check_separator(separator)
         _check_arg_is_1byte("quote_char", quote_char, can_be_empty=False)
+        _check_arg_is_1byte("escape_char", escape_char, can_be_empty=True)
         if not null_value:
             null_value = None
         engine = _select_engine(engine)
@@ -3245,9 +3461,10 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             target=target,
             include_bom=include_bom,
             include_header=include_header,
-            separator=ord(separator),
+            separator=separator,
             line_terminator=line_terminator,
             quote_char=ord(quote_char),
+            escape_char=ord(escape_char) if escape_char else None,
             batch_size=batch_size,
             datetime_format=datetime_format,
             date_format=date_format,
@@ -3256,6 +3473,9 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
             float_precision=float_precision,
             null_value=null_value,
             quote_style=quote_style,
+            compression=compression,
+            compression_level=compression_level,
+            encoding=encoding,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -3274,6 +3494,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3292,6 +3514,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3309,6 +3533,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         self,
         path: str | Path | IO[bytes] | IO[str] | PartitioningScheme,
         *,
+        compression: ExternalCompression = "uncompressed",
+        compression_level: int | None = None,
         maintain_order: bool = True,
         storage_options: dict[str, Any] | None = None,
         credential_provider: CredentialProviderFunction
@@ -3330,6 +3556,16 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         path
             File path to which the file should be written.
+        compression : {'uncompressed', 'gzip', 'zstd'}
+            Compress the whole file with this algorithm. The data is compressed in
+            parts that are written as consecutive gzip members or zstd frames, which
+            decompress as a single file.
+        compression_level
+            The level of compression to use. Higher compression means smaller files on
+            disk.
+
+            - "gzip" : min-level: 0, max-level: 9.
+            - "zstd" : min-level: 1, max-level: 22.
         maintain_order
             Maintain the order in which data is processed.
             Setting this to `False` will be slightly faster.
@@ -3435,6 +3671,8 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
 
         ldf = self._ldf.sink_json(
             target=target,
+            compression=compression,
+            compression_level=compression_level,
             cloud_options=storage_options,
             credential_provider=credential_provider_builder,
             retries=retries,
@@ -7033,7 +7271,7 @@ naive plan: (run LazyFrame.explain(optimized=True) to see the optimized plan)
         ----------
         quantile
             Quantile between 0.0 and 1.0.
-        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable'}
+        interpolation : {'nearest', 'higher', 'lower', 'midpoint', 'linear', 'equiprobable', 'tdigest'}
             Interpolation method.
 
         Examples
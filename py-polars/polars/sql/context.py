@@ -123,6 +123,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
+        max_recursion_iterations: int = ...,
         eager: Literal[False] = False,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
@@ -134,6 +135,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
+        max_recursion_iterations: int = ...,
         eager: Literal[True],
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
@@ -145,6 +147,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = ...,
         all_compatible: bool = ...,
+        max_recursion_iterations: int = ...,
         eager: bool,
         **named_frames: CompatibleFrameType | None,
     ) -> None: ...
@@ -156,6 +159,7 @@ class SQLContext(Generic[FrameType]):
         *,
         register_globals: bool | int = False,
         eager: bool = False,
+        max_recursion_iterations: int = 1000,
         **named_frames: CompatibleFrameType | None,
     ) -> None:
         """
@@ -179,6 +183,9 @@ class SQLContext(Generic[FrameType]):
             If True, returns execution results as `DataFrame` instead of `LazyFrame`.
             (Note that the query itself is always executed in lazy-mode; this parameter
             impacts whether :meth:`execute` returns an eager or lazy result frame).
+        max_recursion_iterations
+            The maximum number of iterations used to evaluate a recursive CTE
+            (`WITH RECURSIVE`); the query fails if the CTE has not completed by then.
         **named_frames
             Named eager/lazy frames, provided as kwargs.
 
@@ -203,6 +210,7 @@ class SQLContext(Generic[FrameType]):
             "`SQLContext` is considered **unstable**, although it is close to being considered stable."
         )
         self._ctxt = PySQLContext.new()
+        self._ctxt.set_max_recursion_iterations(max_recursion_iterations)
         self._eager_execution = eager
 
         frames = dict(frames or {})
@@ -11,7 +11,7 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import AvroCompression
+    from polars._typing import AvroCompression, EngineType
 
 
 COMPRESSIONS = ["uncompressed", "snappy", "deflate"]
@@ -89,3 +89,131 @@ def test_with_name() -> None:
     read_df = pl.read_json(raw[raw.find(b"{") : raw.rfind(b"}") + 1])
 
     assert_frame_equal(expected, read_df)
+
+
+@pytest.fixture
+def multi_block_avro() -> tuple[pl.DataFrame, bytes]:
+    # Every chunk is written as a separate Avro block.
+    df = pl.concat(
+        [
+            pl.DataFrame({"a": range(i, i + 7), "b": [str(x) for x in range(7)]})
+            for i in range(0, 70, 7)
+        ],
+        rechunk=False,
+    )
+    f = io.BytesIO()
+    df.write_avro(f)
+    return df, f.getvalue()
+
+
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+def test_scan_avro(example_df: pl.DataFrame, compression: AvroCompression) -> None:
+    f = io.BytesIO()
+    example_df.write_avro(f, compression=compression)
+
+    assert_frame_equal(pl.scan_avro(f.getvalue()).collect(), example_df)
+    assert_frame_equal(
+        pl.scan_avro(f.getvalue()).select("str", "i64").collect(),
+        example_df.select("str", "i64"),
+    )
+
+
+@pytest.mark.parametrize(
+    ("offset", "length"), [(0, 5), (3, 20), (7, 7), (66, 100), (100, 5), (-12, 8)]
+)
+def test_scan_avro_slice(
+    multi_block_avro: tuple[pl.DataFrame, bytes], offset: int, length: int
+) -> None:
+    df, data = multi_block_avro
+
+    assert_frame_equal(
+        pl.scan_avro(data).slice(offset, length).collect(), df.slice(offset, length)
+    )
+    assert_frame_equal(
+        pl.scan_avro(data, row_index_name="idx", row_index_offset=10)
+        .select("idx", "b")
+        .slice(offset, length)
+        .collect(),
+        df.with_row_index("idx", offset=10).select("idx", "b").slice(offset, length),
+    )
+    assert_frame_equal(pl.scan_avro(data, n_rows=length).collect(), df.head(length))
+
+
+def test_scan_avro_row_index_filter(
+    multi_block_avro: tuple[pl.DataFrame, bytes],
+) -> None:
+    df, data = multi_block_avro
+
+    assert_frame_equal(
+        pl.scan_avro(data, row_index_name="idx")
+        .filter(pl.col("idx") % 9 == 0)
+        .collect(),
+        df.with_row_index("idx").filter(pl.col("idx") % 9 == 0),
+    )
+
+
+def test_scan_avro_count(multi_block_avro: tuple[pl.DataFrame, bytes]) -> None:
+    df, data = multi_block_avro
+
+    assert pl.scan_avro(data).select(pl.len()).item() == df.height
+    assert pl.scan_avro([data, data]).select(pl.len()).item() == 2 * df.height
+
+
+@pytest.mark.write_disk
+def test_scan_avro_multiple_files(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    pl.DataFrame({"a": [1, 2], "b": ["x", "y"]}).write_avro(tmp_path / "1.avro")
+    pl.DataFrame({"a": [3]}, schema={"a": pl.Int32}).write_avro(tmp_path / "2.avro")
+
+    with pytest.raises(pl.exceptions.SchemaError):
+        pl.scan_avro(tmp_path / "*.avro").collect()
+
+    with pytest.raises(pl.exceptions.ColumnNotFoundError):
+        pl.scan_avro(
+            tmp_path / "*.avro",
+            cast_options=pl.ScanCastOptions(integer_cast="upcast"),
+        ).collect()
+
+    expect = pl.DataFrame({"a": [1, 2, 3], "b": ["x", "y", None]})
+    assert_frame_equal(
+        pl.scan_avro(
+            tmp_path / "*.avro",
+            allow_missing_columns=True,
+            cast_options=pl.ScanCastOptions(integer_cast="upcast"),
+            include_file_paths="path",
+        )
+        .with_columns(pl.col("path").str.extract(r"(\d)\.avro$"))
+        .collect(),
+        expect.with_columns(pl.Series("path", ["1", "1", "2"])),
+    )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("compression", COMPRESSIONS)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_sink_avro(
+    multi_block_avro: tuple[pl.DataFrame, bytes],
+    compression: AvroCompression,
+    engine: EngineType,
+    tmp_path: Path,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    df, _ = multi_block_avro
+
+    path = tmp_path / "out.avro"
+    df.lazy().sink_avro(path, compression=compression, name="r", engine=engine)
+
+    assert_frame_equal(pl.read_avro(path), df)
+    assert_frame_equal(pl.scan_avro(path).slice(30, 20).collect(), df.slice(30, 20))
+
+
+@pytest.mark.write_disk
+def test_sink_avro_empty(tmp_path: Path) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    df = pl.DataFrame(schema={"a": pl.Int64, "b": pl.String})
+
+    path = tmp_path / "empty.avro"
+    df.lazy().sink_avro(path)
+
+    assert_frame_equal(pl.read_avro(path), df)
@@ -1,7 +1,9 @@
 from __future__ import annotations
 
+import bz2
 import gzip
 import io
+import lzma
 import os
 import sys
 import textwrap
@@ -9,7 +11,7 @@ import zlib
 from datetime import date, datetime, time, timedelta, timezone
 from decimal import Decimal as D
 from tempfile import NamedTemporaryFile
-from typing import TYPE_CHECKING, TypedDict
+from typing import TYPE_CHECKING, Callable, TypedDict
 
 import numpy as np
 import pyarrow as pa
@@ -25,7 +27,7 @@ from polars.testing import assert_frame_equal, assert_series_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
-    from polars._typing import TimeUnit
+    from polars._typing import CsvEncoding, EngineType, ExternalCompression, TimeUnit
     from tests.unit.conftest import MemoryUsage
 
 
@@ -533,6 +535,76 @@ def test_read_csv_encoding_lossy(tmp_path: Path) -> None:
         )
 
 
+@pytest.mark.parametrize(
+    ("encoding", "codec"),
+    [
+        ("latin1", "latin-1"),
+        ("windows-1252", "cp1252"),
+        ("utf-16-le", "utf-16-le"),
+        ("utf-16-be", "utf-16-be"),
+    ],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_read_csv_transcoded_encodings(
+    encoding: CsvEncoding, codec: str, engine: EngineType, tmp_path: Path
+) -> None:
+    words = ["café", "naïve", "line\nbreak", "Øre"]
+    if encoding != "latin1":
+        words.append("€uro")
+    if encoding.startswith("utf-16"):
+        words.append("日本😀")
+    n = 20_000
+    df = pl.DataFrame(
+        {
+            "id": range(n),
+            "text": [words[i % len(words)] for i in range(n)],
+            "f": [i / 4 for i in range(n)],
+        }
+    )
+    data = df.write_csv().encode(codec)
+    path = tmp_path / "data.csv"
+    path.write_bytes(data)
+
+    for source in [path, str(path), data, io.BytesIO(data)]:
+        result = pl.read_csv(source, encoding=encoding)  # type: ignore[arg-type]
+        assert_frame_equal(result, df)
+
+    lf = pl.scan_csv(path, encoding=encoding)
+    assert_frame_equal(lf.collect(engine=engine), df)
+    assert_frame_equal(
+        lf.slice(7001, 3000).collect(engine=engine), df.slice(7001, 3000)
+    )
+    assert lf.select(pl.len()).collect(engine=engine).item() == n
+    assert_frame_equal(
+        lf.filter(pl.col("text") == "café").collect(engine=engine),
+        df.filter(pl.col("text") == "café"),
+    )
+
+    # Transcoded while decompressing.
+    gz_path = tmp_path / "data.csv.gz"
+    gz_path.write_bytes(gzip.compress(data))
+    result = pl.scan_csv(gz_path, encoding=encoding).collect(engine=engine)
+    assert_frame_equal(result, df)
+    assert_frame_equal(pl.read_csv(gz_path, encoding=encoding, n_rows=5), df.head(5))
+
+    reader = pl.read_csv_batched(path, encoding=encoding, batch_size=1000)
+    batches = reader.next_batches(100)
+    assert batches is not None
+    assert_frame_equal(pl.concat(batches), df)
+
+
+def test_read_csv_utf16_bom_and_invalid_data() -> None:
+    data = "\ufeffa,b\nx,1\n".encode("utf-16-le")
+    assert_frame_equal(
+        pl.read_csv(data, encoding="utf-16-le"), pl.DataFrame({"a": ["x"], "b": [1]})
+    )
+
+    # An unpaired surrogate and a trailing odd byte are replaced.
+    data = "a\nx".encode("utf-16-le") + b"\x00\xd8" + "y\n".encode("utf-16-le") + b"z"
+    assert pl.read_csv(data, encoding="utf-16-le")["a"].to_list() == ["x�y", "�"]
+
+
 @pytest.mark.may_fail_auto_streaming  # read->scan_csv dispatch
 def test_column_rename_and_schema_overrides() -> None:
     csv = textwrap.dedent(
@@ -2530,6 +2602,214 @@ def test_csv_compressed_new_columns_19916() -> None:
     assert_frame_equal(q.collect(), df)
 
 
+@pytest.mark.parametrize(
+    "compress",
+    [gzip.compress, zlib.compress, zstandard.compress, bz2.compress, lzma.compress],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scan_csv_compressed_streaming_decompression(
+    compress: Callable[[bytes], bytes],
+    engine: EngineType,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    # Use a small chunk size to catch failures at chunk boundaries.
+    monkeypatch.setenv("POLARS_FORCE_DECOMPRESS_CHUNK_SIZE", "4096")
+    n_rows = 20_000
+    df = pl.DataFrame(
+        {
+            "a": range(n_rows),
+            "b": [f"line\n{i}" if i % 7 == 0 else str(i) for i in range(n_rows)],
+        }
+    )
+    data = compress(df.write_csv().encode())
+
+    lf = pl.scan_csv(data)
+    assert_frame_equal(lf.collect(engine=engine), df)
+    assert_frame_equal(
+        lf.slice(12_345, 10_000).collect(engine=engine),
+        df.slice(12_345, 10_000),
+    )
+    assert_frame_equal(
+        lf.filter(pl.col("a") % 1000 == 0).collect(engine=engine),
+        df.filter(pl.col("a") % 1000 == 0),
+    )
+    assert lf.select(pl.len()).collect(engine=engine).item() == n_rows
+
+
+@pytest.mark.parametrize("compression", ["gzip", "zstd"])
+def test_write_csv_compressed(compression: ExternalCompression) -> None:
+    df = pl.DataFrame({"a": [1, 2, 3], "b": ["x", None, "z"]})
+
+    f = io.BytesIO()
+    df.write_csv(f, compression=compression)
+    assert f.getvalue() != df.write_csv().encode()
+
+    f.seek(0)
+    assert_frame_equal(pl.read_csv(f), df)
+
+    with pytest.raises(ValueError, match="requires a `file`"):
+        df.write_csv(compression=compression)
+
+
+@pytest.mark.parametrize(
+    ("encoding", "codec"),
+    [
+        ("latin1", "latin-1"),
+        ("windows-1252", "cp1252"),
+        ("utf-16-le", "utf-16-le"),
+        ("utf-16-be", "utf-16-be"),
+    ],
+)
+@pytest.mark.write_disk
+def test_write_csv_encoding(encoding: CsvEncoding, codec: str, tmp_path: Path) -> None:
+    words = ["café", "naïve", "Øre"]
+    if encoding != "latin1":
+        words.append("€uro")
+    df = pl.DataFrame({"a": [1, 2, 3, 4][: len(words)], "b": words})
+
+    f = io.BytesIO()
+    df.write_csv(f, encoding=encoding)
+    assert f.getvalue() == df.write_csv().encode(codec)
+
+    path = tmp_path / "out.csv.gz"
+    df.lazy().sink_csv(path, encoding=encoding, compression="gzip")
+    assert gzip.decompress(path.read_bytes()) == df.write_csv().encode(codec)
+    assert_frame_equal(pl.read_csv(path, encoding=encoding), df)
+
+    with pytest.raises(ValueError, match="requires a `file`"):
+        df.write_csv(encoding=encoding)
+
+    if encoding.startswith("utf-16"):
+        f = io.BytesIO()
+        df.write_csv(f, encoding=encoding, include_bom=True)
+        assert f.getvalue() == ("\ufeff" + df.write_csv()).encode(codec)
+    else:
+        with pytest.raises(InvalidOperationError, match="BOM"):
+            df.write_csv(io.BytesIO(), encoding=encoding, include_bom=True)
+        # rejected when the sink is defined, not when it is executed
+        with pytest.raises(InvalidOperationError, match="BOM"):
+            df.lazy().sink_csv(
+                tmp_path / "bom.csv", encoding=encoding, include_bom=True, lazy=True
+            )
+
+
+def test_write_csv_encoding_unrepresentable_character() -> None:
+    df = pl.DataFrame({"a": ["ő"]})
+    with pytest.raises(OSError, match="cannot be represented in the Latin1 encoding"):
+        df.write_csv(io.BytesIO(), encoding="latin1")
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_scan_csv_rejects_path(engine: EngineType, tmp_path: Path) -> None:
+    path = tmp_path / "data.csv"
+    path.write_bytes(
+        b"a,b,c\n"
+        b"1,10:00:00,x\n"
+        b"x2,11:00:00,y\n"
+        b'3,12:00:00,"multi\nline"\n'
+        b"4,nope,z\n"
+        b"5,13:00:00,z,extra\n"
+        b"6,14:00:00,\xff\n"
+        b"7,15:00:00,w\n"
+    )
+    rejects_path = tmp_path / "rejects.csv"
+
+    lf = pl.scan_csv(
+        path,
+        schema={"a": pl.Int64, "b": pl.Time, "c": pl.String},
+        rejects_path=rejects_path,
+    )
+    expected = pl.DataFrame(
+        {
+            "a": [1, 3, 7],
+            "b": [time(10), time(12), time(15)],
+            "c": ["x", "multi\nline", "w"],
+        }
+    )
+    assert_frame_equal(lf.collect(engine=engine), expected)
+
+    rejects = pl.read_csv(rejects_path)
+    assert rejects.columns == ["file", "line", "raw_line", "error"]
+    assert rejects["file"].to_list() == [normalize_filepath(path)] * 4
+    assert rejects["line"].to_list() == [3, 6, 7, 8]
+    assert rejects["raw_line"].to_list() == [
+        "x2,11:00:00,y",
+        "4,nope,z",
+        "5,13:00:00,z,extra",
+        "6,14:00:00,�",
+    ]
+    assert rejects["error"].str.contains("x2").to_list() == [True, False, False, False]
+
+    assert lf.select(pl.len()).collect(engine=engine).item() == 3
+    assert_frame_equal(lf.select("c").collect(engine=engine), expected.select("c"))
+
+    with pytest.raises(InvalidOperationError, match="ignore_errors"):
+        lf = pl.scan_csv(
+            path,
+            schema={"a": pl.Int64, "b": pl.Time, "c": pl.String},
+            rejects_path=rejects_path,
+            ignore_errors=True,
+        )
+        lf.collect(engine=engine)
+
+
+@pytest.mark.write_disk
+def test_read_csv_rejects_path(tmp_path: Path) -> None:
+    rejects_path = tmp_path / "rejects.csv"
+
+    df = pl.read_csv(
+        b"a,b\n1,2\nx,3\n4,5\n",
+        schema_overrides={"a": pl.Int64},
+        rejects_path=rejects_path,
+    )
+    assert_frame_equal(df, pl.DataFrame({"a": [1, 4], "b": [2, 5]}))
+
+    rejects = pl.read_csv(rejects_path, schema_overrides={"file": pl.String})
+    assert rejects.select("file", "line", "raw_line").rows() == [(None, 3, "x,3")]
+    assert "could not parse `x` as dtype `i64` at column 'a'" in rejects["error"][0]
+
+    pl.read_csv(b"a,b\n1,2\n", rejects_path=rejects_path)
+    assert rejects_path.read_text() == "file,line,raw_line,error\n"
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.parametrize("separator", ["||", "\t|\t"])
+def test_scan_csv_multi_byte_separator(separator: str, engine: EngineType) -> None:
+    csv = separator.join(["a", "b", "c"]) + "\n"
+    csv += separator.join(["1", "x|y", '"q\n' + separator + '"']) + "\n"
+    csv += separator.join(["2", "", "z"]) + "\n"
+
+    expected = pl.DataFrame(
+        {"a": [1, 2], "b": ["x|y", None], "c": ["q\n" + separator, "z"]}
+    )
+    lf = pl.scan_csv(csv.encode(), separator=separator)
+    assert_frame_equal(lf.collect(engine=engine), expected)
+    assert lf.select(pl.len()).collect(engine=engine).item() == 2
+    assert_frame_equal(pl.read_csv(csv.encode(), separator=separator), expected)
+
+
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scan_csv_escape_char(engine: EngineType) -> None:
+    csv = b'a,b\n"say \\"hi\\"",x\\,y\nline\\\nbreak,\\\\\n'
+    expected = pl.DataFrame({"a": ['say "hi"', "line\nbreak"], "b": ["x,y", "\\"]})
+
+    lf = pl.scan_csv(csv, escape_char="\\")
+    assert_frame_equal(lf.collect(engine=engine), expected)
+    assert_frame_equal(pl.read_csv(csv, escape_char="\\"), expected)
+
+
+def test_write_csv_multi_byte_separator_escape_char() -> None:
+    df = pl.DataFrame({"a": ['say "hi"', "line\nbreak"], "b": ["x||y", "\\"]})
+
+    csv = df.write_csv(separator="||", escape_char="\\")
+    assert csv == 'a||b\nsay \\"hi\\"||"x||y"\n"line\nbreak"||\\\\\n'
+    assert_frame_equal(pl.read_csv(csv.encode(), separator="||", escape_char="\\"), df)
+
+    with pytest.raises(ValueError, match="`separator` cannot be empty"):
+        df.write_csv(separator="")
+
+
 def test_trailing_separator_8240() -> None:
     csv = "A|B|"
 
@@ -1,8 +1,13 @@
 from __future__ import annotations
 
-from typing import TYPE_CHECKING
+import bz2
+import gzip
+import lzma
+import zlib
+from typing import TYPE_CHECKING, Callable
 
 import pytest
+import zstandard
 
 import polars as pl
 from polars.testing import assert_frame_equal
@@ -10,6 +15,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import EngineType
+
 
 @pytest.fixture
 def foods_ndjson_path(io_files_path: Path) -> Path:
@@ -327,3 +334,31 @@ def test_scan_ndjson_schema_overwrite_22514() -> None:
     q = pl.scan_ndjson(buf, schema_overrides={"a": pl.String})
     assert q.collect_schema() == {"a": pl.String}
     assert_frame_equal(q.collect(), pl.DataFrame({"a": "1"}))
+
+
+@pytest.mark.parametrize(
+    "compress",
+    [gzip.compress, zlib.compress, zstandard.compress, bz2.compress, lzma.compress],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+def test_scan_ndjson_compressed_streaming_decompression(
+    compress: Callable[[bytes], bytes],
+    engine: EngineType,
+    monkeypatch: pytest.MonkeyPatch,
+) -> None:
+    # Use a small chunk size to catch failures at chunk boundaries.
+    monkeypatch.setenv("POLARS_FORCE_DECOMPRESS_CHUNK_SIZE", "4096")
+    n_rows = 20_000
+    df = pl.DataFrame(
+        {"a": range(n_rows), "b": [str(i) * (i % 5) for i in range(n_rows)]}
+    )
+    data = compress(df.write_ndjson().encode())
+
+    lf = pl.scan_ndjson(data)
+    assert_frame_equal(lf.collect(engine=engine), df)
+    assert_frame_equal(
+        lf.slice(12_345, 10_000).collect(engine=engine),
+        df.slice(12_345, 10_000),
+    )
+    assert_frame_equal(lf.slice(-10_000).collect(engine=engine), df.slice(-10_000))
+    assert lf.select(pl.len()).collect(engine=engine).item() == n_rows
@@ -271,6 +271,99 @@ def test_parquet_statistics(monkeypatch: Any, capfd: Any, tmp_path: Path) -> Non
     assert "Predicate pushdown: reading 1 / 2 row groups" in captured
 
 
+@pytest.mark.write_disk
+def test_parquet_page_index_pushdown(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    n = 100_000
+    df = pl.DataFrame(
+        {
+            "ts": pl.int_range(0, 3 * n, 3, eager=True),
+            "s": pl.int_range(n, eager=True).cast(pl.String),
+            "nn": pl.int_range(n, eager=True).map_elements(
+                lambda x: None if (x // 5000) % 2 == 0 else float(x),
+                return_dtype=pl.Float64,
+            ),
+            "l": pl.int_range(n, eager=True).map_elements(
+                lambda x: [x, 1], return_dtype=pl.List(pl.Int64)
+            ),
+        }
+    )
+
+    file_path = tmp_path / "page_index.parquet"
+    df.write_parquet(
+        file_path, statistics=True, row_group_size=n, data_page_size=8 * 1024
+    )
+
+    for pred in [
+        pl.col("ts") == 30_003,
+        (pl.col("ts") > 100_000) & (pl.col("ts") < 130_000),
+        pl.col("nn").is_null() & (pl.col("ts") > 290_000),
+        pl.col("nn").is_not_null() & (pl.col("ts") < 40_000),
+        pl.col("ts") < -1,
+    ]:
+        result = pl.scan_parquet(file_path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+    pred = (pl.col("index") > 80_000) & (pl.col("ts") < 250_000)
+    result = pl.scan_parquet(file_path).with_row_index().filter(pred)
+    expected = df.with_row_index().filter(pred)
+    assert_frame_equal(
+        result.select("index", "l").collect(), expected.select("index", "l")
+    )
+
+    captured = capfd.readouterr().err
+    assert "Page index pushdown: reading" in captured
+
+
+@pytest.mark.write_disk
+def test_parquet_bloom_filter_pushdown(
+    monkeypatch: Any, capfd: Any, tmp_path: Path
+) -> None:
+    from polars.io.parquet import ParquetFieldOverwrites
+
+    tmp_path.mkdir(exist_ok=True)
+
+    n = 50_000
+    df = pl.DataFrame(
+        {
+            "id": pl.int_range(n, eager=True).shuffle(seed=0).cast(pl.String),
+            "k": pl.int_range(n, eager=True).shuffle(seed=1),
+            "x": pl.int_range(n, eager=True),
+        }
+    )
+
+    file_path = tmp_path / "bloom_filter.parquet"
+    df.lazy().sink_parquet(
+        file_path,
+        row_group_size=5_000,
+        field_overwrites={
+            "id": ParquetFieldOverwrites(bloom_filter=True),
+            "k": ParquetFieldOverwrites(bloom_filter=True),
+        },
+    )
+
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    for pred in [
+        pl.col("id") == "12345",
+        pl.col("id") == "10000x",
+        pl.col("id").is_in(["123", "4567", "10000x"]),
+        pl.col("id").is_in([None, "123"], nulls_equal=True),
+        (pl.col("k") == 777) | (pl.col("x") == 1),
+        (pl.col("k") == 777) & (pl.col("id") == "12345"),
+    ]:
+        result = pl.scan_parquet(file_path).filter(pred).collect()
+        assert_frame_equal(result, df.filter(pred))
+
+    captured = capfd.readouterr().err
+    assert "Bloom filter pushdown: skipping" in captured
+
+
 @pytest.mark.write_disk
 @pytest.mark.usefixtures("test_global_and_local")
 def test_categorical(tmp_path: Path) -> None:
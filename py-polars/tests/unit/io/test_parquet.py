@@ -3329,3 +3329,98 @@ def test_field_overwrites_metadata() -> None:
     assert schema[2].metadata[b"struct"] == b"true"
     assert schema[2].type.fields[0].metadata[b"md"] == b"yes"
     assert schema[2].type.fields[1].metadata[b"md2"] == b"Yes!"
+
+
+def test_field_overwrites_encoding_compression() -> None:
+    n = 1_000
+    df = pl.DataFrame(
+        {
+            "ts": pl.datetime_range(
+                datetime(2025, 1, 1), datetime(2025, 1, 1, 0, 16, 39), "1s", eager=True
+            ),
+            "f": pl.Series([None if i % 7 == 0 else i / 3 for i in range(n)]),
+            "s": [f"sensor-{i // 3:06}" for i in range(n)],
+            "b": [None if i % 5 == 0 else i % 2 == 0 for i in range(n)],
+            "k": [i % 4 for i in range(n)],
+        }
+    )
+
+    f = io.BytesIO()
+    df.write_parquet(
+        f,
+        field_overwrites={
+            "ts": ParquetFieldOverwrites(encoding="delta_binary_packed"),
+            "f": ParquetFieldOverwrites(
+                encoding="byte_stream_split", compression="zstd", compression_level=9
+            ),
+            "s": ParquetFieldOverwrites(
+                encoding="delta_byte_array", compression="snappy", statistics=False
+            ),
+            "b": ParquetFieldOverwrites(encoding="rle", compression="uncompressed"),
+            "k": ParquetFieldOverwrites(dictionary=False, statistics="full"),
+        },
+    )
+
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    f.seek(0)
+    rg = pq.ParquetFile(f).metadata.row_group(0)
+    columns = {rg.column(i).path_in_schema: rg.column(i) for i in range(5)}
+
+    assert "DELTA_BINARY_PACKED" in columns["ts"].encodings
+    assert "BYTE_STREAM_SPLIT" in columns["f"].encodings
+    assert columns["f"].compression == "ZSTD"
+    assert "DELTA_BYTE_ARRAY" in columns["s"].encodings
+    assert columns["s"].compression == "SNAPPY"
+    assert not columns["s"].is_stats_set
+    assert "RLE" in columns["b"].encodings
+    assert columns["b"].compression == "UNCOMPRESSED"
+    assert "RLE_DICTIONARY" not in columns["k"].encodings
+    assert columns["k"].statistics.distinct_count == 4
+
+
+def test_field_overwrites_encoding_sink_parquet() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [1.5, 2.25, None, 4.0] * 100,
+            "b": ["x", "xy", "xyz", None] * 100,
+        }
+    )
+
+    f = io.BytesIO()
+    df.lazy().sink_parquet(
+        f,
+        row_group_size=128,
+        field_overwrites={
+            "a": ParquetFieldOverwrites(encoding="byte_stream_split"),
+            "b": ParquetFieldOverwrites(
+                dictionary=True, encoding="delta_length_byte_array"
+            ),
+        },
+    )
+
+    f.seek(0)
+    assert_frame_equal(pl.read_parquet(f), df)
+
+    f.seek(0)
+    rg = pq.ParquetFile(f).metadata.row_group(0)
+    assert "BYTE_STREAM_SPLIT" in rg.column(0).encodings
+    assert "RLE_DICTIONARY" in rg.column(1).encodings
+
+
+def test_field_overwrites_encoding_invalid() -> None:
+    with pytest.raises(ComputeError):
+        pl.DataFrame({"a": ["x", "y"]}).write_parquet(
+            io.BytesIO(),
+            field_overwrites={
+                "a": ParquetFieldOverwrites(encoding="byte_stream_split")
+            },
+        )
+
+    with pytest.raises(ValueError, match="use_pyarrow"):
+        pl.DataFrame({"a": [1]}).write_parquet(
+            io.BytesIO(),
+            use_pyarrow=True,
+            field_overwrites={"a": ParquetFieldOverwrites(encoding="plain")},
+        )
@@ -34,6 +34,7 @@ io_types: list[IOType] = [
     {"ext": "jsonl", "scan": pl.scan_ndjson, "sink": pl.LazyFrame.sink_ndjson},
     {"ext": "parquet", "scan": pl.scan_parquet, "sink": pl.LazyFrame.sink_parquet},
     {"ext": "ipc", "scan": pl.scan_ipc, "sink": pl.LazyFrame.sink_ipc},
+    {"ext": "avro", "scan": pl.scan_avro, "sink": pl.LazyFrame.sink_avro},
 ]
 
 
@@ -320,6 +321,28 @@ def test_partition_by_key_parametric(
         )
 
 
+@pytest.mark.parametrize(("compression", "ext"), [("gzip", "gz"), ("zstd", "zst")])
+@pytest.mark.parametrize("io_type", io_types[:2])
+@pytest.mark.write_disk
+def test_max_size_partition_compressed(
+    tmp_path: Path, io_type: IOType, compression: str, ext: str
+) -> None:
+    df = pl.DataFrame({"a": range(5)})
+
+    (io_type["sink"])(
+        df.lazy(),
+        PartitionMaxSize(tmp_path, max_size=2),
+        compression=compression,
+        engine="streaming",
+        sync_on_close="data",
+    )
+
+    files = sorted(p.name for p in tmp_path.iterdir())
+    assert files == [f"{i}.{io_type['ext']}.{ext}" for i in range(3)]
+    out = pl.concat([(io_type["scan"])(tmp_path / f).collect() for f in files])
+    assert_frame_equal(out, df)
+
+
 def test_max_size_partition_collect_files(tmp_path: Path) -> None:
     length = 17
     max_size = 3
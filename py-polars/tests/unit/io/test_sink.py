@@ -1,8 +1,10 @@
+import gzip
 import io
 from pathlib import Path
 from typing import Any
 
 import pytest
+import zstandard
 
 import polars as pl
 from polars._typing import EngineType
@@ -106,3 +108,59 @@ def test_sink_to_file(tmp_path: Path, sink: Any, scan: Any) -> None:
             scan(f).collect(),
             df,
         )
+
+
+def _zstd_decompress(data: bytes) -> bytes:
+    with zstandard.ZstdDecompressor().stream_reader(
+        data, read_across_frames=True
+    ) as reader:
+        return reader.read()
+
+
+@pytest.mark.parametrize(
+    ("scan", "sink"),
+    [
+        (pl.scan_csv, pl.LazyFrame.sink_csv),
+        (pl.scan_ndjson, pl.LazyFrame.sink_ndjson),
+    ],
+)
+@pytest.mark.parametrize(
+    ("compression", "decompress"),
+    [("gzip", gzip.decompress), ("zstd", _zstd_decompress)],
+)
+@pytest.mark.parametrize("engine", ["in-memory", "streaming"])
+@pytest.mark.write_disk
+def test_sink_compressed(
+    tmp_path: Path,
+    scan: Any,
+    sink: Any,
+    compression: str,
+    decompress: Any,
+    engine: EngineType,
+) -> None:
+    df = pl.DataFrame({"a": range(100_000), "b": ["x", None] * 50_000})
+
+    sink(df.lazy(), tmp_path / "plain", engine=engine)
+    sink(
+        df.lazy(),
+        tmp_path / "compressed",
+        compression=compression,
+        compression_level=3,
+        engine=engine,
+    )
+
+    compressed = (tmp_path / "compressed").read_bytes()
+    assert decompress(compressed) == (tmp_path / "plain").read_bytes()
+    assert_frame_equal(scan(tmp_path / "compressed").collect(), df)
+
+
+@pytest.mark.parametrize("sink", [pl.LazyFrame.sink_csv, pl.LazyFrame.sink_ndjson])
+def test_sink_compression_level_out_of_range(sink: Any) -> None:
+    lf = pl.LazyFrame({"a": [1, 2, 3]})
+
+    with pytest.raises(ValueError, match="between 0 and 9, got 10"):
+        sink(lf, io.BytesIO(), compression="gzip", compression_level=10)
+    with pytest.raises(ValueError, match="between 1 and 22, got 0"):
+        sink(lf, io.BytesIO(), compression="zstd", compression_level=0)
+    with pytest.raises(ValueError, match="must be one of"):
+        sink(lf, io.BytesIO(), compression="bzip2")
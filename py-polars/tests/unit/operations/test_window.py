@@ -701,3 +701,19 @@ def test_window_fold_22493() -> None:
                 .alias("prod"),
             )
         )
+
+
+def test_window_sort_by_multiple_keys_non_column_input() -> None:
+    df = pl.DataFrame(
+        {
+            "g": ["a", "b", "a", "a", "b", "a", "b"],
+            "k": [3, 1, 1, 2, 1, 2, 5],
+            "v": [10, 20, 30, None, 50, 60, 70],
+        }
+    )
+    out = df.select(
+        pl.int_range(pl.len())
+        .sort_by(["k", "v"], descending=[True, False], nulls_last=True)
+        .over("g")
+    )
+    assert out.to_series().to_list() == [0, 2, 3, 2, 0, 1, 1]
@@ -2,6 +2,7 @@ from __future__ import annotations
 
 from datetime import date
 from pathlib import Path
+from typing import Any
 
 import pytest
 
@@ -250,3 +251,124 @@ def test_group_by_output_struct() -> None:
     df = pl.DataFrame({"g": [1], "x": [2], "y": [3]})
     out = df.group_by("g").agg(pl.struct(pl.col.x.min(), pl.col.y.sum()))
     assert out.rows() == [(1, {"x": 2, "y": 3})]
+
+
+@pytest.fixture
+def df_sales() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "region": ["n", "n", "s", "s", "n"],
+            "year": [2023, 2024, 2023, 2024, 2024],
+            "amount": [10, 20, 30, 40, 50],
+        }
+    )
+
+
+@pytest.mark.parametrize(
+    ("group_by", "expected"),
+    [
+        (
+            "ROLLUP (region, year)",
+            [
+                ("n", 2023, 10),
+                ("n", 2024, 70),
+                ("n", None, 80),
+                ("s", 2023, 30),
+                ("s", 2024, 40),
+                ("s", None, 70),
+                (None, None, 150),
+            ],
+        ),
+        (
+            "region, year WITH ROLLUP",
+            [
+                ("n", 2023, 10),
+                ("n", 2024, 70),
+                ("n", None, 80),
+                ("s", 2023, 30),
+                ("s", 2024, 40),
+                ("s", None, 70),
+                (None, None, 150),
+            ],
+        ),
+        (
+            "CUBE (region, year)",
+            [
+                ("n", 2023, 10),
+                ("n", 2024, 70),
+                ("n", None, 80),
+                ("s", 2023, 30),
+                ("s", 2024, 40),
+                ("s", None, 70),
+                (None, 2023, 40),
+                (None, 2024, 110),
+                (None, None, 150),
+            ],
+        ),
+        (
+            "GROUPING SETS ((region), (year), ())",
+            [
+                ("n", None, 80),
+                ("s", None, 70),
+                (None, 2023, 40),
+                (None, 2024, 110),
+                (None, None, 150),
+            ],
+        ),
+        (
+            "region, ROLLUP (year)",
+            [
+                ("n", 2023, 10),
+                ("n", 2024, 70),
+                ("n", None, 80),
+                ("s", 2023, 30),
+                ("s", 2024, 40),
+                ("s", None, 70),
+            ],
+        ),
+    ],
+)
+def test_group_by_grouping_sets(
+    df_sales: pl.DataFrame, group_by: str, expected: list[tuple[Any, ...]]
+) -> None:
+    res = df_sales.sql(
+        f"""
+        SELECT region, year, SUM(amount) AS total
+        FROM self
+        GROUP BY {group_by}
+        ORDER BY region NULLS LAST, year NULLS LAST
+        """
+    )
+    assert res.rows() == expected
+
+
+def test_group_by_grouping_function(df_sales: pl.DataFrame) -> None:
+    res = df_sales.sql(
+        """
+        SELECT
+          CASE WHEN GROUPING(region) = 1 THEN 'total' ELSE region END AS label,
+          GROUPING(region, year) AS g,
+          SUM(amount) AS total
+        FROM self
+        GROUP BY CUBE (region, year)
+        ORDER BY g, label, total
+        """
+    )
+    assert res.rows() == [
+        ("n", 0, 10),
+        ("n", 0, 70),
+        ("s", 0, 30),
+        ("s", 0, 40),
+        ("n", 1, 80),
+        ("s", 1, 70),
+        ("total", 2, 40),
+        ("total", 2, 110),
+        ("total", 3, 150),
+    ]
+    assert res.schema["g"] == pl.Int64
+
+    with pytest.raises(
+        SQLSyntaxError,
+        match="GROUPING arguments must be GROUP BY expressions",
+    ):
+        df_sales.sql("SELECT region, GROUPING(year) FROM self GROUP BY ROLLUP (region)")
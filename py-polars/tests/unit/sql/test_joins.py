@@ -309,25 +309,32 @@ def test_join_misc_16255() -> None:
 
 
 @pytest.mark.parametrize(
-    "constraint", ["tbl.a != tbl.b", "tbl.a > tbl.b", "a >= b", "a < b", "b <= a"]
-)
-def test_non_equi_joins(constraint: str) -> None:
-    # no support (yet) for non equi-joins in polars joins
-    # TODO: integrate awareness of new IEJoin
-    with (
-        pytest.raises(
-            SQLInterfaceError,
-            match=r"only equi-join constraints \(combined with 'AND'\) are currently supported",
+    ("constraint", "expected"),
+    [
+        ("t1.a > t2.b", [(1, None), (2, None), (3, 2)]),
+        ("t1.a >= t2.b", [(1, None), (2, 2), (3, 2), (3, 3)]),
+        ("t2.b <= t1.a", [(1, None), (2, 2), (3, 2), (3, 3)]),
+        ("t1.a BETWEEN t2.b - 1 AND t2.b", [(1, 2), (2, 2), (2, 3), (3, 3), (3, 4)]),
+        (
+            "t1.a < t2.b AND t1.b > t2.a",
+            [(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)],
         ),
-        pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx,
-    ):
-        ctx.execute(
+        ("t1.a = t2.a + 1", [(1, None), (2, 4), (3, 3)]),
+        ("t1.a = t2.a AND t1.b != t2.b", [(1, None), (2, None), (3, None)]),
+    ],
+)
+def test_non_equi_joins(constraint: str, expected: list[tuple[Any, ...]]) -> None:
+    with pl.SQLContext({"tbl": pl.DataFrame({"a": [1, 2, 3], "b": [4, 3, 2]})}) as ctx:
+        res = ctx.execute(
             f"""
-            SELECT *
-            FROM tbl
-            LEFT JOIN tbl ON {constraint}  -- not an equi-join
-            """
+            SELECT t1.a, t2.b
+            FROM tbl AS t1
+            LEFT JOIN tbl AS t2 ON {constraint}
+            ORDER BY t1.a, t2.b
+            """,
+            eager=True,
         )
+        assert res.rows() == expected
 
 
 def test_implicit_joins() -> None:
@@ -7,7 +7,12 @@ from typing import TYPE_CHECKING, Any
 import pytest
 
 import polars as pl
-from polars.exceptions import ColumnNotFoundError, SQLInterfaceError, SQLSyntaxError
+from polars.exceptions import (
+    ColumnNotFoundError,
+    ComputeError,
+    SQLInterfaceError,
+    SQLSyntaxError,
+)
 from polars.testing import assert_frame_equal
 from tests.unit.utils.pycapsule_utils import PyCapsuleStreamHolder
 
@@ -300,6 +305,81 @@ def test_nested_cte_column_aliasing() -> None:
     }
 
 
+def test_recursive_cte() -> None:
+    employees = pl.DataFrame(
+        {
+            "id": [1, 2, 3, 4, 5],
+            "name": ["ceo", "cto", "cfo", "dev", "intern"],
+            "manager_id": [None, 1, 1, 2, 4],
+        }
+    )
+    res = pl.SQLContext(employees=employees).execute(
+        """
+        WITH RECURSIVE chain AS (
+          SELECT id, name, 0 AS depth FROM employees WHERE manager_id IS NULL
+          UNION ALL
+          SELECT e.id, e.name, c.depth + 1
+          FROM employees e JOIN chain c ON e.manager_id = c.id
+        )
+        SELECT name, depth FROM chain ORDER BY depth, id
+        """,
+        eager=True,
+    )
+    assert res.to_dict(as_series=False) == {
+        "name": ["ceo", "cto", "cfo", "dev", "intern"],
+        "depth": [0, 1, 1, 2, 3],
+    }
+
+
+def test_recursive_cte_union() -> None:
+    edges = pl.DataFrame({"src": [1, 2, 3, 3], "dst": [2, 3, 1, 4]})
+    query = """
+        WITH RECURSIVE reachable(node) AS (
+          SELECT 1
+          UNION {}
+          SELECT e.dst FROM edges e JOIN reachable r ON e.src = r.node
+        )
+        SELECT node FROM reachable ORDER BY node
+    """
+    with pl.SQLContext(edges=edges) as ctx:
+        # UNION discards rows that were already seen, so the cycle terminates
+        res = ctx.execute(query.format("DISTINCT"), eager=True)
+        assert res.to_series().to_list() == [1, 2, 3, 4]
+
+        # UNION ALL keeps following the cycle until the iteration limit is hit
+        with pytest.raises(
+            ComputeError,
+            match="recursive CTE 'reachable' did not complete within 1000 iterations",
+        ):
+            ctx.execute(query.format("ALL"), eager=True)
+
+    # the iteration limit can be set on the context
+    with pl.SQLContext(edges=edges, max_recursion_iterations=10) as ctx:
+        with pytest.raises(
+            ComputeError,
+            match="recursive CTE 'reachable' did not complete within 10 iterations",
+        ):
+            ctx.execute(query.format("ALL"), eager=True)
+
+        res = ctx.execute(query.format("DISTINCT"), eager=True)
+        assert res.to_series().to_list() == [1, 2, 3, 4]
+
+
+def test_recursive_cte_errors() -> None:
+    with pytest.raises(SQLSyntaxError, match="must be of the form"):
+        pl.sql("WITH RECURSIVE t(n) AS (SELECT n FROM t) SELECT * FROM t").collect()
+
+    with pytest.raises(SQLSyntaxError, match="equal number of columns"):
+        pl.sql(
+            """
+            WITH RECURSIVE t(n) AS (
+              SELECT 1 UNION ALL SELECT n + 1, n FROM t WHERE n < 3
+            )
+            SELECT * FROM t
+            """
+        ).collect()
+
+
 def test_invalid_derived_table_column_aliases() -> None:
     values_query = "SELECT * FROM (VALUES (1,2), (3,4))"
 
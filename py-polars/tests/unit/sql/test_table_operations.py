@@ -6,7 +6,7 @@ from datetime import date
 import pytest
 
 import polars as pl
-from polars.exceptions import SQLInterfaceError
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
 from polars.testing import assert_frame_equal
 
 
@@ -117,3 +117,85 @@ def test_truncate_table(truncate_sql: str, test_frame: pl.LazyFrame) -> None:
 
         res = ctx.execute("SELECT * FROM frame")
         assert_frame_equal(res, expected)
+
+
+def test_pivot() -> None:
+    df = pl.DataFrame(
+        {
+            "region": ["n", "n", "s", "n", "s"],
+            "quarter": ["Q1", "Q2", "Q1", "Q1", "Q3"],
+            "sales": [10, 20, 30, 40, 50],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT * FROM self
+        PIVOT (SUM(sales) FOR quarter IN ('Q1', 'Q2', 'Q4'))
+        ORDER BY region
+        """
+    )
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {
+                "region": ["n", "s"],
+                "Q1": [50, 30],
+                "Q2": [20, None],
+                "Q4": [None, None],
+            },
+            schema_overrides={"Q4": pl.Int64},
+        ),
+    )
+
+    # multiple (aliased) aggregates, aliased values, default on null, table alias
+    res = df.sql(
+        """
+        SELECT * FROM self
+        PIVOT (
+          SUM(sales) AS total, COUNT(sales) AS n
+          FOR quarter IN ('Q1' AS first, 'Q2' AS second)
+          DEFAULT ON NULL (0)
+        ) AS p
+        ORDER BY p.region
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "region": ["n", "s"],
+        "first_total": [50, 30],
+        "second_total": [20, 0],
+        "first_n": [2, 1],
+        "second_n": [1, 0],
+    }
+
+
+def test_pivot_errors() -> None:
+    df = pl.DataFrame({"k": ["a", "b"], "v": [1, 2]})
+    with pytest.raises(SQLInterfaceError, match="explicit list of values"):
+        df.sql("SELECT * FROM self PIVOT (SUM(v) FOR k IN (ANY))")
+    with pytest.raises(SQLSyntaxError, match="requires an alias for each"):
+        df.sql("SELECT * FROM self PIVOT (SUM(v), MAX(v) FOR k IN ('a'))")
+
+
+def test_unpivot() -> None:
+    df = pl.LazyFrame(
+        {
+            "region": ["n", "s"],
+            "q1": [10, 30],
+            "q2": [20, None],
+        }
+    )
+    with pl.SQLContext(sales=df) as ctx:
+        res = ctx.execute(
+            """
+            SELECT * FROM sales
+            UNPIVOT (amount FOR quarter IN (q1, q2)) AS u
+            WHERE u.amount >= 20
+            ORDER BY region, quarter
+            """
+        )
+        assert isinstance(res, pl.LazyFrame)
+        assert res.collect().to_dict(as_series=False) == {
+            "region": ["n", "s"],
+            "quarter": ["q2", "q1"],
+            "amount": [20, 30],
+        }
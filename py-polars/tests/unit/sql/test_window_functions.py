@@ -0,0 +1,203 @@
+from __future__ import annotations
+
+from datetime import date
+
+import pytest
+
+import polars as pl
+from polars.exceptions import SQLInterfaceError, SQLSyntaxError
+from polars.testing import assert_frame_equal
+
+
+@pytest.fixture
+def df() -> pl.DataFrame:
+    return pl.DataFrame(
+        {
+            "category": ["a", "b", "a", "a", "b", "a", "b"],
+            "key": [3, 1, 1, 2, 1, 2, 5],
+            "value": [10, 20, 30, None, 50, 60, 70],
+        }
+    )
+
+
+def test_ranking_functions(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          ROW_NUMBER() OVER (PARTITION BY category ORDER BY key DESC, value) AS rn,
+          RANK() OVER (PARTITION BY category ORDER BY key) AS rnk,
+          DENSE_RANK() OVER (ORDER BY key) AS dense_rnk,
+          NTILE(3) OVER (ORDER BY key, value) AS tile
+        FROM self
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "rn": [1, 2, 4, 3, 3, 2, 1],
+        "rnk": [4, 1, 1, 2, 1, 2, 3],
+        "dense_rnk": [3, 1, 1, 2, 1, 2, 4],
+        "tile": [3, 1, 1, 2, 1, 2, 3],
+    }
+
+
+def test_lag_lead(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          LAG(value) OVER (PARTITION BY category ORDER BY key, value) AS prev,
+          LEAD(value, 2, -1) OVER (ORDER BY key, value) AS next2
+        FROM self
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "prev": [None, None, None, 60, 20, 30, 50],
+        "next2": [-1, 50, 60, 70, None, 10, -1],
+    }
+
+
+def test_rows_frames(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          SUM(value) OVER (
+            ORDER BY key, value ROWS BETWEEN 1 PRECEDING AND CURRENT ROW
+          ) AS s,
+          COUNT(*) OVER (
+            ORDER BY key, value ROWS BETWEEN 1 PRECEDING AND 1 FOLLOWING
+          ) AS c,
+          MIN(value) OVER (
+            ORDER BY key, value ROWS BETWEEN 2 PRECEDING AND 1 PRECEDING
+          ) AS mn,
+          AVG(value) OVER (
+            ORDER BY key, value ROWS BETWEEN UNBOUNDED PRECEDING AND 1 FOLLOWING
+          ) AS a
+        FROM self
+        ORDER BY key, value
+        """
+    )
+    assert_frame_equal(
+        res,
+        pl.DataFrame(
+            {
+                "s": [20, 50, 80, 110, 60, 10, 80],
+                "c": [2, 3, 3, 3, 3, 3, 2],
+                "mn": [None, 20, 20, 30, 50, 60, 10],
+                "a": [25.0, 100 / 3, 40.0, 40.0, 34.0, 40.0, 40.0],
+            }
+        ),
+        check_dtypes=False,
+    )
+
+
+def test_range_frames(df: pl.DataFrame) -> None:
+    res = df.sql(
+        """
+        SELECT
+          COUNT(value) OVER (
+            ORDER BY key RANGE BETWEEN CURRENT ROW AND UNBOUNDED FOLLOWING
+          ) AS c,
+          SUM(value) OVER (ORDER BY key RANGE BETWEEN 1 PRECEDING AND CURRENT ROW) AS s,
+          FIRST_VALUE(value) OVER (PARTITION BY category ORDER BY key) AS first,
+          LAST_VALUE(value) OVER (PARTITION BY category ORDER BY key) AS last
+        FROM self
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "c": [2, 6, 6, 3, 6, 3, 1],
+        "s": [70, 100, 100, 160, 100, 160, 70],
+        "first": [30, 20, 30, 30, 20, 30, 20],
+        "last": [10, 50, 30, 60, 50, 60, 70],
+    }
+
+
+def test_range_frame_interval() -> None:
+    df = pl.DataFrame(
+        {
+            "dt": [date(2024, 1, 1), date(2024, 1, 2), date(2024, 1, 4)],
+            "value": [1, 2, 4],
+        }
+    )
+    res = df.sql(
+        """
+        SELECT
+          SUM(value) OVER (
+            ORDER BY dt RANGE BETWEEN INTERVAL '1 day' PRECEDING AND CURRENT ROW
+          ) AS s
+        FROM self
+        """
+    )
+    assert res.to_dict(as_series=False) == {"s": [1, 3, 4]}
+
+
+def test_qualify(df: pl.DataFrame) -> None:
+    # filter on a window function that is not part of the projection
+    res = df.sql(
+        """
+        SELECT category, key, value FROM self
+        QUALIFY ROW_NUMBER() OVER (PARTITION BY category ORDER BY key DESC, value) = 1
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "category": ["a", "b"],
+        "key": [3, 5],
+        "value": [10, 70],
+    }
+
+    # filter on a projected window function (by alias)
+    res = df.sql(
+        """
+        SELECT category, SUM(value) OVER (PARTITION BY category) AS total FROM self
+        QUALIFY total > 100
+        ORDER BY category
+        """
+    )
+    assert res.to_dict(as_series=False) == {
+        "category": ["b", "b", "b"],
+        "total": [140, 140, 140],
+    }
+
+    # filter on a window function evaluated over the aggregated result
+    res = df.sql(
+        """
+        SELECT category, COUNT(*) AS n FROM self
+        GROUP BY category
+        QUALIFY RANK() OVER (ORDER BY n DESC) = 1
+        """
+    )
+    assert res.to_dict(as_series=False) == {"category": ["a"], "n": [4]}
+
+
+@pytest.mark.parametrize(
+    ("query", "error_type", "error_msg"),
+    [
+        (
+            "SELECT ROW_NUMBER() FROM self",
+            SQLSyntaxError,
+            "ROW_NUMBER requires an OVER clause",
+        ),
+        (
+            "SELECT RANK() OVER (ORDER BY key ROWS 1 PRECEDING) FROM self",
+            SQLSyntaxError,
+            "RANK does not accept a window frame",
+        ),
+        (
+            "SELECT MEDIAN(value) OVER (ORDER BY key ROWS 1 PRECEDING) FROM self",
+            SQLInterfaceError,
+            "window frames are not supported for MEDIAN",
+        ),
+        (
+            "SELECT SUM(value) OVER (ORDER BY key GROUPS 1 PRECEDING) FROM self",
+            SQLInterfaceError,
+            "GROUPS window frames are not supported",
+        ),
+        (
+            "SELECT NTILE(0) OVER (ORDER BY key) FROM self",
+            SQLSyntaxError,
+            "NTILE expects a positive integer",
+        ),
+    ],
+)
+def test_window_function_errors(
+    df: pl.DataFrame, query: str, error_type: type[Exception], error_msg: str
+) -> None:
+    with pytest.raises(error_type, match=error_msg):
+        df.sql(query)
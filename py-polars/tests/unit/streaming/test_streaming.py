@@ -14,7 +14,7 @@ from polars.exceptions import PolarsInefficientMapWarning
 from polars.testing import assert_frame_equal
 
 if TYPE_CHECKING:
-    from polars._typing import JoinStrategy
+    from polars._typing import JoinStrategy, QuantileMethod, WindowMappingStrategy
 
 pytestmark = pytest.mark.xdist_group("streaming")
 
@@ -320,6 +320,20 @@ def test_custom_temp_dir(tmp_path: Path, monkeypatch: Any) -> None:
     assert os.listdir(tmp_path), f"Temp directory '{tmp_path}' is empty"
 
 
+@pytest.mark.write_disk
+def test_streaming_in_memory_sink_spill(tmp_path: Path, monkeypatch: Any) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    df = pl.DataFrame({"a": range(300_000), "b": [str(i) for i in range(300_000)]})
+    q = df.lazy().filter(pl.col("a") % 3 != 0)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+    q = q.map_batches(lambda df: df.reverse()).with_columns(c=pl.col("a") + 1)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
 @pytest.mark.write_disk
 def test_streaming_with_hconcat(tmp_path: Path) -> None:
     df1 = pl.DataFrame(
@@ -411,3 +425,120 @@ def test_streaming_flag_21799() -> None:
         pl.LazyFrame({"a": 1}).collect(streaming=False)  # type: ignore[call-overload]
     with pytest.raises(DeprecationWarning):
         pl.LazyFrame({"a": 1}).collect(streaming=True)  # type: ignore[call-overload]
+
+
+@pytest.mark.parametrize(
+    "method", ["nearest", "higher", "lower", "midpoint", "linear", "equiprobable"]
+)
+def test_streaming_quantile_median_implode(method: QuantileMethod) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [5, None, 1, 3, 8, 2, None, 7] * 50,
+            "b": pl.Series([0.5, 1.5, None, 2.5, -1.0, 4.0, 3.0, 0.0] * 50)
+            .cast(pl.Float32),
+            "c": pl.date_range(date(2024, 1, 1), date(2024, 1, 8), eager=True).to_list()
+            * 50,
+        }
+    )
+
+    q = df.lazy().select(
+        pl.col("a").quantile(0.3, method).alias("q_a"),
+        pl.col("b").quantile(0.75, method).alias("q_b"),
+        pl.col("a", "b", "c").median().name.suffix("_median"),
+        pl.col("a").implode(),
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_quantile_out_of_range() -> None:
+    q = pl.LazyFrame({"a": [1, 2, 3]}).select(pl.col("a").quantile(1.5))
+    with pytest.raises(
+        pl.exceptions.ComputeError, match="should be between 0.0 and 1.0"
+    ):
+        q.collect(engine="streaming")
+
+
+@pytest.mark.parametrize("mapping_strategy", ["group_to_rows", "join"])
+def test_streaming_window_over(mapping_strategy: WindowMappingStrategy) -> None:
+    df = pl.DataFrame(
+        {
+            "k": [3, 1, None, 2, 3, 1, None, 2, 3, 4] * 100,
+            "a": [5, None, 1, 3, 8, 2, None, 7, 4, 6] * 100,
+            "b": ["x", "y", "x", "x", "y", "z", "y", "x", "y", "z"] * 100,
+        }
+    )
+
+    q = df.lazy().with_columns(
+        pl.col("a").sum().over("k", mapping_strategy=mapping_strategy).alias("sum"),
+        pl.col("a")
+        .mean()
+        .over("k", pl.col("b"), mapping_strategy=mapping_strategy)
+        .alias("mean"),
+        pl.col("b").first().over("k", mapping_strategy=mapping_strategy).alias("first"),
+        pl.len().over("k", mapping_strategy=mapping_strategy).alias("len"),
+        (pl.col("a") * 2).over("k", mapping_strategy=mapping_strategy).alias("values"),
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_window_over_explode() -> None:
+    df = pl.DataFrame(
+        {
+            "k": [3, 1, None, 2, 3, 1, None, 2, 3, 4] * 100,
+            "a": range(1000),
+        }
+    )
+
+    for expr in [pl.col("a"), pl.col("a").max()]:
+        q = df.lazy().select(expr.over("k", mapping_strategy="explode"))
+        assert_frame_equal(
+            q.collect(engine="streaming"), q.collect(engine="in-memory")
+        )
+
+
+def test_streaming_stateful_scans() -> None:
+    df = pl.DataFrame(
+        {
+            "a": [5, None, 1, 3, None, None, 7, 2, None, 4] * 100,
+            "b": [None] * 300 + [1.5, None, -2.0, 0.5] * 150 + [None] * 100,
+            "c": [True, None, False, True, False] * 200,
+        }
+    )
+
+    q = df.lazy().with_columns(
+        pl.col("a").cum_sum().alias("cum_sum"),
+        pl.col("a").cum_prod().alias("cum_prod"),
+        pl.col("a", "c").cum_max().name.suffix("_cum_max"),
+        pl.col("b").cum_min().alias("cum_min"),
+        pl.col("b").cum_count().alias("cum_count"),
+        pl.col("a").shift(3).alias("shift"),
+        pl.col("a").shift(2, fill_value=-1).alias("shift_fill"),
+        pl.col("a").diff(2).alias("diff"),
+        pl.col("b").pct_change().alias("pct_change"),
+        pl.col("b").forward_fill().alias("forward_fill"),
+        pl.col("b").forward_fill(limit=1).alias("forward_fill_limit"),
+        pl.col("b").backward_fill().alias("backward_fill"),
+        pl.col("b").backward_fill(limit=2).alias("backward_fill_limit"),
+        (pl.col("a").cum_sum() - pl.col("a").shift(1)).alias("combined"),
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+def test_streaming_quantile_tdigest() -> None:
+    df = pl.DataFrame({"a": [5, None, 1, 3, 8, 2, None, 7]})
+    q = df.lazy().select(
+        pl.col("a").quantile(q, "tdigest").alias(str(q)) for q in (0.0, 0.3, 1.0)
+    )
+    # small inputs are kept exactly by the digest
+    expected = df.select(
+        pl.col("a").quantile(q, "linear").alias(str(q)) for q in (0.0, 0.3, 1.0)
+    )
+    assert_frame_equal(q.collect(engine="streaming"), expected)
+    assert_frame_equal(q.collect(engine="in-memory"), expected)
+
+    # rolling windows are bounded, so they are computed exactly
+    df = pl.DataFrame({"a": [1.0, 4.0, 2.0, None, 3.0]})
+    assert_frame_equal(
+        df.select(pl.col("a").rolling_quantile(0.3, "tdigest", window_size=2)),
+        df.select(pl.col("a").rolling_quantile(0.3, "linear", window_size=2)),
+    )
@@ -1,6 +1,6 @@
 from __future__ import annotations
 
-from datetime import date
+from datetime import date, datetime
 from typing import TYPE_CHECKING, Any
 
 import numpy as np
@@ -14,6 +14,8 @@ from tests.unit.conftest import INTEGER_DTYPES
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import ClosedInterval, QuantileMethod
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -292,6 +294,33 @@ def test_streaming_group_by_ooc_q3(
     assert_frame_equal(result, expected)
 
 
+@pytest.mark.write_disk
+def test_streaming_group_by_spill(tmp_path: Path, monkeypatch: Any) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    rng = np.random.default_rng(0)
+    lf = pl.LazyFrame(
+        {
+            "a": rng.integers(0, 50_000, 300_000),
+            "b": rng.integers(0, 3, 300_000).astype(str),
+            "c": range(300_000),
+        }
+    )
+    q = lf.group_by("a", "b").agg(
+        pl.col("c").first().alias("first"),
+        pl.col("c").last().alias("last"),
+        pl.col("c").sum().alias("sum"),
+        pl.len(),
+    )
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
+
+
 def test_streaming_group_by_struct_key() -> None:
     df = pl.DataFrame(
         {"A": [1, 2, 3, 2], "B": ["google", "ms", "apple", "ms"], "C": [2, 3, 4, 3]}
@@ -531,3 +560,174 @@ def test_streaming_group_by_all_null_21593() -> None:
 
     out = df.lazy().group_by(pl.all()).min().collect(engine="streaming")
     assert_frame_equal(df, out, check_row_order=False)
+
+
+@pytest.mark.parametrize("slice", [None, (0, 5), (3, 100)])
+def test_streaming_group_by_maintain_order(slice: tuple[int, int] | None) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [3, 1, 3, 2, None, 1, 2, 3, None, 4] * 100,
+            "b": ["x", "y", "x", "x", "y", "z", "y", "x", "y", "z"] * 100,
+            "c": range(1000),
+        }
+    )
+
+    q = (
+        df.lazy()
+        .group_by("a", "b", maintain_order=True)
+        .agg(pl.col("c").sum(), pl.col("c").first().alias("first"), pl.len())
+    )
+    if slice is not None:
+        q = q.slice(*slice)
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize(
+    ("every", "period", "offset"),
+    [("1h", "1h", "0h"), ("1h", "3h", "0h"), ("2h", "1h", "-30m")],
+)
+@pytest.mark.parametrize("closed", ["left", "right", "both", "none"])
+@pytest.mark.parametrize("group_by", [None, "k"])
+def test_streaming_group_by_dynamic(
+    every: str, period: str, offset: str, closed: ClosedInterval, group_by: str | None
+) -> None:
+    df = pl.DataFrame(
+        {
+            "t": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 3), "7m", eager=True
+            ),
+        }
+    ).with_columns(k=pl.int_range(pl.len()) % 3, v=pl.int_range(pl.len()))
+
+    q = (
+        df.lazy()
+        .group_by_dynamic(
+            "t",
+            every=every,
+            period=period,
+            offset=offset,
+            closed=closed,
+            include_boundaries=True,
+            group_by=group_by,
+        )
+        .agg(pl.col("v").sum(), pl.col("v").first().alias("first"), pl.len())
+    )
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize(("period", "offset"), [("3h", None), ("1h", "30m")])
+@pytest.mark.parametrize("closed", ["left", "right", "both", "none"])
+@pytest.mark.parametrize("group_by", [None, "k"])
+def test_streaming_rolling(
+    period: str, offset: str | None, closed: ClosedInterval, group_by: str | None
+) -> None:
+    df = pl.DataFrame(
+        {
+            "t": pl.datetime_range(
+                datetime(2024, 1, 1), datetime(2024, 1, 3), "7m", eager=True
+            ),
+        }
+    ).with_columns(k=pl.int_range(pl.len()) % 3, v=pl.int_range(pl.len()))
+
+    q = (
+        df.lazy()
+        .rolling("t", period=period, offset=offset, closed=closed, group_by=group_by)
+        .agg(pl.col("v").sum(), pl.col("v").first().alias("first"), pl.len())
+    )
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize(
+    "method", ["nearest", "higher", "lower", "midpoint", "linear", "equiprobable"]
+)
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_streaming_group_by_quantile_median_implode(
+    method: QuantileMethod, maintain_order: bool
+) -> None:
+    df = pl.DataFrame(
+        {
+            "k": [3, 1, 3, 2, None, 1, 2, 3, None, 4] * 100,
+            "a": [5, None, 1, 3, 8, 2, None, 7, 4, 6] * 100,
+            "b": ["x", "y", "x", "x", "y", "z", "y", "x", "y", "z"] * 100,
+        }
+    ).with_columns(c=pl.int_range(pl.len()))
+
+    q = (
+        df.lazy()
+        .group_by("k", maintain_order=maintain_order)
+        .agg(
+            pl.col("a").quantile(0.3, method).alias("q"),
+            pl.col("a", "c").median().name.suffix("_median"),
+            pl.col("b", "c").implode().name.suffix("_implode"),
+            pl.col("c").agg_groups().alias("groups"),
+            pl.col("a"),
+        )
+    )
+    if not maintain_order:
+        q = q.sort("k")
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("maintain_order", [False, True])
+def test_streaming_group_by_quantile_tdigest(maintain_order: bool) -> None:
+    n = 100_000
+    df = pl.DataFrame(
+        {
+            "k": pl.int_range(n, eager=True) % 3,
+            "a": (pl.int_range(n, eager=True) * 7919) % n,
+            "b": ((pl.int_range(n, eager=True) * 31) % 1000).cast(pl.Float32),
+        }
+    )
+
+    def quantiles(method: QuantileMethod) -> pl.LazyFrame:
+        q = (
+            df.lazy()
+            .group_by("k", maintain_order=maintain_order)
+            .agg(
+                pl.col("a").quantile(0.9, method),
+                pl.col("b").quantile(0.1, method),
+                pl.col("a").median().alias("a_median"),
+            )
+        )
+        return q if maintain_order else q.sort("k")
+
+    approx = quantiles("tdigest").collect(engine="streaming")
+    assert approx.schema == {
+        "k": pl.Int64,
+        "a": pl.Float64,
+        "b": pl.Float32,
+        "a_median": pl.Float64,
+    }
+    assert_frame_equal(
+        approx, quantiles("tdigest").collect(engine="in-memory"), rtol=1e-3
+    )
+    exact = quantiles("linear").collect(engine="in-memory")
+    assert_frame_equal(approx, exact, rtol=1e-2)
+
+    # Only the quantiles asking for the t-digest are approximated.
+    assert_frame_equal(approx.select("k", "a_median"), exact.select("k", "a_median"))
+    assert_frame_equal(quantiles("linear").collect(engine="streaming"), exact)
+
+
+@pytest.mark.parametrize("rolling", [False, True])
+def test_streaming_group_by_dynamic_key_order(rolling: bool) -> None:
+    # enough rows to emit windows over multiple flushes, with keys that are
+    # neither sorted nor in order of appearance
+    n = 300_000
+    df = pl.DataFrame(
+        {"t": pl.int_range(n, eager=True) // 3, "v": pl.int_range(n, eager=True)}
+    ).with_columns(
+        k=(pl.int_range(pl.len()) % 3).replace_strict([0, 1, 2], ["c", "a", "b"])
+    )
+
+    lf = df.lazy()
+    if rolling:
+        gb = lf.rolling("t", period="10i", group_by="k")
+    else:
+        gb = lf.group_by_dynamic("t", every="10i", group_by="k")
+    q = gb.agg(pl.col("v").sum(), pl.len())
+
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
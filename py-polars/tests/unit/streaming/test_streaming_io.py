@@ -128,11 +128,10 @@ def test_sink_csv_14494(tmp_path: Path) -> None:
     assert pl.read_csv(tmp_path / "sink.csv").columns == ["c"]
 
 
-@pytest.mark.parametrize(("value"), ["abc", ""])
-def test_sink_csv_exception_for_separator(value: str) -> None:
+def test_sink_csv_exception_for_separator() -> None:
     df = pl.LazyFrame({"dummy": ["abc"]})
-    with pytest.raises(ValueError, match="should be a single byte character, but is"):
-        df.sink_csv("path", separator=value)
+    with pytest.raises(ValueError, match="`separator` cannot be empty"):
+        df.sink_csv("path", separator="")
 
 
 @pytest.mark.parametrize(("value"), ["abc", ""])
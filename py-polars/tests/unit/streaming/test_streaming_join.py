@@ -1,7 +1,7 @@
 from __future__ import annotations
 
 from datetime import datetime
-from typing import TYPE_CHECKING, Literal
+from typing import TYPE_CHECKING, Any, Literal
 
 import numpy as np
 import pandas as pd
@@ -358,3 +358,161 @@ def test_invert_order_full_join_22295() -> None:
     lf.join(lf, on=["value", "value_at"], how="full", coalesce=True).collect(
         engine="streaming"
     )
+
+
+@pytest.mark.parametrize("n_right", [0, 3, 1_000])
+def test_streaming_join_where(n_right: int) -> None:
+    rng = np.random.default_rng(0)
+    left = pl.LazyFrame(
+        {
+            "id": range(500),
+            "a": rng.integers(0, 100, 500),
+            "b": rng.random(500),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "x": rng.integers(0, 100, n_right),
+            "b": rng.random(n_right),
+        }
+    )
+
+    for predicates in [
+        [pl.col("a") < pl.col("x")],
+        [pl.col("a") >= pl.col("x"), pl.col("b") < pl.col("b_right")],
+    ]:
+        q = left.join_where(right, *predicates)
+        assert_frame_equal(
+            q.collect(engine="streaming"),
+            q.collect(engine="in-memory"),
+            check_row_order=False,
+        )
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("by", [None, "g"])
+@pytest.mark.parametrize("tolerance", [None, 3])
+def test_streaming_join_asof(
+    strategy: Literal["backward", "forward", "nearest"],
+    by: str | None,
+    tolerance: int | None,
+) -> None:
+    rng = np.random.default_rng(0)
+    left = pl.LazyFrame(
+        {
+            "t": np.sort(rng.integers(0, 1_000, 2_000)),
+            "g": rng.integers(0, 5, 2_000),
+            "v": range(2_000),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "t": np.sort(rng.integers(0, 1_000, 500)),
+            "g": rng.integers(0, 5, 500),
+            "w": range(500),
+        }
+    )
+
+    q = left.join_asof(
+        right,
+        on="t",
+        by=by,
+        strategy=strategy,
+        tolerance=tolerance,
+        check_sortedness=by is None,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("strategy", ["forward", "nearest"])
+def test_streaming_join_asof_by_missing_group(
+    strategy: Literal["forward", "nearest"],
+) -> None:
+    # Group 1 only occurs at the start of the right side, its later left rows
+    # are only known to be unmatched once the right side is done.
+    left = pl.LazyFrame({"t": range(0, 20_000, 2), "g": [0, 1] * 5_000})
+    right = pl.LazyFrame(
+        {"t": range(10_000), "g": [1] * 10 + [0] * 9_990, "w": range(10_000)}
+    )
+
+    q = left.join_asof(right, on="t", by="g", strategy=strategy, tolerance=5)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+def test_streaming_join_asof_by_left_only_group(
+    strategy: Literal["backward", "forward", "nearest"],
+) -> None:
+    # Group 2 only exists on the left and group 1 only at the start of the
+    # right side, without a tolerance to bound the search.
+    left = pl.LazyFrame({"t": range(0, 30_000, 2), "g": [0, 1, 2] * 5_000})
+    right = pl.LazyFrame(
+        {"t": range(10_000), "g": [1] * 10 + [0] * 9_990, "w": range(10_000)}
+    )
+
+    q = left.join_asof(right, on="t", by="g", strategy=strategy)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.parametrize("strategy", ["backward", "forward", "nearest"])
+@pytest.mark.parametrize("tolerance", [None, 3])
+def test_streaming_join_asof_by_sorted_within_groups(
+    strategy: Literal["backward", "forward", "nearest"],
+    tolerance: int | None,
+) -> None:
+    # Both sides are sorted by (g, t), so the keys are only sorted within each
+    # group and restart for every group.
+    rng = np.random.default_rng(0)
+    left = pl.LazyFrame(
+        {
+            "g": np.repeat(np.arange(4), 2_000),
+            "t": np.concatenate(
+                [np.sort(rng.integers(0, 1_000, 2_000)) for _ in range(4)]
+            ),
+            "v": range(8_000),
+        }
+    )
+    right = pl.LazyFrame(
+        {
+            "g": np.repeat(np.arange(4), 500),
+            "t": np.concatenate(
+                [np.sort(rng.integers(0, 1_000, 500)) for _ in range(4)]
+            ),
+            "w": range(2_000),
+        }
+    )
+
+    q = left.join_asof(
+        right,
+        on="t",
+        by="g",
+        strategy=strategy,
+        tolerance=tolerance,
+        check_sortedness=False,
+    )
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("how", ["inner", "left", "right", "full", "semi", "anti"])
+@pytest.mark.parametrize("nulls_equal", [False, True])
+def test_streaming_join_spill(
+    how: JoinStrategy, nulls_equal: bool, tmp_path: Path, monkeypatch: Any
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    rng = np.random.default_rng(0)
+    left = pl.LazyFrame({"k": rng.integers(0, 100_000, 300_000), "v": range(300_000)})
+    right = pl.LazyFrame({"k": rng.integers(0, 100_000, 200_000), "w": range(200_000)})
+    # A few null keys, which only match each other with `nulls_equal`.
+    left = left.with_columns(k=pl.when(pl.col("v") % 50_000 != 0).then("k"))
+    right = right.with_columns(k=pl.when(pl.col("w") % 50_000 != 0).then("k"))
+
+    q = left.join(right, on="k", how=how, nulls_equal=nulls_equal)
+    assert_frame_equal(
+        q.collect(engine="streaming"),
+        q.collect(engine="in-memory"),
+        check_row_order=False,
+    )
@@ -1,5 +1,7 @@
 from __future__ import annotations
 
+import stat
+import sys
 from collections import Counter
 from datetime import datetime
 from typing import TYPE_CHECKING, Any
@@ -296,3 +298,99 @@ def test_sort_descending_nulls_last(descending: bool, nulls_last: bool) -> None:
         .collect(engine="streaming"),
         pl.DataFrame({"x": ref_x, "y": ref_y}),
     )
+
+
+@pytest.mark.write_disk
+@pytest.mark.parametrize("maintain_order", [False, True])
+@pytest.mark.parametrize("slice", [None, (0, 10), (1_000, 500)])
+def test_ooc_sort_multiple_columns_slice(
+    tmp_path: Path,
+    monkeypatch: Any,
+    maintain_order: bool,
+    slice: tuple[int, int] | None,
+) -> None:
+    tmp_path.mkdir(exist_ok=True)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    n = 20_000
+    rng = np.random.default_rng(0)
+    df = pl.DataFrame(
+        {
+            "a": rng.integers(0, 10, n),
+            "b": pl.Series(rng.integers(0, 100, n)).cast(pl.String),
+            "c": np.arange(n),
+        }
+    ).with_columns(pl.when(pl.col("c") % 7 == 0).then(None).otherwise("b").alias("b"))
+
+    q = df.lazy().sort(
+        "a",
+        pl.col("b"),
+        descending=[True, False],
+        nulls_last=[False, True],
+        maintain_order=maintain_order,
+    )
+    if slice is not None:
+        q = q.slice(*slice)
+
+    out = q.collect(engine="streaming")
+    expected = q.collect(engine="in-memory")
+    if maintain_order:
+        assert_frame_equal(out, expected)
+    else:
+        assert_frame_equal(out.drop("c"), expected.drop("c"))
+
+
+@pytest.mark.write_disk
+def test_ooc_sort_spills_to_temp_dir(
+    tmp_path: Path, monkeypatch: Any, capfd: Any
+) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+    monkeypatch.setenv("POLARS_VERBOSE", "1")
+
+    s = pl.arange(0, 100_000, eager=True).rename("idx")
+    out = s.shuffle(seed=0).to_frame().lazy().sort("idx").collect(engine="streaming")
+    assert_series_equal(out.to_series(), s)
+
+    err = capfd.readouterr().err
+    assert f"[sort]: spilling to {tmp_path / 'spill'}" in err
+    # the spill directory is removed once the sort is done
+    assert list((tmp_path / "spill").iterdir()) == []
+
+
+@pytest.mark.write_disk
+def test_ooc_sort_categorical(tmp_path: Path, monkeypatch: Any) -> None:
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(tmp_path))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    n = 100_000
+    rng = np.random.default_rng(0)
+    df = pl.DataFrame(
+        {
+            "key": rng.integers(0, 1_000, n),
+            "cat": pl.Series(rng.integers(0, 50, n)).cast(pl.String),
+        }
+    ).with_columns(pl.col("cat").cast(pl.Categorical))
+
+    q = df.lazy().sort("key", "cat", maintain_order=True)
+    out = q.collect(engine="streaming")
+    assert out.schema == df.schema
+    assert_frame_equal(out, q.collect(engine="in-memory"), categorical_as_str=True)
+
+
+@pytest.mark.write_disk
+@pytest.mark.skipif(sys.platform == "win32", reason="unix permissions only")
+def test_ooc_sort_secures_temp_dir(tmp_path: Path, monkeypatch: Any) -> None:
+    temp_dir = tmp_path / "polars"
+    temp_dir.mkdir(mode=0o777)
+    temp_dir.chmod(0o777)
+    monkeypatch.setenv("POLARS_TEMP_DIR", str(temp_dir))
+    monkeypatch.setenv("POLARS_FORCE_OOC", "1")
+
+    s = pl.arange(0, 100_000, eager=True).rename("idx")
+    out = s.shuffle(seed=0).to_frame().lazy().sort("idx").collect(engine="streaming")
+    assert_series_equal(out.to_series(), s)
+
+    # spilled data must only be accessible to the current user
+    assert stat.S_IMODE(temp_dir.stat().st_mode) == 0o700
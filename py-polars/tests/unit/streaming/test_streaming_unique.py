@@ -10,6 +10,8 @@ from polars.testing import assert_frame_equal
 if TYPE_CHECKING:
     from pathlib import Path
 
+    from polars._typing import UniqueKeepStrategy
+
 pytestmark = pytest.mark.xdist_group("streaming")
 
 
@@ -53,3 +55,22 @@ def test_streaming_unique(monkeypatch: Any, capfd: Any) -> None:
     assert_frame_equal(q.collect(engine="old-streaming"), q.collect(engine="in-memory"))  # type: ignore[call-overload]
     (_, err) = capfd.readouterr()
     assert "df -> re-project-sink -> sort_multiple" in err
+
+
+@pytest.mark.parametrize("keep", ["first", "last", "none"])
+@pytest.mark.parametrize("subset", [None, ["a"], ["a", "b"]])
+def test_streaming_unique_maintain_order(
+    keep: UniqueKeepStrategy, subset: list[str] | None
+) -> None:
+    df = pl.DataFrame(
+        {
+            "a": [2, 1, 2, 3, 1, 4, 2, 3] * 50,
+            "b": [1, 1, 1, 2, 2, 1, 1, 2] * 50,
+            "c": range(400),
+        }
+    )
+    if subset is None:
+        df = df.drop("c")
+
+    q = df.lazy().unique(subset, keep=keep, maintain_order=True)
+    assert_frame_equal(q.collect(engine="streaming"), q.collect(engine="in-memory"))